@@ -48,7 +48,7 @@ fn main() -> io::Result<()> {
 
     let names = read_names(names_src)?;
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     let header = reader.read_header()?;
 
     let stdout = io::stdout().lock();
@@ -25,7 +25,7 @@ fn build_self_program() -> Result<Map<Program>, BuildError> {
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     let mut header = reader.read_header()?;
 
     let pg = build_self_program()?;
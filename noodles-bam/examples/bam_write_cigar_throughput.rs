@@ -0,0 +1,86 @@
+//! Measures the BAM record encoding throughput for long CIGARs.
+//!
+//! This writes a number of records (default: 100000), each with a CIGAR of a given number of
+//! operations (default: 500), uncompressed to a sink and prints the number of records written
+//! per second. Most of the time is spent encoding the CIGAR operations.
+//!
+//! Run a release build, e.g., `cargo run --release --example bam_write_cigar_throughput 100000
+//! 500`.
+
+use std::{env, io, num::NonZeroUsize, time::Instant};
+
+use noodles_bam as bam;
+use noodles_core::Position;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        io::Write,
+        record::{
+            cigar::{op::Kind, Op},
+            Flags,
+        },
+        record_buf::{Cigar, Sequence},
+        RecordBuf,
+    },
+    header::record::value::{map::ReferenceSequence, Map},
+};
+
+fn build_record(op_count: usize) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+    // Alternate matches and insertions, so each operation consumes one read base.
+    let cigar: Cigar = (0..op_count)
+        .map(|i| {
+            let kind = if i % 2 == 0 {
+                Kind::Match
+            } else {
+                Kind::Insertion
+            };
+
+            Op::new(kind, 1)
+        })
+        .collect();
+
+    Ok(RecordBuf::builder()
+        .set_flags(Flags::empty())
+        .set_reference_sequence_id(0)
+        .set_alignment_start(Position::MIN)
+        .set_cigar(cigar)
+        .set_sequence(Sequence::from(vec![b'A'; op_count]))
+        .build())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let record_count = args
+        .next()
+        .map(|s| s.parse())
+        .transpose()?
+        .unwrap_or(100_000);
+
+    let op_count = args.next().map(|s| s.parse()).transpose()?.unwrap_or(500);
+
+    let header = sam::Header::builder()
+        .add_reference_sequence(
+            "sq0",
+            Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 20)?),
+        )
+        .build();
+
+    let record = build_record(op_count)?;
+
+    let mut writer = bam::io::Writer::from(io::sink());
+    writer.write_header(&header)?;
+
+    let start = Instant::now();
+
+    for _ in 0..record_count {
+        writer.write_alignment_record(&header, &record)?;
+    }
+
+    let elapsed = start.elapsed();
+    let throughput = record_count as f64 / elapsed.as_secs_f64();
+
+    println!("{op_count} ops: {elapsed:.2?} ({throughput:.0} records/s)");
+
+    Ok(())
+}
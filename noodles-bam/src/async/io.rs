@@ -1,6 +1,6 @@
 //! Async BAM I/O.
 
 mod reader;
-mod writer;
+pub mod writer;
 
 pub use self::{reader::Reader, writer::Writer};
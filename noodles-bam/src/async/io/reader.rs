@@ -7,11 +7,11 @@ use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
-use noodles_sam::{self as sam, alignment::RecordBuf};
+use noodles_sam::{self as sam, alignment::RecordBuf, header::resolve_region};
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
 use self::{header::read_header, query::query, record::read_record, record_buf::read_record_buf};
-use crate::{io::reader::resolve_region, Record, MAGIC_NUMBER};
+use crate::{Record, MAGIC_NUMBER};
 
 /// An async BAM reader.
 ///
@@ -308,6 +308,10 @@ where
 {
     /// Returns a stream over records that intersect the given region.
     ///
+    /// This seeks to each chunk from the index query and reads the records that start in the
+    /// chunk. Records in a chunk that do not intersect the region are skipped. The results are the
+    /// same as the sync reader's [`crate::io::Reader::query`].
+    ///
     /// # Examples
     ///
     /// ```no_run
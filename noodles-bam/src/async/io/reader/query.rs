@@ -59,18 +59,20 @@ where
                         None => State::Done,
                     };
                 }
-                State::Read(chunk_end) => match next_record(ctx.reader).await? {
-                    Some(record) => {
-                        if ctx.reader.get_ref().virtual_position() >= chunk_end {
-                            ctx.state = State::Seek;
-                        }
-
-                        if intersects(&record, ctx.reference_sequence_id, ctx.interval)? {
-                            return Ok(Some((record, ctx)));
+                State::Read(chunk_end) => {
+                    if ctx.reader.get_ref().virtual_position() < chunk_end {
+                        match next_record(ctx.reader).await? {
+                            Some(record) => {
+                                if intersects(&record, ctx.reference_sequence_id, ctx.interval)? {
+                                    return Ok(Some((record, ctx)));
+                                }
+                            }
+                            None => ctx.state = State::Done,
                         }
+                    } else {
+                        ctx.state = State::Seek;
                     }
-                    None => ctx.state = State::Seek,
-                },
+                }
                 State::Done => return Ok(None),
             }
         }
@@ -88,3 +90,132 @@ where
         _ => Some(record),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use futures::TryStreamExt;
+    use noodles_core::Position;
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{self, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(map::header::tag::SORT_ORDER, "coordinate")
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build())
+    }
+
+    fn build_records() -> Result<Vec<RecordBuf>, Box<dyn std::error::Error>> {
+        let build_record = |reference_sequence_id, alignment_start| {
+            RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_flags(Flags::default())
+                .set_alignment_start(alignment_start)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build()
+        };
+
+        Ok(vec![
+            build_record(0, Position::MIN),
+            build_record(1, Position::MIN),
+            build_record(1, Position::try_from(8)?),
+        ])
+    }
+
+    fn write(header: &sam::Header, records: &[RecordBuf]) -> io::Result<Vec<u8>> {
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        writer.into_inner().finish()
+    }
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let records = build_records()?;
+
+        let src = write(&header, &records)?;
+        let index = crate::indexer::index_records(&mut crate::io::Reader::new(&src[..]))?;
+
+        let region = "sq1:2-5".parse()?;
+
+        let mut sync_reader = crate::io::Reader::new(Cursor::new(src.clone()));
+        let expected: Vec<_> = sync_reader
+            .query(&header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+
+        let mut reader = Reader::new(Cursor::new(src));
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .try_collect()
+            .await?;
+
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = actual
+            .iter()
+            .map(|record| RecordBuf::try_from_alignment_record(&header, record))
+            .collect::<Result<_, _>>()?;
+
+        let mut expected = records[1].clone();
+        *expected.bin_mut() = Some(4681);
+
+        assert_eq!(actual, [expected]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_query_with_empty_chunk() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let records = build_records()?;
+
+        let src = write(&header, &records)?;
+
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header().await?;
+
+        let position = reader.get_ref().virtual_position();
+        let chunks = vec![Chunk::new(position, position)];
+        let interval = (Position::MIN..=Position::MIN).into();
+
+        let actual: Vec<_> = query(&mut reader, chunks, 0, interval)
+            .try_collect()
+            .await?;
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+}
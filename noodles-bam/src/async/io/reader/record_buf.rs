@@ -54,7 +54,7 @@ mod tests {
         let block_size = read_record_buf(&mut reader, &header, &mut buf, &mut record).await?;
 
         assert_eq!(block_size, 34);
-        assert_eq!(record, RecordBuf::default());
+        assert_eq!(record, RecordBuf::builder().set_bin(4680).build());
 
         Ok(())
     }
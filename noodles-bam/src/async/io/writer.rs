@@ -1,16 +1,21 @@
+//! Async BAM writer.
+
+mod builder;
 mod header;
 
 use noodles_bgzf as bgzf;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
+pub use self::builder::Builder;
 use self::header::write_header;
-use crate::Record;
+use crate::{record::codec::encoder, Record};
 
 /// An async BAM writer.
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    encode_options: encoder::Options,
 }
 
 impl<W> Writer<W>
@@ -153,7 +158,7 @@ where
         use crate::record::codec::encode;
 
         self.buf.clear();
-        encode(&mut self.buf, header, record)?;
+        encode(&mut self.buf, header, record, &self.encode_options)?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -189,6 +194,40 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            encode_options: encoder::Options::default(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sam::alignment::RecordBuf;
+
+    use super::*;
+    use crate::r#async::io::Reader;
+
+    #[tokio::test]
+    async fn test_write_alignment_record_with_preserve_bin() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        // The calculated bin of an unplaced record is 4680.
+        let record = RecordBuf::builder().set_bin(0).build();
+
+        for (preserve_bin, expected) in [(false, 4680), (true, 0)] {
+            let mut writer = Builder::default()
+                .set_preserve_bin(preserve_bin)
+                .build_from_writer(Vec::new());
+            writer.write_alignment_record(&header, &record).await?;
+            writer.shutdown().await?;
+
+            let src = writer.into_inner().into_inner();
+            let mut reader = Reader::new(&src[..]);
+            let mut actual = Record::default();
+            reader.read_record(&mut actual).await?;
+
+            assert_eq!(actual.bin(), expected);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use noodles_bgzf as bgzf;
+use tokio::io::AsyncWrite;
+
+use super::Writer;
+
+/// An async BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    preserve_bin: bool,
+}
+
+impl Builder {
+    /// Sets whether to preserve the raw bin of BAM records.
+    ///
+    /// See [`crate::io::writer::Builder::set_preserve_bin`].
+    ///
+    /// The default is `false`, i.e., the bin is always calculated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::r#async::io::writer::Builder::default().set_preserve_bin(true);
+    /// ```
+    pub fn set_preserve_bin(mut self, preserve_bin: bool) -> Self {
+        self.preserve_bin = preserve_bin;
+        self
+    }
+
+    /// Builds an async BAM writer from a writer.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use tokio::io;
+    /// let writer = bam::r#async::io::writer::Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<bgzf::AsyncWriter<W>>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut writer = Writer::new(writer);
+        writer.encode_options.preserve_bin = self.preserve_bin;
+        writer
+    }
+}
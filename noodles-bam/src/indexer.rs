@@ -0,0 +1,291 @@
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::binning_index::{index::reference_sequence::bin::Chunk, Indexer};
+use noodles_sam::{self as sam, alignment::Record as _};
+
+use super::{bai, io::Reader, Record};
+
+/// Indexes a BAM file.
+///
+/// The input BAM must be coordinate-sorted, i.e., its header sort order is `SO:coordinate`.
+/// Otherwise, this returns an error.
+///
+/// Records are classified as follows:
+///
+///   * mapped records are binned by their alignment interval;
+///   * unmapped records with a reference sequence ID and alignment start, e.g., an unmapped read
+///     placed next to its mapped mate, are also binned (by their alignment start) so that region
+///     queries return them alongside their mate. They are counted as unmapped in the reference
+///     sequence metadata; and
+///   * records missing a reference sequence ID or alignment start are counted as unplaced,
+///     unmapped records (`n_no_coor`).
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bam as bam;
+/// let index = bam::index("sample.bam")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index<P>(src: P) -> io::Result<bai::Index>
+where
+    P: AsRef<Path>,
+{
+    File::open(src).and_then(index_from_reader)
+}
+
+/// Indexes BAM data from a reader.
+///
+/// The reader is expected to be at the start of the (BGZF-compressed) stream. See [`index`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_csi::BinningIndex;
+/// use noodles_sam as sam;
+///
+/// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+///
+/// let mut writer = bam::io::Writer::new(Vec::new());
+/// writer.write_header(&header)?;
+/// let src = writer.into_inner().finish()?;
+///
+/// let index = bam::index_from_reader(&src[..])?;
+/// assert_eq!(index.unplaced_unmapped_record_count(), Some(0));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn index_from_reader<R>(reader: R) -> io::Result<bai::Index>
+where
+    R: Read,
+{
+    let mut reader = Reader::new(reader);
+    index_records(&mut reader)
+}
+
+pub(crate) fn index_records<R>(reader: &mut Reader<bgzf::Reader<R>>) -> io::Result<bai::Index>
+where
+    R: Read,
+{
+    let header = reader.read_header()?;
+
+    if !is_coordinate_sorted(&header) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the input BAM must be coordinate-sorted to be indexed",
+        ));
+    }
+
+    let mut indexer = Indexer::default();
+    let mut start_position = reader.get_ref().virtual_position();
+
+    let mut record = Record::default();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.get_ref().virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let alignment_context = alignment_context(&record)?;
+        indexer.add_record(alignment_context, chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(header.reference_sequences().len()))
+}
+
+fn is_coordinate_sorted(header: &sam::Header) -> bool {
+    use sam::header::record::value::map::header::{sort_order, tag};
+
+    header
+        .header()
+        .and_then(|hdr| hdr.other_fields().get(&tag::SORT_ORDER))
+        .map(|sort_order| sort_order == sort_order::COORDINATE)
+        .unwrap_or_default()
+}
+
+fn alignment_context(record: &Record) -> io::Result<Option<(usize, Position, Position, bool)>> {
+    match (
+        record.reference_sequence_id().transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ) {
+        (Some(id), Some(start), end) => {
+            // An unmapped record placed next to its mate spans its alignment start.
+            let end = end.unwrap_or(start);
+            let is_mapped = !record.flags().is_unmapped();
+            Ok(Some((id, start, end, is_mapped)))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_csi::{binning_index::ReferenceSequence as _, BinningIndex};
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{self, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(map::header::tag::SORT_ORDER, "coordinate")
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build())
+    }
+
+    fn index_from_records(header: &sam::Header, records: &[RecordBuf]) -> io::Result<bai::Index> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+        let mut reader = Reader::new(&src[..]);
+
+        index_records(&mut reader)
+    }
+
+    #[test]
+    fn test_index_records_with_unsorted_input() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        assert!(matches!(
+            index_from_records(&header, &[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_records_with_mapped_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = [RecordBuf::builder()
+            .set_flags(Flags::default())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build()];
+
+        let index = index_from_records(&header, &records)?;
+
+        let metadata = index.reference_sequences()[0].metadata();
+        assert_eq!(
+            metadata.map(|m| (m.mapped_record_count(), m.unmapped_record_count())),
+            Some((1, 0))
+        );
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_records_with_placed_unmapped_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::default())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .build(),
+        ];
+
+        let index = index_from_records(&header, &records)?;
+
+        let reference_sequence = &index.reference_sequences()[0];
+        let metadata = reference_sequence.metadata();
+        assert_eq!(
+            metadata.map(|m| (m.mapped_record_count(), m.unmapped_record_count())),
+            Some((1, 1))
+        );
+
+        let chunk_count: usize = reference_sequence
+            .bins()
+            .values()
+            .map(|bin| bin.chunks().len())
+            .sum();
+        assert_eq!(chunk_count, 1);
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(0));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_records_with_unplaced_unmapped_record() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = build_header()?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::default())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .build(),
+        ];
+
+        let index = index_from_records(&header, &records)?;
+
+        let metadata = index.reference_sequences()[0].metadata();
+        assert_eq!(
+            metadata.map(|m| (m.mapped_record_count(), m.unmapped_record_count())),
+            Some((1, 0))
+        );
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(2));
+
+        Ok(())
+    }
+}
@@ -10,14 +10,21 @@ mod records;
 
 use std::{
     ffi::CStr,
-    io::{self, Read},
+    io::{self, Read, Seek},
 };
 
 use bstr::BString;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
-use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        io::{RecordMeta, RecordObserver},
+        RecordBuf,
+    },
+    header::resolve_region,
+};
 
 pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
 use self::{record::read_record, record_buf::read_record_buf};
@@ -48,6 +55,7 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    record_observer: Option<RecordObserver>,
 }
 
 impl<R> Reader<R> {
@@ -156,7 +164,17 @@ where
         header: &sam::Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, header, &mut self.buf, record)
+        let block_size = read_record_buf(&mut self.inner, header, &mut self.buf, record)?;
+
+        if let Some(record_observer) = self.record_observer.as_ref().filter(|_| block_size > 0) {
+            record_observer.observe(&RecordMeta::new(
+                Some(block_size),
+                record.reference_sequence_id(),
+                !record.flags().is_unmapped(),
+            ));
+        }
+
+        Ok(block_size)
     }
 
     /// Reads a record.
@@ -194,6 +212,17 @@ where
 
         fields.index()?;
 
+        if let Some(record_observer) = &self.record_observer {
+            // A reference sequence ID that fails to decode is observed as missing.
+            let reference_sequence_id = record.reference_sequence_id().and_then(Result::ok);
+
+            record_observer.observe(&RecordMeta::new(
+                Some(block_size),
+                reference_sequence_id,
+                !record.flags().is_unmapped(),
+            ));
+        }
+
         Ok(block_size)
     }
 
@@ -267,6 +296,30 @@ where
     }
 }
 
+impl<R> Reader<bgzf::Reader<R>>
+where
+    R: Read + Seek,
+{
+    /// Seeks the stream to the given compressed offset.
+    ///
+    /// The offset must be at the start of a BGZF block. See
+    /// [`bgzf::Reader::seek_to_compressed_offset`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.seek_to_compressed_offset(0)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek_to_compressed_offset(&mut self, pos: u64) -> io::Result<bgzf::VirtualPosition> {
+        self.get_mut().seek_to_compressed_offset(pos)
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -327,6 +380,19 @@ where
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
+    /// This only returns unplaced, unmapped records, i.e., records without a reference sequence
+    /// ID. These are at the end of a coordinate-sorted file. Unmapped records placed next to their
+    /// mate are returned by [`Self::query`] instead.
+    ///
+    /// The reader is moved to the start of the unplaced, unmapped records, which is the maximum
+    /// end position of the reference sequences' records, as recorded in the index metadata. If
+    /// the index has no metadata, this falls back to the start of the last linear bin, or the
+    /// first record if the index has no reference sequences. Records before the first unplaced,
+    /// unmapped record are skipped.
+    ///
+    /// If the index records that there are no unplaced, unmapped records, this returns an empty
+    /// iterator without reading.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -352,16 +418,22 @@ where
     where
         I: BinningIndex,
     {
-        if let Some(pos) = index.last_first_record_start_position() {
-            self.get_mut().seek_to_virtual_position(pos)?;
+        let records = if index.unplaced_unmapped_record_count() == Some(0) {
+            None
         } else {
-            self.seek_to_first_record()?;
-        }
+            if let Some(pos) = index.unplaced_unmapped_record_start_position() {
+                self.get_mut().seek_to_virtual_position(pos)?;
+            } else {
+                self.seek_to_first_record()?;
+            }
+
+            Some(self.records())
+        };
 
-        Ok(self.records().filter(|result| {
+        Ok(records.into_iter().flatten().filter(|result| {
             result
                 .as_ref()
-                .map(|record| record.flags().is_unmapped())
+                .map(|record| record.reference_sequence_id().is_none())
                 .unwrap_or(true)
         }))
     }
@@ -372,6 +444,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            record_observer: None,
         }
     }
 }
@@ -402,18 +475,256 @@ pub(crate) fn bytes_with_nul_to_bstring(buf: &[u8]) -> io::Result<BString> {
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
-pub(crate) fn resolve_region(
-    reference_sequences: &ReferenceSequences,
-    region: &Region,
-) -> io::Result<usize> {
-    reference_sequences
-        .get_index_of(region.name())
-        .ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "region reference sequence does not exist in reference sequences: {region:?}"
-                ),
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+        },
+        header::record::value::{
+            map::{self, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+    use crate::{indexer::index_records, io::Writer};
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(map::header::tag::SORT_ORDER, "coordinate")
+                    .build()?,
             )
-        })
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build())
+    }
+
+    fn build_bam(header: &sam::Header, records: &[RecordBuf]) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        writer.into_inner().finish()
+    }
+
+    fn query_unmapped_names(src: &[u8]) -> io::Result<Vec<Vec<u8>>> {
+        let index = index_records(&mut Reader::new(src))?;
+
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header()?;
+
+        let names = reader
+            .query_unmapped(&index)?
+            .map(|result| {
+                result.map(|record| {
+                    record
+                        .name()
+                        .map(|name| name.as_bytes().to_vec())
+                        .unwrap_or_default()
+                })
+            })
+            .collect();
+
+        names
+    }
+
+    #[test]
+    fn test_query_with_unknown_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::region::ResolveError;
+
+        fn t(header: &sam::Header, region: &Region) {
+            let index = crate::bai::Index::default();
+            let mut reader = Reader::new(io::empty());
+
+            let e = match reader.query(header, &index, region) {
+                Ok(_) => panic!("expected error"),
+                Err(e) => e,
+            };
+
+            assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+            assert_eq!(
+                e.get_ref().and_then(|e| e.downcast_ref::<ResolveError>()),
+                Some(&ResolveError::UnknownReferenceSequence(
+                    region.name().into()
+                ))
+            );
+        }
+
+        let region = "sq2:8-13".parse()?;
+        t(&sam::Header::default(), &region);
+        t(&build_header()?, &region);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mapped = |name: &str, id| {
+            RecordBuf::builder()
+                .set_name(name.as_bytes().into())
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(id)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build()
+        };
+
+        let unplaced = |name: &str| {
+            RecordBuf::builder()
+                .set_name(name.as_bytes().into())
+                .set_flags(Flags::UNMAPPED)
+                .build()
+        };
+
+        let placed_unmapped = RecordBuf::builder()
+            .set_name(b"r2".as_slice().into())
+            .set_flags(Flags::UNMAPPED)
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .build();
+
+        let records = [
+            mapped("r0", 0),
+            mapped("r1", 1),
+            placed_unmapped,
+            unplaced("r3"),
+            unplaced("r4"),
+        ];
+
+        let src = build_bam(&header, &records)?;
+        assert_eq!(
+            query_unmapped_names(&src)?,
+            [b"r3".to_vec(), b"r4".to_vec()]
+        );
+
+        let src = build_bam(&header, &records[..3])?;
+        assert!(query_unmapped_names(&src)?.is_empty());
+
+        let src = build_bam(&header, &records[3..])?;
+        assert_eq!(
+            query_unmapped_names(&src)?,
+            [b"r3".to_vec(), b"r4".to_vec()]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_record_observer() -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::{Arc, Mutex};
+
+        use sam::alignment::io::RecordMeta;
+
+        let header = build_header()?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+        ];
+
+        let src = build_bam(&header, &records)?;
+
+        let metas = Arc::new(Mutex::new(Vec::new()));
+
+        let mut reader = {
+            let metas = Arc::clone(&metas);
+
+            Builder::default()
+                .set_record_observer(move |meta: &RecordMeta| metas.lock().unwrap().push(*meta))
+                .build_from_reader(&src[..])
+        };
+
+        reader.read_header()?;
+
+        for result in reader.records() {
+            result?;
+        }
+
+        let expected = [
+            RecordMeta::new(Some(38), Some(1), true),
+            RecordMeta::new(Some(34), None, false),
+        ];
+
+        assert_eq!(*metas.lock().unwrap(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_record_observer_and_invalid_reference_sequence_id(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::Write,
+            sync::{Arc, Mutex},
+        };
+
+        use sam::alignment::io::RecordMeta;
+
+        use crate::record::codec::{encode, encoder::Options};
+
+        let header = sam::Header::default();
+
+        let mut buf = Vec::new();
+        encode(
+            &mut buf,
+            &header,
+            &RecordBuf::default(),
+            &Options::default(),
+        )?;
+        buf[..4].copy_from_slice(&(-2i32).to_le_bytes()); // ref_id = -2
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let mut bgzf_writer = writer.into_inner();
+        bgzf_writer.write_all(&(buf.len() as u32).to_le_bytes())?;
+        bgzf_writer.write_all(&buf)?;
+        let src = bgzf_writer.finish()?;
+
+        let metas = Arc::new(Mutex::new(Vec::new()));
+
+        let mut reader = {
+            let metas = Arc::clone(&metas);
+
+            Builder::default()
+                .set_record_observer(move |meta: &RecordMeta| metas.lock().unwrap().push(*meta))
+                .build_from_reader(&src[..])
+        };
+
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, buf.len());
+        assert_eq!(
+            *metas.lock().unwrap(),
+            [RecordMeta::new(Some(buf.len()), None, false)]
+        );
+
+        Ok(())
+    }
 }
@@ -5,14 +5,57 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
+use noodles_sam::alignment::io::{RecordMeta, RecordObserver};
 
 use super::Reader;
 
 /// A BAM reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    record_observer: Option<RecordObserver>,
+}
 
 impl Builder {
+    /// Sets a record observer.
+    ///
+    /// The observer is called after each record is read, with the record block size, reference
+    /// sequence ID, and whether the record is mapped. A reference sequence ID that fails to decode
+    /// is given to the observer as missing.
+    ///
+    /// Only the synchronous reader calls the observer. The async reader does not support record
+    /// observers.
+    ///
+    /// By default, no observer is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use noodles_bam::io::reader::Builder;
+    /// use noodles_sam::alignment::io::RecordMeta;
+    ///
+    /// let record_count = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let builder = {
+    ///     let record_count = Arc::clone(&record_count);
+    ///
+    ///     Builder::default().set_record_observer(move |_: &RecordMeta| {
+    ///         record_count.fetch_add(1, Ordering::Relaxed);
+    ///     })
+    /// };
+    /// ```
+    pub fn set_record_observer<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RecordMeta) + Send + Sync + 'static,
+    {
+        self.record_observer = Some(RecordObserver::new(f));
+        self
+    }
+
     /// Builds a BAM reader from a path.
     ///
     /// # Examples
@@ -42,6 +85,8 @@ impl Builder {
     where
         R: Read,
     {
-        Reader::new(reader)
+        let mut reader = Reader::new(reader);
+        reader.record_observer = self.record_observer;
+        reader
     }
 }
@@ -67,7 +67,8 @@ pub(crate) fn intersects(
         record.alignment_start().transpose()?,
         record.alignment_end().transpose()?,
     ) {
-        (Some(id), Some(start), Some(end)) => {
+        (Some(id), Some(start), end) => {
+            let end = end.unwrap_or(start);
             let alignment_interval = (start..=end).into();
             Ok(id == reference_sequence_id && region_interval.intersects(alignment_interval))
         }
@@ -149,7 +150,8 @@ mod tests {
                 record.alignment_start().transpose()?,
                 record.alignment_end().transpose()?,
             ) {
-                (Some(id), Some(start), Some(end)) => {
+                (Some(id), Some(start), end) => {
+                    let end = end.unwrap_or(start);
                     let is_mapped = !record.flags().is_unmapped();
                     Some((id, start, end, is_mapped))
                 }
@@ -213,9 +215,10 @@ mod tests {
             })
             .collect::<Result<_, _>>()?;
 
-        let expected = [records[1].clone()];
+        let mut expected = records[1].clone();
+        *expected.bin_mut() = Some(4681);
 
-        assert_eq!(actual, expected);
+        assert_eq!(actual, [expected]);
 
         Ok(())
     }
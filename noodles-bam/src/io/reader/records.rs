@@ -21,6 +21,35 @@ where
             record: Record::default(),
         }
     }
+
+    /// Reads the next record and returns a reference to it.
+    ///
+    /// Unlike [`Iterator::next`], this reuses the iterator's record buffer and does not clone
+    /// the record. The returned record is only valid until the next call. This returns `None`
+    /// when the stream reaches EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// while let Some(record) = records.read_next()? {
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_next(&mut self) -> io::Result<Option<&Record>> {
+        match self.reader.read_record(&mut self.record)? {
+            0 => Ok(None),
+            _ => Ok(Some(&self.record)),
+        }
+    }
 }
 
 impl<'a, R> Iterator for Records<'a, R>
@@ -37,3 +66,44 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write, RecordBuf},
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_read_next() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for name in [b"r0", b"r1"] {
+            let record = RecordBuf::builder().set_name(name.into()).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let mut records = reader.records();
+
+        let mut names = Vec::new();
+
+        while let Some(record) = records.read_next()? {
+            names.push(record.name().map(|name| name.as_bytes().to_vec()));
+        }
+
+        assert_eq!(names, [Some(b"r0".to_vec()), Some(b"r1".to_vec())]);
+
+        Ok(())
+    }
+}
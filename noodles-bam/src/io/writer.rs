@@ -10,7 +10,7 @@ use noodles_bgzf as bgzf;
 use noodles_sam::{self as sam, alignment::io::Write as _};
 
 pub use self::builder::Builder;
-use crate::Record;
+use crate::{record::codec::encoder, Record};
 
 /// A BAM writer.
 ///
@@ -33,6 +33,9 @@ use crate::Record;
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    records_written: u64,
+    encode_options: encoder::Options,
+    ensure_header_line: bool,
 }
 
 impl<W> Writer<W>
@@ -78,11 +81,68 @@ where
         self.inner
     }
 
+    /// Returns the number of records written.
+    ///
+    /// This is reset to 0 when the writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::io::Writer::new(io::sink());
+    /// assert_eq!(writer.records_written(), 0);
+    ///
+    /// let header = sam::Header::default();
+    /// let record = bam::Record::default();
+    /// writer.write_record(&header, &record)?;
+    /// assert_eq!(writer.records_written(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records_written(&self) -> u64 {
+        self.records_written
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// For a BGZF writer, any buffered data is written as a complete block, so a reader can read
+    /// all records written up to this point, e.g., when writing to a pipe. Records are otherwise
+    /// written in blocks of up to 64 KiB (uncompressed). Each flush with buffered data ends a
+    /// block early, which reduces the compression ratio.
+    ///
+    /// This does not write the BGZF EOF marker. Use [`Self::try_finish`] to finish the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = bam::io::Writer::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    ///
+    /// writer.flush()?;
+    /// assert!(!writer.get_ref().get_ref().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
     /// Writes a SAM header.
     ///
     /// This writes the BAM magic number, the raw SAM header, and a copy of the reference sequence
     /// dictionary as binary reference sequences.
     ///
+    /// By default, if the header does not have a header line (`@HD`), a default header line
+    /// (`@HD\tVN:1.6`) is added to the raw SAM header. See [`Builder::set_ensure_header_line`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -98,7 +158,7 @@ where
     /// ```
     pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::header::write_header;
-        write_header(&mut self.inner, header)
+        write_header(&mut self.inner, header, self.ensure_header_line)
     }
 
     /// Writes a BAM record.
@@ -158,6 +218,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn try_finish(&mut self) -> io::Result<()> {
+        self.records_written = 0;
         self.inner.try_finish()
     }
 }
@@ -167,6 +228,9 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            records_written: 0,
+            encode_options: encoder::Options::default(),
+            ensure_header_line: true,
         }
     }
 }
@@ -187,7 +251,7 @@ where
         use crate::record::codec::encode;
 
         self.buf.clear();
-        encode(&mut self.buf, header, record)?;
+        encode(&mut self.buf, header, record, &self.encode_options)?;
 
         let block_size = u32::try_from(self.buf.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -195,10 +259,13 @@ where
 
         self.inner.write_all(&self.buf)?;
 
+        self.records_written += 1;
+
         Ok(())
     }
 
     fn finish(&mut self, _: &sam::Header) -> io::Result<()> {
+        self.records_written = 0;
         Ok(())
     }
 }
@@ -244,6 +311,214 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_alignment_record_with_long_cigar() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Read, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::record::cigar::{op::Kind, Op},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        const OP_COUNT: usize = 70000;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(131072)?),
+            )
+            .build();
+
+        let cigar: sam::alignment::record_buf::Cigar = (0..OP_COUNT)
+            .map(|i| {
+                if i % 2 == 0 {
+                    Op::new(Kind::Match, 1)
+                } else {
+                    Op::new(Kind::Deletion, 1)
+                }
+            })
+            .collect();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(cigar.clone())
+            .set_sequence(Sequence::from(vec![b'A'; OP_COUNT / 2]))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish()?;
+
+        let mut buf = Vec::new();
+        bgzf::Reader::new(writer.get_ref().get_ref().as_slice()).read_to_end(&mut buf)?;
+
+        // block_size (4) + n_cigar_op offset (12)
+        assert_eq!(buf[16..18], [0x02, 0x00]); // n_cigar_op = 2
+
+        // block_size (4) + read_name offset (32) + read_name = "*\x00" (2)
+        assert_eq!(
+            buf[38..46],
+            [
+                0x84, 0x8b, 0x08, 0x00, // cigar[0] = 35000S
+                0x03, 0x17, 0x11, 0x00, // cigar[1] = 70000N
+            ]
+        );
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+
+        assert_eq!(actual.cigar(), &cigar);
+        assert!(actual
+            .data()
+            .get(&sam::alignment::record::data::field::Tag::CIGAR)
+            .is_none());
+
+        let mut writer = Builder::default()
+            .set_move_long_cigar_to_data(false)
+            .build_from_writer(Vec::new());
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_missing_name() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Read;
+
+        use sam::alignment::io::Write;
+
+        fn round_trip(src: &[u8]) -> Result<(Vec<u8>, Vec<u8>), Box<dyn std::error::Error>> {
+            let header = sam::Header::default();
+
+            let mut reader = sam::io::Reader::new(src);
+            let mut record = sam::Record::default();
+            reader.read_record(&mut record)?;
+
+            let mut writer = Writer::new(Vec::new());
+            writer.write_alignment_record(&header, &record)?;
+            writer.try_finish()?;
+
+            let mut raw_record = Vec::new();
+            bgzf::Reader::new(writer.get_ref().get_ref().as_slice())
+                .read_to_end(&mut raw_record)?;
+
+            let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+            let mut record = crate::Record::default();
+            reader.read_record(&mut record)?;
+
+            let mut writer = sam::io::Writer::new(Vec::new());
+            writer.write_alignment_record(&header, &record)?;
+
+            Ok((raw_record, writer.into_inner()))
+        }
+
+        const SRC: &[u8] = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+        let (raw_record, dst) = round_trip(SRC)?;
+        // l_read_name is at 12 (block_size (4) + 8), and read_name is at 36 (block_size (4) + 32).
+        assert_eq!(raw_record[12], 2); // l_read_name = 2
+        assert_eq!(raw_record[36..38], [b'*', 0x00]); // read_name = "*\x00"
+        assert_eq!(dst, SRC);
+
+        const NAMED_SRC: &[u8] = b"r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+        let (raw_record, dst) = round_trip(NAMED_SRC)?;
+        assert_eq!(raw_record[12], 3); // l_read_name = 3
+        assert_eq!(raw_record[36..39], [b'r', b'0', 0x00]); // read_name = "r0\x00"
+        assert_eq!(dst, NAMED_SRC);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_borrowed_fields() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                record::{
+                    cigar::{op::Kind, Op},
+                    data::field::{Tag, Value},
+                    Fields, MappingQuality,
+                },
+                record_buf::data::field::{value::Array as ArrayBuf, Value as ValueBuf},
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let cigar = [Op::new(Kind::Match, 4)];
+        let comment = ValueBuf::from("ndls");
+        let base_modification_probabilities = ValueBuf::Array(ArrayBuf::UInt8(vec![0, 1]));
+
+        let data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)),
+            (Tag::COMMENT, Value::from(&comment)),
+            (
+                Tag::BASE_MODIFICATION_PROBABILITIES,
+                Value::from(&base_modification_probabilities),
+            ),
+        ];
+
+        let fields = Fields::builder()
+            .set_name(b"r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar(&cigar)
+            .set_sequence(b"ACGT")
+            .set_quality_scores(&[45, 35, 43, 50])
+            .set_data(&data)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &fields)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+
+        let mut expected = RecordBuf::try_from_alignment_record(&header, &fields)?;
+        *expected.bin_mut() = Some(4681);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_written() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        assert_eq!(writer.records_written(), 0);
+
+        let header = sam::Header::default();
+        let record = RecordBuf::default();
+        writer.write_alignment_record(&header, &record)?;
+        writer.write_alignment_record(&header, &record)?;
+        assert_eq!(writer.records_written(), 2);
+
+        writer.try_finish()?;
+        assert_eq!(writer.records_written(), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record_with_sequence_length_less_than_quality_scores_length(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -388,4 +663,50 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_preserve_bin() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let write = |preserve_bin: bool, record: &dyn sam::alignment::Record| -> io::Result<_> {
+            let mut writer = Builder::default()
+                .set_preserve_bin(preserve_bin)
+                .build_from_writer(Vec::new());
+            writer.write_alignment_record(&header, record)?;
+            writer.into_inner().finish()
+        };
+
+        let read_record = |src: &[u8]| -> io::Result<Record> {
+            let mut reader = Reader::new(src);
+            let mut record = Record::default();
+            reader.read_record(&mut record)?;
+            Ok(record)
+        };
+
+        // The calculated bin of an unplaced record is 4680.
+        let record_buf = RecordBuf::builder().set_bin(0).build();
+
+        let src = write(false, &record_buf)?;
+        assert_eq!(read_record(&src)?.bin(), 4680);
+
+        let src = write(true, &record_buf)?;
+        let record = read_record(&src)?;
+        assert_eq!(record.bin(), 0);
+
+        let mut reader = Reader::new(&src[..]);
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual.bin(), Some(0));
+
+        let mut writer = Builder::default()
+            .set_preserve_bin(true)
+            .build_from_writer(Vec::new());
+        writer.write_record(&header, &record)?;
+        assert_eq!(writer.into_inner().finish()?, src);
+
+        let src = write(true, &RecordBuf::default())?;
+        assert_eq!(read_record(&src)?.bin(), 4680);
+
+        Ok(())
+    }
 }
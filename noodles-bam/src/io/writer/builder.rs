@@ -1,14 +1,89 @@
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
 
 use noodles_bgzf as bgzf;
 
 use super::Writer;
 
 /// A BAM writer builder.
-#[derive(Debug, Default)]
-pub struct Builder;
+#[derive(Debug)]
+pub struct Builder {
+    move_long_cigar_to_data: bool,
+    ensure_header_line: bool,
+    preserve_bin: bool,
+}
 
 impl Builder {
+    /// Sets whether to move long CIGARs to the data.
+    ///
+    /// The BAM `n_cigar_op` field can only hold up to 65535 operations. When this is set, a record
+    /// with a longer CIGAR has its CIGAR written to the `CG` data field, and the record CIGAR is
+    /// written as a placeholder `<k>S<m>N`, where `k` is the read length and `m` is the alignment
+    /// span of the CIGAR. Readers, including this crate and htslib, restore the original CIGAR.
+    ///
+    /// When this is unset, writing such a record returns an [`io::ErrorKind::InvalidInput`]
+    /// error.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::io::writer::Builder::default().set_move_long_cigar_to_data(false);
+    /// ```
+    pub fn set_move_long_cigar_to_data(mut self, move_long_cigar_to_data: bool) -> Self {
+        self.move_long_cigar_to_data = move_long_cigar_to_data;
+        self
+    }
+
+    /// Sets whether to ensure the header has a header line (`@HD`).
+    ///
+    /// When this is set and a header without a header line is written, a default header line with
+    /// the current SAM format version (`@HD\tVN:1.6`) is added to the SAM header text. A header
+    /// line that is set is always written as is.
+    ///
+    /// The default is `true`, as htslib expects a header line in BAM files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::io::writer::Builder::default().set_ensure_header_line(false);
+    /// ```
+    pub fn set_ensure_header_line(mut self, ensure_header_line: bool) -> Self {
+        self.ensure_header_line = ensure_header_line;
+        self
+    }
+
+    /// Sets whether to preserve the raw bin of BAM records.
+    ///
+    /// The bin (`bin`) is a legacy field for the BAI binning scheme, which some tools leave unset
+    /// or calculate differently. When this is set, the raw bin of a record read from BAM, either a
+    /// [`crate::Record`] or a [`noodles_sam::alignment::RecordBuf`], is written as is, e.g., for
+    /// byte-exact round trips. Records that do not have a raw bin, e.g., those read from SAM,
+    /// always have it calculated from the alignment span.
+    ///
+    /// The raw bin is not updated when a record is changed. If the alignment start or CIGAR of a
+    /// record is edited after it is read, its raw bin is stale and should be cleared (e.g.,
+    /// `*record.bin_mut() = None`) so that the bin is recalculated.
+    ///
+    /// The default is `false`, i.e., the bin is always calculated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::io::writer::Builder::default().set_preserve_bin(true);
+    /// ```
+    pub fn set_preserve_bin(mut self, preserve_bin: bool) -> Self {
+        self.preserve_bin = preserve_bin;
+        self
+    }
+
     /// Builds a BAM writer from a path.
     ///
     /// # Examples
@@ -22,6 +97,38 @@ impl Builder {
     where
         P: AsRef<Path>,
     {
-        File::create(dst).map(Writer::new)
+        File::create(dst).map(|file| self.build_from_writer(file))
+    }
+
+    /// Builds a BAM writer from a writer.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// let writer = bam::io::writer::Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<bgzf::Writer<W>>
+    where
+        W: Write,
+    {
+        let mut writer = Writer::new(writer);
+        writer.encode_options.move_long_cigar_to_data = self.move_long_cigar_to_data;
+        writer.ensure_header_line = self.ensure_header_line;
+        writer.encode_options.preserve_bin = self.preserve_bin;
+        writer
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            move_long_cigar_to_data: true,
+            ensure_header_line: true,
+            preserve_bin: false,
+        }
     }
 }
@@ -7,16 +7,24 @@ use std::{
 use byteorder::{LittleEndian, WriteBytesExt};
 use noodles_sam::{self as sam, header::ReferenceSequences};
 
-pub(super) fn write_header<W>(writer: &mut W, header: &sam::Header) -> io::Result<()>
+pub(super) fn write_header<W>(
+    writer: &mut W,
+    header: &sam::Header,
+    ensure_header_line: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
-    write_raw_header(writer, header)?;
+    write_raw_header(writer, header, ensure_header_line)?;
     write_reference_sequences(writer, header.reference_sequences())?;
     Ok(())
 }
 
-fn write_raw_header<W>(writer: &mut W, header: &sam::Header) -> io::Result<()>
+fn write_raw_header<W>(
+    writer: &mut W,
+    header: &sam::Header,
+    ensure_header_line: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -24,7 +32,7 @@ where
 
     writer.write_all(MAGIC_NUMBER)?;
 
-    let text = serialize_header(header)?;
+    let text = serialize_header(header, ensure_header_line)?;
     let l_text =
         i32::try_from(text.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     writer.write_i32::<LittleEndian>(l_text)?;
@@ -34,10 +42,18 @@ where
     Ok(())
 }
 
-fn serialize_header(header: &sam::Header) -> io::Result<Vec<u8>> {
-    let mut writer = sam::io::Writer::new(Vec::new());
+fn serialize_header(header: &sam::Header, ensure_header_line: bool) -> io::Result<Vec<u8>> {
+    let mut text = Vec::new();
+
+    let mut writer = sam::io::writer::Builder::default()
+        .set_ensure_header_line(ensure_header_line)
+        .build_from_writer(&mut text);
+
     writer.write_header(header)?;
-    Ok(writer.into_inner())
+    writer.flush()?;
+    drop(writer);
+
+    Ok(text)
 }
 
 pub fn write_reference_sequences<W>(
@@ -105,7 +121,7 @@ mod tests {
             .build();
 
         let mut buf = Vec::new();
-        write_raw_header(&mut buf, &header)?;
+        write_raw_header(&mut buf, &header, false)?;
 
         let mut expected = vec![
             b'B', b'A', b'M', 0x01, // magic
@@ -118,6 +134,40 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_ensure_header_line() -> io::Result<()> {
+        use sam::header::record::value::{map, Map};
+
+        fn t(
+            header: &sam::Header,
+            ensure_header_line: bool,
+            expected_text: &[u8],
+        ) -> io::Result<()> {
+            let mut buf = Vec::new();
+            write_header(&mut buf, header, ensure_header_line)?;
+
+            let mut expected = b"BAM\x01".to_vec();
+            expected.extend((expected_text.len() as i32).to_le_bytes());
+            expected.extend(expected_text);
+            expected.extend([0x00, 0x00, 0x00, 0x00]); // n_ref = 0
+
+            assert_eq!(buf, expected);
+
+            Ok(())
+        }
+
+        let header = sam::Header::builder().add_comment("noodles-bam").build();
+        t(&header, false, b"@CO\tnoodles-bam\n")?;
+        t(&header, true, b"@HD\tVN:1.6\n@CO\tnoodles-bam\n")?;
+
+        let header = sam::Header::builder()
+            .set_header(Map::<map::Header>::new(map::header::Version::new(1, 5)))
+            .build();
+        t(&header, true, b"@HD\tVN:1.5\n")?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_reference_sequences() -> io::Result<()> {
         use sam::header::record::value::{map::ReferenceSequence, Map};
@@ -48,10 +48,14 @@
 pub mod r#async;
 
 pub mod bai;
+mod indexer;
 pub mod io;
 pub mod record;
 
-pub use self::record::Record;
+pub use self::{
+    indexer::{index, index_from_reader},
+    record::Record,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::io::{Reader as AsyncReader, Writer as AsyncWriter};
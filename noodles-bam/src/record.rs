@@ -71,6 +71,24 @@ impl Record {
         self.0.mapping_quality().and_then(MappingQuality::new)
     }
 
+    /// Returns the raw bin.
+    ///
+    /// This is the legacy BAI bin (`bin`) as stored in the record. It is not validated and may
+    /// differ from the bin calculated from the alignment span, e.g., when written by a tool that
+    /// sets it to 0. Writers recalculate it unless bin preservation is enabled (see
+    /// [`crate::io::writer::Builder::set_preserve_bin`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let record = bam::Record::default();
+    /// assert_eq!(record.bin(), 4680);
+    /// ```
+    pub fn bin(&self) -> u16 {
+        self.0.bin()
+    }
+
     /// Returns the flags.
     ///
     /// # Examples
@@ -141,6 +159,10 @@ impl Record {
 
     /// Returns the CIGAR operations.
     ///
+    /// If the CIGAR field is the placeholder `kSmN`, where `k` is the sequence length, and the
+    /// data has a `CG` field, the CIGAR operations in the `CG` field are returned instead. Other
+    /// CIGARs, including other soft clip-skip CIGARs, are returned as is.
+    ///
     /// # Examples
     ///
     /// ```
@@ -269,6 +291,10 @@ impl sam::alignment::Record for Record {
     fn data(&self) -> Box<dyn sam::alignment::record::Data + '_> {
         Box::new(self.data())
     }
+
+    fn bin(&self) -> Option<io::Result<u16>> {
+        Some(Ok(self.bin()))
+    }
 }
 
 fn try_to_reference_sequence_id(n: i32) -> io::Result<usize> {
@@ -303,7 +329,7 @@ mod tests {
             header::record::value::{map::ReferenceSequence, Map},
         };
 
-        use crate::record::codec::encode;
+        use crate::record::codec::{encode, encoder::Options};
 
         const BASE_COUNT: usize = 65536;
 
@@ -334,11 +360,94 @@ mod tests {
             )
             .build();
 
-        encode(&mut buf, &header, &record)?;
+        encode(&mut buf, &header, &record, &Options::default())?;
 
         let record = Fields::try_from(buf).map(Record)?;
         assert_eq!(record.cigar().len(), BASE_COUNT);
 
         Ok(())
     }
+
+    #[test]
+    fn test_alignment_end() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_sam::{
+            alignment::{
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::record::codec::{encode, encoder::Options};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .build();
+
+        let t = |flags: Flags, ops: &[Op]| -> io::Result<Option<Position>> {
+            let read_length = ops
+                .iter()
+                .filter(|op| op.kind().consumes_read())
+                .map(|op| op.len())
+                .sum();
+
+            let record = RecordBuf::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(8).unwrap())
+                .set_cigar(ops.iter().copied().collect())
+                .set_sequence(vec![b'A'; read_length].into())
+                .build();
+
+            let mut buf = Vec::new();
+            encode(&mut buf, &header, &record, &Options::default())?;
+            let record = Fields::try_from(buf).map(Record)?;
+
+            let actual = sam::alignment::Record::alignment_end(&record).transpose()?;
+
+            let record_buf = RecordBuf::try_from_alignment_record(&header, &record)?;
+            assert_eq!(actual, record_buf.alignment_end());
+
+            Ok(actual)
+        };
+
+        assert_eq!(
+            t(Flags::empty(), &[Op::new(Kind::Match, 4)])?,
+            Position::new(11)
+        );
+
+        assert_eq!(
+            t(
+                Flags::empty(),
+                &[
+                    Op::new(Kind::SoftClip, 2),
+                    Op::new(Kind::Match, 3),
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Insertion, 1),
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Skip, 100),
+                    Op::new(Kind::SequenceMatch, 4),
+                    Op::new(Kind::HardClip, 5),
+                ]
+            )?,
+            Position::new(118)
+        );
+
+        assert_eq!(
+            t(Flags::empty(), &[Op::new(Kind::SoftClip, 4)])?,
+            Position::new(8)
+        );
+
+        assert!(t(Flags::UNMAPPED, &[])?.is_none());
+
+        Ok(())
+    }
 }
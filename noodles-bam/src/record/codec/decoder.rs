@@ -16,7 +16,7 @@ pub(crate) use self::{
     reference_sequence_id::get_reference_sequence_id, sequence::get_sequence,
 };
 
-use std::{error, fmt, mem};
+use std::{error, fmt};
 
 use bytes::Buf;
 use noodles_sam::{self as sam, alignment::RecordBuf};
@@ -116,8 +116,7 @@ where
     *record.mapping_quality_mut() =
         get_mapping_quality(src).map_err(DecodeError::InvalidMappingQuality)?;
 
-    // Discard bin.
-    src.advance(mem::size_of::<u16>());
+    *record.bin_mut() = Some(src.get_u16_le());
 
     let n_cigar_op = cigar::get_op_count(src).map_err(DecodeError::InvalidCigar)?;
 
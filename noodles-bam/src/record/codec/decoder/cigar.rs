@@ -76,29 +76,48 @@ where
     Ok(())
 }
 
+/// Returns whether the CIGAR operations are the placeholder of a CIGAR stored in the data (`CG`).
+///
+/// A CIGAR with more than 65535 operations cannot be stored in the CIGAR field. Instead, the
+/// operations are stored in the `CG` data field, and the CIGAR field is set to the placeholder
+/// `kSmN`, where `k` is the read length (`l_seq`) and `m` is the alignment span.
+///
+/// The placeholder is exactly two operations: a soft clip with a length of `l_seq` followed by a
+/// skip of any length. The soft clip length is the disambiguator. A soft clip that covers the
+/// entire read and is followed by a skip has no meaning in a real CIGAR, whereas other soft
+/// clip-skip CIGARs, e.g., `2S8N` for a read of length 4, are not placeholders.
+///
+/// The caller is expected to also check that the `CG` data field exists. Otherwise, the CIGAR is
+/// used as is.
+//
 // § 4.2.2 "`N_CIGAR_OP` field" (2022-08-22)
+pub(crate) fn is_placeholder(ops: &[Op], read_length: usize) -> bool {
+    match ops {
+        [op_0, op_1] => *op_0 == Op::new(Kind::SoftClip, read_length) && op_1.kind() == Kind::Skip,
+        _ => false,
+    }
+}
+
 pub(super) fn resolve(record: &mut RecordBuf) -> Result<(), DecodeError> {
     use sam::alignment::{
         record::data::field::Tag,
         record_buf::data::field::{value::Array, Value},
     };
 
-    if let [op_0, op_1] = record.cigar().as_ref() {
-        let k = record.sequence().len();
+    let read_length = record.sequence().len();
 
-        if *op_0 == Op::new(Kind::SoftClip, k) && op_1.kind() == Kind::Skip {
-            if let Some((_, value)) = record.data_mut().remove(&Tag::CIGAR) {
-                let Value::Array(Array::UInt32(values)) = value else {
-                    return Err(DecodeError::InvalidDataType);
-                };
+    if is_placeholder(record.cigar().as_ref(), read_length) {
+        if let Some((_, value)) = record.data_mut().remove(&Tag::CIGAR) {
+            let Value::Array(Array::UInt32(values)) = value else {
+                return Err(DecodeError::InvalidDataType);
+            };
 
-                let cigar = record.cigar_mut().as_mut();
-                cigar.clear();
+            let cigar = record.cigar_mut().as_mut();
+            cigar.clear();
 
-                for n in values {
-                    let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
-                    cigar.push(op);
-                }
+            for n in values {
+                let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
+                cigar.push(op);
             }
         }
     }
@@ -188,6 +207,60 @@ mod tests {
         assert_eq!(record.cigar(), &expected);
         assert!(record.data().get(&Tag::CIGAR).is_none());
 
+        // A soft clip that does not cover the entire read is not a placeholder.
+        let cigar: Cigar = [Op::new(Kind::SoftClip, 2), Op::new(Kind::Skip, 8)]
+            .into_iter()
+            .collect();
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_id(0)
+            .set_cigar(cigar.clone())
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_data(
+                [(Tag::CIGAR, Value::Array(Array::UInt32(vec![0x40])))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        resolve(&mut record)?;
+
+        assert_eq!(record.cigar(), &cigar);
+        assert!(record.data().get(&Tag::CIGAR).is_some());
+
         Ok(())
     }
+
+    #[test]
+    fn test_is_placeholder() {
+        let op = |kind, len| Op::new(kind, len);
+
+        assert!(is_placeholder(
+            &[op(Kind::SoftClip, 4), op(Kind::Skip, 8)],
+            4
+        ));
+        assert!(is_placeholder(
+            &[op(Kind::SoftClip, 0), op(Kind::Skip, 8)],
+            0
+        ));
+
+        assert!(!is_placeholder(&[], 4));
+        assert!(!is_placeholder(&[op(Kind::SoftClip, 4)], 4));
+        assert!(!is_placeholder(
+            &[op(Kind::SoftClip, 2), op(Kind::Skip, 8)],
+            4
+        ));
+        assert!(!is_placeholder(
+            &[op(Kind::SoftClip, 4), op(Kind::Match, 8)],
+            4
+        ));
+        assert!(!is_placeholder(
+            &[op(Kind::Skip, 8), op(Kind::SoftClip, 4)],
+            4
+        ));
+        assert!(!is_placeholder(
+            &[op(Kind::SoftClip, 4), op(Kind::Skip, 8), op(Kind::Match, 1)],
+            4
+        ));
+    }
 }
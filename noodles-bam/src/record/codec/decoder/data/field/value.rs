@@ -4,7 +4,10 @@ use std::{error, fmt, mem, string};
 
 use bstr::{BString, ByteSlice};
 use bytes::Buf;
-use noodles_sam::alignment::{record::data::field::Type, record_buf::data::field::Value};
+use noodles_sam::alignment::{
+    record::data::field::Type,
+    record_buf::data::field::{value::InvalidCharacterError, Value},
+};
 
 use self::array::get_array;
 
@@ -13,6 +16,8 @@ use self::array::get_array;
 pub enum DecodeError {
     /// Unexpected EOF.
     UnexpectedEof,
+    /// The character is invalid.
+    InvalidCharacter(InvalidCharacterError),
     /// The string is not NUL terminated.
     StringNotNulTerminated,
     /// The string is invalid.
@@ -24,6 +29,7 @@ pub enum DecodeError {
 impl error::Error for DecodeError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
+            Self::InvalidCharacter(e) => Some(e),
             Self::InvalidString(e) => Some(e),
             Self::InvalidArray(e) => Some(e),
             _ => None,
@@ -35,6 +41,7 @@ impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::UnexpectedEof => write!(f, "unexpected EOF"),
+            Self::InvalidCharacter(_) => write!(f, "invalid character"),
             Self::StringNotNulTerminated => write!(f, "string is not NUL terminated"),
             Self::InvalidString(_) => write!(f, "invalid string"),
             Self::InvalidArray(_) => write!(f, "invalid array"),
@@ -69,7 +76,7 @@ where
         return Err(DecodeError::UnexpectedEof);
     }
 
-    Ok(Value::Character(src.get_u8()))
+    Value::try_character(src.get_u8()).map_err(DecodeError::InvalidCharacter)
 }
 
 fn get_i8<B>(src: &mut B) -> Result<Value, DecodeError>
@@ -246,4 +253,15 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_value_with_invalid_character() {
+        for b in [0x07, 0xe9] {
+            let mut src = &[b][..];
+            assert!(matches!(
+                get_value(&mut src, Type::Character),
+                Err(DecodeError::InvalidCharacter(_))
+            ));
+        }
+    }
 }
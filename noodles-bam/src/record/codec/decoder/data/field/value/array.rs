@@ -1,6 +1,6 @@
 pub mod subtype;
 
-use std::{error, fmt, num};
+use std::{error, fmt, mem, num};
 
 use bytes::Buf;
 use noodles_sam::alignment::{
@@ -13,10 +13,19 @@ use self::subtype::get_subtype;
 // An error when a raw BAM record data field array value fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DecodeError {
+    /// Unexpected EOF.
+    UnexpectedEof,
     /// The subtype is invalid.
     InvalidSubtype(subtype::DecodeError),
     /// The length is invalid.
     InvalidLength(num::TryFromIntError),
+    /// The declared length is greater than the number of elements available.
+    LengthOverflow {
+        /// The declared number of elements.
+        declared: usize,
+        /// The number of elements available in the remaining data.
+        available: usize,
+    },
 }
 
 impl error::Error for DecodeError {
@@ -24,6 +33,7 @@ impl error::Error for DecodeError {
         match self {
             Self::InvalidSubtype(e) => Some(e),
             Self::InvalidLength(e) => Some(e),
+            _ => None,
         }
     }
 }
@@ -31,8 +41,16 @@ impl error::Error for DecodeError {
 impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::UnexpectedEof => write!(f, "unexpected EOF"),
             Self::InvalidSubtype(_) => write!(f, "invalid subtype"),
             Self::InvalidLength(_) => write!(f, "invalid length"),
+            Self::LengthOverflow {
+                declared,
+                available,
+            } => write!(
+                f,
+                "length overflow: declared {declared} elements, {available} available"
+            ),
         }
     }
 }
@@ -42,8 +60,24 @@ where
     B: Buf,
 {
     let subtype = get_subtype(src).map_err(DecodeError::InvalidSubtype)?;
+
+    if src.remaining() < mem::size_of::<u32>() {
+        return Err(DecodeError::UnexpectedEof);
+    }
+
     let len = usize::try_from(src.get_u32_le()).map_err(DecodeError::InvalidLength)?;
 
+    // All multi-byte elements are little-endian. Check that all elements are available before
+    // reading them.
+    let available = src.remaining() / element_size(subtype);
+
+    if len > available {
+        return Err(DecodeError::LengthOverflow {
+            declared: len,
+            available,
+        });
+    }
+
     match subtype {
         Subtype::Int8 => {
             let mut buf = Vec::with_capacity(len);
@@ -110,3 +144,54 @@ where
         }
     }
 }
+
+fn element_size(subtype: Subtype) -> usize {
+    match subtype {
+        Subtype::Int8 => mem::size_of::<i8>(),
+        Subtype::UInt8 => mem::size_of::<u8>(),
+        Subtype::Int16 => mem::size_of::<i16>(),
+        Subtype::UInt16 => mem::size_of::<u16>(),
+        Subtype::Int32 => mem::size_of::<i32>(),
+        Subtype::UInt32 => mem::size_of::<u32>(),
+        Subtype::Float => mem::size_of::<f32>(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_array() {
+        let mut src = &[
+            b'I', 0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+        ][..];
+        assert_eq!(
+            get_array(&mut src),
+            Ok(Value::Array(Array::UInt32(vec![1, 256])))
+        );
+
+        let mut src = &[b'I', 0x01, 0x00][..];
+        assert_eq!(get_array(&mut src), Err(DecodeError::UnexpectedEof));
+
+        let mut src = &[
+            b'I', 0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ][..];
+        assert_eq!(
+            get_array(&mut src),
+            Err(DecodeError::LengthOverflow {
+                declared: 3,
+                available: 1
+            })
+        );
+
+        let mut src = &[b's', 0xff, 0xff, 0xff, 0xff][..];
+        assert_eq!(
+            get_array(&mut src),
+            Err(DecodeError::LengthOverflow {
+                declared: 4294967295,
+                available: 0
+            })
+        );
+    }
+}
@@ -60,16 +60,14 @@ pub(super) fn get_name<B>(
 where
     B: Buf,
 {
-    const MISSING: [u8; 2] = [b'*', NUL];
-
     let len = usize::from(l_read_name);
 
     if src.remaining() < len {
         return Err(DecodeError::UnexpectedEof);
     }
 
-    *name = if src.take(len).chunk() == MISSING {
-        src.advance(MISSING.len());
+    *name = if is_missing(src.take(len).chunk()) {
+        src.advance(len);
         None
     } else {
         let mut name = name.take().unwrap_or(Name::from(b""));
@@ -91,6 +89,12 @@ where
     Ok(())
 }
 
+// An empty name, i.e., `l_read_name` = 1, is also treated as missing.
+fn is_missing(buf: &[u8]) -> bool {
+    const MISSING: [u8; 2] = [b'*', NUL];
+    buf == MISSING || buf == [NUL]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -123,6 +127,7 @@ mod tests {
         }
 
         t(&[b'*', 0x00], None)?;
+        t(&[0x00], None)?;
         t(&[b'r', b'1', 0x00], Some(Name::from(b"r1")))?;
 
         let src = [0xf0, 0x9f, 0x8d, 0x9c, 0x00]; // "🍜\x00"
@@ -120,6 +120,7 @@ mod tests {
         t(&[], &mut sequence, &Sequence::default())?;
         t(&[0x12, 0x40], &mut sequence, &Sequence::from(b"ACG"))?;
         t(&[0x12, 0x48], &mut sequence, &Sequence::from(b"ACGT"))?;
+        t(&[0x01, 0x08], &mut sequence, &Sequence::from(b"=A=T"))?;
 
         sequence.as_mut().clear();
         let mut src = &b""[..];
@@ -6,6 +6,7 @@ pub mod data;
 mod flags;
 mod mapping_quality;
 mod name;
+mod options;
 mod position;
 mod quality_scores;
 mod reference_sequence_id;
@@ -13,7 +14,7 @@ mod sequence;
 
 pub(crate) use self::{
     cigar::put_cigar, data::put_data, mapping_quality::put_mapping_quality, name::put_name,
-    quality_scores::put_quality_scores, sequence::put_sequence,
+    options::Options, quality_scores::put_quality_scores, sequence::put_sequence,
 };
 
 use std::{error, fmt, io};
@@ -40,6 +41,8 @@ pub enum EncodeError {
     InvalidMateReferenceSequenceId(reference_sequence_id::EncodeError),
     /// The mate alignment start is invalid.
     InvalidMateAlignmentStart(position::EncodeError),
+    /// The number of CIGAR operations overflows the `n_cigar_op` field.
+    CigarOpCountOverflow(usize),
 }
 
 impl error::Error for EncodeError {
@@ -49,6 +52,7 @@ impl error::Error for EncodeError {
             Self::InvalidAlignmentStart(e) => Some(e),
             Self::InvalidMateReferenceSequenceId(e) => Some(e),
             Self::InvalidMateAlignmentStart(e) => Some(e),
+            Self::CigarOpCountOverflow(_) => None,
         }
     }
 }
@@ -62,11 +66,29 @@ impl fmt::Display for EncodeError {
                 write!(f, "invalid mate reference sequence ID")
             }
             Self::InvalidMateAlignmentStart(_) => write!(f, "invalid mate alignment start"),
+            Self::CigarOpCountOverflow(n) => write!(
+                f,
+                "CIGAR op count overflow: expected <= {}, got {n}",
+                u16::MAX
+            ),
         }
     }
 }
 
-pub(crate) fn encode<B, R>(dst: &mut B, header: &sam::Header, record: &R) -> io::Result<()>
+// § 4.2.2 "`N_CIGAR_OP` field" (2023-05-24): If `options.move_long_cigar_to_data` is set, a CIGAR with
+// more than 65535 operations is written to the `CG` data field, and a placeholder `<k>S<m>N` CIGAR,
+// where `k` is the sequence length (`l_seq`) and `m` is the alignment span of the CIGAR, is written
+// in its place.
+// Otherwise, this returns an error.
+//
+// If `options.preserve_bin` is set and the record has a raw bin, it is written as is. Otherwise,
+// the bin is calculated from the alignment span.
+pub(crate) fn encode<B, R>(
+    dst: &mut B,
+    header: &sam::Header,
+    record: &R,
+    options: &Options,
+) -> io::Result<()>
 where
     B: BufMut,
     R: Record + ?Sized,
@@ -90,11 +112,22 @@ where
     put_mapping_quality(dst, mapping_quality);
 
     // bin
-    let alignment_end = record.alignment_end().transpose()?;
-    put_bin(dst, alignment_start, alignment_end);
+    let bin = if options.preserve_bin {
+        record.bin().transpose()?
+    } else {
+        None
+    };
+
+    if let Some(bin) = bin {
+        dst.put_u16_le(bin);
+    } else {
+        // An unmapped record placed next to its mate is binned by its alignment start.
+        let alignment_end = record.alignment_end().transpose()?.or(alignment_start);
+        put_bin(dst, alignment_start, alignment_end);
+    }
 
     // n_cigar_op
-    let cigar = overflowing_put_cigar_op_count(dst, record)?;
+    let cigar = overflowing_put_cigar_op_count(dst, record, options.move_long_cigar_to_data)?;
 
     // flag
     let flags = record.flags()?;
@@ -170,7 +203,11 @@ where
     Ok(())
 }
 
-fn overflowing_put_cigar_op_count<B, R>(dst: &mut B, record: &R) -> io::Result<Option<Cigar>>
+fn overflowing_put_cigar_op_count<B, R>(
+    dst: &mut B,
+    record: &R,
+    move_long_cigar_to_data: bool,
+) -> io::Result<Option<Cigar>>
 where
     B: BufMut,
     R: Record + ?Sized,
@@ -182,9 +219,11 @@ where
     if let Ok(op_count) = u16::try_from(cigar.len()) {
         dst.put_u16_le(op_count);
         Ok(None)
-    } else {
+    } else if move_long_cigar_to_data {
         dst.put_u16_le(2);
 
+        // The placeholder soft clip length must be `l_seq`, even when the sequence is missing,
+        // for it to be recognized when decoding.
         let k = record.sequence().len();
         let m = cigar.alignment_span()?;
 
@@ -193,6 +232,11 @@ where
                 .into_iter()
                 .collect(),
         ))
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            EncodeError::CigarOpCountOverflow(cigar.len()),
+        ))
     }
 }
 
@@ -220,7 +264,7 @@ mod tests {
         let mut buf = Vec::new();
         let header = sam::Header::default();
         let record = RecordBuf::default();
-        encode(&mut buf, &header, &record)?;
+        encode(&mut buf, &header, &record, &Options::default())?;
 
         let expected = [
             0xff, 0xff, 0xff, 0xff, // ref_id = -1
@@ -293,7 +337,7 @@ mod tests {
             )
             .build();
 
-        encode(&mut buf, &header, &record)?;
+        encode(&mut buf, &header, &record, &Options::default())?;
 
         let expected = [
             0x01, 0x00, 0x00, 0x00, // ref_id = 1
@@ -360,7 +404,7 @@ mod tests {
             )
             .build();
 
-        encode(&mut buf, &header, &record)?;
+        encode(&mut buf, &header, &record, &Options::default())?;
 
         let mut expected = vec![
             0x00, 0x00, 0x00, 0x00, // ref_id = 0
@@ -395,4 +439,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encode_with_oversized_cigar_and_missing_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Flags,
+        };
+
+        use crate::record::codec::decode;
+
+        const OP_COUNT: usize = 65536;
+
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(131072) {
+            Some(length) => length,
+            None => unreachable!(),
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let cigar = Cigar::from(vec![Op::new(Kind::Match, 1); OP_COUNT]);
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(cigar.clone())
+            .build();
+
+        let mut buf = Vec::new();
+        encode(&mut buf, &header, &record, &Options::default())?;
+
+        // cigar = 0S65536N
+        assert_eq!(
+            &buf[34..42],
+            [0x04, 0x00, 0x00, 0x00, 0x03, 0x00, 0x10, 0x00]
+        );
+
+        let mut actual = RecordBuf::default();
+        decode(&mut &buf[..], &header, &mut actual)?;
+
+        assert_eq!(actual.cigar(), &cigar);
+        assert!(actual.data().get(&Tag::CIGAR).is_none());
+
+        Ok(())
+    }
 }
@@ -1,23 +1,39 @@
 mod op;
 
-use std::io;
+use std::{io, mem};
 
 use bytes::BufMut;
 use noodles_sam::alignment::record::Cigar;
 
 use self::op::encode_op;
 
+// The number of packed ops staged before each write to the destination.
+const CHUNK_OP_COUNT: usize = 64;
+const OP_SIZE: usize = mem::size_of::<u32>();
+
 pub fn put_cigar<B, C>(dst: &mut B, cigar: &C) -> io::Result<()>
 where
     B: BufMut,
     C: Cigar,
 {
+    let mut chunk = [0; CHUNK_OP_COUNT * OP_SIZE];
+    let mut len = 0;
+
     for result in cigar.iter() {
         let op = result?;
         let n = encode_op(op).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-        dst.put_u32_le(n);
+
+        chunk[len..len + OP_SIZE].copy_from_slice(&n.to_le_bytes());
+        len += OP_SIZE;
+
+        if len == chunk.len() {
+            dst.put_slice(&chunk);
+            len = 0;
+        }
     }
 
+    dst.put_slice(&chunk[..len]);
+
     Ok(())
 }
 
@@ -55,6 +71,13 @@ mod tests {
             &[0x40, 0x00, 0x00, 0x00, 0x25, 0x00, 0x00, 0x00],
         )?;
 
+        let op_count = CHUNK_OP_COUNT * 2 + 1;
+        let cigar: CigarBuf = (0..op_count).map(|i| Op::new(Kind::Match, i + 1)).collect();
+        let expected: Vec<_> = (0..op_count)
+            .flat_map(|i| (((i as u32) + 1) << 4).to_le_bytes())
+            .collect();
+        t(&mut buf, &cigar, &expected)?;
+
         Ok(())
     }
 }
@@ -3,7 +3,9 @@ pub mod array;
 use std::io;
 
 use bytes::BufMut;
-use noodles_sam::alignment::record::data::field::Value;
+use noodles_sam::alignment::{
+    record::data::field::Value, record_buf::data::field::Value as ValueBuf,
+};
 
 use self::array::put_array;
 
@@ -12,7 +14,7 @@ where
     B: BufMut,
 {
     match value {
-        Value::Character(c) => dst.put_u8(*c),
+        Value::Character(c) => put_character(dst, *c)?,
         Value::Int8(n) => dst.put_i8(*n),
         Value::UInt8(n) => dst.put_u8(*n),
         Value::Int16(n) => dst.put_i16_le(*n),
@@ -28,6 +30,15 @@ where
     Ok(())
 }
 
+fn put_character<B>(dst: &mut B, c: u8) -> io::Result<()>
+where
+    B: BufMut,
+{
+    ValueBuf::try_character(c).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    dst.put_u8(c);
+    Ok(())
+}
+
 fn put_string<B>(dst: &mut B, buf: &[u8])
 where
     B: BufMut,
@@ -58,6 +69,15 @@ mod tests {
         let mut buf = Vec::new();
 
         t(&mut buf, &Value::Character(b'n'), &[b'n'])?;
+
+        for c in [0x07, 0xe9] {
+            buf.clear();
+            assert!(matches!(
+                put_value(&mut buf, &Value::Character(c)),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
         t(&mut buf, &Value::Int8(1), &[0x01])?;
         t(&mut buf, &Value::UInt8(2), &[0x02])?;
         t(&mut buf, &Value::Int16(3), &[0x03, 0x00])?;
@@ -0,0 +1,17 @@
+/// BAM record encoder options.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Options {
+    /// Whether to move a CIGAR with more than 65535 operations to the `CG` data field.
+    pub move_long_cigar_to_data: bool,
+    /// Whether to write the raw bin of a record, if it has one, rather than calculate it.
+    pub preserve_bin: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            move_long_cigar_to_data: true,
+            preserve_bin: false,
+        }
+    }
+}
@@ -79,6 +79,7 @@ mod tests {
         t(&mut buf, &SequenceBuf::default(), &[])?;
         t(&mut buf, &SequenceBuf::from(b"ACG"), &[0x12, 0x40])?;
         t(&mut buf, &SequenceBuf::from(b"ACGT"), &[0x12, 0x48])?;
+        t(&mut buf, &SequenceBuf::from(b"=A=T"), &[0x01, 0x08])?;
 
         buf.clear();
         put_sequence(&mut buf, 2, &SequenceBuf::default())?;
@@ -6,7 +6,10 @@ use std::io;
 
 use bstr::{BStr, ByteSlice};
 use byteorder::{LittleEndian, ReadBytesExt};
-use noodles_sam::alignment::record::data::field::{Type, Value};
+use noodles_sam::alignment::{
+    record::data::field::{Type, Value},
+    record_buf::data::field::Value as ValueBuf,
+};
 
 use self::array::decode_array;
 
@@ -27,7 +30,11 @@ pub(crate) fn decode_value<'a>(src: &mut &'a [u8], ty: Type) -> io::Result<Value
 }
 
 fn decode_character<'a>(src: &mut &'a [u8]) -> io::Result<Value<'a>> {
-    src.read_u8().map(Value::Character)
+    let b = src.read_u8()?;
+
+    ValueBuf::try_character(b)
+        .map(|_| Value::Character(b))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
 fn decode_i8<'a>(src: &mut &'a [u8]) -> io::Result<Value<'a>> {
@@ -109,6 +116,14 @@ mod tests {
             Value::Character(b'n')
         ));
 
+        for src in [&[0x07][..], &[0xe9][..]] {
+            let mut src = src;
+            assert!(matches!(
+                decode_value(&mut src, Type::Character),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
         let mut src = &[0x00][..];
         assert!(matches!(
             decode_value(&mut src, Type::Int8)?,
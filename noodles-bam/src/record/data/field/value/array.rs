@@ -34,17 +34,26 @@ fn decode_length(src: &mut &[u8]) -> io::Result<usize> {
 pub(crate) fn decode_raw_array<'a>(src: &mut &'a [u8], subtype: Subtype) -> io::Result<&'a [u8]> {
     let n = decode_length(src)?;
 
-    let len = match subtype {
-        Subtype::Int8 => n,
-        Subtype::UInt8 => n,
-        Subtype::Int16 => n * mem::size_of::<i16>(),
-        Subtype::UInt16 => n * mem::size_of::<u16>(),
-        Subtype::Int32 => n * mem::size_of::<i32>(),
-        Subtype::UInt32 => n * mem::size_of::<u32>(),
-        Subtype::Float => n * mem::size_of::<f32>(),
+    let element_size = match subtype {
+        Subtype::Int8 => mem::size_of::<i8>(),
+        Subtype::UInt8 => mem::size_of::<u8>(),
+        Subtype::Int16 => mem::size_of::<i16>(),
+        Subtype::UInt16 => mem::size_of::<u16>(),
+        Subtype::Int32 => mem::size_of::<i32>(),
+        Subtype::UInt32 => mem::size_of::<u32>(),
+        Subtype::Float => mem::size_of::<f32>(),
     };
 
-    let (buf, rest) = src.split_at(len);
+    let available = src.len() / element_size;
+
+    if n > available {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("array length overflow: declared {n} elements, {available} available"),
+        ));
+    }
+
+    let (buf, rest) = src.split_at(n * element_size);
 
     *src = rest;
 
@@ -115,4 +124,13 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_raw_array_with_length_overflow() {
+        let mut src = &[0x03, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x01][..];
+        assert!(matches!(
+            decode_raw_array(&mut src, Subtype::UInt32),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
@@ -36,6 +36,12 @@ impl Fields {
         }
     }
 
+    pub(super) fn bin(&self) -> u16 {
+        let src = &self.buf[bounds::BIN_RANGE];
+        // SAFETY: `src` is 2 bytes.
+        u16::from_le_bytes(src.try_into().unwrap())
+    }
+
     pub(super) fn flags(&self) -> u16 {
         let src = &self.buf[bounds::FLAGS_RANGE];
         // SAFETY: `src` is 2 bytes.
@@ -61,35 +67,37 @@ impl Fields {
 
     pub(super) fn name(&self) -> Option<Name<'_>> {
         const MISSING: &[u8] = &[b'*', 0x00];
+        const EMPTY: &[u8] = &[0x00];
 
         match &self.buf[self.bounds.name_range()] {
-            MISSING => None,
+            MISSING | EMPTY => None,
             buf => Some(Name::new(buf)),
         }
     }
 
     pub(super) fn cigar(&self) -> Cigar<'_> {
-        use super::data::get_raw_cigar;
+        use noodles_sam::alignment::record::cigar::Op;
 
-        const SKIP: u8 = 3;
-        const SOFT_CLIP: u8 = 4;
+        use super::{
+            codec::decoder::cigar::{is_placeholder, op::decode_op},
+            data::get_raw_cigar,
+        };
 
-        fn decode_op(buf: &[u8]) -> (u8, usize) {
-            // SAFETY: `buf` is 4 bytes.
-            let n = u32::from_le_bytes(buf.try_into().unwrap());
-            ((n & 0x0f) as u8, usize::try_from(n >> 4).unwrap())
+        fn decode_ops(buf: &[u8]) -> Option<[Op; 2]> {
+            // SAFETY: `buf` is 8 bytes.
+            let n_0 = u32::from_le_bytes(buf[0..4].try_into().unwrap());
+            let n_1 = u32::from_le_bytes(buf[4..8].try_into().unwrap());
+            Some([decode_op(n_0).ok()?, decode_op(n_1).ok()?])
         }
 
         let src = &self.buf[self.bounds.cigar_range()];
 
         if src.len() == 2 * mem::size_of::<u32>() {
-            let k = self.sequence().len();
-
-            // SAFETY: `src` is 8 bytes.
-            let op_1 = decode_op(&src[0..4]);
-            let op_2 = decode_op(&src[4..8]);
+            let read_length = self.sequence().len();
+            let is_placeholder =
+                decode_ops(src).is_some_and(|ops| is_placeholder(&ops, read_length));
 
-            if op_1 == (SOFT_CLIP, k) && matches!(op_2, (SKIP, _)) {
+            if is_placeholder {
                 let mut data_src = &self.buf[self.bounds.data_range()];
 
                 if let Ok(Some(buf)) = get_raw_cigar(&mut data_src) {
@@ -243,6 +251,31 @@ mod tests {
         b'N', b'D', b'L', b'S', // quality scores
     ];
 
+    #[test]
+    fn test_name() -> io::Result<()> {
+        let fields = Fields::try_from(Vec::from(DATA))?;
+        assert!(fields.name().is_none());
+
+        let mut data = Vec::from(&DATA[..32]);
+        data[bounds::NAME_LENGTH_INDEX] = 1;
+        data.push(0x00); // read_name = "\x00"
+        data.extend(&DATA[34..]);
+        let fields = Fields::try_from(data)?;
+        assert!(fields.name().is_none());
+
+        let mut data = Vec::from(&DATA[..32]);
+        data[bounds::NAME_LENGTH_INDEX] = 3;
+        data.extend([b'r', b'0', 0x00]); // read_name = "r0\x00"
+        data.extend(&DATA[34..]);
+        let fields = Fields::try_from(data)?;
+        assert_eq!(
+            fields.name().map(|name| name.as_bytes().to_vec()),
+            Some(b"r0".to_vec())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_cigar() -> io::Result<()> {
         let fields = Fields::try_from(Vec::from(DATA))?;
@@ -307,6 +340,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cigar_with_soft_clip_and_skip_cigar() -> io::Result<()> {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x02, 0x00, // n_cigar_op = 2
+            0x04, 0x00, // flag = 4
+            0x04, 0x00, 0x00, 0x00, // l_seq = 4
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'*', 0x00, // read_name = "*\x00"
+            0x24, 0x00, 0x00, 0x00, 0x23, 0x00, 0x00, 0x00, // cigar = 2S2N
+            0x12, 0x48, // sequence = ACGT
+            b'N', b'D', b'L', b'S', // quality scores
+            b'C', b'G', b'B', b'I', 0x01, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00,
+            0x00, // data["CG"] = [4M]
+        ];
+
+        // The soft clip does not cover the entire read, so this is not a placeholder.
+        let fields = Fields::try_from(Vec::from(&data))?;
+        let cigar = fields.cigar();
+        assert_eq!(cigar.as_ref(), &data[34..42]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_index() -> io::Result<()> {
         let mut fields = Fields::default();
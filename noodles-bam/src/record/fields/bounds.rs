@@ -4,6 +4,7 @@ pub const REFERENCE_SEQUENCE_ID_RANGE: Range<usize> = 0..4;
 pub const ALIGNMENT_START_RANGE: Range<usize> = 4..8;
 pub const NAME_LENGTH_INDEX: usize = 8;
 pub const MAPPING_QUALITY_INDEX: usize = 9;
+pub const BIN_RANGE: Range<usize> = 10..12;
 pub const CIGAR_OP_COUNT_RANGE: Range<usize> = 12..14;
 pub const FLAGS_RANGE: Range<usize> = 14..16;
 pub const READ_LENGTH_RANGE: Range<usize> = 16..20;
@@ -105,4 +105,27 @@ mod tests {
         let sequence = Sequence::new(&[0x12, 0x48], 4);
         assert_eq!(format!("{sequence:?}"), r#"Sequence("ACGT")"#);
     }
+
+    #[test]
+    fn test_base_composition() {
+        use noodles_core::sequence::BaseCounts;
+        use sam::alignment::record::Sequence as _;
+
+        // ACGT=NR
+        let sequence = Sequence::new(&[0x12, 0x48, 0x0f, 0x50], 7);
+
+        assert_eq!(
+            sequence.base_composition(),
+            BaseCounts {
+                a: 1,
+                c: 1,
+                g: 1,
+                t: 1,
+                n: 1,
+                other: 2,
+            }
+        );
+
+        assert_eq!(sequence.gc_content(), 2.0 / 6.0);
+    }
 }
@@ -1,7 +1,13 @@
 //! BGZF I/O.
 
 mod buf_read;
+mod compression_method;
 mod read;
 mod seek;
 
-pub use self::{buf_read::BufRead, read::Read, seek::Seek};
+pub use self::{
+    buf_read::BufRead,
+    compression_method::{detect_compression_method, CompressionMethod},
+    read::Read,
+    seek::Seek,
+};
@@ -0,0 +1,86 @@
+use std::io;
+
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+const FEXTRA: u8 = 0x04;
+const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+/// A gzip-based compression method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompressionMethod {
+    /// BGZF.
+    Bgzf,
+    /// gzip.
+    Gzip,
+}
+
+/// Detects the compression method of a stream from its first bytes.
+///
+/// This peeks at the buffered input without consuming it. A gzip stream with a BGZF block header,
+/// i.e., an extra field with the subfield ID `BC`, is BGZF; any other gzip stream is gzip; and
+/// anything else is assumed to be uncompressed (`None`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf::{self as bgzf, io::{detect_compression_method, CompressionMethod}};
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+/// assert_eq!(detect_compression_method(&mut &src[..])?, Some(CompressionMethod::Bgzf));
+///
+/// assert_eq!(detect_compression_method(&mut &b"noodles"[..])?, None);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
+where
+    R: io::BufRead,
+{
+    let src = reader.fill_buf()?;
+
+    if !src.starts_with(&GZIP_MAGIC_NUMBER) {
+        return Ok(None);
+    }
+
+    // A BGZF block header has an extra field with the subfield ID `BC` at offset 12.
+    let is_bgzf = src.get(3).map(|flg| flg & FEXTRA != 0).unwrap_or(false)
+        && src.get(12..14) == Some(&BGZF_SUBFIELD_ID[..]);
+
+    if is_bgzf {
+        Ok(Some(CompressionMethod::Bgzf))
+    } else {
+        Ok(Some(CompressionMethod::Gzip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    #[test]
+    fn test_detect_compression_method() -> io::Result<()> {
+        const DATA: &[u8] = b"noodles";
+
+        fn t(src: &[u8]) -> io::Result<Option<CompressionMethod>> {
+            detect_compression_method(&mut &src[..])
+        }
+
+        assert_eq!(t(DATA)?, None);
+        assert_eq!(t(b"")?, None);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(DATA)?;
+        assert_eq!(t(&encoder.finish()?)?, Some(CompressionMethod::Gzip));
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        assert_eq!(t(&writer.finish()?)?, Some(CompressionMethod::Bgzf));
+
+        Ok(())
+    }
+}
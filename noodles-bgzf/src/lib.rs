@@ -46,6 +46,7 @@ pub mod io;
 mod multithreaded_reader;
 pub mod multithreaded_writer;
 pub mod reader;
+pub mod transform;
 pub mod virtual_position;
 pub mod writer;
 
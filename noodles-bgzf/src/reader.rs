@@ -7,7 +7,8 @@ pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
-use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
+use self::frame::is_valid_header;
+use super::{gzi, Block, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
 ///
@@ -185,6 +186,56 @@ where
         Ok(pos)
     }
 
+    /// Seeks the stream to the given compressed offset.
+    ///
+    /// This is for offsets of the underlying stream, e.g., from a recovery tool or a custom index,
+    /// that are known to be at the start of a block. It is equivalent to seeking to the virtual
+    /// position `(pos, 0)`, i.e., the uncompressed cursor is reset to the start of the block, but
+    /// the target is first validated to be a BGZF block header.
+    ///
+    /// This returns an [`io::ErrorKind::InvalidData`] error if the offset is not at the start of
+    /// a BGZF block. The stream position is then unspecified and must be reset with another seek.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(Cursor::new(data));
+    /// let virtual_position = reader.seek_to_compressed_offset(0)?;
+    /// assert_eq!(virtual_position, bgzf::VirtualPosition::MIN);
+    ///
+    /// assert!(reader.seek_to_compressed_offset(1).is_err());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek_to_compressed_offset(&mut self, pos: u64) -> io::Result<VirtualPosition> {
+        let virtual_position = VirtualPosition::new(pos, 0).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "compressed offset exceeds the maximum virtual position",
+            )
+        })?;
+
+        self.inner.seek(SeekFrom::Start(pos))?;
+
+        let mut header = [0; BGZF_HEADER_SIZE];
+        self.inner.read_exact(&mut header)?;
+
+        if !is_valid_header(&header[..]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("compressed offset is not at the start of a BGZF block: {pos}"),
+            ));
+        }
+
+        self.seek(virtual_position)
+    }
+
     /// Seeks the stream to the given uncompressed position.
     ///
     /// # Examples
@@ -419,4 +470,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_to_compressed_offset() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+
+        assert_eq!(
+            reader.seek_to_compressed_offset(35)?,
+            VirtualPosition::try_from((35, 0))?
+        );
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((35, 0))?
+        );
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        assert!(matches!(
+            reader.seek_to_compressed_offset(18),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        reader.seek_to_compressed_offset(0)?;
+        let mut buf = [0; 7];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"noodles");
+
+        Ok(())
+    }
 }
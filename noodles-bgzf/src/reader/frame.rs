@@ -66,7 +66,7 @@ fn parse_header(src: &[u8]) -> io::Result<()> {
     }
 }
 
-fn is_valid_header<B>(mut src: B) -> bool
+pub(crate) fn is_valid_header<B>(mut src: B) -> bool
 where
     B: Buf,
 {
@@ -0,0 +1,236 @@
+//! BGZF block transforms.
+//!
+//! These rewrite a BGZF stream block by block, which uses memory bounded by the size of a block.
+//!
+//! [`transform`] decompresses each input block, passes its uncompressed data to a hook, and
+//! recompresses the result as a new block. [`append_missing_eof_marker`] copies the blocks
+//! verbatim.
+//!
+//! Recompressing blocks changes their compressed sizes, and a transform that changes the size of
+//! the uncompressed data also moves the data after it. In both cases, the virtual positions of the
+//! input are _not_ valid in the output, and any existing index, e.g., BAI, CSI, tabix, or GZI,
+//! must be rebuilt. The output virtual positions are tracked by the writer (see
+//! [`Writer::virtual_position`]). Verbatim copies keep the block offsets, so an index of the input
+//! is still valid.
+
+use std::io::{self, BufRead, Read, Write};
+
+use super::{
+    reader::frame::{is_valid_header, read_frame_into},
+    writer::BGZF_EOF,
+    Reader, Writer,
+};
+
+/// Transforms a BGZF stream block by block.
+///
+/// For each nonempty input block, the hook is given a buffer with its uncompressed data, which it
+/// can modify in place, e.g., to filter lines. The buffer is then written as a complete block. If
+/// the buffer is empty after the transform, no block is written. If it is larger than the maximum
+/// block size, it is split into multiple blocks.
+///
+/// Empty input blocks, including EOF markers in concatenated streams, are skipped. The output
+/// EOF marker is written when the writer is finished.
+///
+/// See the [module documentation](self) for how this affects virtual positions and indices.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+///
+/// let mut reader = bgzf::Reader::new(&src[..]);
+/// let mut writer = bgzf::Writer::new(Vec::new());
+///
+/// bgzf::transform::transform(&mut reader, &mut writer, |buf| {
+///     buf.make_ascii_uppercase();
+///     Ok(())
+/// })?;
+///
+/// let dst = writer.finish()?;
+///
+/// let mut reader = bgzf::Reader::new(&dst[..]);
+/// let mut buf = String::new();
+/// reader.read_to_string(&mut buf)?;
+/// assert_eq!(buf, "NOODLES");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn transform<R, W, F>(
+    reader: &mut Reader<R>,
+    writer: &mut Writer<W>,
+    mut f: F,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+    F: FnMut(&mut Vec<u8>) -> io::Result<()>,
+{
+    let mut buf = Vec::new();
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            break;
+        }
+
+        buf.clear();
+        buf.extend_from_slice(src);
+
+        let len = src.len();
+        reader.consume(len);
+
+        f(&mut buf)?;
+
+        writer.write_block(&buf)?;
+    }
+
+    Ok(())
+}
+
+/// Copies a BGZF stream and appends an EOF marker if it does not end with one.
+///
+/// Blocks are copied verbatim, i.e., they are not decompressed. This returns whether an EOF
+/// marker was appended.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::writer::Builder::default()
+///     .set_eof_marker(false)
+///     .build_with_writer(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+///
+/// let mut dst = Vec::new();
+/// assert!(bgzf::transform::append_missing_eof_marker(&src[..], &mut dst)?);
+///
+/// let mut src = Vec::new();
+/// assert!(!bgzf::transform::append_missing_eof_marker(&dst[..], &mut src)?);
+/// assert_eq!(src, dst);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn append_missing_eof_marker<R, W>(mut reader: R, mut writer: W) -> io::Result<bool>
+where
+    R: Read,
+    W: Write,
+{
+    let mut buf = Vec::new();
+    let mut is_eof = false;
+
+    while read_frame_into(&mut reader, &mut buf)?.is_some() {
+        if !is_valid_header(&buf[..]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid BGZF header",
+            ));
+        }
+
+        writer.write_all(&buf)?;
+        is_eof = buf == BGZF_EOF;
+    }
+
+    if is_eof {
+        Ok(false)
+    } else {
+        writer.write_all(BGZF_EOF)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compress(blocks: &[&[u8]], eof_marker: bool) -> io::Result<Vec<u8>> {
+        let mut writer = crate::writer::Builder::default()
+            .set_eof_marker(eof_marker)
+            .build_with_writer(Vec::new());
+
+        for block in blocks {
+            writer.write_all(block)?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    #[test]
+    fn test_transform() -> io::Result<()> {
+        let src = [
+            compress(&[b"noodles", b"bgzf"], true)?,
+            compress(&[b"sam"], true)?,
+        ]
+        .concat();
+
+        let mut reader = Reader::new(&src[..]);
+        let mut writer = Writer::new(Vec::new());
+
+        let mut blocks = Vec::new();
+
+        transform(&mut reader, &mut writer, |buf| {
+            blocks.push(buf.clone());
+
+            if buf == b"bgzf" {
+                buf.clear();
+            } else {
+                buf.push(b'\n');
+            }
+
+            Ok(())
+        })?;
+
+        let virtual_position = writer.virtual_position();
+        let dst = writer.finish()?;
+
+        assert_eq!(blocks, [&b"noodles"[..], b"bgzf", b"sam"]);
+
+        // The output has two data blocks and an EOF marker.
+        assert_eq!(
+            virtual_position.compressed(),
+            (dst.len() - BGZF_EOF.len()) as u64
+        );
+        assert!(dst.ends_with(BGZF_EOF));
+
+        let mut reader = Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles\nsam\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_missing_eof_marker() -> io::Result<()> {
+        let src = compress(&[b"noodles", b"bgzf"], false)?;
+
+        let mut dst = Vec::new();
+        assert!(append_missing_eof_marker(&src[..], &mut dst)?);
+        assert_eq!(&dst[..src.len()], &src[..]);
+        assert_eq!(&dst[src.len()..], BGZF_EOF);
+
+        let src = dst;
+        let mut dst = Vec::new();
+        assert!(!append_missing_eof_marker(&src[..], &mut dst)?);
+        assert_eq!(dst, src);
+
+        // A concatenated stream with an EOF marker in the middle.
+        let src = [compress(&[b"noodles"], true)?, compress(&[b"bgzf"], false)?].concat();
+        let mut dst = Vec::new();
+        assert!(append_missing_eof_marker(&src[..], &mut dst)?);
+        assert_eq!(&dst[src.len()..], BGZF_EOF);
+
+        let mut dst = Vec::new();
+        assert!(append_missing_eof_marker(&[][..], &mut dst)?);
+        assert_eq!(dst, BGZF_EOF);
+
+        Ok(())
+    }
+}
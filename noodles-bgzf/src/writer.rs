@@ -72,6 +72,7 @@ where
     staging_buf: Vec<u8>,
     compression_buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
+    eof_marker: bool,
 }
 
 impl<W> Writer<W>
@@ -170,9 +171,27 @@ where
         Ok(())
     }
 
+    // Writes the given data as complete blocks.
+    //
+    // Any buffered data is first written as its own block.
+    pub(crate) fn write_block(&mut self, buf: &[u8]) -> io::Result<()> {
+        if !self.staging_buf.is_empty() {
+            self.flush_block()?;
+        }
+
+        self.write_all(buf)?;
+
+        if !self.staging_buf.is_empty() {
+            self.flush_block()?;
+        }
+
+        Ok(())
+    }
+
     /// Attempts to finish the output stream by flushing any remaining buffers.
     ///
-    /// This then appends the final BGZF EOF block.
+    /// This then appends the final BGZF EOF block, unless disabled by
+    /// [`Builder::set_eof_marker`].
     ///
     /// # Examples
     ///
@@ -189,6 +208,10 @@ where
     pub fn try_finish(&mut self) -> io::Result<()> {
         self.flush()?;
 
+        if !self.eof_marker {
+            return Ok(());
+        }
+
         let inner = self.inner.as_mut().unwrap();
         let result = inner.write_all(BGZF_EOF);
 
@@ -248,18 +271,22 @@ where
         self.staging_buf.extend(&buf[..amt]);
 
         if !self.has_remaining() {
-            self.flush()?;
+            self.flush_block()?;
         }
 
         Ok(amt)
     }
 
+    /// Writes any buffered data as a complete block and flushes the underlying writer.
+    ///
+    /// A reader can then read all data written up to this point, e.g., from a pipe. Each flush
+    /// with buffered data ends a block early, so frequent flushes reduce the compression ratio.
     fn flush(&mut self) -> io::Result<()> {
-        if self.staging_buf.is_empty() {
-            Ok(())
-        } else {
-            self.flush_block()
+        if !self.staging_buf.is_empty() {
+            self.flush_block()?;
         }
+
+        self.inner.as_mut().unwrap().flush()
     }
 }
 
@@ -290,6 +317,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_flush() -> io::Result<()> {
+        use std::io::{BufWriter, Read};
+
+        use crate::Reader;
+
+        let mut writer = Writer::new(BufWriter::new(Vec::new()));
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+
+        // The underlying writer is flushed, and the block is complete without an EOF marker.
+        let data = writer.get_ref().get_ref().clone();
+        assert!(!data.ends_with(BGZF_EOF));
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
@@ -302,4 +351,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_finish_without_eof_marker() -> io::Result<()> {
+        use std::io::Read;
+
+        use crate::Reader;
+
+        let mut writer = Builder::default()
+            .set_eof_marker(false)
+            .build_with_writer(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.try_finish()?;
+        assert_eq!(
+            writer.virtual_position().compressed(),
+            writer.get_ref().len() as u64
+        );
+        let mut data = writer.finish()?;
+
+        assert!(!data.ends_with(BGZF_EOF));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"-bgzf")?;
+        data.extend(writer.finish()?);
+
+        assert!(data.ends_with(BGZF_EOF));
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_concatenated_streams_with_eof_markers() -> io::Result<()> {
+        use std::io::Read;
+
+        use crate::Reader;
+
+        let mut data = Vec::new();
+
+        for chunk in [&b"noodles"[..], b"-bgzf"] {
+            let mut writer = Writer::new(Vec::new());
+            writer.write_all(chunk)?;
+            data.extend(writer.finish()?);
+        }
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
 }
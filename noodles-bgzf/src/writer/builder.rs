@@ -3,9 +3,10 @@ use std::io::Write;
 use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
 
 /// A BGZF writer builder.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Builder {
     compression_level: CompressionLevel,
+    eof_marker: bool,
 }
 
 impl Builder {
@@ -26,6 +27,28 @@ impl Builder {
         self
     }
 
+    /// Sets whether to append the BGZF end-of-file (EOF) marker when the stream is finished.
+    ///
+    /// The EOF marker is the canonical 28-byte empty BGZF block (§ 4.1.2 "End-of-file marker"
+    /// (2020-12-03)). By default, it is appended.
+    ///
+    /// Disable the marker when writing a shard that is to be concatenated with other BGZF
+    /// streams, e.g., using `cat`. Only the final shard then needs the marker. Readers use a
+    /// trailing marker to detect truncated files, but an empty block in the middle of a stream is
+    /// valid. [`crate::Reader`] skips empty blocks and continues reading the next block, so a
+    /// concatenation of streams that each end with a marker still reads as a whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::writer::Builder::default().set_eof_marker(false);
+    /// ```
+    pub fn set_eof_marker(mut self, eof_marker: bool) -> Self {
+        self.eof_marker = eof_marker;
+        self
+    }
+
     /// Builds a BGZF writer from a writer.
     ///
     /// # Examples
@@ -45,6 +68,16 @@ impl Builder {
             staging_buf: Vec::with_capacity(MAX_BUF_SIZE),
             compression_buf: Vec::new(),
             compression_level: self.compression_level.into(),
+            eof_marker: self.eof_marker,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            compression_level: CompressionLevel::default(),
+            eof_marker: true,
         }
     }
 }
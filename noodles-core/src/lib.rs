@@ -3,6 +3,9 @@
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
 pub mod position;
+pub mod quality_scores;
 pub mod region;
+pub mod sequence;
+pub mod sequence_dictionary;
 
-pub use self::{position::Position, region::Region};
+pub use self::{position::Position, region::Region, sequence_dictionary::SequenceDictionary};
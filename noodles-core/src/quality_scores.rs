@@ -0,0 +1,79 @@
+//! Quality scores transforms.
+
+/// Applies Illumina 8-level quality score binning to raw Phred quality scores in place.
+///
+/// This is a lossy transform. Each score is replaced with the representative value of the bin it
+/// falls in:
+///
+/// | Phred quality score | Binned score |
+/// |---------------------|-------------:|
+/// | 0–1 (no call)       |    unchanged |
+/// | 2–9                 |            6 |
+/// | 10–19               |           15 |
+/// | 20–24               |           22 |
+/// | 25–29               |           27 |
+/// | 30–34               |           33 |
+/// | 35–39               |           37 |
+/// | ≥ 40                |           40 |
+///
+/// The scores are expected to be raw Phred values, i.e., not offset by 33 as they are in, e.g.,
+/// FASTQ or SAM text.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::quality_scores::bin_illumina8;
+///
+/// let mut scores = [0, 2, 9, 10, 19, 20, 24, 25, 29, 30, 34, 35, 39, 40, 41];
+/// bin_illumina8(&mut scores);
+///
+/// assert_eq!(scores, [0, 6, 6, 15, 15, 22, 22, 27, 27, 33, 33, 37, 37, 40, 40]);
+/// ```
+pub fn bin_illumina8(scores: &mut [u8]) {
+    for score in scores {
+        *score = bin_illumina8_score(*score);
+    }
+}
+
+fn bin_illumina8_score(score: u8) -> u8 {
+    match score {
+        0..=1 => score,
+        2..=9 => 6,
+        10..=19 => 15,
+        20..=24 => 22,
+        25..=29 => 27,
+        30..=34 => 33,
+        35..=39 => 37,
+        _ => 40,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_illumina8_score() {
+        let bins: [(std::ops::RangeInclusive<u8>, u8); 7] = [
+            (2..=9, 6),
+            (10..=19, 15),
+            (20..=24, 22),
+            (25..=29, 27),
+            (30..=34, 33),
+            (35..=39, 37),
+            (40..=u8::MAX, 40),
+        ];
+
+        assert_eq!(bin_illumina8_score(0), 0);
+        assert_eq!(bin_illumina8_score(1), 1);
+
+        for (range, expected) in bins {
+            assert_eq!(bin_illumina8_score(*range.start()), expected);
+            assert_eq!(bin_illumina8_score(*range.end()), expected);
+
+            for score in range {
+                assert_eq!(bin_illumina8_score(score), expected);
+            }
+        }
+    }
+}
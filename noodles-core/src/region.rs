@@ -23,7 +23,12 @@ use super::Position;
 /// end positions are 1-based, inclusive. If no end position is given, it is assumed to span from
 /// the start to the end of the reference sequence. If no interval is given, it is assumed to span
 /// the entirety of the reference sequence.
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// Regions are equal if their names and intervals are equal, and equal regions have equal hashes,
+/// e.g., for deduplication in a `HashSet`. Intervals are compared by their bounds and not by the
+/// positions they cover. For example, `sq0` (unbounded) and `sq0:1` (starting at 1) are not
+/// equal, even though both span the entire reference sequence.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Region {
     name: BString,
     interval: Interval,
@@ -175,6 +180,25 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// An error returned when a genomic region fails to resolve to a reference sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResolveError {
+    /// The reference sequence name is not in the reference sequences.
+    UnknownReferenceSequence(BString),
+}
+
+impl error::Error for ResolveError {}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownReferenceSequence(name) => {
+                write!(f, "unknown reference sequence: {name}")
+            }
+        }
+    }
+}
+
 impl FromStr for Region {
     type Err = ParseError;
 
@@ -196,6 +220,22 @@ impl FromStr for Region {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_hash() -> Result<(), ParseError> {
+        use std::collections::HashSet;
+
+        let regions: HashSet<Region> = ["sq0:5-8", "sq0", "sq0:5-8", "sq0:1", "sq0:1", "sq1"]
+            .into_iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(regions.len(), 4);
+        assert!(regions.contains(&Region::new("sq0", ..)));
+        assert!(regions.contains(&Region::new("sq0", Position::MIN..)));
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), crate::position::TryFromIntError> {
         let start = Position::try_from(5)?;
@@ -12,7 +12,7 @@ use crate::{position, Position};
 ///
 /// An interval can be closed ([a, b]), left-closed and right-unbounded ([a, ∞)), left-unbounded
 /// and right-closed ((-∞, b]), or unbounded ((-∞, ∞)).
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub struct Interval {
     start: Option<Position>,
     end: Option<Position>,
@@ -0,0 +1,162 @@
+//! Sequence base composition.
+
+/// Counts of bases in a sequence.
+///
+/// Bases are counted case-insensitively, i.e., `a` and `A` are both counted as `A`. `N` is the
+/// unknown base. All other bases, including IUPAC ambiguity codes (e.g., `R`, `S`) and the BAM
+/// `=` code (the base is equal to the reference), are counted as other.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::sequence::BaseCounts;
+///
+/// let counts: BaseCounts = b"ACgtNn=R".iter().copied().collect();
+///
+/// assert_eq!(
+///     counts,
+///     BaseCounts {
+///         a: 1,
+///         c: 1,
+///         g: 1,
+///         t: 1,
+///         n: 2,
+///         other: 2,
+///     }
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BaseCounts {
+    /// The number of `A` bases.
+    pub a: usize,
+    /// The number of `C` bases.
+    pub c: usize,
+    /// The number of `G` bases.
+    pub g: usize,
+    /// The number of `T` bases.
+    pub t: usize,
+    /// The number of `N` bases.
+    pub n: usize,
+    /// The number of other bases.
+    pub other: usize,
+}
+
+impl BaseCounts {
+    /// Returns the total number of bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence::BaseCounts;
+    /// let counts: BaseCounts = b"ACGTN".iter().copied().collect();
+    /// assert_eq!(counts.len(), 5);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.a + self.c + self.g + self.t + self.n + self.other
+    }
+
+    /// Returns whether there are no bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence::BaseCounts;
+    /// assert!(BaseCounts::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the GC content.
+    ///
+    /// This is the fraction of bases that are `G` or `C`. `N` bases are excluded from the total,
+    /// but other bases are not. If there are no bases other than `N`, this returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence::BaseCounts;
+    ///
+    /// let counts: BaseCounts = b"ACGGNN".iter().copied().collect();
+    /// assert_eq!(counts.gc_content(), 0.75);
+    ///
+    /// assert_eq!(BaseCounts::default().gc_content(), 0.0);
+    /// ```
+    pub fn gc_content(&self) -> f64 {
+        let len = self.len() - self.n;
+
+        if len == 0 {
+            0.0
+        } else {
+            (self.g + self.c) as f64 / len as f64
+        }
+    }
+}
+
+impl Extend<u8> for BaseCounts {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        for base in iter {
+            match base.to_ascii_uppercase() {
+                b'A' => self.a += 1,
+                b'C' => self.c += 1,
+                b'G' => self.g += 1,
+                b'T' => self.t += 1,
+                b'N' => self.n += 1,
+                _ => self.other += 1,
+            }
+        }
+    }
+}
+
+impl FromIterator<u8> for BaseCounts {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let mut counts = Self::default();
+        counts.extend(iter);
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter() {
+        let counts: BaseCounts = b"ACGTacgtNnRYSWKMBDHV=.".iter().copied().collect();
+
+        assert_eq!(
+            counts,
+            BaseCounts {
+                a: 2,
+                c: 2,
+                g: 2,
+                t: 2,
+                n: 2,
+                other: 12,
+            }
+        );
+
+        assert_eq!(counts.len(), 22);
+    }
+
+    #[test]
+    fn test_gc_content() {
+        fn t(bases: &[u8], expected: f64) {
+            let counts: BaseCounts = bases.iter().copied().collect();
+            assert_eq!(counts.gc_content(), expected);
+        }
+
+        t(b"", 0.0);
+        t(b"NNNN", 0.0);
+        t(b"ATAT", 0.0);
+        t(b"GCgc", 1.0);
+        t(b"ACGTNN", 0.5);
+        t(b"AGSR", 0.25);
+    }
+}
@@ -0,0 +1,228 @@
+//! Sequence dictionary.
+
+use std::{collections::HashMap, error, fmt};
+
+use bstr::{BStr, BString};
+
+/// A sequence dictionary.
+///
+/// A sequence dictionary is an ordered list of reference sequence names and lengths. The index of
+/// a reference sequence is its position in the list, i.e., its reference sequence ID.
+///
+/// Lookups of a name by index, an index by name, and a length by index are O(1).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::SequenceDictionary;
+///
+/// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8), ("sq1", 13)])?;
+///
+/// assert_eq!(sequence_dictionary.get_index_of(b"sq1"), Some(1));
+/// assert_eq!(sequence_dictionary.get_name(1), Some(b"sq1".as_ref().into()));
+/// assert_eq!(sequence_dictionary.get_length(1), Some(13));
+/// # Ok::<_, noodles_core::sequence_dictionary::DuplicateNameError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SequenceDictionary {
+    entries: Vec<(BString, usize)>,
+    indices: HashMap<BString, usize>,
+}
+
+impl SequenceDictionary {
+    /// Returns the number of reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let sequence_dictionary = SequenceDictionary::default();
+    /// assert_eq!(sequence_dictionary.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    /// let sequence_dictionary = SequenceDictionary::default();
+    /// assert!(sequence_dictionary.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a reference sequence.
+    ///
+    /// This returns the index of the added reference sequence. If a reference sequence with the
+    /// same name is already in the dictionary, this returns an error, and the dictionary is not
+    /// changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    ///
+    /// let mut sequence_dictionary = SequenceDictionary::default();
+    /// assert_eq!(sequence_dictionary.push("sq0", 8), Ok(0));
+    /// assert_eq!(sequence_dictionary.push("sq1", 13), Ok(1));
+    /// assert!(sequence_dictionary.push("sq0", 21).is_err());
+    /// ```
+    pub fn push<N>(&mut self, name: N, length: usize) -> Result<usize, DuplicateNameError>
+    where
+        N: Into<BString>,
+    {
+        let name = name.into();
+
+        if self.indices.contains_key(&name) {
+            return Err(DuplicateNameError(name));
+        }
+
+        let index = self.entries.len();
+        self.indices.insert(name.clone(), index);
+        self.entries.push((name, length));
+
+        Ok(index)
+    }
+
+    /// Returns the index of the reference sequence with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    ///
+    /// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8), ("sq1", 13)])?;
+    ///
+    /// assert_eq!(sequence_dictionary.get_index_of(b"sq1"), Some(1));
+    /// assert!(sequence_dictionary.get_index_of(b"sq2").is_none());
+    /// # Ok::<_, noodles_core::sequence_dictionary::DuplicateNameError>(())
+    /// ```
+    pub fn get_index_of<N>(&self, name: N) -> Option<usize>
+    where
+        N: AsRef<[u8]>,
+    {
+        self.indices.get(BStr::new(name.as_ref())).copied()
+    }
+
+    /// Returns the name of the reference sequence at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    ///
+    /// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8), ("sq1", 13)])?;
+    ///
+    /// assert_eq!(sequence_dictionary.get_name(1), Some(b"sq1".as_ref().into()));
+    /// assert!(sequence_dictionary.get_name(2).is_none());
+    /// # Ok::<_, noodles_core::sequence_dictionary::DuplicateNameError>(())
+    /// ```
+    pub fn get_name(&self, index: usize) -> Option<&BStr> {
+        self.entries.get(index).map(|(name, _)| name.as_ref())
+    }
+
+    /// Returns the length of the reference sequence at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    ///
+    /// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8), ("sq1", 13)])?;
+    ///
+    /// assert_eq!(sequence_dictionary.get_length(1), Some(13));
+    /// assert!(sequence_dictionary.get_length(2).is_none());
+    /// # Ok::<_, noodles_core::sequence_dictionary::DuplicateNameError>(())
+    /// ```
+    pub fn get_length(&self, index: usize) -> Option<usize> {
+        self.entries.get(index).map(|(_, length)| *length)
+    }
+
+    /// Returns an iterator over reference sequence names and lengths, in index order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::SequenceDictionary;
+    ///
+    /// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8), ("sq1", 13)])?;
+    /// let mut iter = sequence_dictionary.iter();
+    ///
+    /// assert_eq!(iter.next(), Some((b"sq0".as_ref().into(), 8)));
+    /// assert_eq!(iter.next(), Some((b"sq1".as_ref().into(), 13)));
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, noodles_core::sequence_dictionary::DuplicateNameError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&BStr, usize)> {
+        self.entries
+            .iter()
+            .map(|(name, length)| (name.as_ref(), *length))
+    }
+}
+
+impl<N> TryFrom<Vec<(N, usize)>> for SequenceDictionary
+where
+    N: Into<BString>,
+{
+    type Error = DuplicateNameError;
+
+    fn try_from(entries: Vec<(N, usize)>) -> Result<Self, Self::Error> {
+        let mut sequence_dictionary = Self::default();
+
+        for (name, length) in entries {
+            sequence_dictionary.push(name, length)?;
+        }
+
+        Ok(sequence_dictionary)
+    }
+}
+
+/// An error returned when a reference sequence name is duplicated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DuplicateNameError(BString);
+
+impl DuplicateNameError {
+    /// Returns the duplicate name.
+    pub fn name(&self) -> &BStr {
+        self.0.as_ref()
+    }
+}
+
+impl error::Error for DuplicateNameError {}
+
+impl fmt::Display for DuplicateNameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "duplicate reference sequence name: {}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let mut sequence_dictionary = SequenceDictionary::default();
+
+        assert_eq!(sequence_dictionary.push("sq0", 8), Ok(0));
+        assert_eq!(sequence_dictionary.push("sq1", 13), Ok(1));
+        assert_eq!(
+            sequence_dictionary.push("sq0", 21),
+            Err(DuplicateNameError(BString::from("sq0")))
+        );
+
+        assert_eq!(sequence_dictionary.len(), 2);
+        assert_eq!(sequence_dictionary.get_index_of(b"sq0"), Some(0));
+        assert_eq!(sequence_dictionary.get_length(0), Some(8));
+    }
+
+    #[test]
+    fn test_try_from_vec_for_sequence_dictionary() {
+        assert!(SequenceDictionary::try_from(vec![("sq0", 8), ("sq0", 13)]).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+//! Measures CRAM write throughput.
+//!
+//! This writes a number of generated unmapped records (default: 200000) to a sink with each given
+//! worker count (default: 1, 2, and 4) and prints the number of records written per second.
+//!
+//! Only block compression uses the worker pool, so run a release build on a host with at least as
+//! many CPUs as the largest worker count, e.g., `cargo run --release --example
+//! cram_write_throughput 200000 1 2 4 8`.
+
+use std::{env, io, num::NonZeroUsize, time::Instant};
+
+use noodles_cram as cram;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        io::Write,
+        record_buf::{QualityScores, Sequence},
+        RecordBuf,
+    },
+};
+
+const READ_LENGTH: usize = 100;
+
+fn build_records(record_count: usize) -> Vec<RecordBuf> {
+    const BASES: [u8; 4] = *b"ACGT";
+
+    // A linear congruential generator, so the input is the same for each run.
+    let mut state: u32 = 1;
+
+    let mut next = move || {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        (state >> 16) as usize
+    };
+
+    (0..record_count)
+        .map(|i| {
+            let sequence: Vec<_> = (0..READ_LENGTH).map(|_| BASES[next() % 4]).collect();
+            let quality_scores: Vec<_> = (0..READ_LENGTH).map(|_| (next() % 41) as u8).collect();
+
+            RecordBuf::builder()
+                .set_name(format!("r{i}").into_bytes().into())
+                .set_sequence(Sequence::from(sequence))
+                .set_quality_scores(QualityScores::from(quality_scores))
+                .build()
+        })
+        .collect()
+}
+
+fn write_records(
+    header: &sam::Header,
+    records: &[RecordBuf],
+    worker_count: NonZeroUsize,
+) -> io::Result<()> {
+    let mut writer = cram::io::writer::Builder::default()
+        .set_worker_count(worker_count)
+        .build_with_writer(io::sink());
+
+    writer.write_header(header)?;
+
+    for record in records {
+        writer.write_alignment_record(header, record)?;
+    }
+
+    writer.try_finish(header)
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let record_count = args
+        .next()
+        .map(|s| s.parse())
+        .transpose()?
+        .unwrap_or(200_000);
+
+    let worker_counts: Vec<NonZeroUsize> = args.map(|s| s.parse()).collect::<Result<_, _>>()?;
+
+    let worker_counts = if worker_counts.is_empty() {
+        [1, 2, 4]
+            .into_iter()
+            .map(NonZeroUsize::try_from)
+            .collect::<Result<_, _>>()?
+    } else {
+        worker_counts
+    };
+
+    let header = sam::Header::default();
+    let records = build_records(record_count);
+
+    for worker_count in worker_counts {
+        let start = Instant::now();
+        write_records(&header, &records, worker_count)?;
+        let elapsed = start.elapsed();
+
+        let throughput = record_count as f64 / elapsed.as_secs_f64();
+
+        println!("worker count {worker_count}: {elapsed:.2?} ({throughput:.0} records/s)");
+    }
+
+    Ok(())
+}
@@ -11,10 +11,13 @@ use bytes::BytesMut;
 use futures::Stream;
 use noodles_core::Region;
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, header::resolve_region};
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
 
-use crate::{crai, file_definition::Version, DataContainer, FileDefinition, Record};
+use crate::{
+    crai, data_container::RawContainer, file_definition::Version, DataContainer, FileDefinition,
+    Record,
+};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
@@ -143,6 +146,37 @@ where
         read_header_container(&mut self.inner, &mut self.buf).await
     }
 
+    /// Reads the SAM header.
+    ///
+    /// This verifies the CRAM magic number, discards the file definition, and reads and parses the
+    /// file header as a SAM header. No data containers are read or decoded.
+    ///
+    /// The position of the stream is expected to be at the start. When successful, the stream is
+    /// positioned at the first data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_cram as cram;
+    /// use tokio::fs::File;
+    /// let mut reader = File::open("sample.cram").await.map(cram::r#async::io::Reader::new)?;
+    /// let header = reader.read_header().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_header(&mut self) -> io::Result<sam::Header> {
+        self.read_file_definition().await?;
+
+        self.read_file_header().await.and_then(|s| {
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+    }
+
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
@@ -174,6 +208,39 @@ where
         read_data_container(&mut self.inner, &mut self.buf).await
     }
 
+    /// Reads a data container without decoding it.
+    ///
+    /// This parses the container header but leaves the container body, i.e., the compression
+    /// header and slices, as raw bytes. It is useful for copying containers verbatim.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_cram as cram;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.cram").await.map(cram::r#async::io::Reader::new)?;
+    /// reader.read_file_definition().await?;
+    /// reader.read_file_header().await?;
+    ///
+    /// while let Some(raw_container) = reader.read_raw_container().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_raw_container(&mut self) -> io::Result<Option<RawContainer>> {
+        use self::data_container::read_raw_container;
+        read_raw_container(&mut self.inner, &mut self.buf).await
+    }
+
     /// Returns an (async) stream over records starting from the current (input) stream position.
     ///
     /// The (input) stream position is expected to be at the start of a data container.
@@ -295,15 +362,7 @@ where
     ) -> io::Result<impl Stream<Item = io::Result<Record>> + '_> {
         use self::query::query;
 
-        let reference_sequence_id = header
-            .reference_sequences()
-            .get_index_of(region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "invalid reference sequence name",
-                )
-            })?;
+        let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
 
         Ok(query(
             self,
@@ -357,6 +416,52 @@ where
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_read_raw_container() -> io::Result<()> {
+        use noodles_sam::{
+            self as sam,
+            alignment::{io::Write as _, record::Flags, RecordBuf},
+        };
+
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".as_slice().into())
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let src = writer.get_ref();
+
+        let mut expected = Vec::new();
+        let mut sync_reader = crate::io::Reader::new(&src[..]);
+        sync_reader.read_header()?;
+
+        while let Some(raw_container) = sync_reader.read_raw_container()? {
+            expected.push(raw_container);
+        }
+
+        let mut actual = Vec::new();
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header().await?;
+
+        while let Some(raw_container) = reader.read_raw_container().await? {
+            actual.push(raw_container);
+        }
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual, expected);
+        assert!(!actual[0].header_src().is_empty());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_magic_number() {
         let data = b"CRAM";
@@ -378,6 +483,28 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_read_header() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = sam::Header::builder().add_comment("noodles-cram").build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&expected)?;
+        writer.write_record(&expected, Record::default())?;
+        writer.try_finish(&expected)?;
+
+        let src = writer.get_ref();
+        let mut reader = Reader::new(&src[..]);
+
+        let actual = reader.read_header().await?;
+        assert_eq!(actual, expected);
+
+        let data_container = reader.read_data_container().await?;
+        assert!(data_container.is_some());
+        assert!(reader.read_data_container().await?.is_none());
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_format() -> io::Result<()> {
         let data = [0x03, 0x00];
@@ -1,11 +1,16 @@
 mod header;
 
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
 use bytes::BytesMut;
-use tokio::io::{self, AsyncRead, AsyncReadExt};
+use tokio::io::{self, AsyncRead, AsyncReadExt, ReadBuf};
 
 use self::header::read_header;
 use crate::{
-    data_container::DataContainer,
+    data_container::{DataContainer, RawContainer},
     io::reader::data_container::{read_compression_header_from_block, read_slice},
 };
 
@@ -37,3 +42,52 @@ where
 
     Ok(Some(DataContainer::new(compression_header, slices)))
 }
+
+pub async fn read_raw_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+) -> io::Result<Option<RawContainer>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut header_src = Vec::new();
+
+    let mut tee_reader = TeeReader {
+        inner: reader,
+        dst: &mut header_src,
+    };
+
+    let header = match read_header(&mut tee_reader).await? {
+        Some(header) => header,
+        None => return Ok(None),
+    };
+
+    buf.resize(header.len(), 0);
+    reader.read_exact(buf).await?;
+    let src = buf.split().freeze();
+
+    Ok(Some(RawContainer::new(header, header_src.into(), src)))
+}
+
+// A reader that copies the bytes it reads.
+struct TeeReader<'a, R> {
+    inner: &'a mut R,
+    dst: &'a mut Vec<u8>,
+}
+
+impl<R> AsyncRead for TeeReader<'_, R>
+where
+    R: AsyncRead + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let start = buf.filled().len();
+        ready!(Pin::new(&mut *this.inner).poll_read(cx, buf))?;
+        this.dst.extend_from_slice(&buf.filled()[start..]);
+        Poll::Ready(Ok(()))
+    }
+}
@@ -1,6 +1,6 @@
 mod header;
 
-use std::str;
+use std::{mem, str};
 
 use bytes::{Buf, Bytes, BytesMut};
 use tokio::io::{self, AsyncRead, AsyncReadExt};
@@ -62,9 +62,24 @@ fn read_raw_sam_header(block: &Block) -> io::Result<String> {
 
     let mut data = block.decompressed_data()?;
 
+    if data.remaining() < mem::size_of::<i32>() {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
     let len = usize::try_from(data.get_i32_le())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+    // The header text must not be truncated to a partial header.
+    if data.len() < len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "truncated SAM header: expected {len} bytes, got {}",
+                data.len()
+            ),
+        ));
+    }
+
     data.truncate(len);
 
     str::from_utf8(&data[..])
@@ -103,6 +118,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_raw_sam_header_with_truncated_header() {
+        fn t(data: Vec<u8>) {
+            let block = Block::builder()
+                .set_content_type(ContentType::FileHeader)
+                .set_uncompressed_len(data.len())
+                .set_data(data.into())
+                .build();
+
+            assert!(matches!(
+                read_raw_sam_header(&block),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        t(Vec::new());
+
+        let mut data = Vec::new();
+        data.put_i32_le(64);
+        data.extend(b"@HD\tVN:1.6\n");
+        t(data);
+    }
+
     #[test]
     fn test_read_raw_sam_header_with_invalid_compression_method() {
         let block = Block::builder()
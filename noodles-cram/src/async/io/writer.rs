@@ -13,8 +13,9 @@ use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
 pub use self::builder::Builder;
 use crate::{
-    file_definition::Version, io::writer::Options, DataContainer, FileDefinition, Record,
-    MAGIC_NUMBER,
+    file_definition::Version,
+    io::{writer::Options, WorkerPool},
+    DataContainer, FileDefinition, Record, MAGIC_NUMBER,
 };
 
 /// An async CRAM writer.
@@ -25,6 +26,7 @@ pub struct Writer<W> {
     reference_sequence_repository: fasta::Repository,
     options: Options,
     data_container_builder: crate::data_container::Builder,
+    worker_pool: WorkerPool,
     record_counter: u64,
 }
 
@@ -185,8 +187,15 @@ where
     ) -> io::Result<()> {
         use crate::data_container::builder::AddRecordError;
 
+        if let Some(tag_filter) = &self.options.tag_filter {
+            tag_filter.apply(&mut record);
+        }
+
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -223,6 +232,7 @@ where
             &self.options,
             &self.reference_sequence_repository,
             header,
+            &self.worker_pool,
         )?;
 
         write_data_container(&mut self.inner, &data_container, base_count).await
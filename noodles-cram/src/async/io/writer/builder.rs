@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::Path};
 
 use noodles_fasta as fasta;
 use tokio::{
@@ -8,15 +8,22 @@ use tokio::{
 
 use super::Writer;
 use crate::{
-    data_container::BlockContentEncoderMap, file_definition::Version, io::writer::Options,
+    data_container::{
+        compression_header::preservation_map::SubstitutionMatrix, BlockContentEncoderMap,
+    },
+    file_definition::Version,
+    io::{
+        writer::{Options, TagFilter},
+        WorkerPool,
+    },
     DataContainer,
 };
 
 /// An async CRAM writer builder.
-#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: NonZeroUsize,
 }
 
 impl Builder {
@@ -31,7 +38,11 @@ impl Builder {
 
     /// Sets whether to preserve read names.
     ///
-    /// If `false`, read names are discarded.
+    /// If `false`, read names are discarded, except for records that have no mate in the same
+    /// slice (detached records), which always store their read names. When read, a discarded read
+    /// name is generated from the record counter, and mates share the name of the first segment.
+    ///
+    /// A missing read name (`*`) is always written as missing, regardless of this option.
     ///
     /// The default is `true`.
     pub fn preserve_read_names(mut self, value: bool) -> Self {
@@ -49,12 +60,86 @@ impl Builder {
         self
     }
 
+    /// Sets whether decoding requires the reference sequence.
+    ///
+    /// If `false`, the compression header marks the reference as not required (`RR` is false),
+    /// and the output is decodable without a reference sequence repository. The writer also does
+    /// not need one, and slice reference MD5 checksums are zeros. However, CRAM requires an MD5
+    /// checksum (`M5`) for each header reference sequence, and it can only be calculated when the
+    /// sequence is in the repository. Otherwise, it must be set in the given header.
+    ///
+    /// Read bases are always stored as literal bases rather than as differences to the reference,
+    /// so this does not change the size of the record data. It does lose the reference checks done
+    /// when reading. Reference-based compression, which this writer does not implement, would
+    /// require a reference and produce smaller output.
+    ///
+    /// The default is `true`.
+    pub fn set_reference_required(mut self, reference_required: bool) -> Self {
+        self.options.reference_required = reference_required;
+        self
+    }
+
     /// Sets the block content-encoder map.
     pub fn set_block_content_encoder_map(mut self, map: BlockContentEncoderMap) -> Self {
         self.options.block_content_encoder_map = map;
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// The default is 10240.
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// The default is 1.
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets the filter of the data fields written for each record.
+    ///
+    /// By default, all fields are written.
+    pub fn set_tag_filter(mut self, tag_filter: TagFilter) -> Self {
+        self.options.tag_filter = Some(tag_filter);
+        self
+    }
+
+    /// Sets the substitution matrix.
+    ///
+    /// By default, the substitution matrix is computed for each container from the frequencies of
+    /// its substitutions.
+    pub fn set_substitution_matrix(mut self, substitution_matrix: SubstitutionMatrix) -> Self {
+        self.options.substitution_matrix = Some(substitution_matrix);
+        self
+    }
+
+    /// Sets the worker count.
+    ///
+    /// See [`crate::io::writer::Builder::set_worker_count`].
+    ///
+    /// Blocks are compressed on the writer's worker pool, and the writer waits for them, i.e., it
+    /// blocks the current task while a container is compressed.
+    ///
+    /// By default, the worker count is 1, i.e., blocks are compressed on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::r#async::io::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
     /// Builds an async CRAM writer from a path.
     ///
     /// # Examples
@@ -100,7 +185,18 @@ impl Builder {
             reference_sequence_repository: self.reference_sequence_repository,
             options: self.options,
             data_container_builder: DataContainer::builder(0),
+            worker_pool: WorkerPool::new(self.worker_count),
             record_counter: 0,
         }
     }
 }
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            options: Options::default(),
+            worker_count: NonZeroUsize::MIN,
+        }
+    }
+}
@@ -1,15 +1,23 @@
 //! CRAM block content codecs.
 
+#[cfg(feature = "std")]
 pub mod aac;
+#[cfg(feature = "std")]
 pub(crate) mod bzip2;
+#[cfg(feature = "std")]
 pub(crate) mod fqzcomp;
+#[cfg(feature = "std")]
 pub(crate) mod gzip;
+#[cfg(feature = "std")]
 pub(crate) mod lzma;
+#[cfg(feature = "std")]
 pub(crate) mod name_tokenizer;
 pub mod rans_4x8;
+#[cfg(feature = "std")]
 pub mod rans_nx16;
 
 /// A CRAM block content encoder.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
 pub enum Encoder {
     /// gzip
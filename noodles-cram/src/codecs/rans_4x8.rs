@@ -1,13 +1,18 @@
 //! rANS 4x8 codec.
 
 mod decode;
+#[cfg(feature = "std")]
 mod encode;
 mod order;
 
-pub use self::order::Order;
+#[cfg(feature = "std")]
 pub(crate) use self::{decode::decode, encode::encode};
+pub use self::{
+    decode::{decode_slice, DecodeError},
+    order::Order,
+};
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use std::io;
 
@@ -1,43 +1,112 @@
+//! rANS 4x8 decoder.
+//!
+//! The decoder is split into a thin `std::io` wrapper ([`decode`]) and the core decoding
+//! ([`decode_slice`]), which reads from a byte slice.
+
+mod cursor;
 mod order_0;
 mod order_1;
 
+use alloc::{vec, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
 use std::io::{self, Read};
 
-use byteorder::{LittleEndian, ReadBytesExt};
+use self::cursor::Cursor;
+use super::{order, Order};
+
+#[cfg(feature = "std")]
+const HEADER_LEN: usize = 9;
 
-use super::Order;
+/// An error returned when rANS 4x8 data fails to decode.
+#[derive(Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// Unexpected EOF.
+    UnexpectedEof,
+    /// The order is invalid.
+    InvalidOrder(order::TryFromByteError),
+}
 
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "unexpected EOF"),
+            Self::InvalidOrder(_) => write!(f, "invalid order"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::UnexpectedEof => None,
+            Self::InvalidOrder(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<DecodeError> for io::Error {
+    fn from(e: DecodeError) -> Self {
+        match e {
+            DecodeError::UnexpectedEof => io::Error::new(io::ErrorKind::UnexpectedEof, e),
+            DecodeError::InvalidOrder(_) => io::Error::new(io::ErrorKind::InvalidData, e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
-    let (order, _, data_len) = read_header(reader)?;
+    let mut header = [0; HEADER_LEN];
+    reader.read_exact(&mut header)?;
+
+    let (_, compressed_len, _) = read_header(&mut Cursor::new(&header))?;
+
+    let mut src = header.to_vec();
+    reader.take(compressed_len as u64).read_to_end(&mut src)?;
+
+    decode_slice(&src).map_err(io::Error::from)
+}
+
+/// Decodes rANS 4x8 data from a byte slice.
+///
+/// `src` is the entire stream, i.e., the header followed by the compressed data.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_4x8::{self, DecodeError};
+///
+/// let src = [0x00, 0x0a, 0x00, 0x00, 0x00];
+/// assert_eq!(rans_4x8::decode_slice(&src), Err(DecodeError::UnexpectedEof));
+/// ```
+pub fn decode_slice(src: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut reader = Cursor::new(src);
+
+    let (order, _, data_len) = read_header(&mut reader)?;
 
     let mut dst = vec![0; data_len];
 
     match order {
-        Order::Zero => order_0::decode(reader, &mut dst)?,
-        Order::One => order_1::decode(reader, &mut dst)?,
+        Order::Zero => order_0::decode(&mut reader, &mut dst)?,
+        Order::One => order_1::decode(&mut reader, &mut dst)?,
     }
 
     Ok(dst)
 }
 
-fn read_header<R>(reader: &mut R) -> io::Result<(Order, usize, usize)>
-where
-    R: Read,
-{
-    let order = reader.read_u8().and_then(|order| {
-        Order::try_from(order).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+fn read_header(reader: &mut Cursor<'_>) -> Result<(Order, usize, usize), DecodeError> {
+    let order = reader
+        .read_u8()
+        .and_then(|n| Order::try_from(n).map_err(DecodeError::InvalidOrder))?;
 
-    let compressed_len = reader.read_u32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
-
-    let data_len = reader.read_u32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // `u32` to `usize` is lossless on 32- and 64-bit targets.
+    let compressed_len = reader.read_u32_le()? as usize;
+    let data_len = reader.read_u32_le()? as usize;
 
     Ok((order, compressed_len, data_len))
 }
@@ -50,10 +119,7 @@ pub fn rans_advance_step(r: u32, c: u32, f: u32) -> u32 {
     f * (r >> 12) + (r & 0x0fff) - c
 }
 
-pub fn rans_renorm<R>(reader: &mut R, mut r: u32) -> io::Result<u32>
-where
-    R: Read,
-{
+pub fn rans_renorm(reader: &mut Cursor<'_>, mut r: u32) -> Result<u32, DecodeError> {
     while r < (1 << 23) {
         r = (r << 8) + reader.read_u8().map(u32::from)?;
     }
@@ -61,22 +127,29 @@ where
     Ok(r)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_read_header() -> io::Result<()> {
+    fn test_read_header() {
         let data = [
             0x00, // order = 0
             0x25, 0x00, 0x00, 0x00, // compressed size = 37
             0x07, 0x00, 0x00, 0x00, // data size = 7
         ];
 
-        let mut reader = &data[..];
-        assert_eq!(read_header(&mut reader)?, (Order::Zero, 37, 7));
+        let mut reader = Cursor::new(&data);
+        assert_eq!(read_header(&mut reader), Ok((Order::Zero, 37, 7)));
 
-        Ok(())
+        let mut reader = Cursor::new(&data[..5]);
+        assert_eq!(read_header(&mut reader), Err(DecodeError::UnexpectedEof));
+
+        let mut reader = Cursor::new(&[0x02]);
+        assert!(matches!(
+            read_header(&mut reader),
+            Err(DecodeError::InvalidOrder(_))
+        ));
     }
 
     #[test]
@@ -117,4 +190,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_slice() -> Result<(), DecodeError> {
+        let data = [
+            0x00, 0x25, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x64, 0x82, 0x49, 0x65, 0x00,
+            0x82, 0x49, 0x6c, 0x82, 0x49, 0x6e, 0x82, 0x49, 0x6f, 0x00, 0x84, 0x92, 0x73, 0x82,
+            0x49, 0x00, 0xe2, 0x06, 0x83, 0x18, 0x74, 0x7b, 0x41, 0x0c, 0x2b, 0xa9, 0x41, 0x0c,
+            0x25, 0x31, 0x80, 0x03,
+        ];
+
+        assert_eq!(decode_slice(&data)?, b"noodles");
+
+        assert_eq!(
+            decode_slice(&data[..data.len() - 1]),
+            Err(DecodeError::UnexpectedEof)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_with_trailing_data() -> io::Result<()> {
+        let data = [
+            0x00, 0x25, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00, 0x64, 0x82, 0x49, 0x65, 0x00,
+            0x82, 0x49, 0x6c, 0x82, 0x49, 0x6e, 0x82, 0x49, 0x6f, 0x00, 0x84, 0x92, 0x73, 0x82,
+            0x49, 0x00, 0xe2, 0x06, 0x83, 0x18, 0x74, 0x7b, 0x41, 0x0c, 0x2b, 0xa9, 0x41, 0x0c,
+            0x25, 0x31, 0x80, 0x03, 0xff,
+        ];
+
+        let mut reader = &data[..];
+        assert_eq!(decode(&mut reader)?, b"noodles");
+        assert_eq!(reader, [0xff]);
+
+        Ok(())
+    }
 }
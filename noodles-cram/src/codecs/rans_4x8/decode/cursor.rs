@@ -0,0 +1,113 @@
+use super::DecodeError;
+
+// A reader over a byte slice.
+//
+// Reads past the end of the input return `DecodeError::UnexpectedEof`.
+pub struct Cursor<'a> {
+    src: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(src: &'a [u8]) -> Self {
+        Self { src }
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, DecodeError> {
+        let (&b, rest) = self.src.split_first().ok_or(DecodeError::UnexpectedEof)?;
+        self.src = rest;
+        Ok(b)
+    }
+
+    pub fn read_u32_le(&mut self) -> Result<u32, DecodeError> {
+        const SIZE: usize = 4;
+
+        if self.src.len() < SIZE {
+            return Err(DecodeError::UnexpectedEof);
+        }
+
+        let (buf, rest) = self.src.split_at(SIZE);
+        self.src = rest;
+
+        // SAFETY: `buf.len() == SIZE`.
+        Ok(u32::from_le_bytes(buf.try_into().unwrap()))
+    }
+
+    pub fn read_u32s_le(&mut self, dst: &mut [u32]) -> Result<(), DecodeError> {
+        for n in dst {
+            *n = self.read_u32_le()?;
+        }
+
+        Ok(())
+    }
+
+    // This is the same as `crate::io::reader::num::read_itf8` but does not depend on `std::io`.
+    pub fn read_itf8(&mut self) -> Result<i32, DecodeError> {
+        let b0 = self.read_u8_as_i32()?;
+
+        let value = if b0 & 0x80 == 0 {
+            b0
+        } else if b0 & 0x40 == 0 {
+            let b1 = self.read_u8_as_i32()?;
+            (b0 & 0x7f) << 8 | b1
+        } else if b0 & 0x20 == 0 {
+            let b1 = self.read_u8_as_i32()?;
+            let b2 = self.read_u8_as_i32()?;
+            (b0 & 0x3f) << 16 | b1 << 8 | b2
+        } else if b0 & 0x10 == 0 {
+            let b1 = self.read_u8_as_i32()?;
+            let b2 = self.read_u8_as_i32()?;
+            let b3 = self.read_u8_as_i32()?;
+            (b0 & 0x1f) << 24 | b1 << 16 | b2 << 8 | b3
+        } else {
+            let b1 = self.read_u8_as_i32()?;
+            let b2 = self.read_u8_as_i32()?;
+            let b3 = self.read_u8_as_i32()?;
+            let b4 = self.read_u8_as_i32()?;
+            (b0 & 0x0f) << 28 | b1 << 20 | b2 << 12 | b3 << 4 | b4 & 0x0f
+        };
+
+        Ok(value)
+    }
+
+    fn read_u8_as_i32(&mut self) -> Result<i32, DecodeError> {
+        self.read_u8().map(i32::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_u8() {
+        let mut cursor = Cursor::new(&[0x05]);
+        assert_eq!(cursor.read_u8(), Ok(0x05));
+        assert_eq!(cursor.read_u8(), Err(DecodeError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_read_u32_le() {
+        let mut cursor = Cursor::new(&[0x08, 0x00, 0x00, 0x00, 0x0d, 0x00]);
+        assert_eq!(cursor.read_u32_le(), Ok(8));
+        assert_eq!(cursor.read_u32_le(), Err(DecodeError::UnexpectedEof));
+    }
+
+    #[test]
+    fn test_read_itf8() {
+        fn t(src: &[u8], expected: i32) {
+            let mut cursor = Cursor::new(src);
+            assert_eq!(cursor.read_itf8(), Ok(expected));
+        }
+
+        t(&[0x00], 0);
+        t(&[0x87, 0x55], 1877);
+        t(&[0xc7, 0x55, 0x99], 480665);
+        t(&[0xe7, 0x55, 0x99, 0x66], 123050342);
+        t(&[0xf7, 0x55, 0x99, 0x66, 0x02], 1968805474);
+        t(&[0xf7, 0x55, 0x99, 0x66, 0x82], 1968805474);
+        t(&[0xff, 0xff, 0xff, 0xff, 0x0f], -1);
+
+        let mut cursor = Cursor::new(&[0x80]);
+        assert_eq!(cursor.read_itf8(), Err(DecodeError::UnexpectedEof));
+    }
+}
@@ -1,15 +1,6 @@
-use std::io::{self, Read};
+use super::{rans_advance_step, rans_get_cumulative_freq, rans_renorm, Cursor, DecodeError};
 
-use byteorder::{LittleEndian, ReadBytesExt};
-
-use crate::io::reader::num::read_itf8;
-
-use super::{rans_advance_step, rans_get_cumulative_freq, rans_renorm};
-
-pub fn decode<R>(reader: &mut R, dst: &mut [u8]) -> io::Result<()>
-where
-    R: Read,
-{
+pub fn decode(reader: &mut Cursor<'_>, dst: &mut [u8]) -> Result<(), DecodeError> {
     let mut freqs = [0; 256];
     let mut cumulative_freqs = [0; 256];
 
@@ -18,7 +9,7 @@ where
     let cumulative_freqs_symbols_table = build_cumulative_freqs_symbols_table_0(&cumulative_freqs);
 
     let mut states = [0; 4];
-    reader.read_u32_into::<LittleEndian>(&mut states)?;
+    reader.read_u32s_le(&mut states)?;
 
     for chunk in dst.chunks_mut(states.len()) {
         for (d, state) in chunk.iter_mut().zip(states.iter_mut()) {
@@ -40,20 +31,17 @@ where
     Ok(())
 }
 
-pub fn read_frequencies_0<R>(
-    reader: &mut R,
+pub fn read_frequencies_0(
+    reader: &mut Cursor<'_>,
     freqs: &mut [u32; 256],
     cumulative_freqs: &mut [u32; 256],
-) -> io::Result<()>
-where
-    R: Read,
-{
+) -> Result<(), DecodeError> {
     let mut sym = reader.read_u8()?;
     let mut last_sym = sym;
     let mut rle = 0;
 
     loop {
-        let f = read_itf8(reader)? as u32;
+        let f = reader.read_itf8()? as u32;
 
         freqs[usize::from(sym)] = f;
 
@@ -1,13 +1,10 @@
-use std::io::{self, Read};
+use alloc::boxed::Box;
 
-use byteorder::{LittleEndian, ReadBytesExt};
+use super::{
+    order_0, rans_advance_step, rans_get_cumulative_freq, rans_renorm, Cursor, DecodeError,
+};
 
-use super::{order_0, rans_advance_step, rans_get_cumulative_freq, rans_renorm};
-
-pub fn decode<R>(reader: &mut R, dst: &mut [u8]) -> io::Result<()>
-where
-    R: Read,
-{
+pub fn decode(reader: &mut Cursor<'_>, dst: &mut [u8]) -> Result<(), DecodeError> {
     let mut freqs = [[0; 256]; 256];
     let mut cumulative_freqs = [[0; 256]; 256];
 
@@ -16,7 +13,7 @@ where
     let cumulative_freqs_symbols_tables = build_cumulative_freqs_symbols_table_1(&cumulative_freqs);
 
     let mut states = [0; 4];
-    reader.read_u32_into::<LittleEndian>(&mut states)?;
+    reader.read_u32s_le(&mut states)?;
 
     let state_count = states.len();
     let chunk_size = dst.len() / state_count;
@@ -70,14 +67,11 @@ where
     Ok(())
 }
 
-fn read_frequencies_1<R>(
-    reader: &mut R,
+fn read_frequencies_1(
+    reader: &mut Cursor<'_>,
     freqs: &mut [[u32; 256]; 256],
     cumulative_freqs: &mut [[u32; 256]; 256],
-) -> io::Result<()>
-where
-    R: Read,
-{
+) -> Result<(), DecodeError> {
     let mut sym = reader.read_u8()?;
     let mut last_sym = sym;
     let mut rle = 0;
@@ -1,4 +1,4 @@
-use std::{error, fmt};
+use core::fmt;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct TryFromByteError(u8);
@@ -9,7 +9,8 @@ impl fmt::Display for TryFromByteError {
     }
 }
 
-impl error::Error for TryFromByteError {}
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromByteError {}
 
 /// The number of bytes of context used to compute frequencies.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
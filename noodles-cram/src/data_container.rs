@@ -4,16 +4,15 @@ pub mod block_content_encoder_map;
 pub(crate) mod builder;
 pub mod compression_header;
 mod header;
+mod raw_container;
 mod reference_sequence_context;
 pub(crate) mod slice;
 
 pub use self::{
     block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
-    slice::Slice,
-};
-pub(crate) use self::{
-    builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
+    header::Header, raw_container::RawContainer, slice::Slice,
 };
+pub(crate) use self::{builder::Builder, reference_sequence_context::ReferenceSequenceContext};
 
 /// A CRAM data container.
 pub struct DataContainer {
@@ -4,9 +4,10 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::{slice, CompressionHeader, DataContainer, Slice};
-use crate::{io::writer::Options, Record};
-
-const MAX_SLICE_COUNT: usize = 1;
+use crate::{
+    io::{writer::Options, WorkerPool},
+    Record,
+};
 
 #[derive(Debug)]
 pub struct Builder {
@@ -33,6 +34,10 @@ impl Builder {
         }
     }
 
+    pub fn record_counter(&self) -> u64 {
+        self.record_counter
+    }
+
     pub fn is_empty(&self) -> bool {
         self.slice_builder.is_empty() && self.slice_builders.is_empty()
     }
@@ -42,12 +47,15 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container.get() {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        match self
+            .slice_builder
+            .add_record(record, options.records_per_slice.get())
+        {
             Ok(r) => {
                 self.base_count += u64::try_from(r.read_length())
                     .map_err(AddRecordError::InvalidRecordReadLength)?;
@@ -68,6 +76,7 @@ impl Builder {
         options: &Options,
         reference_sequence_repository: &fasta::Repository,
         header: &sam::Header,
+        worker_pool: &WorkerPool,
     ) -> io::Result<DataContainer> {
         if !self.slice_builder.is_empty() {
             self.slice_builders.push(self.slice_builder);
@@ -83,22 +92,37 @@ impl Builder {
             options.encode_alignment_start_positions_as_deltas = false;
         }
 
+        if let Some(substitution_matrix) = &options.substitution_matrix {
+            if !substitution_matrix.has_distinct_codes() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "substitution matrix assigns the same code to more than one read base",
+                ));
+            }
+        }
+
         let compression_header = build_compression_header(&options, &self.slice_builders);
 
-        let record_counter = self.record_counter;
-        let slices = self
-            .slice_builders
-            .into_iter()
-            .map(|builder| {
-                builder.build(
-                    &options.block_content_encoder_map,
-                    reference_sequence_repository,
-                    header,
-                    &compression_header,
-                    record_counter,
-                )
-            })
-            .collect::<Result<_, _>>()?;
+        // Each slice starts at the record counter after the records in the previous slices.
+        let mut record_counter = self.record_counter;
+        let mut slices = Vec::with_capacity(self.slice_builders.len());
+
+        for builder in self.slice_builders {
+            let record_count = u64::try_from(builder.records().len())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            let slice = builder.build(
+                &options.block_content_encoder_map,
+                reference_sequence_repository,
+                header,
+                &compression_header,
+                record_counter,
+                worker_pool,
+            )?;
+
+            slices.push(slice);
+            record_counter += record_count;
+        }
 
         Ok(DataContainer {
             compression_header,
@@ -2,13 +2,11 @@
 
 mod builder;
 pub(crate) mod key;
-pub(crate) mod substitution_matrix;
+pub mod substitution_matrix;
 pub mod tag_ids_dictionary;
 
-pub(crate) use {
-    builder::Builder, key::Key, substitution_matrix::SubstitutionMatrix,
-    tag_ids_dictionary::TagIdsDictionary,
-};
+pub use self::substitution_matrix::SubstitutionMatrix;
+pub(crate) use {builder::Builder, key::Key, tag_ids_dictionary::TagIdsDictionary};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct PreservationMap {
@@ -1,4 +1,4 @@
-use super::{substitution_matrix, tag_ids_dictionary, PreservationMap};
+use super::{substitution_matrix, tag_ids_dictionary, PreservationMap, SubstitutionMatrix};
 use crate::{io::writer::Options, Record};
 
 #[derive(Debug)]
@@ -6,6 +6,7 @@ pub struct Builder {
     read_names_included: bool,
     ap_data_series_delta: bool,
     reference_required: bool,
+    substitution_matrix: Option<SubstitutionMatrix>,
     substitution_matrix_builder: substitution_matrix::Builder,
     tag_ids_dictionary_builder: tag_ids_dictionary::Builder,
 }
@@ -14,15 +15,22 @@ impl Builder {
     pub fn apply_options(&mut self, options: &Options) {
         self.read_names_included = options.preserve_read_names;
         self.ap_data_series_delta = options.encode_alignment_start_positions_as_deltas;
+        self.reference_required = options.reference_required;
+        self.substitution_matrix = options.substitution_matrix.clone();
     }
 
     pub fn update(&mut self, record: &Record) {
-        self.substitution_matrix_builder.update(record);
+        if self.substitution_matrix.is_none() {
+            self.substitution_matrix_builder.update(record);
+        }
+
         self.tag_ids_dictionary_builder.update(record);
     }
 
     pub(crate) fn build(self) -> PreservationMap {
-        let substitution_matrix = self.substitution_matrix_builder.build();
+        let substitution_matrix = self
+            .substitution_matrix
+            .unwrap_or_else(|| self.substitution_matrix_builder.build());
         let tag_ids_dictionary = self.tag_ids_dictionary_builder.build();
 
         PreservationMap::new(
@@ -43,6 +51,7 @@ impl Default for Builder {
             read_names_included: true,
             ap_data_series_delta: true,
             reference_required: true,
+            substitution_matrix: None,
             substitution_matrix_builder: substitution_matrix::Builder::default(),
             tag_ids_dictionary_builder: tag_ids_dictionary::Builder::default(),
         }
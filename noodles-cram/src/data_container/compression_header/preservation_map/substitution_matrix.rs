@@ -1,7 +1,9 @@
+//! CRAM data container preservation map substitution matrix.
+
 mod builder;
 mod histogram;
 
-pub use self::builder::Builder;
+pub(crate) use self::builder::Builder;
 
 use std::{cmp, error, fmt};
 
@@ -10,20 +12,57 @@ use crate::record::feature::substitution::Base;
 
 type Substitutions = [[Base; 4]; 5];
 
+/// A CRAM data container preservation map substitution matrix.
+///
+/// A substitution matrix maps a (reference base, read base) pair to a 2-bit substitution code.
+/// For each reference base (`A`, `C`, `G`, `T`, `N`), the four other bases are each assigned a
+/// distinct code. Assigning the lowest codes to the most frequent substitutions improves the
+/// compression of the base substitution code (`BS`) data series.
+///
+/// The serialized form (`SM`) is 5 bytes, one per reference base, in the order `ACGTN`. Each byte
+/// holds the four 2-bit codes of the read bases, in order of the remaining bases `ACGTN`, from the
+/// most significant bits. For example, `0x1b` (`0b00_01_10_11`) for the reference base `A` assigns
+/// codes 0, 1, 2, and 3 to the read bases `C`, `G`, `T`, and `N`, respectively.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubstitutionMatrix {
     substitutions: Substitutions,
 }
 
 impl SubstitutionMatrix {
-    pub fn builder() -> Builder {
-        Builder::default()
-    }
-
+    /// Returns the read base for the given reference base and substitution code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let matrix = SubstitutionMatrix::default();
+    /// assert_eq!(matrix.get(Base::A, 0b00), Base::C);
+    /// ```
     pub fn get(&self, reference_base: Base, substitution_code: u8) -> Base {
         self.substitutions[reference_base as usize][substitution_code as usize]
     }
 
+    /// Returns the substitution code for the given reference and read bases.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the reference and read bases are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let matrix = SubstitutionMatrix::default();
+    /// assert_eq!(matrix.find_code(Base::A, Base::C), 0b00);
+    /// ```
     pub fn find_code(&self, reference_base: Base, read_base: Base) -> u8 {
         for code in [0b00, 0b01, 0b10, 0b11] {
             if self.get(reference_base, code) == read_base {
@@ -33,6 +72,19 @@ impl SubstitutionMatrix {
 
         unreachable!();
     }
+
+    // Returns whether each reference base assigns a distinct code to each read base.
+    //
+    // A raw substitution matrix that assigns the same code to more than one read base is read as
+    // is, but it cannot be used to encode substitutions.
+    pub(crate) fn has_distinct_codes(&self) -> bool {
+        self.substitutions.iter().all(|read_bases| {
+            read_bases
+                .iter()
+                .enumerate()
+                .all(|(i, base)| !read_bases[i + 1..].contains(base))
+        })
+    }
 }
 
 impl Default for SubstitutionMatrix {
@@ -77,6 +129,7 @@ impl From<Histogram> for SubstitutionMatrix {
     }
 }
 
+/// An error returned when a raw substitution matrix fails to convert.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TryFromByteArrayError([u8; 5]);
 
@@ -215,6 +268,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_has_distinct_codes() -> Result<(), TryFromByteArrayError> {
+        assert!(SubstitutionMatrix::default().has_distinct_codes());
+
+        let matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x6c, 0xb1, 0xc6])?;
+        assert!(matrix.has_distinct_codes());
+
+        let matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x6c, 0xb1, 0x00])?;
+        assert!(!matrix.has_distinct_codes());
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_substitution_matrix_for_5_byte_array() {
         let matrix = SubstitutionMatrix {
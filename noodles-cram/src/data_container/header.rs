@@ -2,8 +2,11 @@ mod builder;
 
 pub use self::builder::Builder;
 
+use noodles_core::Position;
+
 use super::ReferenceSequenceContext;
 
+/// A CRAM data container header.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     length: usize,
@@ -17,35 +20,116 @@ pub struct Header {
 
 #[allow(clippy::len_without_is_empty)]
 impl Header {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the length of the container body, i.e., the size of the compression header block
+    /// and all slices.
     pub fn len(&self) -> usize {
         self.length
     }
 
-    pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
+    pub(crate) fn reference_sequence_context(&self) -> ReferenceSequenceContext {
         self.reference_sequence_context
     }
 
+    /// Returns the reference sequence ID of the records in the container.
+    ///
+    /// This is only set when all records in the container are placed on the same reference
+    /// sequence.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        match self.reference_sequence_context {
+            ReferenceSequenceContext::Some(context) => Some(context.reference_sequence_id()),
+            _ => None,
+        }
+    }
+
+    /// Returns the start position of the records in the container.
+    ///
+    /// This is only set when all records in the container are placed on the same reference
+    /// sequence.
+    pub fn alignment_start(&self) -> Option<Position> {
+        match self.reference_sequence_context {
+            ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
+            _ => None,
+        }
+    }
+
+    /// Returns the end position of the records in the container.
+    ///
+    /// This is only set when all records in the container are placed on the same reference
+    /// sequence.
+    pub fn alignment_end(&self) -> Option<Position> {
+        match self.reference_sequence_context {
+            ReferenceSequenceContext::Some(context) => Some(context.alignment_end()),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the container has records placed on multiple reference sequences.
+    pub fn is_multi_reference(&self) -> bool {
+        self.reference_sequence_context.is_many()
+    }
+
+    /// Returns the number of records in the container.
     pub fn record_count(&self) -> i32 {
         self.record_count
     }
 
+    /// Returns the index of the first record in the container relative to the start of the stream.
     pub fn record_counter(&self) -> u64 {
         self.record_counter
     }
 
+    /// Returns the number of read bases in the container.
     pub fn base_count(&self) -> u64 {
         self.base_count
     }
 
+    /// Returns the number of blocks in the container.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the start positions of the slices relative to the end of the container header.
     pub fn landmarks(&self) -> &[usize] {
         &self.landmarks
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_sequence_context() -> Result<(), noodles_core::position::TryFromIntError> {
+        let header = Header::builder()
+            .set_reference_sequence_context(ReferenceSequenceContext::some(
+                2,
+                Position::try_from(8)?,
+                Position::try_from(13)?,
+            ))
+            .build();
+
+        assert_eq!(header.reference_sequence_id(), Some(2));
+        assert_eq!(header.alignment_start(), Position::new(8));
+        assert_eq!(header.alignment_end(), Position::new(13));
+        assert!(!header.is_multi_reference());
+
+        let header = Header::builder()
+            .set_reference_sequence_context(ReferenceSequenceContext::Many)
+            .build();
+
+        assert!(header.reference_sequence_id().is_none());
+        assert!(header.alignment_start().is_none());
+        assert!(header.alignment_end().is_none());
+        assert!(header.is_multi_reference());
+
+        let header = Header::default();
+        assert!(header.reference_sequence_id().is_none());
+        assert!(!header.is_multi_reference());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use bytes::Bytes;
+
+use super::Header;
+
+/// An undecoded CRAM data container.
+///
+/// This holds the parsed container header, its raw bytes, and the raw bytes of the container
+/// body, i.e., the compression header block followed by the slices. The body is not decompressed
+/// or decoded, which allows it to be copied verbatim, e.g., when concatenating or repacking CRAM
+/// files.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawContainer {
+    header: Header,
+    header_src: Bytes,
+    src: Bytes,
+}
+
+impl RawContainer {
+    pub(crate) fn new(header: Header, header_src: Bytes, src: Bytes) -> Self {
+        Self {
+            header,
+            header_src,
+            src,
+        }
+    }
+
+    /// Returns the container header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the raw container header.
+    ///
+    /// This is the container header as read, including its CRC32.
+    pub fn header_src(&self) -> &[u8] {
+        &self.header_src
+    }
+
+    /// Returns the slice start positions relative to the start of the container body.
+    pub fn landmarks(&self) -> &[usize] {
+        self.header.landmarks()
+    }
+
+    /// Returns the raw container body.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.src
+    }
+
+    /// Returns the raw compression header block.
+    ///
+    /// This is the part of the container body before the first slice.
+    pub fn compression_header_src(&self) -> &[u8] {
+        let end = self
+            .landmarks()
+            .first()
+            .copied()
+            .unwrap_or(self.src.len())
+            .min(self.src.len());
+
+        &self.src[..end]
+    }
+
+    /// Returns an iterator over the raw slices.
+    ///
+    /// Each item is the undecoded bytes of a slice, i.e., its header block and data blocks.
+    pub fn slices(&self) -> impl Iterator<Item = &[u8]> {
+        let landmarks = self.landmarks();
+        let len = self.src.len();
+
+        landmarks.iter().enumerate().map(move |(i, &start)| {
+            let end = landmarks.get(i + 1).copied().unwrap_or(len).min(len);
+            let start = start.min(end);
+            &self.src[start..end]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slices() {
+        let header = Header::builder()
+            .set_length(8)
+            .set_landmarks(vec![2, 5])
+            .build();
+
+        let raw_container = RawContainer::new(
+            header,
+            Bytes::from_static(b"hhhh"),
+            Bytes::from_static(b"ccsssttt"),
+        );
+
+        assert_eq!(raw_container.header_src(), b"hhhh");
+
+        assert_eq!(raw_container.compression_header_src(), b"cc");
+
+        let slices: Vec<_> = raw_container.slices().collect();
+        assert_eq!(slices, [&b"sss"[..], &b"ttt"[..]]);
+    }
+}
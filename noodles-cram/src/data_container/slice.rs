@@ -3,7 +3,9 @@ pub(crate) mod header;
 
 pub use self::{builder::Builder, header::Header};
 
-use std::io;
+use std::{io, iter};
+
+use bytes::Bytes;
 
 use noodles_core::Position;
 use noodles_fasta as fasta;
@@ -12,7 +14,7 @@ use noodles_sam::{self as sam, alignment::record_buf::Name};
 use super::{CompressionHeader, ReferenceSequenceContext};
 use crate::{
     container::Block,
-    io::BitReader,
+    io::{BitReader, WorkerPool},
     record::{resolve, Features},
     Record,
 };
@@ -74,17 +76,29 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.records_with_worker_pool(compression_header, &WorkerPool::default())
+    }
+
+    /// Reads and returns a list of raw records in this slice, decompressing its blocks using the
+    /// given worker pool.
+    ///
+    /// The records are read after all blocks are decompressed, so the result is the same for any
+    /// worker count.
+    pub(crate) fn records_with_worker_pool(
+        &self,
+        compression_header: &CompressionHeader,
+        worker_pool: &WorkerPool,
+    ) -> io::Result<Vec<Record>> {
         use crate::io::reader::record::ExternalDataReaders;
 
-        let core_data_reader = self
-            .core_data_block
-            .decompressed_data()
-            .map(BitReader::new)?;
+        let mut blocks = self.decompress_blocks(worker_pool)?.into_iter();
+
+        // The core data block is always first.
+        let core_data_reader = BitReader::new(blocks.next().unwrap_or_default());
 
         let mut external_data_readers = ExternalDataReaders::new();
 
-        for block in self.external_blocks() {
-            let reader = block.decompressed_data()?;
+        for (block, reader) in self.external_blocks().iter().zip(blocks) {
             external_data_readers.insert(block.content_id(), reader);
         }
 
@@ -111,6 +125,19 @@ impl Slice {
         Ok(records)
     }
 
+    // Decompresses the core data block and external blocks, in that order.
+    //
+    // The blocks are independent, so each is decompressed as a separate job. Blocks share their
+    // data buffers, so they are cheap to clone into the pool.
+    fn decompress_blocks(&self, worker_pool: &WorkerPool) -> io::Result<Vec<Bytes>> {
+        let blocks: Vec<_> = iter::once(&self.core_data_block)
+            .chain(&self.external_blocks)
+            .cloned()
+            .collect();
+
+        worker_pool.map(blocks, |block| block.decompressed_data())
+    }
+
     /// Resolves records.
     ///
     /// This resolves mates, read names, bases, and quality scores.
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, io};
+use std::{collections::HashMap, io, sync::Arc};
 
 use bytes::Bytes;
 use md5::{Digest, Md5};
@@ -12,7 +12,7 @@ use crate::{
         compression_header::data_series_encoding_map::data_series::STANDARD_DATA_SERIES,
         BlockContentEncoderMap, CompressionHeader, ReferenceSequenceContext,
     },
-    io::{writer, BitWriter},
+    io::{writer, BitWriter, WorkerPool},
     record::Flags,
     Record,
 };
@@ -20,7 +20,6 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
-const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -47,8 +46,12 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
-        if self.records.len() >= MAX_RECORD_COUNT {
+    pub fn add_record(
+        &mut self,
+        record: Record,
+        max_record_count: usize,
+    ) -> Result<&Record, AddRecordError> {
+        if self.records.len() >= max_record_count {
             return Err(AddRecordError::SliceFull(record));
         }
 
@@ -83,12 +86,14 @@ impl Builder {
         header: &sam::Header,
         compression_header: &CompressionHeader,
         record_counter: u64,
+        worker_pool: &WorkerPool,
     ) -> io::Result<Slice> {
         let (core_data_block, external_blocks) = write_records(
             block_content_encoder_map,
             compression_header,
             self.reference_sequence_context,
             &mut self.records,
+            worker_pool,
         )?;
 
         let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
@@ -98,8 +103,14 @@ impl Builder {
             block_content_ids.push(block.content_id());
         }
 
+        let is_reference_required = compression_header
+            .preservation_map()
+            .is_reference_required();
+
+        // When the reference is not required, it may not be available, and readers do not check
+        // the checksum.
         let reference_md5 = match self.reference_sequence_context {
-            ReferenceSequenceContext::Some(context) => {
+            ReferenceSequenceContext::Some(context) if is_reference_required => {
                 let reference_sequence_name = header
                     .reference_sequences()
                     .get_index(context.reference_sequence_id())
@@ -137,22 +148,8 @@ fn write_records(
     compression_header: &CompressionHeader,
     reference_sequence_context: ReferenceSequenceContext,
     records: &mut [Record],
+    worker_pool: &WorkerPool,
 ) -> io::Result<(Block, Vec<Block>)> {
-    use crate::codecs::fqzcomp;
-
-    fn set_block_data(
-        builder: block::Builder,
-        buf: Vec<u8>,
-        encoder: Option<&Encoder>,
-    ) -> io::Result<block::Builder> {
-        match encoder {
-            Some(encoder) => builder.compress_and_set_data(buf, encoder.clone()),
-            None => Ok(builder
-                .set_uncompressed_len(buf.len())
-                .set_data(Bytes::from(buf))),
-        }
-    }
-
     let mut core_data_writer = BitWriter::new(Vec::new());
 
     let mut external_data_writers = HashMap::new();
@@ -184,56 +181,111 @@ fn write_records(
         record_writer.write_record(record)?;
     }
 
-    let core_data_block = core_data_writer.finish().and_then(|buf| {
-        let mut builder = Block::builder()
-            .set_content_type(block::ContentType::CoreData)
-            .set_content_id(block::ContentId::from(CORE_DATA_BLOCK_CONTENT_ID));
-
-        builder = set_block_data(builder, buf, block_content_encoder_map.core_data_encoder())?;
+    let buf = core_data_writer.finish()?;
+    let mut jobs = vec![BlockJob {
+        content_type: block::ContentType::CoreData,
+        content_id: block::ContentId::from(CORE_DATA_BLOCK_CONTENT_ID),
+        encoder: block_content_encoder_map.core_data_encoder().cloned(),
+        buf,
+    }];
+
+    let mut external_data = Vec::with_capacity(external_data_writers.len());
+    external_data.extend(
+        external_data_writers
+            .into_iter()
+            .filter(|(_, buf)| !buf.is_empty()),
+    );
 
-        Ok(builder.build())
-    })?;
-
-    let external_blocks: Vec<_> = external_data_writers
-        .into_iter()
-        .filter(|(_, buf)| !buf.is_empty())
-        .map(|(block_content_id, buf)| {
-            let mut builder = Block::builder()
-                .set_content_type(block::ContentType::ExternalData)
-                .set_content_id(block_content_id);
-
-            builder = if let Some(encoder) =
-                block_content_encoder_map.get_data_series_encoder(block_content_id)
-            {
-                match encoder {
-                    Some(Encoder::Fqzcomp) => {
-                        if all_quality_scores_stored_as_arrays {
-                            let lens: Vec<_> = records.iter().map(|r| r.read_length()).collect();
-                            let data = fqzcomp::encode(&lens, &buf)?;
-
-                            builder
-                                .set_uncompressed_len(buf.len())
-                                .set_compression_method(block::CompressionMethod::Fqzcomp)
-                                .set_data(Bytes::from(data))
-                        } else {
-                            set_block_data(builder, buf, Some(&Encoder::Gzip(Default::default())))?
-                        }
-                    }
-                    _ => set_block_data(builder, buf, encoder)?,
+    // The external data writers are unordered, so the blocks are sorted by content ID to make
+    // the output deterministic.
+    external_data.sort_unstable_by_key(|(block_content_id, _)| i32::from(*block_content_id));
+
+    for (block_content_id, buf) in external_data {
+        let encoder = if let Some(encoder) =
+            block_content_encoder_map.get_data_series_encoder(block_content_id)
+        {
+            match encoder {
+                Some(Encoder::Fqzcomp) if !all_quality_scores_stored_as_arrays => {
+                    Some(Encoder::Gzip(Default::default()))
                 }
-            } else if let Some(encoder) =
-                block_content_encoder_map.get_tag_values_encoders(block_content_id)
-            {
-                set_block_data(builder, buf, encoder)?
-            } else {
-                set_block_data(builder, buf, Some(&Encoder::Gzip(Default::default())))?
-            };
+                _ => encoder.cloned(),
+            }
+        } else if let Some(encoder) =
+            block_content_encoder_map.get_tag_values_encoders(block_content_id)
+        {
+            encoder.cloned()
+        } else {
+            Some(Encoder::Gzip(Default::default()))
+        };
+
+        jobs.push(BlockJob {
+            content_type: block::ContentType::ExternalData,
+            content_id: block_content_id,
+            encoder,
+            buf,
+        });
+    }
+
+    let read_lengths: Vec<_> = if jobs
+        .iter()
+        .any(|job| matches!(job.encoder, Some(Encoder::Fqzcomp)))
+    {
+        records.iter().map(|r| r.read_length()).collect()
+    } else {
+        Vec::new()
+    };
 
-            Ok(builder.build())
-        })
-        .collect::<io::Result<_>>()?;
+    let mut blocks = encode_blocks(jobs, read_lengths, worker_pool)?;
+    let core_data_block = blocks.remove(0);
+
+    Ok((core_data_block, blocks))
+}
+
+// An uncompressed block to be encoded.
+struct BlockJob {
+    content_type: block::ContentType,
+    content_id: block::ContentId,
+    encoder: Option<Encoder>,
+    buf: Vec<u8>,
+}
+
+impl BlockJob {
+    fn encode(self, read_lengths: &[usize]) -> io::Result<Block> {
+        use crate::codecs::fqzcomp;
+
+        let builder = Block::builder()
+            .set_content_type(self.content_type)
+            .set_content_id(self.content_id);
+
+        let builder = match self.encoder {
+            Some(Encoder::Fqzcomp) => {
+                let data = fqzcomp::encode(read_lengths, &self.buf)?;
+
+                builder
+                    .set_uncompressed_len(self.buf.len())
+                    .set_compression_method(block::CompressionMethod::Fqzcomp)
+                    .set_data(Bytes::from(data))
+            }
+            Some(encoder) => builder.compress_and_set_data(self.buf, encoder)?,
+            None => builder
+                .set_uncompressed_len(self.buf.len())
+                .set_data(Bytes::from(self.buf)),
+        };
+
+        Ok(builder.build())
+    }
+}
 
-    Ok((core_data_block, external_blocks))
+// Encodes the core data block and external blocks, in that order.
+//
+// The blocks are independent, so each is encoded as a separate job.
+fn encode_blocks(
+    jobs: Vec<BlockJob>,
+    read_lengths: Vec<usize>,
+    worker_pool: &WorkerPool,
+) -> io::Result<Vec<Block>> {
+    let read_lengths = Arc::new(read_lengths);
+    worker_pool.map(jobs, move |job| job.encode(&read_lengths))
 }
 
 fn set_mates(records: &mut [Record]) {
@@ -1,4 +1,10 @@
-use std::{cmp, collections::HashMap, fs::File, io, path::Path};
+use std::{
+    cmp,
+    collections::HashMap,
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
 
 use noodles_core::Position;
 
@@ -22,7 +28,35 @@ pub fn index<P>(src: P) -> io::Result<crai::Index>
 where
     P: AsRef<Path>,
 {
-    let mut reader = File::open(src).map(Reader::new)?;
+    File::open(src).and_then(index_from_reader)
+}
+
+/// Indexes CRAM data from a reader.
+///
+/// The reader is expected to be at the start of the stream.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_cram as cram;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+///
+/// let mut writer = cram::io::Writer::new(Vec::new());
+/// writer.write_header(&header)?;
+/// writer.try_finish(&header)?;
+///
+/// let index = cram::index_from_reader(Cursor::new(writer.get_ref()))?;
+/// assert!(index.is_empty());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn index_from_reader<R>(reader: R) -> io::Result<crai::Index>
+where
+    R: Read + Seek,
+{
+    let mut reader = Reader::new(reader);
     reader.read_header()?;
 
     let mut index = Vec::new();
@@ -4,7 +4,8 @@ mod bit_reader;
 mod bit_writer;
 pub mod indexed_reader;
 pub mod reader;
+mod worker_pool;
 pub mod writer;
 
-pub(crate) use self::{bit_reader::BitReader, bit_writer::BitWriter};
+pub(crate) use self::{bit_reader::BitReader, bit_writer::BitWriter, worker_pool::WorkerPool};
 pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
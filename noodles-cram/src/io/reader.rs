@@ -16,10 +16,18 @@ use std::io::{self, Read, Seek, SeekFrom};
 use bytes::BytesMut;
 use noodles_core::Region;
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{
+    self as sam,
+    alignment::io::{RecordMeta, RecordObserver},
+    header::resolve_region,
+};
 
 use crate::{
-    crai, data_container::DataContainer, file_definition::Version, FileDefinition, MAGIC_NUMBER,
+    crai,
+    data_container::{DataContainer, RawContainer},
+    file_definition::Version,
+    io::WorkerPool,
+    FileDefinition, Record, MAGIC_NUMBER,
 };
 
 /// A CRAM reader.
@@ -47,6 +55,8 @@ use crate::{
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    worker_pool: WorkerPool,
+    record_observer: Option<RecordObserver>,
     buf: BytesMut,
 }
 
@@ -114,6 +124,23 @@ where
         &self.reference_sequence_repository
     }
 
+    pub(crate) fn worker_pool(&self) -> &WorkerPool {
+        &self.worker_pool
+    }
+
+    pub(crate) fn observe_records(&self, records: &[Record]) {
+        if let Some(record_observer) = &self.record_observer {
+            for record in records {
+                // CRAM records are not stored contiguously, so they have no length.
+                record_observer.observe(&RecordMeta::new(
+                    None,
+                    record.reference_sequence_id(),
+                    !record.bam_flags().is_unmapped(),
+                ));
+            }
+        }
+    }
+
     /// Reads the CRAM file definition.
     ///
     /// The CRAM magic number is also checked.
@@ -213,10 +240,44 @@ where
         read_data_container(&mut self.inner, &mut self.buf)
     }
 
+    /// Reads a data container without decoding it.
+    ///
+    /// This parses the container header but leaves the container body, i.e., the compression
+    /// header and slices, as raw bytes. It is useful for copying containers verbatim.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// while let Some(raw_container) = reader.read_raw_container()? {
+    ///     for slice_src in raw_container.slices() {
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_raw_container(&mut self) -> io::Result<Option<RawContainer>> {
+        use self::data_container::read_raw_container;
+        read_raw_container(&mut self.inner, &mut self.buf)
+    }
+
     /// Returns a iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be at the start of a data container.
     ///
+    /// Records always have read names. Stored read names are returned as is. A record without a
+    /// read name, i.e., missing (`*`) or not preserved by the writer, is given a generated name:
+    /// the decimal string of its record counter (its 0-based position in the file), e.g., `0`,
+    /// `1`, etc. Mates in the same slice share the name of the first segment.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -306,15 +367,7 @@ where
         index: &'a crai::Index,
         region: &Region,
     ) -> io::Result<Query<'_, R>> {
-        let reference_sequence_id = header
-            .reference_sequences()
-            .get_index_of(region.name())
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "invalid reference sequence name",
-                )
-            })?;
+        let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
 
         Ok(Query::new(
             self,
@@ -432,4 +485,183 @@ mod tests {
             Err(ref e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
+
+    fn build_cram_with_containers(header: &sam::Header) -> io::Result<Vec<u8>> {
+        use sam::alignment::{io::Write as _, record::Flags, RecordBuf};
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for name in ["r0", "r1"] {
+            let record = RecordBuf::builder()
+                .set_name(name.as_bytes().into())
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(b"ACGT".to_vec().into())
+                .build();
+
+            writer.write_alignment_record(header, &record)?;
+            writer.flush(header)?;
+        }
+
+        writer.try_finish(header)?;
+
+        Ok(writer.get_ref().clone())
+    }
+
+    #[test]
+    fn test_read_raw_container() -> io::Result<()> {
+        let header = sam::Header::default();
+        let src = build_cram_with_containers(&header)?;
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let mut raw_containers = Vec::new();
+
+        while let Some(raw_container) = reader.read_raw_container()? {
+            raw_containers.push(raw_container);
+        }
+
+        assert_eq!(raw_containers.len(), 2);
+
+        for (i, raw_container) in raw_containers.iter().enumerate() {
+            let container_header = raw_container.header();
+            assert_eq!(container_header.record_count(), 1);
+            assert_eq!(container_header.record_counter(), i as u64);
+            assert!(container_header.reference_sequence_id().is_none());
+            assert_eq!(raw_container.as_bytes().len(), container_header.len());
+
+            let mut header_src = raw_container.header_src();
+            let actual = self::data_container::header::read_header(&mut header_src)?;
+            assert_eq!(actual.as_ref(), Some(container_header));
+            assert!(header_src.is_empty());
+        }
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for raw_container in &raw_containers {
+            writer.write_raw_container(&header, raw_container)?;
+        }
+
+        assert_eq!(writer.records_written(), 2);
+
+        writer.try_finish(&header)?;
+
+        assert_eq!(writer.get_ref(), &src);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::alignment::{
+            io::Write as _,
+            record::{data::field::Tag, Flags},
+            record_buf::{data::field::Value, QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..64 {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}").as_bytes().into())
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(Sequence::from(b"ACGTNACGTN".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![i % 41; 10]))
+                .set_data(
+                    [(Tag::ALIGNMENT_HIT_COUNT, Value::from(i))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.get_ref().clone();
+
+        let read_records = |worker_count| -> io::Result<Vec<_>> {
+            let mut reader = Builder::default()
+                .set_worker_count(worker_count)
+                .build_from_reader(&src[..]);
+
+            reader.read_header()?;
+            reader.records(&header).collect()
+        };
+
+        let expected = read_records(NonZeroUsize::MIN)?;
+        assert_eq!(expected.len(), 64);
+
+        for worker_count in [2, 3, 64] {
+            let actual = read_records(NonZeroUsize::try_from(worker_count)?)?;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_record_observer() -> Result<(), Box<dyn std::error::Error>> {
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        use sam::alignment::{
+            io::Write as _,
+            record::Flags,
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..8 {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}").as_bytes().into())
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(Sequence::from(b"ACGT".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![45; 4]))
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.get_ref().clone();
+
+        let unmapped_record_count = Arc::new(AtomicUsize::new(0));
+
+        let mut reader = {
+            let unmapped_record_count = Arc::clone(&unmapped_record_count);
+
+            Builder::default()
+                .set_record_observer(move |meta: &RecordMeta| {
+                    assert!(meta.byte_len().is_none());
+                    assert!(meta.reference_sequence_id().is_none());
+
+                    if !meta.is_mapped() {
+                        unmapped_record_count.fetch_add(1, Ordering::Relaxed);
+                    }
+                })
+                .build_from_reader(&src[..])
+        };
+
+        reader.read_header()?;
+        assert_eq!(reader.records(&header).count(), 8);
+        assert_eq!(unmapped_record_count.load(Ordering::Relaxed), 8);
+
+        Ok(())
+    }
 }
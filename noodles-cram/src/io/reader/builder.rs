@@ -1,18 +1,23 @@
 use std::{
     fs::File,
     io::{self, Read},
+    num::NonZeroUsize,
     path::Path,
 };
 
 use bytes::BytesMut;
 use noodles_fasta as fasta;
+use noodles_sam::alignment::io::{RecordMeta, RecordObserver};
 
 use super::Reader;
+use crate::io::WorkerPool;
 
 /// A CRAM reader builder.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    worker_count: NonZeroUsize,
+    record_observer: Option<RecordObserver>,
 }
 
 impl Builder {
@@ -37,6 +42,72 @@ impl Builder {
         self
     }
 
+    /// Sets the worker count.
+    ///
+    /// This is the number of threads in the reader's worker pool, which is used to decompress the
+    /// blocks of a slice. Blocks are independent, so they can be decompressed in parallel, e.g.,
+    /// when there are many rANS compressed external blocks. The pool is created with the reader
+    /// and reused for every slice. Records are decoded after all blocks of a slice are
+    /// decompressed, so the records read are the same for any worker count.
+    ///
+    /// By default, the worker count is 1, i.e., blocks are decompressed on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets a record observer.
+    ///
+    /// The observer is called for each record decoded, with the reference sequence ID and whether
+    /// the record is mapped. Records are decoded a container at a time, so the observer is called
+    /// for all records in a container before the first of them is returned. CRAM records are not
+    /// stored contiguously, so they have no length.
+    ///
+    /// Only the synchronous reader calls the observer. The async reader does not support record
+    /// observers.
+    ///
+    /// By default, no observer is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use noodles_cram::io::reader::Builder;
+    /// use noodles_sam::alignment::io::RecordMeta;
+    ///
+    /// let mapped_record_count = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let builder = {
+    ///     let mapped_record_count = Arc::clone(&mapped_record_count);
+    ///
+    ///     Builder::default().set_record_observer(move |meta: &RecordMeta| {
+    ///         if meta.is_mapped() {
+    ///             mapped_record_count.fetch_add(1, Ordering::Relaxed);
+    ///         }
+    ///     })
+    /// };
+    /// ```
+    pub fn set_record_observer<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RecordMeta) + Send + Sync + 'static,
+    {
+        self.record_observer = Some(RecordObserver::new(f));
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -70,7 +141,19 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            worker_pool: WorkerPool::new(self.worker_count),
+            record_observer: self.record_observer,
             buf: BytesMut::new(),
         }
     }
 }
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            worker_count: NonZeroUsize::MIN,
+            record_observer: None,
+        }
+    }
+}
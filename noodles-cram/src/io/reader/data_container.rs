@@ -9,7 +9,11 @@ use std::io::{self, Read};
 use bytes::{Bytes, BytesMut};
 
 use self::header::read_header;
-use crate::{container::block::ContentType, data_container::CompressionHeader, DataContainer};
+use crate::{
+    container::block::ContentType,
+    data_container::{CompressionHeader, RawContainer},
+    DataContainer,
+};
 
 pub fn read_data_container<R>(
     reader: &mut R,
@@ -69,6 +73,45 @@ where
     Ok(Some((header, data_container)))
 }
 
+pub fn read_raw_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<Option<RawContainer>>
+where
+    R: Read,
+{
+    let mut header_src = Vec::new();
+
+    let mut tee_reader = TeeReader {
+        inner: reader,
+        dst: &mut header_src,
+    };
+
+    let Some(header) = read_header(&mut tee_reader)? else {
+        return Ok(None);
+    };
+
+    buf.resize(header.len(), 0);
+    reader.read_exact(buf)?;
+    let src = buf.split().freeze();
+
+    Ok(Some(RawContainer::new(header, header_src.into(), src)))
+}
+
+// A reader that copies the bytes it reads.
+struct TeeReader<'a, R> {
+    inner: &'a mut R,
+    dst: &'a mut Vec<u8>,
+}
+
+impl<R> Read for TeeReader<'_, R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.dst.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
 pub(crate) fn read_compression_header_from_block(src: &mut Bytes) -> io::Result<CompressionHeader> {
     use super::container::read_block;
 
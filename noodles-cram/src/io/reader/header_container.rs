@@ -76,12 +76,25 @@ fn read_sam_header(block: &Block) -> io::Result<sam::Header> {
     let mut header_reader = reader.take(len);
     let mut buf = Vec::new();
 
+    // The header text is read line by line across the entire block payload, which may be
+    // decompressed in chunks. The declared length is checked at the end so that a truncated
+    // header is not silently returned as a partial header.
     while read_line(&mut header_reader, &mut buf)? != 0 {
         parser
             .parse_partial(&buf)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     }
 
+    if header_reader.limit() > 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "truncated SAM header: expected {len} bytes, got {}",
+                len - header_reader.limit()
+            ),
+        ));
+    }
+
     Ok(parser.finish())
 }
 
@@ -151,6 +164,81 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_sam_header_with_large_header() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Write, num::NonZeroUsize};
+
+        use flate2::{write::GzEncoder, Compression};
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        const REFERENCE_SEQUENCE_COUNT: usize = 50000;
+
+        let mut raw_header = String::from("@HD\tVN:1.6\n");
+        let mut builder = sam::Header::builder().set_header(Default::default());
+
+        for i in 0..REFERENCE_SEQUENCE_COUNT {
+            let name = format!("sq{i}");
+            let length = i + 1;
+
+            raw_header.push_str(&format!("@SQ\tSN:{name}\tLN:{length}\n"));
+
+            builder = builder.add_reference_sequence(
+                name,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(length)?),
+            );
+        }
+
+        let expected = builder.build();
+
+        let mut data = Vec::new();
+        data.put_i32_le(i32::try_from(raw_header.len())?);
+        data.extend(raw_header.as_bytes());
+        assert!(data.len() > 1 << 20);
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.clone().into())
+            .build();
+
+        assert_eq!(read_sam_header(&block)?, expected);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&data)?;
+        let compressed_data = encoder.finish()?;
+
+        let block = Block::builder()
+            .set_compression_method(CompressionMethod::Gzip)
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(compressed_data.into())
+            .build();
+
+        assert_eq!(read_sam_header(&block)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sam_header_with_truncated_header() {
+        let raw_header = b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n";
+
+        let mut data = Vec::new();
+        data.put_i32_le(64);
+        data.extend(raw_header);
+
+        let block = Block::builder()
+            .set_content_type(ContentType::FileHeader)
+            .set_uncompressed_len(data.len())
+            .set_data(data.into())
+            .build();
+
+        assert!(matches!(
+            read_sam_header(&block),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
     #[test]
     fn test_read_sam_header_with_invalid_compression_method() {
         let block = Block::builder()
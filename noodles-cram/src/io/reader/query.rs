@@ -76,16 +76,18 @@ where
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
+                slice
+                    .records_with_worker_pool(compression_header, self.reader.worker_pool())
+                    .and_then(|mut records| {
+                        slice.resolve_records(
+                            self.reader.reference_sequence_repository(),
+                            self.header,
+                            compression_header,
+                            &mut records,
+                        )?;
+
+                        Ok(records)
+                    })
             })
             .collect::<Result<Vec<_>, _>>();
 
@@ -94,11 +96,9 @@ where
             Err(e) => return Some(Err(e)),
         };
 
-        self.records = records
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
+        let records: Vec<_> = records.into_iter().flatten().collect();
+        self.reader.observe_records(&records);
+        self.records = records.into_iter();
 
         Some(Ok(()))
     }
@@ -30,6 +30,8 @@ use crate::{
 pub enum ReadRecordError {
     MissingDataSeriesEncoding(DataSeries),
     MissingTagEncoding(tag_ids_dictionary::Key),
+    UnsortedFeatures,
+    InvalidFeaturePositionDelta(i32),
 }
 
 impl error::Error for ReadRecordError {}
@@ -41,6 +43,10 @@ impl fmt::Display for ReadRecordError {
                 write!(f, "missing data series encoding: {data_series:?}")
             }
             Self::MissingTagEncoding(key) => write!(f, "missing tag encoding: {key:?}"),
+            Self::UnsortedFeatures => f.write_str("features are not sorted by position"),
+            Self::InvalidFeaturePositionDelta(n) => {
+                write!(f, "invalid feature position delta: {n}")
+            }
         }
     }
 }
@@ -233,7 +239,9 @@ where
     }
 
     fn read_read_name(&mut self) -> io::Result<Option<sam::alignment::record_buf::Name>> {
-        const MISSING: &[u8] = &[b'*', 0x00];
+        const MISSING: &[u8] = b"*";
+        // Previous versions wrote missing read names with a trailing NUL.
+        const LEGACY_MISSING: &[u8] = &[b'*', 0x00];
 
         let buf = self
             .compression_header
@@ -248,7 +256,7 @@ where
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
         let name = match &buf[..] {
-            MISSING => None,
+            MISSING | LEGACY_MISSING => None,
             _ => Some(sam::alignment::record_buf::Name::from(buf)),
         };
 
@@ -445,6 +453,13 @@ where
             record.features.push(feature);
         }
 
+        if !record.features.is_sorted_by_position() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ReadRecordError::UnsortedFeatures,
+            ));
+        }
+
         record.mapping_quality = self.read_mapping_quality()?;
 
         if flags.are_quality_scores_stored_as_array() {
@@ -561,7 +576,12 @@ where
             })?
             .decode(&mut self.core_data_reader, &mut self.external_data_readers)
             .and_then(|n| {
-                usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                usize::try_from(n).map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        ReadRecordError::InvalidFeaturePositionDelta(n),
+                    )
+                })
             })
     }
 
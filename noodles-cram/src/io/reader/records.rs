@@ -37,28 +37,32 @@ where
             return Ok(true);
         };
 
-        self.records = container
+        let records = container
             .slices()
             .iter()
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
+                slice
+                    .records_with_worker_pool(compression_header, self.reader.worker_pool())
+                    .and_then(|mut records| {
+                        slice.resolve_records(
+                            self.reader.reference_sequence_repository(),
+                            self.header,
+                            compression_header,
+                            &mut records,
+                        )?;
 
-                    Ok(records)
-                })
+                        Ok(records)
+                    })
             })
             .collect::<Result<Vec<_>, _>>()?
             .into_iter()
             .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
+            .collect::<Vec<_>>();
+
+        self.reader.observe_records(&records);
+        self.records = records.into_iter();
 
         Ok(false)
     }
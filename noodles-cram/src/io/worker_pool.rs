@@ -0,0 +1,173 @@
+use std::{
+    fmt, io,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    thread::{self, JoinHandle},
+};
+
+use crossbeam_channel::Sender;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+// A fixed-size pool of worker threads.
+//
+// The threads are spawned once, when the pool is created, and are shared by every call to
+// `map`. Jobs are taken from a single queue, so an idle worker takes the next pending job
+// regardless of which call submitted it.
+//
+// A pool with a worker count of 1 does not spawn any threads; jobs are run on the calling thread.
+pub(crate) struct WorkerPool {
+    worker_count: NonZeroUsize,
+    job_tx: Option<Sender<Job>>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    pub fn new(worker_count: NonZeroUsize) -> Self {
+        if worker_count.get() == 1 {
+            return Self::default();
+        }
+
+        let (job_tx, job_rx) = crossbeam_channel::unbounded::<Job>();
+
+        let handles = (0..worker_count.get())
+            .map(|_| {
+                let job_rx = job_rx.clone();
+
+                thread::spawn(move || {
+                    while let Ok(job) = job_rx.recv() {
+                        job();
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            worker_count,
+            job_tx: Some(job_tx),
+            handles,
+        }
+    }
+
+    // Applies `f` to each item, in parallel when the pool has workers.
+    //
+    // Each item is a separate job. The results are in item order regardless of which worker
+    // finishes first. A panic in `f` is resumed on the calling thread.
+    pub fn map<T, U, F>(&self, items: Vec<T>, f: F) -> io::Result<Vec<U>>
+    where
+        T: Send + 'static,
+        U: Send + 'static,
+        F: Fn(T) -> io::Result<U> + Send + Sync + 'static,
+    {
+        let job_tx = match &self.job_tx {
+            Some(job_tx) if items.len() > 1 => job_tx,
+            _ => return items.into_iter().map(f).collect(),
+        };
+
+        let item_count = items.len();
+        let f = Arc::new(f);
+        let (result_tx, result_rx) = crossbeam_channel::bounded(item_count);
+
+        for (i, item) in items.into_iter().enumerate() {
+            let f = Arc::clone(&f);
+            let result_tx = result_tx.clone();
+
+            let job = Box::new(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)));
+                // The receiver is only dropped after all results are received.
+                result_tx.send((i, result)).ok();
+            });
+
+            job_tx
+                .send(job)
+                .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "worker pool closed"))?;
+        }
+
+        drop(result_tx);
+
+        let mut results: Vec<Option<io::Result<U>>> = (0..item_count).map(|_| None).collect();
+
+        for (i, result) in result_rx {
+            let result = result.unwrap_or_else(|e| panic::resume_unwind(e));
+            results[i] = Some(result);
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("missing worker result"))
+            .collect()
+    }
+}
+
+impl Default for WorkerPool {
+    fn default() -> Self {
+        Self {
+            worker_count: NonZeroUsize::MIN,
+            job_tx: None,
+            handles: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for WorkerPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WorkerPool")
+            .field("worker_count", &self.worker_count)
+            .finish()
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        // Closing the queue stops the workers once the pending jobs are done.
+        drop(self.job_tx.take());
+
+        for handle in self.handles.drain(..) {
+            handle.join().ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() -> Result<(), Box<dyn std::error::Error>> {
+        let items: Vec<_> = (0..64).collect();
+        let expected: Vec<_> = items.iter().map(|n| n * 2).collect();
+
+        for worker_count in [1, 2, 3, 8] {
+            let pool = WorkerPool::new(NonZeroUsize::try_from(worker_count)?);
+
+            // The pool is reused across calls.
+            for _ in 0..2 {
+                let actual = pool.map(items.clone(), |n| Ok(n * 2))?;
+                assert_eq!(actual, expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_with_error() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = WorkerPool::new(NonZeroUsize::try_from(4)?);
+
+        let result = pool.map((0..8).collect(), |n| {
+            if n == 5 {
+                Err(io::Error::from(io::ErrorKind::InvalidData))
+            } else {
+                Ok(n)
+            }
+        });
+
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidData));
+
+        // The pool is still usable after an error.
+        assert_eq!(pool.map(vec![1, 2], Ok)?, [1, 2]);
+
+        Ok(())
+    }
+}
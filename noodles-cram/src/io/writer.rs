@@ -7,9 +7,10 @@ pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
 pub(crate) mod record;
+mod tag_filter;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, tag_filter::TagFilter};
 
 use std::{
     io::{self, Write},
@@ -19,7 +20,10 @@ use std::{
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, header::ReferenceSequences};
 
-use crate::{file_definition::Version, DataContainer, FileDefinition, Record, MAGIC_NUMBER};
+use crate::{
+    data_container::RawContainer, file_definition::Version, io::WorkerPool, DataContainer,
+    FileDefinition, Record, MAGIC_NUMBER,
+};
 
 /// A CRAM writer.
 ///
@@ -52,7 +56,9 @@ where
     reference_sequence_repository: fasta::Repository,
     options: Options,
     data_container_builder: crate::data_container::Builder,
+    worker_pool: WorkerPool,
     record_counter: u64,
+    records_written: u64,
 }
 
 impl<W> Writer<W>
@@ -84,6 +90,32 @@ where
         &self.inner
     }
 
+    /// Returns the number of records written.
+    ///
+    /// Records are buffered and only counted once the data container they are part of is written.
+    /// This is reset to 0 when the writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::io::Writer::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let record = cram::Record::default();
+    /// writer.write_record(&header, record)?;
+    /// assert_eq!(writer.records_written(), 0);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records_written(&self) -> u64 {
+        self.records_written
+    }
+
     /// Attempts to finish the output stream by writing any pending containers and a final EOF
     /// container.
     ///
@@ -104,7 +136,8 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
-        self.flush(header)?;
+        self.write_data_container(header)?;
+        self.records_written = 0;
         write_eof_container(&mut self.inner)
     }
 
@@ -217,8 +250,15 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use crate::data_container::builder::AddRecordError;
 
+        if let Some(tag_filter) = &self.options.tag_filter {
+            tag_filter.apply(&mut record);
+        }
+
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -226,7 +266,7 @@ where
                 Err(e) => match e {
                     AddRecordError::ContainerFull(r) => {
                         record = r;
-                        self.flush(header)?;
+                        self.write_data_container(header)?;
                     }
                     AddRecordError::SliceFull(r) => {
                         record = r;
@@ -237,7 +277,99 @@ where
         }
     }
 
-    fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
+    /// Writes any pending records as a data container and flushes the underlying writer.
+    ///
+    /// Records are otherwise buffered until a data container is full (see
+    /// [`Builder::set_records_per_slice`] and
+    /// [`Builder::set_slices_per_container`]). After a flush, a reader can read all
+    /// records written up to this point, e.g., when writing to a pipe. Each flush with pending
+    /// records ends a container early, which reduces the compression ratio.
+    ///
+    /// This does not write the EOF container. Use [`Self::try_finish`] to finish the stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::io::Writer::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    ///
+    /// let record = cram::Record::default();
+    /// writer.write_record(&header, record)?;
+    /// assert_eq!(writer.records_written(), 0);
+    ///
+    /// writer.flush(&header)?;
+    /// assert_eq!(writer.records_written(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.write_data_container(header)?;
+        self.inner.flush()
+    }
+
+    /// Writes a raw data container.
+    ///
+    /// Any pending records are first written in their own data container. The raw container, i.e.,
+    /// its header and body, is then written as is, e.g., to copy containers read using
+    /// [`crate::io::Reader::read_raw_container`] into a new file without decoding them.
+    ///
+    /// The container header is not changed. Its record counter refers to the position of its first
+    /// record in the source file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let mut writer = cram::io::Writer::new(Vec::new());
+    /// writer.write_header(&header)?;
+    /// writer.write_record(&header, cram::Record::default())?;
+    /// writer.try_finish(&header)?;
+    ///
+    /// let mut reader = cram::io::Reader::new(&writer.get_ref()[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut writer = cram::io::Writer::new(Vec::new());
+    /// writer.write_header(&header)?;
+    ///
+    /// while let Some(raw_container) = reader.read_raw_container()? {
+    ///     writer.write_raw_container(&header, &raw_container)?;
+    /// }
+    ///
+    /// writer.try_finish(&header)?;
+    /// assert_eq!(writer.records_written(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_raw_container(
+        &mut self,
+        header: &sam::Header,
+        raw_container: &RawContainer,
+    ) -> io::Result<()> {
+        self.write_data_container(header)?;
+
+        self.inner.write_all(raw_container.header_src())?;
+        self.inner.write_all(raw_container.as_bytes())?;
+
+        let record_count = u64::try_from(raw_container.header().record_count())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        self.record_counter += record_count;
+        self.data_container_builder = DataContainer::builder(self.record_counter);
+        self.records_written += record_count;
+
+        Ok(())
+    }
+
+    fn write_data_container(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::data_container::write_data_container;
 
         if self.data_container_builder.is_empty() {
@@ -250,14 +382,20 @@ where
         );
 
         let base_count = data_container_builder.base_count();
+        let record_count = self.record_counter - data_container_builder.record_counter();
 
         let data_container = data_container_builder.build(
             &self.options,
             &self.reference_sequence_repository,
             header,
+            &self.worker_pool,
         )?;
 
-        write_data_container(&mut self.inner, &data_container, base_count)
+        write_data_container(&mut self.inner, &data_container, base_count)?;
+
+        self.records_written += record_count;
+
+        Ok(())
     }
 }
 
@@ -315,10 +453,10 @@ pub(crate) fn add_missing_reference_sequence_checksums(
             .other_fields_mut()
             .entry(tag::MD5_CHECKSUM)
         {
-            let sequence = reference_sequence_repository
-                .get(name)
-                .transpose()?
-                .expect("missing reference sequence");
+            // A missing checksum is an error when the header is written.
+            let Some(sequence) = reference_sequence_repository.get(name).transpose()? else {
+                continue;
+            };
 
             let checksum = calculate_normalized_sequence_digest(&sequence[..]);
 
@@ -398,4 +536,511 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_records_per_slice_and_slices_per_container(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(2)?)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let mut counts = Vec::new();
+
+        while let Some(raw_container) = reader.read_raw_container()? {
+            let header = raw_container.header();
+            counts.push((header.record_count(), raw_container.slices().count()));
+        }
+
+        assert_eq!(counts, [(4, 2), (1, 1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_slices_per_container_and_generated_read_names(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::{collections::HashSet, num::NonZeroUsize};
+
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(3)?)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for _ in 0..8 {
+            writer.write_alignment_record(&header, &RecordBuf::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .records(&header)
+            .map(|result| {
+                result.map(|record| record.name().map(|name| <[u8]>::to_vec(name.as_ref())))
+            })
+            .collect::<io::Result<_>>()?;
+
+        // Read names are generated from the record counter, which continues across slices.
+        let expected: Vec<_> = (0..8).map(|i| Some(i.to_string().into_bytes())).collect();
+        assert_eq!(names, expected);
+
+        let unique_names: HashSet<_> = names.iter().collect();
+        assert_eq!(unique_names.len(), names.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_read_names() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::{Definition, Sequence};
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        fn build_mate(flags: Flags, alignment_start: usize, sequence: &[u8]) -> RecordBuf {
+            RecordBuf::builder()
+                .set_name(b"r3".into())
+                .set_flags(Flags::SEGMENTED | flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(alignment_start).unwrap())
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(sequence.to_vec().into())
+                .set_quality_scores(vec![45, 35, 43, 50].into())
+                .build()
+        }
+
+        fn t(preserve_read_names: bool) -> io::Result<Vec<Option<Vec<u8>>>> {
+            let repository = fasta::Repository::new(vec![fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"TTCACCCA".to_vec()),
+            )]);
+
+            let header = sam::Header::builder()
+                .add_reference_sequence(
+                    "sq0",
+                    Map::<ReferenceSequence>::new(NonZeroUsize::new(8).unwrap()),
+                )
+                .build();
+
+            let mut writer = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .preserve_read_names(preserve_read_names)
+                .build_with_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            let records = [
+                RecordBuf::builder().set_name(b"r0".into()).build(),
+                RecordBuf::builder().build(),
+                RecordBuf::builder().set_name(b"r2".into()).build(),
+                // A mate pair in the same slice.
+                build_mate(Flags::FIRST_SEGMENT, 1, b"TTCA"),
+                build_mate(Flags::LAST_SEGMENT, 5, b"CCCA"),
+            ];
+
+            for record in &records {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            let mut reader = crate::io::reader::Builder::default()
+                .set_reference_sequence_repository(repository)
+                .build_from_reader(&writer.get_ref()[..]);
+
+            reader.read_header()?;
+
+            reader
+                .records(&header)
+                .map(|result| {
+                    result.map(|record| record.name().map(|name| <[u8]>::to_vec(name.as_ref())))
+                })
+                .collect()
+        }
+
+        assert_eq!(
+            t(true)?,
+            [
+                Some(b"r0".to_vec()),
+                Some(b"1".to_vec()),
+                Some(b"r2".to_vec()),
+                Some(b"r3".to_vec()),
+                Some(b"r3".to_vec()),
+            ]
+        );
+
+        // Records without a mate in the same slice are detached, and detached records always
+        // store their read names. The names of mates are discarded and generated from the record
+        // counter of the first segment.
+        assert_eq!(
+            t(false)?,
+            [
+                Some(b"r0".to_vec()),
+                Some(b"1".to_vec()),
+                Some(b"r2".to_vec()),
+                Some(b"3".to_vec()),
+                Some(b"3".to_vec()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_tag_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write as _,
+            record::data::field::Tag,
+            record_buf::{data::field::Value, Data},
+            RecordBuf,
+        };
+
+        fn t(tag_filter: TagFilter) -> io::Result<Vec<Vec<Tag>>> {
+            let header = sam::Header::default();
+
+            let mut writer = Builder::default()
+                .set_tag_filter(tag_filter)
+                .build_with_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            let data: Data = [
+                (Tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+                (Tag::ORIGINAL_QUALITY_SCORES, Value::from("NDLS")),
+                (Tag::COMMENT, Value::from("noodles")),
+            ]
+            .into_iter()
+            .collect();
+
+            let record = RecordBuf::builder().set_data(data).build();
+            writer.write_alignment_record(&header, &record)?;
+
+            writer.try_finish(&header)?;
+
+            let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+            reader.read_header()?;
+
+            reader
+                .records(&header)
+                .map(|result| result.map(|record| record.tags().keys().collect()))
+                .collect()
+        }
+
+        let tag_filter = TagFilter::Drop([Tag::ORIGINAL_QUALITY_SCORES].into_iter().collect());
+        assert_eq!(
+            t(tag_filter)?,
+            [vec![Tag::ALIGNMENT_HIT_COUNT, Tag::COMMENT]]
+        );
+
+        let tag_filter = TagFilter::Keep([Tag::ORIGINAL_QUALITY_SCORES].into_iter().collect());
+        assert_eq!(t(tag_filter)?, [vec![Tag::ORIGINAL_QUALITY_SCORES]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_without_reference_required() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::cigar::{op::Kind, Op},
+                RecordBuf,
+            },
+            header::record::value::{
+                map::{reference_sequence::tag, ReferenceSequence},
+                Map,
+            },
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::builder()
+                    .set_length(NonZeroUsize::try_from(8)?)
+                    .insert(tag::MD5_CHECKSUM, "be19336b7e15968f7ac7dc82493d9cd8")
+                    .build()?,
+            )
+            .build();
+
+        // No reference sequence repository is set.
+        let mut writer = Builder::default()
+            .set_reference_required(false)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![45, 35, 43, 50].into())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 1),
+                        Op::new(Kind::Deletion, 1),
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::SoftClip, 1),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(b"TGCA".to_vec().into())
+                .set_quality_scores(vec![45, 35, 43, 50].into())
+                .build(),
+        ];
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let container = reader.read_data_container()?.unwrap();
+        assert!(!container
+            .compression_header()
+            .preservation_map()
+            .is_reference_required());
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.sequence().as_ref().to_vec()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, [b"ACGT".to_vec(), b"TGCA".to_vec()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::alignment::{
+            io::Write as _,
+            record::data::field::Tag,
+            record_buf::{data::field::Value, Data},
+            RecordBuf,
+        };
+
+        fn t(worker_count: NonZeroUsize) -> io::Result<Vec<u8>> {
+            let header = sam::Header::default();
+
+            let mut writer = Builder::default()
+                .set_records_per_slice(NonZeroUsize::try_from(8).unwrap())
+                .set_worker_count(worker_count)
+                .build_with_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            for i in 0..32 {
+                let data: Data = [
+                    (Tag::ALIGNMENT_HIT_COUNT, Value::from(i)),
+                    (Tag::COMMENT, Value::from("noodles")),
+                ]
+                .into_iter()
+                .collect();
+
+                let record = RecordBuf::builder()
+                    .set_name(format!("r{i}").into_bytes().into())
+                    .set_sequence(b"ACGTACGT".to_vec().into())
+                    .set_quality_scores(vec![45, 35, 43, 50, 45, 35, 43, 50].into())
+                    .set_data(data)
+                    .build();
+
+                writer.write_alignment_record(&header, &record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            Ok(writer.get_ref().clone())
+        }
+
+        let expected = t(NonZeroUsize::MIN)?;
+
+        for worker_count in [2, 4, 64] {
+            let actual = t(NonZeroUsize::try_from(worker_count)?)?;
+            assert!(actual == expected);
+        }
+
+        let header = sam::Header::default();
+        let mut reader = crate::io::Reader::new(&expected[..]);
+        reader.read_header()?;
+        assert_eq!(reader.records(&header).count(), 32);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_substitution_matrix() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::{Definition, Sequence};
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::data_container::compression_header::preservation_map::SubstitutionMatrix;
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"TTCACCCA".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        // T -> [G, N, A, C]
+        let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x6c, 0xb1, 0xc6])?;
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .set_substitution_matrix(substitution_matrix.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let records = [
+            // sq0:1-4 = TTCA, with a T -> A substitution at 1
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ATCA".to_vec().into())
+                .set_quality_scores(vec![45, 35, 43, 50].into())
+                .build(),
+            RecordBuf::builder()
+                .set_sequence(b"ACGT".to_vec().into())
+                .build(),
+        ];
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let container = reader.read_data_container()?.unwrap();
+
+        assert_eq!(
+            container
+                .compression_header()
+                .preservation_map()
+                .substitution_matrix(),
+            &substitution_matrix
+        );
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&writer.get_ref()[..]);
+
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.sequence().as_ref().to_vec()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, [b"ATCA".to_vec(), b"ACGT".to_vec()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_non_distinct_substitution_matrix(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::data_container::compression_header::preservation_map::SubstitutionMatrix;
+
+        let header = sam::Header::default();
+
+        // N -> [A, A, A, A]
+        let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x6c, 0xb1, 0x00])?;
+
+        let mut writer = Builder::default()
+            .set_substitution_matrix(substitution_matrix)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+
+        assert!(matches!(
+            writer.try_finish(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -1,22 +1,28 @@
 use std::{
     fs::File,
     io::{self, Write},
+    num::NonZeroUsize,
     path::Path,
 };
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
+use super::{Options, TagFilter, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder,
+    data_container::{
+        compression_header::preservation_map::SubstitutionMatrix, BlockContentEncoderMap,
+    },
+    file_definition::Version,
+    io::WorkerPool,
     DataContainer,
 };
 
 /// A CRAM writer builder.
-#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: NonZeroUsize,
 }
 
 impl Builder {
@@ -42,7 +48,11 @@ impl Builder {
 
     /// Sets whether to preserve read names.
     ///
-    /// If `false`, read names are discarded.
+    /// If `false`, read names are discarded, except for records that have no mate in the same
+    /// slice (detached records), which always store their read names. When read, a discarded read
+    /// name is generated from the record counter, and mates share the name of the first segment.
+    ///
+    /// A missing read name (`*`) is always written as missing, regardless of this option.
     ///
     /// The default is `true`.
     ///
@@ -75,6 +85,32 @@ impl Builder {
         self
     }
 
+    /// Sets whether decoding requires the reference sequence.
+    ///
+    /// If `false`, the compression header marks the reference as not required (`RR` is false),
+    /// and the output is decodable without a reference sequence repository. The writer also does
+    /// not need one, and slice reference MD5 checksums are zeros. However, CRAM requires an MD5
+    /// checksum (`M5`) for each header reference sequence, and it can only be calculated when the
+    /// sequence is in the repository. Otherwise, it must be set in the given header.
+    ///
+    /// Read bases are always stored as literal bases rather than as differences to the reference,
+    /// so this does not change the size of the record data. It does lose the reference checks done
+    /// when reading. Reference-based compression, which this writer does not implement, would
+    /// require a reference and produce smaller output.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_reference_required(false);
+    /// ```
+    pub fn set_reference_required(mut self, reference_required: bool) -> Self {
+        self.options.reference_required = reference_required;
+        self
+    }
+
     /// Sets the block content-encoder map.
     ///
     /// # Examples
@@ -91,6 +127,119 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// Smaller slices allow finer-grained random access (e.g., via a CRAI index) but generally
+    /// compress worse.
+    ///
+    /// The default is 10240.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_records_per_slice(NonZeroUsize::try_from(1024)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// A container is written when it has this number of full slices.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_slices_per_container(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets the filter of the data fields written for each record.
+    ///
+    /// This can be used to drop large fields that are not needed, e.g., original quality scores
+    /// (`OQ`), or to keep only a list of fields. The filter is not checked against fields that
+    /// downstream tools may require, e.g., the read group (`RG`).
+    ///
+    /// By default, all fields are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::{Builder, TagFilter};
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    ///
+    /// let tag_filter = TagFilter::Drop([Tag::ORIGINAL_QUALITY_SCORES].into_iter().collect());
+    /// let builder = Builder::default().set_tag_filter(tag_filter);
+    /// ```
+    pub fn set_tag_filter(mut self, tag_filter: TagFilter) -> Self {
+        self.options.tag_filter = Some(tag_filter);
+        self
+    }
+
+    /// Sets the substitution matrix.
+    ///
+    /// The substitution matrix assigns the codes written for base substitutions (see
+    /// [`SubstitutionMatrix`] for its format). It is stored in the preservation map of each
+    /// container's compression header, which readers use to decode the substitutions.
+    ///
+    /// By default, the substitution matrix is computed for each container from the frequencies of
+    /// its substitutions, i.e., the most frequent substitutions get the lowest codes.
+    ///
+    /// Writing fails if the substitution matrix assigns the same code to more than one read base
+    /// for a reference base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     io::writer::Builder,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::try_from([0x1b, 0x1b, 0x1b, 0x1b, 0x1b])?;
+    /// let builder = Builder::default().set_substitution_matrix(substitution_matrix);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_substitution_matrix(mut self, substitution_matrix: SubstitutionMatrix) -> Self {
+        self.options.substitution_matrix = Some(substitution_matrix);
+        self
+    }
+
+    /// Sets the worker count.
+    ///
+    /// This is the number of threads in the writer's worker pool, which is used to compress the
+    /// blocks of a slice. Blocks are independent, so they can be compressed in parallel, e.g.,
+    /// when external blocks use CPU bound codecs such as rANS. The pool is created with the
+    /// writer and reused for every slice. Records are encoded and laid out before any blocks are
+    /// compressed, so the output is the same for any worker count.
+    ///
+    /// By default, the worker count is 1, i.e., blocks are compressed on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -128,7 +277,19 @@ impl Builder {
             reference_sequence_repository: self.reference_sequence_repository,
             options: self.options,
             data_container_builder: DataContainer::builder(0),
+            worker_pool: WorkerPool::new(self.worker_count),
             record_counter: 0,
+            records_written: 0,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            options: Options::default(),
+            worker_count: NonZeroUsize::MIN,
         }
     }
 }
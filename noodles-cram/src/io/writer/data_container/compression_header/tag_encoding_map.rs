@@ -16,8 +16,16 @@ where
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
     write_itf8(&mut buf, map_len)?;
 
-    for (&key, encoding) in tag_encoding_map.iter() {
-        write_itf8(&mut buf, i32::from(key))?;
+    // The map is unordered, so the entries are sorted by key to make the output deterministic.
+    let mut entries: Vec<_> = tag_encoding_map
+        .iter()
+        .map(|(&key, encoding)| (i32::from(key), encoding))
+        .collect();
+
+    entries.sort_unstable_by_key(|(key, _)| *key);
+
+    for (key, encoding) in entries {
+        write_itf8(&mut buf, key)?;
         write_encoding_for_byte_array_codec(&mut buf, encoding)?;
     }
 
@@ -1,11 +1,31 @@
-use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
+use std::num::NonZeroUsize;
+
+use super::TagFilter;
+use crate::{
+    data_container::{
+        compression_header::preservation_map::SubstitutionMatrix, BlockContentEncoderMap,
+    },
+    file_definition::Version,
+};
+
+pub(crate) const DEFAULT_RECORDS_PER_SLICE: NonZeroUsize = match NonZeroUsize::new(10240) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+pub(crate) const DEFAULT_SLICES_PER_CONTAINER: NonZeroUsize = NonZeroUsize::MIN;
 
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub reference_required: bool,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
+    pub records_per_slice: NonZeroUsize,
+    pub slices_per_container: NonZeroUsize,
+    pub tag_filter: Option<TagFilter>,
+    pub substitution_matrix: Option<SubstitutionMatrix>,
 }
 
 impl Default for Options {
@@ -13,8 +33,13 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            reference_required: true,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
+            records_per_slice: DEFAULT_RECORDS_PER_SLICE,
+            slices_per_container: DEFAULT_SLICES_PER_CONTAINER,
+            tag_filter: None,
+            substitution_matrix: None,
         }
     }
 }
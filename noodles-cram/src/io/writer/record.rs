@@ -251,7 +251,7 @@ where
         &mut self,
         name: Option<&sam::alignment::record_buf::Name>,
     ) -> io::Result<()> {
-        const MISSING: &[u8] = &[b'*', 0x00];
+        const MISSING: &[u8] = b"*";
 
         let buf = name.map(|name| name.as_ref()).unwrap_or(MISSING);
 
@@ -0,0 +1,75 @@
+use std::collections::HashSet;
+
+use noodles_sam::alignment::record::data::field::Tag;
+
+use crate::Record;
+
+/// A filter of the data fields written for each record.
+///
+/// The filter is applied to each record before it is encoded. It is not checked against tags
+/// that downstream tools may require, e.g., the read group (`RG`). Keeping these is the
+/// responsibility of the caller.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TagFilter {
+    /// Keep only the fields with the given tags.
+    Keep(HashSet<Tag>),
+    /// Drop the fields with the given tags.
+    Drop(HashSet<Tag>),
+}
+
+impl TagFilter {
+    fn is_kept(&self, tag: &Tag) -> bool {
+        match self {
+            Self::Keep(tags) => tags.contains(tag),
+            Self::Drop(tags) => !tags.contains(tag),
+        }
+    }
+
+    pub(crate) fn apply(&self, record: &mut Record) {
+        record.tags.retain(|tag, _| self.is_kept(tag));
+
+        // The read group is stored separately from the other data fields.
+        if !self.is_kept(&Tag::READ_GROUP) {
+            record.read_group_id = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record_buf::{data::field::Value, Data};
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let tags: Data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+            (Tag::ORIGINAL_QUALITY_SCORES, Value::from("NDLS")),
+            (Tag::COMMENT, Value::from("noodles")),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = Record {
+            read_group_id: Some(0),
+            tags,
+            ..Default::default()
+        };
+
+        let filter = TagFilter::Drop([Tag::ORIGINAL_QUALITY_SCORES].into_iter().collect());
+        let mut actual = record.clone();
+        filter.apply(&mut actual);
+        assert_eq!(
+            actual.tags().keys().collect::<Vec<_>>(),
+            [Tag::ALIGNMENT_HIT_COUNT, Tag::COMMENT]
+        );
+        assert_eq!(actual.read_group_id(), Some(0));
+
+        let filter = TagFilter::Keep([Tag::COMMENT].into_iter().collect());
+        let mut actual = record;
+        filter.apply(&mut actual);
+        assert_eq!(actual.tags().keys().collect::<Vec<_>>(), [Tag::COMMENT]);
+        assert!(actual.read_group_id().is_none());
+    }
+}
@@ -1,26 +1,49 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
 //! **noodles-cram** handles the reading and writing of the CRAM format.
+//!
+//! # Features
+//!
+//!   * `std` (default): Enables everything but the core rANS 4x8 decoder
+//!     ([`codecs::rans_4x8::decode_slice`]). Without it, the crate is `no_std` and requires
+//!     `alloc`.
+
+extern crate alloc;
 
 #[cfg(feature = "async")]
 pub mod r#async;
 
 pub mod codecs;
+#[cfg(feature = "std")]
 pub(crate) mod container;
+#[cfg(feature = "std")]
 pub mod crai;
+#[cfg(feature = "std")]
 pub mod data_container;
+#[cfg(feature = "std")]
 pub mod file_definition;
+#[cfg(feature = "std")]
 mod huffman;
+#[cfg(feature = "std")]
 mod indexer;
+#[cfg(feature = "std")]
 pub mod io;
+#[cfg(feature = "std")]
 mod num;
+#[cfg(feature = "std")]
 pub mod record;
 
+#[cfg(feature = "std")]
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, record::Record,
+    data_container::DataContainer,
+    file_definition::FileDefinition,
+    indexer::{index, index_from_reader},
+    record::Record,
 };
 
 #[cfg(feature = "async")]
 pub use self::r#async::io::{Reader as AsyncReader, Writer as AsyncWriter};
 
+#[cfg(feature = "std")]
 static MAGIC_NUMBER: &[u8] = b"CRAM";
@@ -262,9 +262,7 @@ impl<'a> sam::alignment::record::Cigar for Cigar<'a> {
         if self.is_unmapped {
             Box::new(iter::empty())
         } else {
-            Box::new(TrySimplify::new(
-                self.features.cigar(self.read_length).map(Ok),
-            ))
+            Box::new(TrySimplify::new(self.features.cigar(self.read_length)))
         }
     }
 }
@@ -1,6 +1,10 @@
 use std::{error, fmt};
 
 /// A substitution base.
+///
+/// A base is converted from an ASCII byte case-insensitively, i.e., `a` and `A` are both
+/// [`Base::A`]. `N` (or `n`) is [`Base::N`], which is any base. Other bytes, including the other
+/// IUPAC codes, are invalid. A base is converted to an uppercase ASCII byte.
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub enum Base {
     /// Adenine.
@@ -31,7 +35,7 @@ impl TryFrom<u8> for Base {
     type Error = TryFromError;
 
     fn try_from(n: u8) -> Result<Self, Self::Error> {
-        match n {
+        match n.to_ascii_uppercase() {
             b'A' => Ok(Self::A),
             b'C' => Ok(Self::C),
             b'G' => Ok(Self::G),
@@ -42,6 +46,12 @@ impl TryFrom<u8> for Base {
     }
 }
 
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", char::from(u8::from(*self)))
+    }
+}
+
 impl From<Base> for u8 {
     fn from(base: Base) -> Self {
         match base {
@@ -70,7 +80,25 @@ mod tests {
         assert_eq!(Base::try_from(b'G'), Ok(Base::G));
         assert_eq!(Base::try_from(b'T'), Ok(Base::T));
         assert_eq!(Base::try_from(b'N'), Ok(Base::N));
+
+        assert_eq!(Base::try_from(b'a'), Ok(Base::A));
+        assert_eq!(Base::try_from(b'c'), Ok(Base::C));
+        assert_eq!(Base::try_from(b'g'), Ok(Base::G));
+        assert_eq!(Base::try_from(b't'), Ok(Base::T));
+        assert_eq!(Base::try_from(b'n'), Ok(Base::N));
+
         assert_eq!(Base::try_from(b'U'), Err(TryFromError));
+        assert_eq!(Base::try_from(b'R'), Err(TryFromError));
+        assert_eq!(Base::try_from(b'.'), Err(TryFromError));
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Base::A.to_string(), "A");
+        assert_eq!(Base::C.to_string(), "C");
+        assert_eq!(Base::G.to_string(), "G");
+        assert_eq!(Base::T.to_string(), "T");
+        assert_eq!(Base::N.to_string(), "N");
     }
 
     #[test]
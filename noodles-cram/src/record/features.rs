@@ -4,7 +4,7 @@ mod with_positions;
 pub use self::{cigar::Cigar, with_positions::WithPositions};
 
 use std::{
-    io,
+    io, iter,
     ops::{Deref, DerefMut},
     slice,
 };
@@ -12,7 +12,10 @@ use std::{
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
-    alignment::record_buf::{QualityScores, Sequence},
+    alignment::{
+        record::cigar::Op,
+        record_buf::{QualityScores, Sequence},
+    },
 };
 
 use super::{Feature, Flags};
@@ -54,6 +57,10 @@ impl Features {
         let mut read_position = Position::MIN;
 
         for feature in self.iter() {
+            if is_cigar_op(feature) && feature.position() < read_position {
+                return Err(unsorted_features_error());
+            }
+
             if feature.position() > read_position {
                 let len = usize::from(feature.position()) - usize::from(read_position);
                 merge_or_insert_op(&mut ops, Kind::Match, len);
@@ -75,9 +82,7 @@ impl Features {
             merge_or_insert_op(&mut ops, kind, len);
 
             if kind.consumes_read() {
-                read_position = read_position
-                    .checked_add(len)
-                    .expect("attempt to add with overflow");
+                read_position = cigar::consume_read(read_position, len)?;
             }
         }
 
@@ -97,6 +102,114 @@ impl Features {
         Cigar::new(&self.0, read_length)
     }
 
+    /// Formats the features as a SAM CIGAR string.
+    ///
+    /// This runs the [`Self::cigar`] iterator and merges adjacent operations of the same kind,
+    /// e.g., the alignment matches around a substitution, so the string is the same as the one
+    /// written for the record in SAM, e.g., by `samtools view`. No list of operations is
+    /// collected.
+    ///
+    /// If there are no operations, e.g., when the read length is 0, this returns `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{feature::substitution, Feature, Features};
+    ///
+    /// let features = Features::from(vec![
+    ///     Feature::SoftClip(Position::try_from(1)?, vec![b'A']),
+    ///     Feature::Substitution(Position::try_from(3)?, substitution::Value::Code(0)),
+    /// ]);
+    ///
+    /// assert_eq!(features.to_cigar_string(4)?, "1S3M");
+    /// assert_eq!(Features::default().to_cigar_string(0)?, "*");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_cigar_string(&self, read_length: usize) -> io::Result<String> {
+        let cigar = MergedCigar {
+            features: self,
+            read_length,
+        };
+
+        let mut buf = Vec::new();
+        sam::io::writer::record::write_cigar(&mut buf, &cigar)?;
+
+        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns whether the features are sorted by position.
+    ///
+    /// Each feature must start at or after the previous feature. A feature with read bases, e.g.,
+    /// a substitution or a soft clip, covers its bases, so the next feature must also start after
+    /// them. The only exceptions are quality score features (`Scores` and `QualityScore`), which
+    /// only annotate read positions and may overlap other features.
+    ///
+    /// Converting unsorted features to CIGAR operations fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::record::{feature::substitution, Feature, Features};
+    ///
+    /// let features = Features::from(vec![
+    ///     Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+    ///     Feature::Substitution(Position::try_from(3)?, substitution::Value::Code(0)),
+    /// ]);
+    /// assert!(features.is_sorted_by_position());
+    ///
+    /// let features = Features::from(vec![
+    ///     Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+    ///     Feature::Substitution(Position::try_from(2)?, substitution::Value::Code(0)),
+    /// ]);
+    /// assert!(!features.is_sorted_by_position());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_sorted_by_position(&self) -> bool {
+        let mut prev_position = Position::MIN;
+        let mut min_position = Position::MIN;
+
+        for feature in self.iter() {
+            let position = feature.position();
+
+            let read_len = match feature {
+                Feature::Scores(..) | Feature::QualityScore(..) => {
+                    if position < prev_position {
+                        return false;
+                    }
+
+                    prev_position = position;
+
+                    continue;
+                }
+                Feature::Bases(_, bases) => bases.len(),
+                Feature::Insertion(_, bases) => bases.len(),
+                Feature::SoftClip(_, bases) => bases.len(),
+                Feature::ReadBase(..) | Feature::Substitution(..) | Feature::InsertBase(..) => 1,
+                Feature::Deletion(..)
+                | Feature::ReferenceSkip(..)
+                | Feature::Padding(..)
+                | Feature::HardClip(..) => 0,
+            };
+
+            if position < prev_position || position < min_position {
+                return false;
+            }
+
+            prev_position = position;
+
+            if read_len > 0 {
+                match position.checked_add(read_len) {
+                    Some(end) => min_position = end,
+                    None => return false,
+                }
+            }
+        }
+
+        true
+    }
+
     pub(crate) fn with_positions(
         &self,
         alignment_start: Position,
@@ -125,6 +238,61 @@ impl From<Vec<Feature>> for Features {
     }
 }
 
+fn is_cigar_op(feature: &Feature) -> bool {
+    !matches!(
+        feature,
+        Feature::Bases(..)
+            | Feature::Scores(..)
+            | Feature::ReadBase(..)
+            | Feature::QualityScore(..)
+    )
+}
+
+fn unsorted_features_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "CIGAR operation features overlap or are not sorted by position",
+    )
+}
+
+// The CIGAR operations of features with adjacent operations of the same kind merged.
+struct MergedCigar<'a> {
+    features: &'a Features,
+    read_length: usize,
+}
+
+impl sam::alignment::record::Cigar for MergedCigar<'_> {
+    fn is_empty(&self) -> bool {
+        self.features.cigar(self.read_length).next().is_none()
+    }
+
+    fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Op>> + '_> {
+        let mut ops = self.features.cigar(self.read_length).peekable();
+
+        Box::new(iter::from_fn(move || {
+            let mut op = match ops.next()? {
+                Ok(op) => op,
+                Err(e) => return Some(Err(e)),
+            };
+
+            while let Some(Ok(next_op)) = ops.peek() {
+                if next_op.kind() != op.kind() {
+                    break;
+                }
+
+                op = Op::new(op.kind(), op.len() + next_op.len());
+                ops.next();
+            }
+
+            Some(Ok(op))
+        }))
+    }
+}
+
 fn cigar_to_features(
     flags: Flags,
     cigar: &sam::alignment::record_buf::Cigar,
@@ -467,6 +635,38 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_to_cigar_string() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::feature::substitution;
+
+        let features = Features::default();
+        assert_eq!(features.to_cigar_string(4)?, "4M");
+        assert_eq!(features.to_cigar_string(0)?, "*");
+
+        let features = Features::from(vec![
+            Feature::HardClip(Position::try_from(1)?, 2),
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+            Feature::Substitution(Position::try_from(4)?, substitution::Value::Code(0)),
+            Feature::Deletion(Position::try_from(6)?, 3),
+            Feature::Insertion(Position::try_from(6)?, vec![b'G']),
+            Feature::InsertBase(Position::try_from(7)?, b'T'),
+            Feature::ReferenceSkip(Position::try_from(8)?, 100),
+            Feature::SoftClip(Position::try_from(10)?, vec![b'A']),
+        ]);
+
+        let expected = "2H2S3M3D2I100N2M1S";
+        assert_eq!(features.to_cigar_string(10)?, expected);
+
+        let mut buf = Vec::new();
+        sam::io::writer::record::write_cigar(&mut buf, &features.try_into_cigar(10)?)?;
+        assert_eq!(buf, expected.as_bytes());
+
+        let features = Features::from(vec![Feature::SoftClip(Position::MAX, vec![b'A'])]);
+        assert!(features.to_cigar_string(4).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_try_into_cigar() -> Result<(), Box<dyn std::error::Error>> {
         use crate::record::feature::substitution;
@@ -524,6 +724,88 @@ mod tests {
             [Op::new(Kind::Match, 4)].into_iter().collect()
         );
 
+        let features = Features::from(vec![Feature::SoftClip(Position::MAX, vec![b'A'])]);
+        assert!(matches!(
+            features.try_into_cigar(4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_sorted_by_position() -> Result<(), noodles_core::position::TryFromIntError> {
+        use crate::record::feature::substitution;
+
+        fn t(features: Vec<Feature>, expected: bool) {
+            assert_eq!(Features::from(features).is_sorted_by_position(), expected);
+        }
+
+        t(Vec::new(), true);
+
+        t(
+            vec![
+                Feature::Bases(Position::try_from(1)?, vec![b'A', b'C']),
+                Feature::Scores(Position::try_from(1)?, vec![45, 35]),
+                Feature::Deletion(Position::try_from(3)?, 2),
+                Feature::InsertBase(Position::try_from(3)?, b'G'),
+                Feature::QualityScore(Position::try_from(3)?, 43),
+                Feature::SoftClip(Position::try_from(4)?, vec![b'T']),
+            ],
+            true,
+        );
+
+        // out of order
+        t(
+            vec![
+                Feature::Substitution(Position::try_from(3)?, substitution::Value::Code(0)),
+                Feature::Deletion(Position::try_from(2)?, 1),
+            ],
+            false,
+        );
+
+        t(
+            vec![
+                Feature::QualityScore(Position::try_from(3)?, 45),
+                Feature::QualityScore(Position::try_from(2)?, 35),
+            ],
+            false,
+        );
+
+        // overlapping read bases
+        t(
+            vec![
+                Feature::Insertion(Position::try_from(1)?, vec![b'A', b'C']),
+                Feature::Substitution(Position::try_from(2)?, substitution::Value::Code(0)),
+            ],
+            false,
+        );
+
+        t(
+            vec![
+                Feature::ReadBase(Position::try_from(2)?, b'A', 45),
+                Feature::ReadBase(Position::try_from(2)?, b'C', 35),
+            ],
+            false,
+        );
+
+        t(vec![Feature::SoftClip(Position::MAX, vec![b'A'])], false);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_into_cigar_with_unsorted_features() -> Result<(), Box<dyn std::error::Error>> {
+        let features = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+            Feature::Deletion(Position::try_from(2)?, 1),
+        ]);
+
+        assert!(matches!(
+            features.try_into_cigar(4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
         Ok(())
     }
 }
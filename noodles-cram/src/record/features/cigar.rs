@@ -1,4 +1,4 @@
-use std::slice;
+use std::{io, slice};
 
 use noodles_core::Position;
 use noodles_sam::alignment::record::cigar::{op::Kind, Op};
@@ -6,6 +6,9 @@ use noodles_sam::alignment::record::cigar::{op::Kind, Op};
 use crate::record::Feature;
 
 /// An iterator over features as CIGAR operations.
+///
+/// This fails if a feature position and length overflow the read position or if the features are
+/// not sorted by position (see [`super::Features::is_sorted_by_position`]).
 pub struct Cigar<'a> {
     features: slice::Iter<'a, Feature>,
     read_length: usize,
@@ -23,61 +26,84 @@ impl<'a> Cigar<'a> {
         }
     }
 
-    fn consume_read(&mut self, len: usize) {
-        self.read_position = self
-            .read_position
-            .checked_add(len)
-            .expect("attempt to add with overflow");
+    fn consume_read(&mut self, len: usize) -> io::Result<()> {
+        self.read_position = consume_read(self.read_position, len)?;
+        Ok(())
     }
 }
 
 impl<'a> Iterator for Cigar<'a> {
-    type Item = Op;
+    type Item = io::Result<Op>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some((kind, len)) = self.next_op.take() {
-            return Some(Op::new(kind, len));
+            return Some(Ok(Op::new(kind, len)));
         }
 
-        let Some(feature) = self.features.next() else {
-            if usize::from(self.read_position) <= self.read_length {
-                let len = self.read_length - usize::from(self.read_position) + 1;
-                self.consume_read(len);
-                return Some(Op::new(Kind::Match, len));
-            } else {
-                return None;
+        loop {
+            let Some(feature) = self.features.next() else {
+                if usize::from(self.read_position) <= self.read_length {
+                    let len = self.read_length - usize::from(self.read_position) + 1;
+
+                    if let Err(e) = self.consume_read(len) {
+                        return Some(Err(e));
+                    }
+
+                    return Some(Ok(Op::new(Kind::Match, len)));
+                } else {
+                    return None;
+                }
+            };
+
+            let (kind, len) = match feature {
+                Feature::Substitution(..) => (Kind::Match, 1),
+                Feature::Insertion(_, bases) => (Kind::Insertion, bases.len()),
+                Feature::Deletion(_, len) => (Kind::Deletion, *len),
+                Feature::InsertBase(..) => (Kind::Insertion, 1),
+                Feature::ReferenceSkip(_, len) => (Kind::Skip, *len),
+                Feature::SoftClip(_, bases) => (Kind::SoftClip, bases.len()),
+                Feature::Padding(_, len) => (Kind::Pad, *len),
+                Feature::HardClip(_, len) => (Kind::HardClip, *len),
+                _ => continue,
+            };
+
+            if feature.position() < self.read_position {
+                return Some(Err(super::unsorted_features_error()));
             }
-        };
 
-        if feature.position() > self.read_position {
-            let len = usize::from(feature.position()) - usize::from(self.read_position);
-            self.read_position = feature.position();
-            self.next_op = Some((Kind::Match, len));
-        }
+            if feature.position() > self.read_position {
+                let len = usize::from(feature.position()) - usize::from(self.read_position);
+                self.read_position = feature.position();
+                self.next_op = Some((Kind::Match, len));
+            }
 
-        let (kind, len) = match feature {
-            Feature::Substitution(..) => (Kind::Match, 1),
-            Feature::Insertion(_, bases) => (Kind::Insertion, bases.len()),
-            Feature::Deletion(_, len) => (Kind::Deletion, *len),
-            Feature::InsertBase(..) => (Kind::Insertion, 1),
-            Feature::ReferenceSkip(_, len) => (Kind::Skip, *len),
-            Feature::SoftClip(_, bases) => (Kind::SoftClip, bases.len()),
-            Feature::Padding(_, len) => (Kind::Pad, *len),
-            Feature::HardClip(_, len) => (Kind::HardClip, *len),
-            _ => todo!(),
-        };
-
-        if kind.consumes_read() {
-            self.consume_read(len);
-        }
+            if kind.consumes_read() {
+                if let Err(e) = self.consume_read(len) {
+                    self.next_op = None;
+                    return Some(Err(e));
+                }
+            }
 
-        match self.next_op.replace((kind, len)) {
-            Some((kind, len)) => Some(Op::new(kind, len)),
-            None => self.next_op.take().map(|(kind, len)| Op::new(kind, len)),
+            return match self.next_op.replace((kind, len)) {
+                Some((kind, len)) => Some(Ok(Op::new(kind, len))),
+                None => self
+                    .next_op
+                    .take()
+                    .map(|(kind, len)| Ok(Op::new(kind, len))),
+            };
         }
     }
 }
 
+pub(super) fn consume_read(read_position: Position, len: usize) -> io::Result<Position> {
+    read_position.checked_add(len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "read position overflow: feature extends past the maximum position",
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,7 +113,7 @@ mod tests {
     fn test_next() -> Result<(), noodles_core::position::TryFromIntError> {
         fn t(features: &Features, read_length: usize, expected: &[Op]) {
             let cigar = Cigar::new(features, read_length);
-            let actual: Vec<_> = cigar.collect();
+            let actual: Vec<_> = cigar.collect::<io::Result<_>>().unwrap();
             assert_eq!(actual, expected);
         }
 
@@ -149,4 +175,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_read_position_overflow() {
+        let features = Features::from(vec![Feature::SoftClip(Position::MAX, vec![b'A'])]);
+        let mut cigar = Cigar::new(&features, 4);
+
+        assert!(matches!(
+            cigar.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_next_with_unsorted_features() -> Result<(), noodles_core::position::TryFromIntError> {
+        let features = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![b'A', b'C']),
+            Feature::Substitution(Position::try_from(2)?, substitution::Value::Code(0)),
+        ]);
+
+        let result: io::Result<Vec<_>> = Cigar::new(&features, 4).collect();
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidData));
+
+        Ok(())
+    }
 }
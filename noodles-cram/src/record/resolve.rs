@@ -231,6 +231,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_bases_with_lowercase_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = fasta::record::Sequence::from(b"acgt".to_vec());
+        let substitution_matrix = SubstitutionMatrix::default();
+
+        let features = Features::from(vec![Feature::Substitution(
+            Position::try_from(2)?,
+            substitution::Value::Code(1),
+        )]);
+
+        let mut actual = Sequence::default();
+
+        resolve_bases(
+            Some(&reference_sequence),
+            &substitution_matrix,
+            &features,
+            Position::MIN,
+            4,
+            &mut actual,
+        )?;
+
+        // c -> G
+        assert_eq!(actual, Sequence::from(b"aGgt"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_bases_without_a_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
         let substitution_matrix = SubstitutionMatrix::default();
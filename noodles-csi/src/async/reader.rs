@@ -209,6 +209,7 @@ where
     let mut bins = IndexMap::with_capacity(n_bin);
     let mut index = IndexMap::with_capacity(n_bin);
 
+    let max_id = Bin::max_id(depth);
     let metadata_id = Bin::metadata_id(depth);
     let mut metadata = None;
 
@@ -217,6 +218,14 @@ where
             usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         })?;
 
+        // Bin IDs are in [0, max_id), except for the pseudo-bin that holds the metadata.
+        if id >= max_id && id != metadata_id {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("bin ID out of range: {id}"),
+            ));
+        }
+
         let loffset = reader
             .read_u64_le()
             .await
@@ -364,4 +373,22 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_bins_with_out_of_range_bin_id() {
+        const DEPTH: u8 = 5;
+
+        let data = [
+            0x01, 0x00, 0x00, 0x00, // n_bin = 1
+            0x49, 0x92, 0x00, 0x00, // bins[0].id = 37449
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // bins[0].loffset = 0
+            0x00, 0x00, 0x00, 0x00, // bins[0].n_chunk = 0
+        ];
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_bins(&mut reader, DEPTH).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
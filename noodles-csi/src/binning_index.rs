@@ -27,9 +27,18 @@ pub trait BinningIndex {
     fn reference_sequences(&self) -> Box<dyn Iterator<Item = &dyn ReferenceSequence> + '_>;
 
     /// Returns the number of unplaced, unmapped records in the associated file.
+    ///
+    /// This is the optional trailing `n_no_coor` field. `None` means the index does not record
+    /// the count, e.g., an index written by an older tool, which is distinct from `Some(0)`, i.e.,
+    /// the file has no unplaced, unmapped records.
     fn unplaced_unmapped_record_count(&self) -> Option<u64>;
 
     /// Returns the chunks that overlap with the given region.
+    ///
+    /// This collects the chunks of the bins that overlap the interval (`reg2bins`), drops chunks
+    /// that end at or before the minimum offset of the interval start (from the linear index or
+    /// the bin `loffset`), and merges the rest into a sorted list of non-overlapping chunks (see
+    /// [`optimize_chunks`]).
     fn query(&self, reference_sequence_id: usize, interval: Interval) -> io::Result<Vec<Chunk>>;
 
     /// Returns the last first record start position.
@@ -37,6 +46,34 @@ pub trait BinningIndex {
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
     /// in an index.
     fn last_first_record_start_position(&self) -> Option<bgzf::VirtualPosition>;
+
+    /// Returns the start position of the unplaced, unmapped records.
+    ///
+    /// This is the maximum end position of the reference sequences' records, as recorded in the
+    /// index metadata of the reference sequences that have metadata. It is exact when every
+    /// reference sequence has metadata; when only some do, it may be before the first unplaced,
+    /// unmapped record. If no reference sequence has metadata, this falls back to
+    /// [`Self::last_first_record_start_position`], which is at or before the first unplaced,
+    /// unmapped record. This returns `None` if neither is available, e.g., the index has no
+    /// reference sequences.
+    ///
+    /// This does not consider whether any unplaced, unmapped records exist (see
+    /// [`Self::unplaced_unmapped_record_count`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let index = csi::Index::default();
+    /// assert!(index.unplaced_unmapped_record_start_position().is_none());
+    /// ```
+    fn unplaced_unmapped_record_start_position(&self) -> Option<bgzf::VirtualPosition> {
+        self.reference_sequences()
+            .filter_map(|reference_sequence| reference_sequence.metadata())
+            .map(|metadata| metadata.end_position())
+            .max()
+            .or_else(|| self.last_first_record_start_position())
+    }
 }
 
 impl<I> BinningIndex for Box<I>
@@ -245,4 +282,44 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_unplaced_unmapped_record_start_position() {
+        use self::index::reference_sequence::{index::BinnedIndex, Metadata};
+
+        fn metadata(end: u64) -> Metadata {
+            Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(end),
+                1,
+                0,
+            )
+        }
+
+        let index = crate::Index::default();
+        assert!(index.unplaced_unmapped_record_start_position().is_none());
+
+        let reference_sequences = vec![
+            index::ReferenceSequence::new(
+                Default::default(),
+                BinnedIndex::default(),
+                Some(metadata(610)),
+            ),
+            index::ReferenceSequence::new(
+                Default::default(),
+                BinnedIndex::default(),
+                Some(metadata(987)),
+            ),
+            index::ReferenceSequence::new(Default::default(), BinnedIndex::default(), None),
+        ];
+
+        let index = crate::Index::builder()
+            .set_reference_sequences(reference_sequences)
+            .build();
+
+        assert_eq!(
+            index.unplaced_unmapped_record_start_position(),
+            Some(bgzf::VirtualPosition::from(987))
+        );
+    }
 }
@@ -166,6 +166,57 @@ fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use indexmap::IndexMap;
+
+        use super::reference_sequence::{index::BinnedIndex, Bin};
+
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        fn chunk(start: u64, end: u64) -> Chunk {
+            Chunk::new(
+                bgzf::VirtualPosition::from(start),
+                bgzf::VirtualPosition::from(end),
+            )
+        }
+
+        // bin 0 = [1, 1024], bin 1 = [1, 128], bin 10 = [17, 32], bin 11 = [33, 48], bin 12 = [49, 64]
+        let bins: IndexMap<_, _> = [
+            (0, Bin::new(vec![chunk(1, 5), chunk(100, 110)])),
+            (1, Bin::new(vec![chunk(50, 60)])),
+            (10, Bin::new(vec![chunk(20, 30)])),
+            (11, Bin::new(vec![chunk(28, 40)])),
+            (12, Bin::new(vec![chunk(70, 80)])),
+        ]
+        .into_iter()
+        .collect();
+
+        let index: BinnedIndex = [(10, bgzf::VirtualPosition::from(25))]
+            .into_iter()
+            .collect();
+
+        let index = crate::Index::builder()
+            .set_min_shift(MIN_SHIFT)
+            .set_depth(DEPTH)
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, index, None)])
+            .build();
+
+        let start = Position::try_from(20)?;
+        let end = Position::try_from(40)?;
+        let actual = index.query(0, (start..=end).into())?;
+        let expected = [chunk(20, 40), chunk(50, 60), chunk(100, 110)];
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            index.query(1, (start..=end).into()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_max_position() -> Result<(), Box<dyn std::error::Error>> {
         const MIN_SHIFT: u8 = 14;
@@ -76,6 +76,42 @@ impl Bin {
     }
 }
 
+/// Merges overlapping and adjacent chunks in place.
+///
+/// This is the in-place form of [`crate::binning_index::merge_chunks`]. The chunks are sorted by
+/// start position and then coalesced. Two chunks are merged when the start of one is less than or
+/// equal to the end of the other, as compared by virtual position.
+/// That is, chunks are adjacent when the (exclusive) end virtual position of one equals the
+/// (inclusive) start virtual position of the next, e.g., a chunk that ends at `(1024, 0)` and a
+/// chunk that starts at `(1024, 0)`.
+///
+/// The end of a BGZF block and the start of the following block are different virtual positions
+/// (e.g., `(0, 65280)` and `(1024, 0)`), so chunks that only meet across a block boundary this
+/// way are not merged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::binning_index::index::reference_sequence::bin::{merge_chunks, Chunk};
+///
+/// let mut chunks = vec![
+///     Chunk::new(bgzf::VirtualPosition::from(8), bgzf::VirtualPosition::from(13)),
+///     Chunk::new(bgzf::VirtualPosition::from(2), bgzf::VirtualPosition::from(5)),
+///     Chunk::new(bgzf::VirtualPosition::from(5), bgzf::VirtualPosition::from(7)),
+/// ];
+///
+/// merge_chunks(&mut chunks);
+///
+/// assert_eq!(chunks, [
+///     Chunk::new(bgzf::VirtualPosition::from(2), bgzf::VirtualPosition::from(7)),
+///     Chunk::new(bgzf::VirtualPosition::from(8), bgzf::VirtualPosition::from(13)),
+/// ]);
+/// ```
+pub fn merge_chunks(chunks: &mut Vec<Chunk>) {
+    *chunks = crate::binning_index::merge_chunks(chunks);
+}
+
 // `CSIv1.pdf` (2020-07-21)
 const fn bin_limit(depth: u8) -> i32 {
     assert!(depth <= 10);
@@ -137,4 +173,39 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_merge_chunks() {
+        fn vp(compressed_pos: u64, uncompressed_pos: u16) -> bgzf::VirtualPosition {
+            bgzf::VirtualPosition::try_from((compressed_pos, uncompressed_pos)).unwrap()
+        }
+
+        let mut chunks = Vec::new();
+        merge_chunks(&mut chunks);
+        assert!(chunks.is_empty());
+
+        let mut chunks = vec![
+            // touches the start of the next chunk at a block boundary
+            Chunk::new(vp(1024, 0), vp(2048, 0)),
+            Chunk::new(vp(0, 8), vp(1024, 0)),
+            // contained
+            Chunk::new(vp(0, 13), vp(0, 21)),
+            // ends at the end of a block but not at the start of the next block
+            Chunk::new(vp(2048, 0), vp(2048, 65280)),
+            Chunk::new(vp(3072, 0), vp(3072, 5)),
+            // separated by a gap of one uncompressed byte
+            Chunk::new(vp(3072, 6), vp(3072, 8)),
+        ];
+
+        merge_chunks(&mut chunks);
+
+        assert_eq!(
+            chunks,
+            [
+                Chunk::new(vp(0, 8), vp(2048, 65280)),
+                Chunk::new(vp(3072, 0), vp(3072, 5)),
+                Chunk::new(vp(3072, 6), vp(3072, 8)),
+            ]
+        );
+    }
 }
@@ -60,6 +60,12 @@ where
 
     /// Adds a record.
     ///
+    /// The alignment context is a tuple of the reference sequence ID, start position, end
+    /// position, and whether the record is mapped. Unmapped records that are placed, i.e., have a
+    /// reference sequence ID and start position, are binned the same as mapped records but are
+    /// counted as unmapped in the reference sequence metadata. Records without an alignment
+    /// context are counted as unplaced, unmapped records.
+    ///
     /// # Examples
     ///
     /// ```
@@ -38,6 +38,71 @@ impl<'r, R> Query<'r, R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
 {
+    /// Removes the first `n` lines of the stream from the chunks.
+    ///
+    /// This is for the line skip count (`skip`) of a tabix index header. These lines are header
+    /// lines regardless of their prefix, so they are not read even if a chunk includes them, e.g.,
+    /// a chunk that starts at the beginning of the stream.
+    ///
+    /// This reads the first `n` lines to find where they end. It does nothing if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Read, Write};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{binning_index::index::reference_sequence::bin::Chunk, io::Query};
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"track\nsq0\t8\t13\n")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(Cursor::new(data));
+    /// let chunks = vec![Chunk::new(
+    ///     bgzf::VirtualPosition::from(0),
+    ///     bgzf::VirtualPosition::from(16),
+    /// )];
+    ///
+    /// let mut query = Query::new(&mut reader, chunks).skip_lines(1)?;
+    ///
+    /// let mut buf = String::new();
+    /// query.read_to_string(&mut buf)?;
+    /// assert_eq!(buf, "sq0\t8\t13\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn skip_lines(mut self, n: u32) -> io::Result<Self> {
+        if n == 0 {
+            return Ok(self);
+        }
+
+        self.reader
+            .seek_to_virtual_position(bgzf::VirtualPosition::default())?;
+
+        let mut buf = Vec::new();
+
+        for _ in 0..n {
+            buf.clear();
+
+            if self.reader.read_until(b'\n', &mut buf)? == 0 {
+                break;
+            }
+        }
+
+        let header_end = self.reader.virtual_position();
+
+        let chunks: Vec<_> = self
+            .chunks
+            .by_ref()
+            .filter(|chunk| chunk.end() > header_end)
+            .map(|chunk| Chunk::new(chunk.start().max(header_end), chunk.end()))
+            .collect();
+
+        self.chunks = chunks.into_iter();
+        self.state = State::Seek;
+
+        Ok(self)
+    }
+
     /// Creates an iterator that parses indexed records.
     pub fn indexed_records(self, header: &Header) -> IndexedRecords<Self> {
         IndexedRecords::new(self, header)
@@ -88,3 +153,47 @@ where
         self.reader.consume(amt);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+
+    #[test]
+    fn test_skip_lines() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"track\nbrowser\n")?;
+        writer.flush()?;
+        let header_end = writer.virtual_position();
+        writer.write_all(b"sq0\t8\t13\n")?;
+        let end = writer.virtual_position();
+        let data = writer.finish()?;
+
+        let t = |chunks: Vec<Chunk>, n: u32, expected: &[u8]| -> io::Result<()> {
+            let mut reader = bgzf::Reader::new(Cursor::new(&data));
+            let mut query = Query::new(&mut reader, chunks).skip_lines(n)?;
+
+            let mut buf = Vec::new();
+            query.read_to_end(&mut buf)?;
+            assert_eq!(buf, expected);
+
+            Ok(())
+        };
+
+        let start = bgzf::VirtualPosition::default();
+
+        t(
+            vec![Chunk::new(start, end)],
+            0,
+            b"track\nbrowser\nsq0\t8\t13\n",
+        )?;
+        t(vec![Chunk::new(start, end)], 1, b"browser\nsq0\t8\t13\n")?;
+        t(vec![Chunk::new(start, end)], 2, b"sq0\t8\t13\n")?;
+        t(vec![Chunk::new(header_end, end)], 2, b"sq0\t8\t13\n")?;
+        t(vec![Chunk::new(start, header_end)], 2, b"")?;
+        t(vec![Chunk::new(start, end)], 8, b"")?;
+
+        Ok(())
+    }
+}
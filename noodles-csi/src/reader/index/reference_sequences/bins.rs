@@ -23,6 +23,8 @@ pub enum ReadError {
     InvalidBinCount(num::TryFromIntError),
     /// A bin ID is invalid.
     InvalidBinId(num::TryFromIntError),
+    /// A bin ID is out of range for the depth.
+    BinIdOutOfRange(usize),
     /// A bin is duplicated.
     DuplicateBin(usize),
     /// Metadata is invalid.
@@ -37,6 +39,7 @@ impl error::Error for ReadError {
             Self::Io(e) => Some(e),
             Self::InvalidBinCount(e) => Some(e),
             Self::InvalidBinId(e) => Some(e),
+            Self::BinIdOutOfRange(_) => None,
             Self::DuplicateBin(_) => None,
             Self::InvalidMetadata(e) => Some(e),
             Self::InvalidChunks(e) => Some(e),
@@ -50,6 +53,7 @@ impl fmt::Display for ReadError {
             Self::Io(_) => write!(f, "I/O error"),
             Self::InvalidBinCount(_) => write!(f, "invalid bin count"),
             Self::InvalidBinId(_) => write!(f, "invalid bin ID"),
+            Self::BinIdOutOfRange(id) => write!(f, "bin ID out of range: {id}"),
             Self::DuplicateBin(id) => write!(f, "duplicate bin: {id}"),
             Self::InvalidMetadata(_) => write!(f, "invalid metadata"),
             Self::InvalidChunks(_) => write!(f, "invalid chunks"),
@@ -79,6 +83,7 @@ where
     let mut bins = IndexMap::with_capacity(n_bin);
     let mut index = BinnedIndex::with_capacity(n_bin);
 
+    let max_id = Bin::max_id(depth);
     let metadata_id = Bin::metadata_id(depth);
     let mut metadata = None;
 
@@ -88,6 +93,11 @@ where
             .map_err(ReadError::Io)
             .and_then(|n| usize::try_from(n).map_err(ReadError::InvalidBinId))?;
 
+        // Bin IDs are in [0, max_id), except for the pseudo-bin that holds the metadata.
+        if id >= max_id && id != metadata_id {
+            return Err(ReadError::BinIdOutOfRange(id));
+        }
+
         let loffset = reader
             .read_u64::<LittleEndian>()
             .map(bgzf::VirtualPosition::from)?;
@@ -202,6 +212,18 @@ mod tests {
             Err(ReadError::DuplicateBin(37450))
         ));
 
+        let data = [
+            0x01, 0x00, 0x00, 0x00, // n_bin = 1
+            0x49, 0x92, 0x00, 0x00, // bins[0].id = 37449
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // bins[0].loffset = 0
+            0x00, 0x00, 0x00, 0x00, // bins[0].n_chunk = 0
+        ];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_bins(&mut reader, DEPTH),
+            Err(ReadError::BinIdOutOfRange(37449))
+        ));
+
         Ok(())
     }
 }
@@ -18,6 +18,8 @@ use std::{
     path::Path,
 };
 
+use noodles_core::SequenceDictionary;
+
 /// A FASTA index.
 pub type Index = Vec<Record>;
 
@@ -41,3 +43,56 @@ where
     let mut reader = File::open(src).map(BufReader::new).map(Reader::new)?;
     reader.read_index()
 }
+
+/// Builds a sequence dictionary from a FASTA index.
+///
+/// The dictionary has the record names and lengths in index order. This fails if a name is
+/// duplicated or a length overflows a `usize`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::fai;
+///
+/// let index = vec![
+///     fai::Record::new("sq0", 8, 5, 4, 5),
+///     fai::Record::new("sq1", 13, 21, 4, 5),
+/// ];
+///
+/// let sequence_dictionary = fai::sequence_dictionary(&index)?;
+/// assert_eq!(sequence_dictionary.get_index_of(b"sq1"), Some(1));
+/// assert_eq!(sequence_dictionary.get_length(1), Some(13));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn sequence_dictionary(index: &[Record]) -> io::Result<SequenceDictionary> {
+    let mut sequence_dictionary = SequenceDictionary::default();
+
+    for record in index {
+        let length = usize::try_from(record.length())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        sequence_dictionary
+            .push(record.name(), length)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    }
+
+    Ok(sequence_dictionary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_dictionary() {
+        let index = vec![
+            Record::new("sq0", 8, 5, 4, 5),
+            Record::new("sq0", 13, 21, 4, 5),
+        ];
+
+        assert!(matches!(
+            sequence_dictionary(&index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
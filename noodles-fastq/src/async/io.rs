@@ -1,6 +1,6 @@
 //! Async FASTQ I/O.
 
-mod reader;
+pub mod reader;
 mod writer;
 
 pub use self::{reader::Reader, writer::Writer};
@@ -1,7 +1,19 @@
+//! Async FASTQ reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use futures::{stream, Stream};
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt, AsyncReadExt};
 
-use crate::Record;
+use crate::{
+    io::reader::{
+        record::{unexpected_eof, validate_plus_line_name},
+        ReadError,
+    },
+    Record,
+};
 
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
@@ -9,6 +21,7 @@ const CARRIAGE_RETURN: u8 = b'\r';
 /// An async FASTQ reader.
 pub struct Reader<R> {
     inner: R,
+    lenient_plus_line: bool,
 }
 
 impl<R> Reader<R> {
@@ -69,11 +82,18 @@ where
     /// let reader = fastq::r#async::io::Reader::new(io::empty());
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Builder::default().build_from_reader(inner)
     }
 
     /// Reads a FASTQ record.
     ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
+    /// stream reached EOF.
+    ///
+    /// If the stream ends in the middle of a record, an [`io::ErrorKind::UnexpectedEof`] error
+    /// with a [`ReadError::UnexpectedEof`] is returned. This includes when the quality scores at
+    /// the end of the stream are shorter than the sequence.
+    ///
     /// # Examples
     ///
     /// ```
@@ -94,7 +114,7 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record).await
+        read_record(&mut self.inner, record, self.lenient_plus_line).await
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -119,19 +139,27 @@ where
     /// # }
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        let lenient_plus_line = self.lenient_plus_line;
+
         Box::pin(stream::try_unfold(
             (&mut self.inner, Record::default()),
-            |(mut reader, mut buf)| async {
-                read_record(&mut reader, &mut buf).await.map(|n| match n {
-                    0 => None,
-                    _ => Some((buf.clone(), (reader, buf))),
-                })
+            move |(mut reader, mut buf)| async move {
+                read_record(&mut reader, &mut buf, lenient_plus_line)
+                    .await
+                    .map(|n| match n {
+                        0 => None,
+                        _ => Some((buf.clone(), (reader, buf))),
+                    })
             },
         ))
     }
 }
 
-async fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+async fn read_record<R>(
+    reader: &mut R,
+    record: &mut Record,
+    lenient_plus_line: bool,
+) -> io::Result<usize>
 where
     R: AsyncBufRead + Unpin,
 {
@@ -142,9 +170,46 @@ where
         n => n,
     };
 
-    len += read_line(reader, record.sequence_mut()).await?;
-    len += read_description(reader, &mut Vec::new()).await?;
-    len += read_line(reader, record.quality_scores_mut()).await?;
+    len += match read_line(reader, record.sequence_mut()).await? {
+        0 => return Err(unexpected_eof(record)),
+        n => n,
+    };
+
+    let mut description = Vec::new();
+
+    len += match read_description(reader, &mut description).await {
+        Ok(n) => n,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            return Err(unexpected_eof(record))
+        }
+        Err(e) => return Err(e),
+    };
+
+    if !lenient_plus_line {
+        validate_plus_line_name(record.name(), &description)?;
+    }
+
+    len += match read_line(reader, record.quality_scores_mut()).await? {
+        0 => return Err(unexpected_eof(record)),
+        n => n,
+    };
+
+    let sequence_len = record.sequence().len();
+    let quality_scores_len = record.quality_scores().len();
+
+    if quality_scores_len != sequence_len {
+        if quality_scores_len < sequence_len && reader.fill_buf().await?.is_empty() {
+            return Err(unexpected_eof(record));
+        }
+
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            ReadError::QualityScoresLengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            },
+        ));
+    }
 
     Ok(len)
 }
@@ -235,20 +300,76 @@ dcba
         let mut reader = &data[..];
         let mut record = Record::default();
 
-        read_record(&mut reader, &mut record).await?;
+        read_record(&mut reader, &mut record, false).await?;
         let expected = Record::new(Definition::new("noodles:1/1", ""), "AGCT", "abcd");
         assert_eq!(record, expected);
 
-        read_record(&mut reader, &mut record).await?;
+        read_record(&mut reader, &mut record, false).await?;
         let expected = Record::new(Definition::new("noodles:2/1", ""), "TCGA", "dcba");
         assert_eq!(record, expected);
 
-        let n = read_record(&mut reader, &mut record).await?;
+        let n = read_record(&mut reader, &mut record, false).await?;
         assert_eq!(n, 0);
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_record_with_truncated_record() {
+        async fn read_error(src: &[u8]) -> Option<ReadError> {
+            let mut reader = src;
+            let mut record = Record::default();
+
+            read_record(&mut reader, &mut record, false)
+                .await
+                .err()
+                .filter(|e| e.kind() == io::ErrorKind::UnexpectedEof)
+                .and_then(|e| e.into_inner())
+                .and_then(|e| e.downcast::<ReadError>().ok())
+                .map(|e| *e)
+        }
+
+        let expected = Some(ReadError::UnexpectedEof {
+            partial_record_read_name: b"r0".to_vec(),
+        });
+
+        assert_eq!(read_error(b"@r0\n").await, expected);
+        assert_eq!(read_error(b"@r0\nACGT\n").await, expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\n").await, expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\nND").await, expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\nND\n").await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_read_record_with_quality_scores_length_mismatch() {
+        let data = b"@r0\nACGT\n+\nND\n@r1\nACGT\n+\nNDLS\n";
+        let mut reader = &data[..];
+        let mut record = Record::default();
+
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_read_record_with_plus_line_name_mismatch() -> io::Result<()> {
+        let data = b"@r0\nACGT\n+r1\nNDLS\n";
+        let mut record = Record::default();
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = &data[..];
+        read_record(&mut reader, &mut record, true).await?;
+        assert_eq!(record.name(), b"r0");
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_name() -> io::Result<()> {
         let mut record = Record::default();
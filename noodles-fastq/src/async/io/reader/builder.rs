@@ -0,0 +1,48 @@
+use tokio::io::AsyncBufRead;
+
+use super::Reader;
+
+/// An async FASTQ reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    lenient_plus_line: bool,
+}
+
+impl Builder {
+    /// Sets whether the read name on the plus line is left unchecked.
+    ///
+    /// The plus line may optionally repeat the read name. By default, this is disabled, i.e., if
+    /// the plus line has a name, it must match the record's read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let builder = fastq::r#async::io::reader::Builder::default().set_lenient_plus_line(true);
+    /// ```
+    pub fn set_lenient_plus_line(mut self, lenient_plus_line: bool) -> Self {
+        self.lenient_plus_line = lenient_plus_line;
+        self
+    }
+
+    /// Builds an async FASTQ reader from an async reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+r1\nNDLS\n";
+    /// let reader = fastq::r#async::io::reader::Builder::default()
+    ///     .set_lenient_plus_line(true)
+    ///     .build_from_reader(&data[..]);
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        Reader {
+            inner: reader,
+            lenient_plus_line: self.lenient_plus_line,
+        }
+    }
+}
@@ -4,10 +4,62 @@ mod indexer;
 pub mod reader;
 mod writer;
 
-use std::{fs::File, io::BufReader, path::Path};
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
 
 pub use self::{indexer::Indexer, reader::Reader, writer::Writer};
-use super::fai;
+use super::{fai, Record};
+
+/// Reads all records from a FASTQ file.
+///
+/// The compression method is autodetected (see [`reader::Builder::build_from_path`]).
+///
+/// This eagerly reads and buffers every record in memory and is only suitable for small files.
+/// Use a [`Reader`] to stream records from large files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_fastq as fastq;
+/// let records = fastq::io::read("sample.fq")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read<P>(src: P) -> io::Result<Vec<Record>>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = reader::Builder::default().build_from_path(src)?;
+    reader.records().collect()
+}
+
+/// Writes records to a FASTQ file.
+///
+/// The file is created or truncated, and records are written uncompressed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_fastq::{self as fastq, record::Definition};
+/// let records = [fastq::Record::new(Definition::new("r0", ""), "ATCG", "NDLS")];
+/// fastq::io::write("sample.fq", &records)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn write<P>(dst: P, records: &[Record]) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut file = File::create(dst).map(BufWriter::new)?;
+    let mut writer = Writer::new(&mut file);
+
+    for record in records {
+        writer.write_record(record)?;
+    }
+
+    file.flush()
+}
 
 /// Indexes a FASTQ file.
 ///
@@ -19,7 +71,7 @@ use super::fai;
 /// let index = fastq::io::index("sample.fastq")?;
 /// # Ok::<(), io::Error>(())
 /// ```
-pub fn index<P>(src: P) -> std::io::Result<fai::Index>
+pub fn index<P>(src: P) -> io::Result<fai::Index>
 where
     P: AsRef<Path>,
 {
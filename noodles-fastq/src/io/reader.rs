@@ -1,9 +1,10 @@
 //! FASTQ reader.
 
+mod builder;
 pub(crate) mod record;
 mod records;
 
-pub use self::records::Records;
+pub use self::{builder::Builder, record::ReadError, records::Records};
 
 use std::io::{self, BufRead};
 
@@ -13,6 +14,8 @@ use crate::Record;
 /// A FASTQ reader.
 pub struct Reader<R> {
     inner: R,
+    lenient_plus_line: bool,
+    trim_trailing_whitespace: bool,
 }
 
 impl<R> Reader<R>
@@ -29,7 +32,7 @@ where
     /// let reader = fastq::io::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Builder::default().build_from_reader(inner)
     }
 
     /// Returns a reference to the underlying reader.
@@ -84,6 +87,10 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// If the stream ends in the middle of a record, an [`io::ErrorKind::UnexpectedEof`] error
+    /// with a [`ReadError::UnexpectedEof`] is returned. This includes when the quality scores at
+    /// the end of the stream are shorter than the sequence.
+    ///
     /// # Examples
     ///
     /// ```
@@ -102,7 +109,12 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        read_record(
+            &mut self.inner,
+            record,
+            self.lenient_plus_line,
+            self.trim_trailing_whitespace,
+        )
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -154,15 +166,15 @@ dcba
         let mut reader = &data[..];
         let mut record = Record::default();
 
-        read_record(&mut reader, &mut record)?;
+        read_record(&mut reader, &mut record, false, false)?;
         let expected = Record::new(Definition::new("noodles:1/1", ""), "AGCT", "abcd");
         assert_eq!(record, expected);
 
-        read_record(&mut reader, &mut record)?;
+        read_record(&mut reader, &mut record, false, false)?;
         let expected = Record::new(Definition::new("noodles:2/1", ""), "TCGA", "dcba");
         assert_eq!(record, expected);
 
-        let n = read_record(&mut reader, &mut record)?;
+        let n = read_record(&mut reader, &mut record, false, false)?;
         assert_eq!(n, 0);
 
         Ok(())
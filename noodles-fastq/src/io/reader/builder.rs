@@ -0,0 +1,152 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::bufread::MultiGzDecoder;
+use noodles_bgzf as bgzf;
+
+use super::Reader;
+
+/// A FASTQ reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    lenient_plus_line: bool,
+    trim_trailing_whitespace: bool,
+}
+
+impl Builder {
+    /// Sets whether the read name on the plus line is left unchecked.
+    ///
+    /// The plus line may optionally repeat the read name. By default, this is disabled, i.e., if
+    /// the plus line has a name, it must match the record's read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let builder = fastq::io::reader::Builder::default().set_lenient_plus_line(true);
+    /// ```
+    pub fn set_lenient_plus_line(mut self, lenient_plus_line: bool) -> Self {
+        self.lenient_plus_line = lenient_plus_line;
+        self
+    }
+
+    /// Sets whether to trim trailing whitespace from the sequence and quality scores lines.
+    ///
+    /// If enabled, trailing ASCII whitespace, e.g., spaces and tabs, is removed from both the
+    /// sequence and quality scores lines before their lengths are compared. Whitespace inside a
+    /// line is never removed. By default, this is disabled, i.e., trailing whitespace is kept and
+    /// typically results in a length mismatch error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nACGT \n+\nNDLS\n";
+    /// let mut reader = fastq::io::reader::Builder::default()
+    ///     .set_trim_trailing_whitespace(true)
+    ///     .build_from_reader(&data[..]);
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record.sequence(), b"ACGT");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn set_trim_trailing_whitespace(mut self, trim_trailing_whitespace: bool) -> Self {
+        self.trim_trailing_whitespace = trim_trailing_whitespace;
+        self
+    }
+
+    /// Builds a FASTQ reader from a path.
+    ///
+    /// The compression method is autodetected. A gzip-compressed file, which includes BGZF, is
+    /// decompressed; otherwise, the file is read as uncompressed FASTQ.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fastq as fastq;
+    /// let reader = fastq::io::reader::Builder::default().build_from_path("sample.fq.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead>>>
+    where
+        P: AsRef<Path>,
+    {
+        let reader = File::open(src).map(BufReader::new)?;
+        let inner = decompress(reader)?;
+        Ok(self.build_from_reader(inner))
+    }
+
+    /// Builds a FASTQ reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let data = b"@r0\nATCG\n+r1\nNDLS\n";
+    /// let reader = fastq::io::reader::Builder::default()
+    ///     .set_lenient_plus_line(true)
+    ///     .build_from_reader(&data[..]);
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: BufRead,
+    {
+        Reader {
+            inner: reader,
+            lenient_plus_line: self.lenient_plus_line,
+            trim_trailing_whitespace: self.trim_trailing_whitespace,
+        }
+    }
+}
+
+fn decompress<R>(mut reader: R) -> io::Result<Box<dyn BufRead>>
+where
+    R: BufRead + 'static,
+{
+    // BGZF is also read as gzip, i.e., as concatenated gzip members.
+    if bgzf::io::detect_compression_method(&mut reader)?.is_some() {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    #[test]
+    fn test_decompress() -> io::Result<()> {
+        const DATA: &[u8] = b"@r0\nATCG\n+\nNDLS\n";
+
+        fn t(src: Vec<u8>) -> io::Result<Vec<u8>> {
+            let mut buf = Vec::new();
+            decompress(io::Cursor::new(src))?.read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+
+        assert_eq!(t(DATA.to_vec())?, DATA);
+
+        // Concatenated gzip members, e.g., BGZF blocks.
+        let mut src = Vec::new();
+
+        for chunk in DATA.chunks(8) {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(chunk)?;
+            src.extend(encoder.finish()?);
+        }
+
+        assert_eq!(t(src)?, DATA);
+
+        Ok(())
+    }
+}
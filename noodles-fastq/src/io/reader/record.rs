@@ -2,14 +2,82 @@ mod definition;
 
 pub(crate) use self::definition::read_definition;
 
-use std::io::{self, BufRead, Read};
+use std::{
+    error, fmt,
+    io::{self, BufRead, Read},
+};
 
 use crate::Record;
 
 const LINE_FEED: u8 = b'\n';
 const CARRIAGE_RETURN: u8 = b'\r';
 
-pub(super) fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+/// An error returned when a FASTQ record fails to read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadError {
+    /// The stream ended before the record was complete.
+    ///
+    /// This is returned when the stream ends after the name line but before the sequence, plus,
+    /// or quality scores lines, or when the quality scores at the end of the stream are shorter
+    /// than the sequence.
+    UnexpectedEof {
+        /// The read name of the incomplete record.
+        partial_record_read_name: Vec<u8>,
+    },
+    /// The quality scores length does not match the sequence length.
+    QualityScoresLengthMismatch {
+        /// The sequence length.
+        sequence_len: usize,
+        /// The quality scores length.
+        quality_scores_len: usize,
+    },
+    /// The read name on the plus line does not match the read name on the name line.
+    PlusLineNameMismatch {
+        /// The read name.
+        name: Vec<u8>,
+        /// The read name on the plus line.
+        plus_line_name: Vec<u8>,
+    },
+}
+
+impl error::Error for ReadError {}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof {
+                partial_record_read_name,
+            } => write!(
+                f,
+                "unexpected EOF in record: {}",
+                String::from_utf8_lossy(partial_record_read_name)
+            ),
+            Self::QualityScoresLengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            } => write!(
+                f,
+                "quality scores length mismatch: expected {sequence_len}, got {quality_scores_len}"
+            ),
+            Self::PlusLineNameMismatch {
+                name,
+                plus_line_name,
+            } => write!(
+                f,
+                "plus line name mismatch: expected {}, got {}",
+                String::from_utf8_lossy(name),
+                String::from_utf8_lossy(plus_line_name)
+            ),
+        }
+    }
+}
+
+pub(super) fn read_record<R>(
+    reader: &mut R,
+    record: &mut Record,
+    lenient_plus_line: bool,
+    trim_trailing_whitespace: bool,
+) -> io::Result<usize>
 where
     R: BufRead,
 {
@@ -21,13 +89,64 @@ where
         Err(e) => return Err(e),
     };
 
-    len += read_line(reader, record.sequence_mut())?;
-    len += consume_plus_line(reader)?;
-    len += read_line(reader, record.quality_scores_mut())?;
+    len += match read_line(reader, record.sequence_mut())? {
+        0 => return Err(unexpected_eof(record)),
+        n => n,
+    };
+
+    if trim_trailing_whitespace {
+        trim_end(record.sequence_mut());
+    }
+
+    let mut plus_line = Vec::new();
+
+    len += match read_plus_line(reader, &mut plus_line)? {
+        0 => return Err(unexpected_eof(record)),
+        n => n,
+    };
+
+    if !lenient_plus_line {
+        validate_plus_line_name(record.name(), &plus_line)?;
+    }
+
+    len += match read_line(reader, record.quality_scores_mut())? {
+        0 => return Err(unexpected_eof(record)),
+        n => n,
+    };
+
+    if trim_trailing_whitespace {
+        trim_end(record.quality_scores_mut());
+    }
+
+    let sequence_len = record.sequence().len();
+    let quality_scores_len = record.quality_scores().len();
+
+    if quality_scores_len != sequence_len {
+        if quality_scores_len < sequence_len && reader.fill_buf()?.is_empty() {
+            return Err(unexpected_eof(record));
+        }
+
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            ReadError::QualityScoresLengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            },
+        ));
+    }
 
     Ok(len)
 }
 
+pub(crate) fn unexpected_eof(record: &Record) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        ReadError::UnexpectedEof {
+            partial_record_read_name: record.name().to_vec(),
+        },
+    )
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
@@ -49,36 +168,14 @@ where
     }
 }
 
-fn consume_line<R>(reader: &mut R) -> io::Result<usize>
-where
-    R: BufRead,
-{
-    use memchr::memchr;
-
-    let mut is_eol = false;
-    let mut len = 0;
-
-    loop {
-        let src = reader.fill_buf()?;
-
-        if src.is_empty() || is_eol {
-            break;
-        }
-
-        let n = match memchr(LINE_FEED, src) {
-            Some(i) => {
-                is_eol = true;
-                i + 1
-            }
-            None => src.len(),
-        };
-
-        reader.consume(n);
-
-        len += n;
-    }
+fn trim_end(buf: &mut Vec<u8>) {
+    let len = buf
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map(|i| i + 1)
+        .unwrap_or_default();
 
-    Ok(len)
+    buf.truncate(len);
 }
 
 fn read_u8<R>(reader: &mut R) -> io::Result<u8>
@@ -90,18 +187,50 @@ where
     Ok(buf[0])
 }
 
-fn consume_plus_line<R>(reader: &mut R) -> io::Result<usize>
+// Reads the plus line into `buf`, excluding the prefix.
+//
+// This returns 0 if the stream is at EOF.
+fn read_plus_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
 where
     R: BufRead,
 {
     const PREFIX: u8 = b'+';
 
-    match read_u8(reader)? {
-        PREFIX => consume_line(reader).map(|n| n + 1),
-        _ => Err(io::Error::new(
+    match read_u8(reader) {
+        Ok(PREFIX) => read_line(reader, buf).map(|n| n + 1),
+        Ok(_) => Err(io::Error::new(
             io::ErrorKind::InvalidData,
             "invalid description prefix",
         )),
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(0),
+        Err(e) => Err(e),
+    }
+}
+
+// The plus line may optionally repeat the definition. If it does, its name must match the read
+// name.
+pub(crate) fn validate_plus_line_name(name: &[u8], plus_line: &[u8]) -> io::Result<()> {
+    const DELIMITER: u8 = b' ';
+
+    if plus_line.is_empty() {
+        return Ok(());
+    }
+
+    let plus_line_name = plus_line
+        .split(|&b| b == DELIMITER)
+        .next()
+        .unwrap_or_default();
+
+    if plus_line_name == name {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            ReadError::PlusLineNameMismatch {
+                name: name.to_vec(),
+                plus_line_name: plus_line_name.to_vec(),
+            },
+        ))
     }
 }
 
@@ -135,18 +264,117 @@ mod tests {
     }
 
     #[test]
-    fn test_consume_plus_line() -> io::Result<()> {
+    fn test_read_plus_line() -> io::Result<()> {
+        let mut buf = Vec::new();
+
         let data = b"+r0\n";
         let mut reader = &data[..];
-        consume_plus_line(&mut reader)?;
+        assert_eq!(read_plus_line(&mut reader, &mut buf)?, 4);
+        assert_eq!(buf, b"r0");
+
+        let data = b"";
+        let mut reader = &data[..];
+        buf.clear();
+        assert_eq!(read_plus_line(&mut reader, &mut buf)?, 0);
 
         let data = b"r0\n";
         let mut reader = &data[..];
         assert!(matches!(
-            consume_plus_line(&mut reader),
+            read_plus_line(&mut reader, &mut buf),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_plus_line_name() {
+        assert!(validate_plus_line_name(b"r0", b"").is_ok());
+        assert!(validate_plus_line_name(b"r0", b"r0").is_ok());
+        assert!(validate_plus_line_name(b"r0", b"r0 LN:4").is_ok());
+
+        assert!(matches!(
+            validate_plus_line_name(b"r0", b"r1"),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_record_with_truncated_record() {
+        fn read_error(src: &[u8]) -> Option<ReadError> {
+            let mut reader = src;
+            let mut record = Record::default();
+
+            read_record(&mut reader, &mut record, false, false)
+                .err()
+                .filter(|e| e.kind() == io::ErrorKind::UnexpectedEof)
+                .and_then(|e| e.into_inner())
+                .and_then(|e| e.downcast::<ReadError>().ok())
+                .map(|e| *e)
+        }
+
+        let expected = Some(ReadError::UnexpectedEof {
+            partial_record_read_name: b"r0".to_vec(),
+        });
+
+        assert_eq!(read_error(b"@r0\n"), expected);
+        assert_eq!(read_error(b"@r0\nACGT\n"), expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\n"), expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\nND"), expected);
+        assert_eq!(read_error(b"@r0\nACGT\n+\nND\n"), expected);
+    }
+
+    #[test]
+    fn test_read_record_with_quality_scores_length_mismatch() {
+        let data = b"@r0\nACGT\n+\nND\n@r1\nACGT\n+\nNDLS\n";
+        let mut reader = &data[..];
+        let mut record = Record::default();
+
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false, false),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_read_record_with_plus_line_name_mismatch() -> io::Result<()> {
+        let data = b"@r0\nACGT\n+r1\nNDLS\n";
+        let mut record = Record::default();
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false, false),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = &data[..];
+        read_record(&mut reader, &mut record, true, false)?;
+        assert_eq!(record.name(), b"r0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_trailing_whitespace() -> io::Result<()> {
+        let data = b"@r0\nACGT  \n+\nNDLS\t\n@r1\nAC GT\n+\nND LS \n";
+        let mut record = Record::default();
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_record(&mut reader, &mut record, false, false),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidData
         ));
 
+        let mut reader = &data[..];
+
+        read_record(&mut reader, &mut record, false, true)?;
+        assert_eq!(record.sequence(), b"ACGT");
+        assert_eq!(record.quality_scores(), b"NDLS");
+
+        read_record(&mut reader, &mut record, false, true)?;
+        assert_eq!(record.sequence(), b"AC GT");
+        assert_eq!(record.quality_scores(), b"ND LS");
+
         Ok(())
     }
 }
@@ -36,6 +36,24 @@ where
         &self.inner
     }
 
+    /// Flushes the underlying writer.
+    ///
+    /// Buffered output is written to the destination, so a reader can read all records written
+    /// up to this point, e.g., when writing to a pipe.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let mut writer = fastq::io::Writer::new(Vec::new());
+    /// writer.flush()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
     /// Writes a FASTQ record.
     ///
     /// # Examples
@@ -4,7 +4,9 @@ mod definition;
 
 pub use self::definition::Definition;
 
-use std::fmt;
+use std::{fmt, io};
+
+use noodles_core::sequence::BaseCounts;
 
 /// A FASTQ record.
 #[derive(Clone, Default, Debug, Eq, PartialEq)]
@@ -153,6 +155,131 @@ impl Record {
         &mut self.quality_scores
     }
 
+    /// Applies Illumina 8-level binning to the quality scores in place.
+    ///
+    /// This is a lossy transform. FASTQ quality scores are offset by 33, and values less than the
+    /// offset are left unchanged. See [`noodles_core::quality_scores::bin_illumina8`] for the bin
+    /// boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let mut record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "#+5I");
+    /// record.bin_quality_scores_illumina8();
+    /// assert_eq!(record.quality_scores(), b"'07I");
+    /// ```
+    pub fn bin_quality_scores_illumina8(&mut self) {
+        use noodles_core::quality_scores::bin_illumina8;
+
+        const OFFSET: u8 = b'!';
+
+        for score in &mut self.quality_scores {
+            if let Some(n) = score.checked_sub(OFFSET) {
+                let mut buf = [n];
+                bin_illumina8(&mut buf);
+                *score = buf[0] + OFFSET;
+            }
+        }
+    }
+
+    /// Counts the bases of the sequence.
+    ///
+    /// See [`noodles_core::sequence::BaseCounts`] for how bases are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence::BaseCounts;
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "ACgtNR", "NDLSNN");
+    ///
+    /// assert_eq!(
+    ///     record.base_composition(),
+    ///     BaseCounts { a: 1, c: 1, g: 1, t: 1, n: 1, other: 1 }
+    /// );
+    /// ```
+    pub fn base_composition(&self) -> BaseCounts {
+        self.sequence.iter().copied().collect()
+    }
+
+    /// Calculates the GC content of the sequence.
+    ///
+    /// See [`BaseCounts::gc_content`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "ACGGNN", "NDLSNN");
+    /// assert_eq!(record.gc_content(), 0.75);
+    /// ```
+    pub fn gc_content(&self) -> f64 {
+        self.base_composition().gc_content()
+    }
+
+    /// Returns a copy of the record with its sequence reverse complemented.
+    ///
+    /// See [`Self::reverse_complement_mut`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let record = fastq::Record::new(Definition::new("r0", "LN:4"), "AGCN", "NDLS");
+    /// let actual = record.reverse_complement()?;
+    /// assert_eq!(actual.name(), b"r0");
+    /// assert_eq!(actual.description(), b"LN:4");
+    /// assert_eq!(actual.sequence(), b"NGCT");
+    /// assert_eq!(actual.quality_scores(), b"SLDN");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reverse_complement(&self) -> io::Result<Self> {
+        let mut record = self.clone();
+        record.reverse_complement_mut()?;
+        Ok(record)
+    }
+
+    /// Reverse complements the sequence in place.
+    ///
+    /// The sequence is complemented and reversed, and the quality scores are only reversed. The
+    /// definition, i.e., the read name and description, is not changed.
+    ///
+    /// Bases can be IUPAC nucleotide codes, including ambiguity codes, e.g., `R` (`A` or `G`) is
+    /// complemented to `Y` (`C` or `T`), in either case. `U` is complemented to `A`. If the
+    /// sequence has any other byte, this returns an [`io::ErrorKind::InvalidData`] error, and the
+    /// record is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let mut record = fastq::Record::new(Definition::new("r0", ""), "AGCR", "NDLS");
+    /// record.reverse_complement_mut()?;
+    /// assert_eq!(record.sequence(), b"YGCT");
+    /// assert_eq!(record.quality_scores(), b"SLDN");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reverse_complement_mut(&mut self) -> io::Result<()> {
+        if let Some(&b) = self.sequence.iter().find(|&&b| complement(b).is_none()) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid base: {:?}", char::from(b)),
+            ));
+        }
+
+        for b in &mut self.sequence {
+            // SAFETY: all bases are validated above.
+            *b = complement(*b).unwrap();
+        }
+
+        self.sequence.reverse();
+        self.quality_scores.reverse();
+
+        Ok(())
+    }
+
     // Truncates all field buffers to 0.
     pub(crate) fn clear(&mut self) {
         self.definition.clear();
@@ -161,6 +288,33 @@ impl Record {
     }
 }
 
+fn complement(b: u8) -> Option<u8> {
+    let c = match b.to_ascii_uppercase() {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' | b'U' => b'A',
+        b'W' => b'W',
+        b'S' => b'S',
+        b'M' => b'K',
+        b'K' => b'M',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'B' => b'V',
+        b'D' => b'H',
+        b'H' => b'D',
+        b'V' => b'B',
+        b'N' => b'N',
+        _ => return None,
+    };
+
+    if b.is_ascii_lowercase() {
+        Some(c.to_ascii_lowercase())
+    } else {
+        Some(c)
+    }
+}
+
 impl fmt::Display for Record {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("@")?;
@@ -210,6 +364,46 @@ mod tests {
         assert_eq!(record.to_string(), "@r0 LN:4\nATCG\n+\nNDLS\n");
     }
 
+    #[test]
+    fn test_reverse_complement_mut() -> io::Result<()> {
+        let mut record = Record::new(Definition::new("r0", "LN:6"), "ACgtRn", "ABCDEF");
+        record.reverse_complement_mut()?;
+
+        assert_eq!(record.name(), b"r0");
+        assert_eq!(record.description(), b"LN:6");
+        assert_eq!(record.sequence(), b"nYacGT");
+        assert_eq!(record.quality_scores(), b"FEDCBA");
+
+        let mut record = Record::new(Definition::new("r0", ""), "AC.T", "NDLS");
+        let expected = record.clone();
+        assert!(matches!(
+            record.reverse_complement_mut(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(record, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_base_composition() {
+        let record = Record::new(Definition::new("r0", ""), "ACGTacgtNnRY", "NDLSNDLSNDLS");
+
+        assert_eq!(
+            record.base_composition(),
+            BaseCounts {
+                a: 2,
+                c: 2,
+                g: 2,
+                t: 2,
+                n: 2,
+                other: 2,
+            }
+        );
+
+        assert_eq!(record.gc_content(), 0.4);
+    }
+
     #[test]
     fn test_clear() {
         let mut record = Record::new(Definition::new("r0", ""), "AGCT", "NDLS");
@@ -2,11 +2,13 @@
 
 pub mod genome_build;
 pub mod gff_version;
+pub mod gvf_version;
 pub mod name;
 pub mod sequence_region;
 
 pub use self::{
-    genome_build::GenomeBuild, gff_version::GffVersion, sequence_region::SequenceRegion,
+    genome_build::GenomeBuild, gff_version::GffVersion, gvf_version::GvfVersion,
+    sequence_region::SequenceRegion,
 };
 
 use std::{error, fmt, str::FromStr};
@@ -22,6 +24,8 @@ pub(crate) const PREFIX: &str = "##";
 pub enum Directive {
     /// The GFF version (`gff-version`).
     GffVersion(GffVersion),
+    /// The GVF version (`gvf-version`).
+    GvfVersion(GvfVersion),
     /// A reference to a sequence segment (`sequence-region`).
     SequenceRegion(SequenceRegion),
     /// The ontology used for the feature types (`feature-ontology`).
@@ -40,6 +44,9 @@ pub enum Directive {
     /// (`FASTA`).
     StartOfFasta,
     /// A nonstandard directive.
+    ///
+    /// This includes dialect-specific pragmas, e.g., GVF's `file-date` or `individual-id`, which
+    /// are kept verbatim.
     Other(name::Other, Option<String>),
 }
 
@@ -47,6 +54,7 @@ impl fmt::Display for Directive {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::GffVersion(version) => write!(f, "{PREFIX}gff-version {version}"),
+            Self::GvfVersion(version) => write!(f, "{PREFIX}gvf-version {version}"),
             Self::SequenceRegion(sequence_region) => write!(f, "{sequence_region}"),
             Self::FeatureOntology(uri) => write!(f, "{PREFIX}feature-ontology {uri}"),
             Self::AttributeOntology(uri) => write!(f, "{PREFIX}attribute-ontology {uri}"),
@@ -79,6 +87,8 @@ pub enum ParseError {
     MissingValue,
     /// The GFF version is invalid.
     InvalidGffVersion(gff_version::ParseError),
+    /// The GVF version is invalid.
+    InvalidGvfVersion(gvf_version::ParseError),
     /// A sequence region is invalid.
     InvalidSequenceRegion(sequence_region::ParseError),
     /// A genome build is invalid.
@@ -89,6 +99,7 @@ impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::InvalidGffVersion(e) => Some(e),
+            Self::InvalidGvfVersion(e) => Some(e),
             Self::InvalidSequenceRegion(e) => Some(e),
             Self::InvalidGenomeBuild(e) => Some(e),
             _ => None,
@@ -103,6 +114,7 @@ impl fmt::Display for ParseError {
             Self::MissingName => f.write_str("directive name is missing"),
             Self::MissingValue => f.write_str("directive value is missing"),
             Self::InvalidGffVersion(_) => f.write_str("invalid GFF version"),
+            Self::InvalidGvfVersion(_) => f.write_str("invalid GVF version"),
             Self::InvalidSequenceRegion(_) => f.write_str("invalid sequence region"),
             Self::InvalidGenomeBuild(_) => f.write_str("invalid genome build"),
         }
@@ -130,6 +142,11 @@ impl FromStr for Directive {
                 .ok_or(ParseError::MissingValue)
                 .and_then(|s| s.parse().map_err(ParseError::InvalidGffVersion))
                 .map(Self::GffVersion),
+            name::GVF_VERSION => components
+                .next()
+                .ok_or(ParseError::MissingValue)
+                .and_then(|s| s.parse().map_err(ParseError::InvalidGvfVersion))
+                .map(Self::GvfVersion),
             name::SEQUENCE_REGION => components
                 .next()
                 .ok_or(ParseError::MissingValue)
@@ -171,7 +188,25 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_from_str() -> Result<(), name::other::ParseError> {
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(
+            "##gvf-version 1.07".parse(),
+            Ok(Directive::GvfVersion("1.07".parse()?))
+        );
+
+        assert_eq!(
+            "##gvf-version".parse::<Directive>(),
+            Err(ParseError::MissingValue)
+        );
+
+        assert_eq!(
+            "##file-date 2024-01-01".parse(),
+            Ok(Directive::Other(
+                "file-date".parse()?,
+                Some(String::from("2024-01-01"))
+            )),
+        );
+
         assert_eq!(
             "##noodles".parse(),
             Ok(Directive::Other("noodles".parse()?, None)),
@@ -195,6 +230,11 @@ mod tests {
             "##gff-version 3"
         );
 
+        assert_eq!(
+            Directive::GvfVersion(GvfVersion::default()).to_string(),
+            "##gvf-version 1.10"
+        );
+
         let directive = Directive::SequenceRegion(SequenceRegion::new(String::from("sq0"), 8, 13));
         assert_eq!(directive.to_string(), "##sequence-region sq0 8 13");
 
@@ -0,0 +1,213 @@
+//! GVF directive version.
+
+use std::{error, fmt, num, str::FromStr};
+
+const MAJOR_VERSION: u32 = 1;
+const MINOR_VERSION: u32 = 10;
+
+const DELIMITER: char = '.';
+const MAX_COMPONENT_COUNT: usize = 2;
+
+/// A GVF directive version.
+///
+/// GVF (Genome Variation Format) is a GFF3 dialect that declares its version with
+/// `##gvf-version`. Minor versions are conventionally zero-padded (e.g., `1.07`), so the width of
+/// the raw minor version is kept for formatting. The width is not part of the version, so, e.g.,
+/// `1.7` and `1.07` are equal.
+#[derive(Clone, Debug, Eq)]
+pub struct GvfVersion {
+    major: u32,
+    minor: Option<u32>,
+    minor_width: usize,
+}
+
+impl GvfVersion {
+    /// Returns the major version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_gff::directive::gvf_version;
+    /// use noodles_gff::directive::GvfVersion;
+    /// let version: GvfVersion = "1.07".parse()?;
+    /// assert_eq!(version.major(), 1);
+    /// # Ok::<(), gvf_version::ParseError>(())
+    /// ```
+    pub fn major(&self) -> u32 {
+        self.major
+    }
+
+    /// Returns the minor version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use noodles_gff::directive::gvf_version;
+    /// use noodles_gff::directive::GvfVersion;
+    /// let version: GvfVersion = "1.07".parse()?;
+    /// assert_eq!(version.minor(), Some(7));
+    /// # Ok::<(), gvf_version::ParseError>(())
+    /// ```
+    pub fn minor(&self) -> Option<u32> {
+        self.minor
+    }
+}
+
+impl Default for GvfVersion {
+    fn default() -> Self {
+        Self {
+            major: MAJOR_VERSION,
+            minor: Some(MINOR_VERSION),
+            minor_width: 2,
+        }
+    }
+}
+
+impl PartialEq for GvfVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.major == other.major && self.minor == other.minor
+    }
+}
+
+impl fmt::Display for GvfVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.major())?;
+
+        if let Some(minor) = self.minor() {
+            write!(f, "{DELIMITER}{minor:0width$}", width = self.minor_width)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw GVF version directive fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The major version is invalid.
+    InvalidMajorVersion(num::ParseIntError),
+    /// The minor version is invalid.
+    InvalidMinorVersion(num::ParseIntError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidMajorVersion(e) | Self::InvalidMinorVersion(e) => Some(e),
+            Self::Empty => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidMajorVersion(_) => f.write_str("invalid major version"),
+            Self::InvalidMinorVersion(_) => f.write_str("invalid minor version"),
+        }
+    }
+}
+
+impl FromStr for GvfVersion {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut components = s.splitn(MAX_COMPONENT_COUNT, DELIMITER);
+
+        let major = components
+            .next()
+            .ok_or(ParseError::Empty)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidMajorVersion))?;
+
+        let (minor, minor_width) = match components.next() {
+            Some(t) => t
+                .parse()
+                .map(|n| (Some(n), t.len()))
+                .map_err(ParseError::InvalidMinorVersion)?,
+            None => (None, 0),
+        };
+
+        Ok(Self {
+            major,
+            minor,
+            minor_width,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eq() -> Result<(), ParseError> {
+        assert_eq!("1.7".parse::<GvfVersion>()?, "1.07".parse()?);
+        assert_ne!("1.7".parse::<GvfVersion>()?, "1.10".parse()?);
+        assert_ne!("1".parse::<GvfVersion>()?, "1.0".parse()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(GvfVersion::default().to_string(), "1.10");
+
+        let version = GvfVersion {
+            major: 1,
+            minor: None,
+            minor_width: 0,
+        };
+        assert_eq!(version.to_string(), "1");
+
+        let version = GvfVersion {
+            major: 1,
+            minor: Some(7),
+            minor_width: 2,
+        };
+        assert_eq!(version.to_string(), "1.07");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "1".parse(),
+            Ok(GvfVersion {
+                major: 1,
+                minor: None,
+                minor_width: 0,
+            })
+        );
+
+        assert_eq!(
+            "1.07".parse(),
+            Ok(GvfVersion {
+                major: 1,
+                minor: Some(7),
+                minor_width: 2,
+            })
+        );
+
+        assert_eq!("".parse::<GvfVersion>(), Err(ParseError::Empty));
+
+        assert!(matches!(
+            "a".parse::<GvfVersion>(),
+            Err(ParseError::InvalidMajorVersion(_))
+        ));
+
+        assert!(matches!(
+            "1.b".parse::<GvfVersion>(),
+            Err(ParseError::InvalidMinorVersion(_))
+        ));
+
+        assert!(matches!(
+            "1.07.1".parse::<GvfVersion>(),
+            Err(ParseError::InvalidMinorVersion(_))
+        ));
+    }
+}
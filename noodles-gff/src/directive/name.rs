@@ -7,6 +7,7 @@ pub use self::other::Other;
 use self::standard::Standard;
 
 pub(super) const GFF_VERSION: Name = Name::Standard(Standard::GffVersion);
+pub(super) const GVF_VERSION: Name = Name::Standard(Standard::GvfVersion);
 pub(super) const SEQUENCE_REGION: Name = Name::Standard(Standard::SequenceRegion);
 pub(super) const FEATURE_ONTOLOGY: Name = Name::Standard(Standard::FeatureOntology);
 pub(super) const ATTRIBUTE_ONTOLOGY: Name = Name::Standard(Standard::AttributeOntology);
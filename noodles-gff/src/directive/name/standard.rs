@@ -3,6 +3,7 @@ use std::fmt;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Standard {
     GffVersion,
+    GvfVersion,
     SequenceRegion,
     FeatureOntology,
     AttributeOntology,
@@ -17,6 +18,7 @@ impl Standard {
     pub fn new(s: &str) -> Option<Self> {
         match s {
             "gff-version" => Some(Self::GffVersion),
+            "gvf-version" => Some(Self::GvfVersion),
             "sequence-region" => Some(Self::SequenceRegion),
             "feature-ontology" => Some(Self::FeatureOntology),
             "attribute-ontology" => Some(Self::AttributeOntology),
@@ -34,6 +36,7 @@ impl AsRef<str> for Standard {
     fn as_ref(&self) -> &str {
         match self {
             Self::GffVersion => "gff-version",
+            Self::GvfVersion => "gvf-version",
             Self::SequenceRegion => "sequence-region",
             Self::FeatureOntology => "feature-ontology",
             Self::AttributeOntology => "attribute-ontology",
@@ -0,0 +1,320 @@
+//! GFF record interval tree.
+
+use std::collections::HashMap;
+
+use noodles_core::{region::Interval, Position};
+
+use super::Record;
+
+/// A GFF record interval tree.
+///
+/// This groups records by reference sequence name and indexes each group for overlap queries. All
+/// intervals, i.e., the record start and end and the query interval, are 1-based and inclusive.
+///
+/// Each group is stored as an implicit, balanced binary tree over the records sorted by start
+/// position, where each node is augmented with the maximum end position of its subtree. Building
+/// the tree costs O(n log n) time to sort the records and O(n) extra space, i.e., one position
+/// per record. A query then costs O(log n + k) time, where k is the number of overlapping
+/// records. This favors building the tree once and querying it many times.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_gff::{self as gff, interval_tree::IntervalTree};
+///
+/// let records = [
+///     gff::Record::builder()
+///         .set_reference_sequence_name(String::from("sq0"))
+///         .set_start(Position::try_from(8)?)
+///         .set_end(Position::try_from(13)?)
+///         .build(),
+///     gff::Record::builder()
+///         .set_reference_sequence_name(String::from("sq0"))
+///         .set_start(Position::try_from(21)?)
+///         .set_end(Position::try_from(34)?)
+///         .build(),
+/// ];
+///
+/// let tree: IntervalTree = records.into_iter().collect();
+///
+/// let start = Position::try_from(13)?;
+/// let end = Position::try_from(21)?;
+/// assert_eq!(tree.query("sq0", start..=end).count(), 2);
+///
+/// let start = Position::try_from(14)?;
+/// let end = Position::try_from(20)?;
+/// assert_eq!(tree.query("sq0", start..=end).count(), 0);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntervalTree {
+    trees: HashMap<String, Tree>,
+}
+
+impl IntervalTree {
+    /// Returns the number of records in the tree.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::default();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.trees.values().map(|tree| tree.records.len()).sum()
+    }
+
+    /// Returns whether the tree has any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::default();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over records on the given reference sequence that overlap the given
+    /// interval.
+    ///
+    /// Records are returned in order of their start positions. An unbounded interval side is
+    /// treated as the start or end of the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff::interval_tree::IntervalTree;
+    ///
+    /// let tree = IntervalTree::default();
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// assert!(tree.query("sq0", start..=end).next().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn query<I>(&self, reference_sequence_name: &str, interval: I) -> Query<'_>
+    where
+        I: Into<Interval>,
+    {
+        let interval = interval.into();
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        let (tree, stack) = match self.trees.get(reference_sequence_name) {
+            Some(tree) => (Some(tree), vec![Frame::Range(0, tree.records.len())]),
+            None => (None, Vec::new()),
+        };
+
+        Query {
+            tree,
+            start,
+            end,
+            stack,
+        }
+    }
+}
+
+impl FromIterator<Record> for IntervalTree {
+    fn from_iter<T: IntoIterator<Item = Record>>(iter: T) -> Self {
+        let mut groups: HashMap<String, Vec<Record>> = HashMap::new();
+
+        for record in iter {
+            groups
+                .entry(record.reference_sequence_name().into())
+                .or_default()
+                .push(record);
+        }
+
+        let trees = groups
+            .into_iter()
+            .map(|(name, records)| (name, Tree::new(records)))
+            .collect();
+
+        Self { trees }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Tree {
+    records: Vec<Record>,
+    // The maximum end position of the subtree rooted at each node.
+    max_ends: Vec<Position>,
+}
+
+impl Tree {
+    fn new(mut records: Vec<Record>) -> Self {
+        records.sort_by_key(|record| (record.start(), record.end()));
+
+        let mut max_ends: Vec<_> = records.iter().map(|record| record.end()).collect();
+        build_max_ends(&mut max_ends, 0, records.len());
+
+        Self { records, max_ends }
+    }
+}
+
+// The node of the subtree over `[lo, hi)` is at the midpoint.
+fn mid(lo: usize, hi: usize) -> usize {
+    lo + (hi - lo) / 2
+}
+
+fn build_max_ends(max_ends: &mut [Position], lo: usize, hi: usize) -> Option<Position> {
+    if lo >= hi {
+        return None;
+    }
+
+    let i = mid(lo, hi);
+
+    let left_max_end = build_max_ends(max_ends, lo, i);
+    let right_max_end = build_max_ends(max_ends, i + 1, hi);
+
+    let max_end = [left_max_end, right_max_end]
+        .into_iter()
+        .flatten()
+        .fold(max_ends[i], |a, b| a.max(b));
+
+    max_ends[i] = max_end;
+
+    Some(max_end)
+}
+
+enum Frame {
+    Range(usize, usize),
+    Node(usize),
+}
+
+/// An iterator over records in an interval tree that overlap an interval.
+///
+/// This is created by calling [`IntervalTree::query`].
+pub struct Query<'t> {
+    tree: Option<&'t Tree>,
+    start: Position,
+    end: Position,
+    stack: Vec<Frame>,
+}
+
+impl<'t> Iterator for Query<'t> {
+    type Item = &'t Record;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tree = self.tree?;
+
+        while let Some(frame) = self.stack.pop() {
+            match frame {
+                Frame::Range(lo, hi) => {
+                    if lo >= hi {
+                        continue;
+                    }
+
+                    let i = mid(lo, hi);
+
+                    // No record in the subtree ends at or after the query start.
+                    if tree.max_ends[i] < self.start {
+                        continue;
+                    }
+
+                    // The records in the right subtree start at or after this node, so they can
+                    // only overlap if this node starts at or before the query end.
+                    if tree.records[i].start() <= self.end {
+                        self.stack.push(Frame::Range(i + 1, hi));
+                        self.stack.push(Frame::Node(i));
+                    }
+
+                    self.stack.push(Frame::Range(lo, i));
+                }
+                Frame::Node(i) => {
+                    let record = &tree.records[i];
+
+                    if record.end() >= self.start {
+                        return Some(record);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(reference_sequence_name: &str, start: usize, end: usize) -> Record {
+        Record::builder()
+            .set_reference_sequence_name(reference_sequence_name.into())
+            .set_start(Position::new(start).unwrap())
+            .set_end(Position::new(end).unwrap())
+            .build()
+    }
+
+    fn query(tree: &IntervalTree, name: &str, start: usize, end: usize) -> Vec<(usize, usize)> {
+        let interval = Position::new(start).unwrap()..=Position::new(end).unwrap();
+
+        tree.query(name, interval)
+            .map(|record| (usize::from(record.start()), usize::from(record.end())))
+            .collect()
+    }
+
+    #[test]
+    fn test_query() {
+        let tree: IntervalTree = [
+            build_record("sq0", 21, 34),
+            build_record("sq0", 1, 100),
+            build_record("sq0", 8, 13),
+            build_record("sq0", 55, 89),
+            build_record("sq0", 13, 13),
+            build_record("sq1", 8, 13),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(tree.len(), 6);
+
+        assert_eq!(
+            query(&tree, "sq0", 13, 21),
+            [(1, 100), (8, 13), (13, 13), (21, 34)]
+        );
+        assert_eq!(query(&tree, "sq0", 14, 20), [(1, 100)]);
+        assert_eq!(query(&tree, "sq0", 90, 144), [(1, 100)]);
+        assert!(query(&tree, "sq0", 101, 144).is_empty());
+
+        assert_eq!(query(&tree, "sq1", 1, 8), [(8, 13)]);
+        assert!(query(&tree, "sq2", 1, 8).is_empty());
+
+        let unbounded: Vec<_> = tree.query("sq0", ..).collect();
+        assert_eq!(unbounded.len(), 5);
+    }
+
+    #[test]
+    fn test_query_matches_linear_scan() {
+        let records: Vec<_> = (1..=200)
+            .map(|i| {
+                let start = (i * 37) % 500 + 1;
+                let len = (i * 13) % 60;
+                build_record("sq0", start, start + len)
+            })
+            .collect();
+
+        let tree: IntervalTree = records.iter().cloned().collect();
+
+        for (start, end) in [(1, 1), (10, 20), (250, 260), (480, 600), (1, 600)] {
+            let mut expected: Vec<_> = records
+                .iter()
+                .filter(|record| {
+                    usize::from(record.start()) <= end && usize::from(record.end()) >= start
+                })
+                .map(|record| (usize::from(record.start()), usize::from(record.end())))
+                .collect();
+
+            expected.sort_unstable();
+
+            assert_eq!(query(&tree, "sq0", start, end), expected);
+        }
+    }
+}
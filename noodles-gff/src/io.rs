@@ -1,6 +1,7 @@
 //! GFF I/O.
 
-pub(crate) mod reader;
+mod compression_method;
+pub mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{compression_method::CompressionMethod, reader::Reader, writer::Writer};
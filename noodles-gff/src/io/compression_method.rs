@@ -0,0 +1,10 @@
+/// A GFF compression method.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CompressionMethod {
+    /// No compression.
+    None,
+    /// BGZF.
+    Bgzf,
+    /// gzip.
+    Gzip,
+}
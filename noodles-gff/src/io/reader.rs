@@ -1,10 +1,12 @@
 //! GFF reader and iterators.
 
+mod builder;
+mod interval_error;
 mod lazy_line;
 mod lines;
 mod records;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{builder::Builder, interval_error::IntervalError, lines::Lines, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek},
@@ -15,12 +17,15 @@ use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::{self as csi, BinningIndex};
 
+use self::interval_error::validate_interval;
 pub(crate) use self::lazy_line::read_lazy_line;
 use crate::{lazy, Record};
 
 /// A GFF reader.
 pub struct Reader<R> {
     inner: R,
+    lenient_directives: bool,
+    validate_intervals: bool,
 }
 
 impl<R> Reader<R> {
@@ -81,7 +86,7 @@ where
     /// let reader = gff::io::Reader::new(io::empty());
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Builder::default().build_from_reader(inner)
     }
 
     /// Reads a raw GFF line.
@@ -208,6 +213,12 @@ where
 {
     /// Returns an iterator over records that intersects the given region.
     ///
+    /// This reads the chunks from the index query and returns the records whose start and end
+    /// positions intersect the region. A record that spans a BGZF block or chunk boundary is read
+    /// in full. Lines that start with the index header's line comment prefix (`meta`), e.g.,
+    /// directives, are skipped, as are the first lines of the stream given by the index header's line
+    /// skip count (`skip`).
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -258,14 +269,25 @@ where
 
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
+        let validate_intervals = self.validate_intervals;
+
         let records = csi::io::Query::new(&mut self.inner, chunks)
+            .skip_lines(header.line_skip_count())?
             .indexed_records(header)
             .filter_by_region(region)
-            .map(|result| {
+            .map(move |result| {
                 result.and_then(|r| {
-                    r.as_ref()
+                    let record: Record = r
+                        .as_ref()
                         .parse()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                    if validate_intervals {
+                        validate_interval(&record)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    }
+
+                    Ok(record)
                 })
             });
 
@@ -300,6 +322,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Line;
 
     #[test]
     fn test_records() -> io::Result<()> {
@@ -344,6 +367,104 @@ ACGT
         Ok(())
     }
 
+    #[test]
+    fn test_lines_with_gvf_directives() -> io::Result<()> {
+        use crate::{io::Writer, Directive};
+
+        let data = b"\
+##gff-version 3
+##gvf-version 1.07
+##file-date 2024-01-01
+##individual-id Dbxref=NDLS:0;Gender=female
+sq0\tNOODLES\tSNV\t8\t8\t.\t+\t.\tID=v0;Variant_seq=A;Reference_seq=G
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let lines: Vec<_> = reader.lines().collect::<io::Result<_>>()?;
+
+        assert!(matches!(
+            &lines[1],
+            Line::Directive(Directive::GvfVersion(version))
+                if version.major() == 1 && version.minor() == Some(7)
+        ));
+
+        let mut writer = Writer::new(Vec::new());
+
+        for line in &lines {
+            writer.write_line(line)?;
+        }
+
+        assert_eq!(writer.get_ref(), data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lines_with_invalid_directive() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+##sequence-region sq0
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let mut lines = reader.lines();
+        assert!(matches!(lines.next(), Some(Ok(Line::Directive(_)))));
+        assert!(matches!(
+            lines.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = Builder::default()
+            .set_lenient_directives(true)
+            .build_from_reader(&data[..]);
+
+        let mut lines = reader.lines();
+        assert!(matches!(lines.next(), Some(Ok(Line::Directive(_)))));
+        assert!(matches!(
+            lines.next(),
+            Some(Ok(Line::Other(s))) if s == "##sequence-region sq0"
+        ));
+        assert!(matches!(lines.next(), Some(Ok(Line::Record(_)))));
+        assert!(lines.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_end_before_start() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t8\t.\t+\t.\tID=g0
+sq0\tNOODLES\tgene\t13\t8\t.\t+\t.\tID=g1
+";
+
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(reader.records().count(), 2);
+
+        let mut reader = Builder::default()
+            .set_validate_intervals(true)
+            .build_from_reader(&data[..]);
+
+        let mut records = reader.records();
+        assert!(matches!(records.next(), Some(Ok(_))));
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let start = Position::try_from(13)?;
+        let end = Position::try_from(8)?;
+
+        assert_eq!(
+            e.get_ref().and_then(|e| e.downcast_ref::<IntervalError>()),
+            Some(&IntervalError::EndBeforeStart { start, end })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
@@ -361,4 +482,80 @@ ACGT
 
         Ok(())
     }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use csi::binning_index::{
+            index::{header::Builder as HeaderBuilder, reference_sequence::bin::Chunk},
+            Indexer,
+        };
+
+        // The second record crosses the first 16 KiB bin boundary, so it is in a parent bin and
+        // its chunk is separate from the chunks of its neighbors.
+        const LINES: [(usize, &str); 4] = [
+            (0, "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g0"),
+            (0, "sq0\tNOODLES\tgene\t16380\t16390\t.\t+\t.\tID=g1"),
+            (0, "sq0\tNOODLES\tgene\t16400\t16410\t.\t+\t.\tID=g2"),
+            (1, "sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g3"),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        // The first line is not prefixed with the comment prefix and is skipped (`skip`).
+        writer.write_all(b"browser position sq0:8-16410\n##gff-version 3\n")?;
+
+        let mut indexer = Indexer::default().set_header(
+            HeaderBuilder::gff()
+                .set_line_skip_count(1)
+                .set_reference_sequence_names(
+                    [String::from("sq0"), String::from("sq1")]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        );
+
+        for (i, (reference_sequence_id, line)) in LINES.into_iter().enumerate() {
+            // The chunk of the first record includes the header lines.
+            let start_position = if i == 0 {
+                bgzf::VirtualPosition::default()
+            } else {
+                writer.virtual_position()
+            };
+
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+
+            let record: Record = line.parse()?;
+
+            indexer.add_record(
+                Some((reference_sequence_id, record.start(), record.end(), true)),
+                Chunk::new(start_position, writer.virtual_position()),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index: csi::Index = indexer.build(2);
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let mut query_starts = |region: &str| -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+
+            let starts = reader
+                .query(&index, &region)?
+                .map(|result| result.map(|record| usize::from(record.start())))
+                .collect::<io::Result<_>>()?;
+
+            Ok(starts)
+        };
+
+        assert_eq!(query_starts("sq0:8-16410")?, [8, 16380, 16400]);
+        assert_eq!(query_starts("sq0:16391-16400")?, [16400]);
+        assert!(query_starts("sq1:1-7")?.is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,71 @@
+use std::{error, fmt};
+
+use noodles_core::Position;
+
+use crate::Record;
+
+/// An error when a record has an invalid interval.
+///
+/// This is only returned when interval validation is enabled (see
+/// [`super::Builder::set_validate_intervals`]).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IntervalError {
+    /// The end is before the start.
+    EndBeforeStart {
+        /// The start position.
+        start: Position,
+        /// The end position.
+        end: Position,
+    },
+}
+
+impl error::Error for IntervalError {}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EndBeforeStart { start, end } => {
+                write!(f, "end ({end}) is before start ({start})")
+            }
+        }
+    }
+}
+
+// GFF3 requires that the start is less than or equal to the end. Zero-length features, e.g.,
+// insertion sites, have the start equal to the end, with the site to the right of the base. An end
+// of start - 1, which some dialects use for zero-length features, is invalid.
+pub(super) fn validate_interval(record: &Record) -> Result<(), IntervalError> {
+    let (start, end) = (record.start(), record.end());
+
+    if end < start {
+        Err(IntervalError::EndBeforeStart { start, end })
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_interval() -> Result<(), noodles_core::position::TryFromIntError> {
+        fn build_record(start: Position, end: Position) -> Record {
+            Record::builder().set_start(start).set_end(end).build()
+        }
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        assert_eq!(validate_interval(&build_record(start, end)), Ok(()));
+        assert_eq!(validate_interval(&build_record(start, start)), Ok(()));
+
+        let end = Position::try_from(7)?;
+        assert_eq!(
+            validate_interval(&build_record(start, end)),
+            Err(IntervalError::EndBeforeStart { start, end })
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,8 @@
 use std::io::{self, BufRead};
 
-use crate::Line;
+use crate::{line::ParseError, Line};
 
-use super::Reader;
+use super::{validate_interval, Reader};
 
 /// An iterator over lines of a GFF reader.
 ///
@@ -39,7 +39,15 @@ where
         match self.inner.read_line(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => match self.line_buf.parse() {
+                Ok(Line::Record(record)) if self.inner.validate_intervals => Some(
+                    validate_interval(&record)
+                        .map(|_| Line::Record(record))
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                ),
                 Ok(line) => Some(Ok(line)),
+                Err(ParseError::InvalidDirective(_)) if self.inner.lenient_directives => {
+                    Some(Ok(Line::Other(self.line_buf.clone())))
+                }
                 Err(e) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
             },
             Err(e) => Some(Err(e)),
@@ -2,7 +2,7 @@
 
 pub mod field;
 
-use std::{fmt, io, iter};
+use std::{collections::HashMap, fmt, io, iter};
 
 use self::field::{parse_field, Value};
 
@@ -47,6 +47,57 @@ impl<'a> Attributes<'a> {
             }
         })
     }
+
+    /// Returns an iterator over the values of all fields with the given tag.
+    ///
+    /// A tag may be repeated. Fields are parsed as they are iterated.
+    pub fn filter_by_key<'s>(
+        &'s self,
+        tag: &'s str,
+    ) -> impl Iterator<Item = io::Result<Value<'s>>> + 's {
+        self.iter().filter_map(move |result| match result {
+            Ok((t, value)) => (t == tag).then_some(Ok(value)),
+            Err(e) => Some(Err(e)),
+        })
+    }
+
+    /// Returns an iterator over the individual values of all fields with the given tag.
+    ///
+    /// This is [`Self::filter_by_key`] with array values ([`Value::Array`]) expanded into their
+    /// comma-separated elements. A string value ([`Value::String`]) is a single element.
+    pub fn values_for<'s>(
+        &'s self,
+        tag: &'s str,
+    ) -> impl Iterator<Item = io::Result<&'s str>> + 's {
+        let mut values: Option<Split<'s>> = None;
+        let mut fields = self.filter_by_key(tag);
+
+        iter::from_fn(move || loop {
+            if let Some(value) = values.as_mut().and_then(|vs| vs.next()) {
+                return Some(Ok(value));
+            }
+
+            match fields.next()? {
+                Ok(value) => values = Some(split_value(&value)),
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+
+    /// Collects the fields into a map of tags to their individual values.
+    ///
+    /// Values of repeated tags are appended in order, and array values ([`Value::Array`]) are
+    /// expanded as in [`Self::values_for`]. The keys and values borrow from the record.
+    pub fn collect_into_map(&self) -> io::Result<HashMap<&str, Vec<&str>>> {
+        let mut map: HashMap<_, Vec<_>> = HashMap::new();
+
+        for result in self.iter() {
+            let (tag, value) = result?;
+            map.entry(tag).or_default().extend(split_value(&value));
+        }
+
+        Ok(map)
+    }
 }
 
 impl<'a> AsRef<str> for Attributes<'a> {
@@ -68,6 +119,13 @@ impl<'a> fmt::Debug for Attributes<'a> {
     }
 }
 
+type Split<'a> = std::str::Split<'a, char>;
+
+fn split_value<'a>(value: &Value<'a>) -> Split<'a> {
+    const DELIMITER: char = ',';
+    value.as_str().split(DELIMITER)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +161,59 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_filter_by_key() -> io::Result<()> {
+        let attributes = Attributes::new("ID=gene0;Note=a;Parent=tx0,tx1;Note=b,c");
+
+        let actual: Vec<_> = attributes.filter_by_key("Note").collect::<Result<_, _>>()?;
+        let mut src = "Note=b,c";
+        let (_, array) = parse_field(&mut src)?;
+        let expected = [Value::String("a"), array];
+        assert_eq!(actual, expected);
+
+        assert!(attributes.filter_by_key("Name").next().is_none());
+
+        let attributes = Attributes::new("ID=gene0;Note");
+        let mut iter = attributes.filter_by_key("Note");
+        assert!(matches!(iter.next(), Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_values_for() -> io::Result<()> {
+        let attributes = Attributes::new("ID=gene0;Note=a;Parent=tx0,tx1;Note=b,c;Note=");
+
+        let actual: Vec<_> = attributes.values_for("Note").collect::<Result<_, _>>()?;
+        assert_eq!(actual, ["a", "b", "c", ""]);
+
+        let actual: Vec<_> = attributes.values_for("Parent").collect::<Result<_, _>>()?;
+        assert_eq!(actual, ["tx0", "tx1"]);
+
+        assert!(attributes.values_for("Name").next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_into_map() -> io::Result<()> {
+        let attributes = Attributes::new("ID=gene0;Note=a;Parent=tx0,tx1;Note=b,c");
+        let actual = attributes.collect_into_map()?;
+
+        let expected: HashMap<_, _> = [
+            ("ID", vec!["gene0"]),
+            ("Note", vec!["a", "b", "c"]),
+            ("Parent", vec!["tx0", "tx1"]),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+
+        assert!(Attributes::new("").collect_into_map()?.is_empty());
+        assert!(Attributes::new("ID").collect_into_map().is_err());
+
+        Ok(())
+    }
 }
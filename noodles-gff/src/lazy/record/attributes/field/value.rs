@@ -11,6 +11,16 @@ pub enum Value<'a> {
     Array(Array<'a>),
 }
 
+impl<'a> Value<'a> {
+    // Returns the raw value, which is a comma-separated list for an array.
+    pub(crate) fn as_str(&self) -> &'a str {
+        match self {
+            Value::String(s) => s,
+            Value::Array(array) => array.as_str(),
+        }
+    }
+}
+
 impl<'a> AsRef<str> for Value<'a> {
     fn as_ref(&self) -> &str {
         match self {
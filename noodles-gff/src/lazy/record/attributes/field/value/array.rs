@@ -9,6 +9,10 @@ impl<'a> Array<'a> {
         Self(s)
     }
 
+    pub(crate) fn as_str(&self) -> &'a str {
+        self.0
+    }
+
     /// Returns an iterator over values.
     pub fn iter(&self) -> impl Iterator<Item = &'a str> {
         const DELIMITER: char = ',';
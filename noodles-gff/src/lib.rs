@@ -35,6 +35,7 @@
 pub mod r#async;
 
 pub mod directive;
+pub mod interval_tree;
 pub mod io;
 pub mod lazy;
 pub mod line;
@@ -15,6 +15,11 @@ pub enum Line {
     Comment(String),
     /// A record.
     Record(Record),
+    /// An unparsed line.
+    ///
+    /// This holds the raw line of a directive that failed to parse when the reader passes through
+    /// invalid directives (see [`crate::io::reader::Builder::set_lenient_directives`]).
+    Other(String),
 }
 
 impl fmt::Display for Line {
@@ -23,6 +28,7 @@ impl fmt::Display for Line {
             Line::Directive(directive) => write!(f, "{directive}"),
             Line::Comment(comment) => write!(f, "{COMMENT_PREFIX}{comment}"),
             Line::Record(record) => write!(f, "{record}"),
+            Line::Other(s) => f.write_str(s),
         }
     }
 }
@@ -85,7 +91,10 @@ mod tests {
         assert_eq!(line.to_string(), "#format: gff3");
 
         let line = Line::Record(Record::default());
-        assert_eq!(line.to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.")
+        assert_eq!(line.to_string(), ".\t.\t.\t1\t1\t.\t.\t.\t.");
+
+        let line = Line::Other(String::from("##sequence-region sq0"));
+        assert_eq!(line.to_string(), "##sequence-region sq0");
     }
 
     #[test]
@@ -1,5 +1,6 @@
 //! GFF record attributes.
 
+mod builder;
 pub mod field;
 
 use std::{
@@ -10,6 +11,7 @@ use std::{
 
 use indexmap::IndexMap;
 
+pub use self::builder::{BuildError, Builder};
 use self::field::{Tag, Value};
 
 const DELIMITER: char = ';';
@@ -21,6 +23,27 @@ const DELIMITER: char = ';';
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Attributes(IndexMap<Tag, Value>);
 
+impl Attributes {
+    /// Returns a builder to create attributes from fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::Attributes;
+    ///
+    /// let attributes = Attributes::builder()
+    ///     .insert("ID", "gene0")
+    ///     .insert_multi("Parent", ["tx0", "tx1"])
+    ///     .build()?;
+    ///
+    /// assert_eq!(attributes.to_string(), "ID=gene0;Parent=tx0,tx1");
+    /// # Ok::<_, noodles_gff::record::attributes::BuildError>(())
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
+
 impl Deref for Attributes {
     type Target = IndexMap<Tag, Value>;
 
@@ -0,0 +1,188 @@
+use std::{error, fmt};
+
+use super::{
+    field::{Tag, Value},
+    Attributes,
+};
+
+/// A GFF record attributes builder.
+///
+/// Fields are kept in insertion order, which is the order they are written. Inserting a key that
+/// already exists replaces its value but keeps its original position.
+#[derive(Debug, Default)]
+pub struct Builder {
+    fields: Vec<(Tag, Value)>,
+}
+
+impl Builder {
+    /// Inserts a field with a string value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::field::Value, Attributes};
+    ///
+    /// let attributes = Attributes::builder().insert("ID", "gene0").build()?;
+    /// assert_eq!(attributes.get("ID"), Some(&Value::from("gene0")));
+    /// # Ok::<_, noodles_gff::record::attributes::BuildError>(())
+    /// ```
+    pub fn insert<K, V>(self, key: K, value: V) -> Self
+    where
+        K: Into<Tag>,
+        V: Into<String>,
+    {
+        self.insert_value(key.into(), Value::String(value.into()))
+    }
+
+    /// Inserts a field with an array value.
+    ///
+    /// The value is always an array ([`Value::Array`]), even if there is only one element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::field::Value, Attributes};
+    ///
+    /// let attributes = Attributes::builder()
+    ///     .insert_multi("Parent", ["tx0", "tx1"])
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     attributes.get("Parent"),
+    ///     Some(&Value::from(vec![String::from("tx0"), String::from("tx1")]))
+    /// );
+    /// # Ok::<_, noodles_gff::record::attributes::BuildError>(())
+    /// ```
+    pub fn insert_multi<K, I, V>(self, key: K, values: I) -> Self
+    where
+        K: Into<Tag>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let values = values.into_iter().map(|value| value.into()).collect();
+        self.insert_value(key.into(), Value::Array(values))
+    }
+
+    /// Builds the GFF record attributes.
+    ///
+    /// This returns an error if a key is empty or contains a reserved character, i.e., a control
+    /// character, `%`, `;`, `=`, `&`, or `,`. Reserved characters in values are allowed and are
+    /// percent-encoded when written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::record::{attributes::BuildError, Attributes};
+    ///
+    /// let attributes = Attributes::builder()
+    ///     .insert("ID", "gene0")
+    ///     .insert("Note", "a;b")
+    ///     .build()?;
+    ///
+    /// assert_eq!(attributes.to_string(), "ID=gene0;Note=a%3Bb");
+    ///
+    /// assert_eq!(
+    ///     Attributes::builder().insert("", "gene0").build(),
+    ///     Err(BuildError::EmptyKey)
+    /// );
+    /// # Ok::<_, BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<Attributes, BuildError> {
+        let mut attributes = Attributes::default();
+
+        for (key, value) in self.fields {
+            if key.is_empty() {
+                return Err(BuildError::EmptyKey);
+            } else if key.chars().any(is_reserved) {
+                return Err(BuildError::InvalidKey(key));
+            }
+
+            attributes.insert(key, value);
+        }
+
+        Ok(attributes)
+    }
+
+    fn insert_value(mut self, key: Tag, value: Value) -> Self {
+        self.fields.push((key, value));
+        self
+    }
+}
+
+fn is_reserved(c: char) -> bool {
+    c.is_ascii_control() || matches!(c, '%' | ';' | '=' | '&' | ',')
+}
+
+/// An error returned when GFF record attributes fail to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A key is empty.
+    EmptyKey,
+    /// A key contains a reserved character.
+    InvalidKey(Tag),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyKey => f.write_str("empty key"),
+            Self::InvalidKey(key) => write!(f, "invalid key: {key:?}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), BuildError> {
+        let attributes = Builder::default()
+            .insert("ID", "gene0")
+            .insert_multi("Parent", ["tx0", "tx1"])
+            .insert_multi("Alias", ["ndls0"])
+            .insert("Name", "gene0")
+            .insert("ID", "gene1")
+            .build()?;
+
+        let keys: Vec<_> = attributes.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["ID", "Parent", "Alias", "Name"]);
+
+        assert_eq!(attributes.get("ID"), Some(&Value::from("gene1")));
+        assert_eq!(
+            attributes.get("Alias"),
+            Some(&Value::Array(vec![String::from("ndls0")]))
+        );
+
+        assert_eq!(
+            attributes.to_string(),
+            "ID=gene1;Parent=tx0,tx1;Alias=ndls0;Name=gene0"
+        );
+
+        assert_eq!(Builder::default().build()?, Attributes::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_key() {
+        assert_eq!(
+            Builder::default()
+                .insert("ID", "gene0")
+                .insert("", "gene0")
+                .build(),
+            Err(BuildError::EmptyKey)
+        );
+
+        for key in [
+            "gene;id", "gene=id", "gene,id", "gene%id", "gene&id", "gene\tid",
+        ] {
+            assert_eq!(
+                Builder::default().insert(key, "gene0").build(),
+                Err(BuildError::InvalidKey(key.into()))
+            );
+        }
+    }
+}
@@ -1,3 +1,9 @@
+//! GTF reader.
+
+mod builder;
+mod compression_method;
+mod line_parse_error;
+
 use std::{
     io::{self, BufRead, Read, Seek},
     iter, str,
@@ -7,11 +13,25 @@ use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::{self as csi, BinningIndex};
 
+pub use self::{
+    builder::Builder, compression_method::CompressionMethod, line_parse_error::LineParseError,
+};
 use super::{Line, Record};
 
 /// A GTF reader.
 pub struct Reader<R> {
     inner: R,
+    line_position: Option<LinePosition>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct LinePosition {
+    // The number of lines read.
+    line_number: u64,
+    // The byte offset of the start of the last line read.
+    line_offset: u64,
+    // The byte offset of the end of the last line read.
+    offset: u64,
 }
 
 impl<R> Reader<R>
@@ -28,7 +48,73 @@ where
     /// let reader = gtf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_position: None,
+        }
+    }
+
+    /// Sets whether to track line numbers and byte offsets.
+    ///
+    /// When enabled, lines that fail to parse return an [`io::Error`] with a [`LineParseError`]
+    /// as its inner error, which has the line number and byte offset of the line, and
+    /// [`Self::line_number`] returns the number of lines read.
+    ///
+    /// Tracking starts at the current position of the stream, i.e., the next line read is line 1
+    /// (1-based), at byte offset 0. Until then, [`Self::line_number`] is 0. By default, line
+    /// tracking is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+    /// sq0\tNOODLES\tgene\t21\tndls\t.\t+\t.\tgene_id \"g0\";
+    /// ";
+    /// let mut reader = gtf::Reader::new(&data[..]).set_line_tracking(true);
+    ///
+    /// let mut records = reader.records();
+    /// assert!(records.next().transpose()?.is_some());
+    ///
+    /// let e = records.next().transpose().unwrap_err();
+    /// let e = e
+    ///     .get_ref()
+    ///     .and_then(|e| e.downcast_ref::<gtf::LineParseError>())
+    ///     .expect("missing line parse error");
+    /// assert_eq!(e.line(), 2);
+    /// assert_eq!(e.offset(), 42);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn set_line_tracking(mut self, value: bool) -> Self {
+        self.line_position = value.then(LinePosition::default);
+        self
+    }
+
+    /// Returns the number of lines read, if line tracking is enabled.
+    ///
+    /// This is also the 1-based line number of the last line read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let data = b"##format: gtf\n";
+    /// let mut reader = gtf::Reader::new(&data[..]);
+    /// assert!(reader.line_number().is_none());
+    ///
+    /// let mut reader = gtf::Reader::new(&data[..]).set_line_tracking(true);
+    /// assert_eq!(reader.line_number(), Some(0));
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_line(&mut buf)?;
+    /// assert_eq!(reader.line_number(), Some(1));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn line_number(&self) -> Option<u64> {
+        self.line_position.map(|position| position.line_number)
     }
 
     /// Reads a raw GTF line.
@@ -52,7 +138,17 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
-        read_line(&mut self.inner, buf)
+        let n = read_line(&mut self.inner, buf)?;
+
+        if let Some(position) = self.line_position.as_mut() {
+            if n > 0 {
+                position.line_number += 1;
+                position.line_offset = position.offset;
+                position.offset += n as u64;
+            }
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over lines starting from the current stream position.
@@ -87,10 +183,13 @@ where
 
             match self.read_line(&mut buf) {
                 Ok(0) => None,
-                Ok(_) => Some(
-                    buf.parse()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
-                ),
+                Ok(_) => Some(buf.parse().map_err(|e| match self.line_position {
+                    Some(position) => io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        LineParseError::new(position.line_number, position.line_offset, e),
+                    ),
+                    None => io::Error::new(io::ErrorKind::InvalidData, e),
+                })),
                 Err(e) => Some(Err(e)),
             }
         })
@@ -137,6 +236,35 @@ where
     R: Read + Seek,
 {
     /// Returns an iterator over records that intersects the given region.
+    ///
+    /// This reads the chunks from the index query and returns the records whose start and end
+    /// positions intersect the region. A record that spans a BGZF block or chunk boundary is read
+    /// in full. Lines that start with the index header's line comment prefix (`meta`), e.g.,
+    /// comments, are skipped, as are the first lines of the stream given by the index header's line
+    /// skip count (`skip`).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    /// use noodles_gtf as gtf;
+    ///
+    /// let mut reader = File::open("annotations.gtf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(gtf::Reader::new)?;
+    ///
+    /// let index = csi::read("annotations.gtf.gz.csi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&index, &region)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
     pub fn query<'r, I>(
         &'r mut self,
         index: &I,
@@ -165,6 +293,7 @@ where
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
         let records = csi::io::Query::new(&mut self.inner, chunks)
+            .skip_lines(header.line_skip_count())?
             .indexed_records(header)
             .filter_by_region(region)
             .map(|result| {
@@ -224,4 +353,131 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_lines_with_line_tracking() -> io::Result<()> {
+        let src = b"##format: gtf\r
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";
+sq0\tNOODLES\tgene\t8\tndls\t.\t+\t.\tgene_id \"g0\";
+";
+
+        let mut reader = Reader::new(&src[..]).set_line_tracking(true);
+        let mut lines = reader.lines();
+
+        assert!(lines.next().transpose()?.is_some());
+        assert!(lines.next().transpose()?.is_some());
+        assert!(lines.next().transpose()?.is_some());
+
+        let e = lines.next().transpose().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let e = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<LineParseError>())
+            .unwrap();
+
+        let offset = src
+            .split(|&b| b == b'\n')
+            .take(3)
+            .map(|line| line.len() + 1)
+            .sum::<usize>();
+        assert_eq!((e.line(), e.offset()), (4, offset as u64));
+
+        assert!(lines.next().is_none());
+        drop(lines);
+        assert_eq!(reader.line_number(), Some(4));
+
+        let mut reader = Reader::new(&src[..]);
+        let e = reader.lines().nth(3).transpose().unwrap_err();
+        assert!(e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<LineParseError>())
+            .is_none());
+        assert!(reader.line_number().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use csi::binning_index::{
+            index::{header::Builder as HeaderBuilder, reference_sequence::bin::Chunk},
+            Indexer,
+        };
+
+        // The second record crosses the first 16 KiB bin boundary, so it is in a parent bin and
+        // its chunk is separate from the chunks of its neighbors.
+        const LINES: [(usize, &str); 4] = [
+            (0, "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";"),
+            (
+                0,
+                "sq0\tNOODLES\tgene\t16380\t16390\t.\t+\t.\tgene_id \"g1\";",
+            ),
+            (
+                0,
+                "sq0\tNOODLES\tgene\t16400\t16410\t.\t+\t.\tgene_id \"g2\";",
+            ),
+            (1, "sq1\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g3\";"),
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        // The first line is not prefixed with the comment prefix and is skipped (`skip`).
+        writer.write_all(b"browser position sq0:8-16410\n#!genome-build NDLS0\n")?;
+
+        let mut indexer = Indexer::default().set_header(
+            HeaderBuilder::gff()
+                .set_line_skip_count(1)
+                .set_reference_sequence_names(
+                    [String::from("sq0"), String::from("sq1")]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        );
+
+        for (i, (reference_sequence_id, line)) in LINES.into_iter().enumerate() {
+            // The chunk of the first record includes the header lines.
+            let start_position = if i == 0 {
+                bgzf::VirtualPosition::default()
+            } else {
+                writer.virtual_position()
+            };
+
+            writer.write_all(line.as_bytes())?;
+            writer.write_all(b"\n")?;
+            writer.flush()?;
+
+            let record: Record = line.parse()?;
+
+            indexer.add_record(
+                Some((reference_sequence_id, record.start(), record.end(), true)),
+                Chunk::new(start_position, writer.virtual_position()),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index: csi::Index = indexer.build(2);
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+
+        let mut query_starts = |region: &str| -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+
+            let starts = reader
+                .query(&index, &region)?
+                .map(|result| result.map(|record| usize::from(record.start())))
+                .collect::<io::Result<_>>()?;
+
+            Ok(starts)
+        };
+
+        assert_eq!(query_starts("sq0:8-16410")?, [8, 16380, 16400]);
+        assert_eq!(query_starts("sq0:16391-16400")?, [16400]);
+        assert!(query_starts("sq1:1-7")?.is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,140 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use flate2::bufread::MultiGzDecoder;
+use noodles_bgzf as bgzf;
+
+use super::{CompressionMethod, Reader};
+
+/// A GTF reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_method: Option<CompressionMethod>,
+}
+
+impl Builder {
+    /// Sets the compression method.
+    ///
+    /// By default, the compression method is autodetected on build. See [`Self::build_from_path`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gtf::{self as gtf, reader::CompressionMethod};
+    /// let builder = gtf::reader::Builder::default().set_compression_method(CompressionMethod::Gzip);
+    /// ```
+    pub fn set_compression_method(mut self, compression_method: CompressionMethod) -> Self {
+        self.compression_method = Some(compression_method);
+        self
+    }
+
+    /// Builds a GTF reader from a path.
+    ///
+    /// If the compression method is not set, it is detected from the magic number. A BGZF file,
+    /// i.e., a gzip file with a BGZF header, is read as BGZF; any other gzip file is read as
+    /// gzip, which includes multiple concatenated members; and anything else is read as
+    /// uncompressed GTF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_gtf as gtf;
+    /// let reader = gtf::reader::Builder::default().build_from_path("annotations.gtf.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<Box<dyn BufRead>>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(src).map(BufReader::new)?;
+        let reader = decompress(file, self.compression_method)?;
+        Ok(self.build_from_reader(reader))
+    }
+
+    /// Builds a GTF reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gtf as gtf;
+    /// let reader = gtf::reader::Builder::default().build_from_reader(io::empty());
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: BufRead,
+    {
+        Reader::new(reader)
+    }
+}
+
+// Wraps the reader in a decoder for the compression method, detecting it if not given.
+fn decompress<R>(
+    mut reader: R,
+    compression_method: Option<CompressionMethod>,
+) -> io::Result<Box<dyn BufRead>>
+where
+    R: BufRead + 'static,
+{
+    let compression_method = match compression_method {
+        Some(compression_method) => compression_method,
+        None => match bgzf::io::detect_compression_method(&mut reader)? {
+            Some(bgzf::io::CompressionMethod::Bgzf) => CompressionMethod::Bgzf,
+            Some(bgzf::io::CompressionMethod::Gzip) => CompressionMethod::Gzip,
+            None => CompressionMethod::None,
+        },
+    };
+
+    Ok(match compression_method {
+        CompressionMethod::None => Box::new(reader),
+        CompressionMethod::Bgzf => Box::new(bgzf::Reader::new(reader)),
+        CompressionMethod::Gzip => Box::new(BufReader::new(MultiGzDecoder::new(reader))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use flate2::{write::GzEncoder, Compression};
+
+    use super::*;
+
+    const DATA: &[u8] = b"sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id \"g0\";\n";
+
+    fn gzip(src: &[u8]) -> io::Result<Vec<u8>> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(src)?;
+        encoder.finish()
+    }
+
+    fn bgzip(src: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(src)?;
+        writer.finish()
+    }
+
+    #[test]
+    fn test_decompress() -> io::Result<()> {
+        let t = |src: Vec<u8>, compression_method: Option<CompressionMethod>| {
+            let mut reader = decompress(io::Cursor::new(src), compression_method)?;
+
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+
+            Ok::<_, io::Error>(buf)
+        };
+
+        assert_eq!(t(DATA.to_vec(), None)?, DATA);
+        assert_eq!(t(gzip(DATA)?, None)?, DATA);
+        assert_eq!(t(bgzip(DATA)?, None)?, DATA);
+
+        let src = gzip(DATA)?;
+        assert_eq!(t(src.clone(), Some(CompressionMethod::None))?, src);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,50 @@
+use std::{error, fmt};
+
+use crate::line;
+
+/// An error returned when a GTF line read with line tracking fails to parse.
+///
+/// This is the inner error of the [`std::io::Error`] returned by the reader when line tracking is
+/// enabled (see [`super::Reader::set_line_tracking`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineParseError {
+    line: u64,
+    offset: u64,
+    source: line::ParseError,
+}
+
+impl LineParseError {
+    pub(super) fn new(line: u64, offset: u64, source: line::ParseError) -> Self {
+        Self {
+            line,
+            offset,
+            source,
+        }
+    }
+
+    /// Returns the 1-based line number of the line.
+    pub fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the byte offset of the start of the line in the input.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl error::Error for LineParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for LineParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid line at line {} (byte offset {})",
+            self.line, self.offset
+        )
+    }
+}
@@ -56,7 +56,8 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             record.alignment_start(),
             record.alignment_end(),
         ) {
-            (Some(id), Some(start), Some(end)) => {
+            (Some(id), Some(start), end) => {
+                let end = end.unwrap_or(start);
                 Some((id, start, end, !record.flags().is_unmapped()))
             }
             _ => None,
@@ -0,0 +1,88 @@
+//! Measures the cost of reading CIGAR-derived values with and without `ResolvedRecord`.
+//!
+//! This reads the alignment span of a record with a CIGAR of a given number of operations
+//! (default: 7) 1, 2, and 4 times per record, both by parsing the CIGAR of the record on each
+//! access and by using a `ResolvedRecord`, and prints the time per record.
+//!
+//! Run a release build, e.g., `cargo run --release --example sam_resolved_record_throughput 7`.
+
+use std::{env, hint::black_box, io, time::Instant};
+
+use noodles_sam::{self as sam, alignment::record::Cigar, record::ResolvedRecord};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn build_record(op_count: usize) -> io::Result<sam::Record> {
+    const KINDS: [char; 2] = ['M', 'I'];
+
+    // Alternate matches and insertions, ending on a match.
+    let cigar: String = (0..op_count)
+        .map(|i| {
+            let kind = if i + 1 == op_count { 'M' } else { KINDS[i % 2] };
+            format!("{}{kind}", i + 1)
+        })
+        .collect();
+
+    let src = format!("r0\t0\tsq0\t1\t255\t{cigar}\t*\t0\t0\t*\t*\n");
+
+    let mut reader = sam::io::Reader::new(src.as_bytes());
+    let mut record = sam::Record::default();
+    reader.read_record(&mut record)?;
+
+    Ok(record)
+}
+
+fn reparse(record: &sam::Record, access_count: usize) -> io::Result<usize> {
+    let mut sum = 0;
+
+    for _ in 0..access_count {
+        sum += record.cigar().alignment_span()?;
+    }
+
+    Ok(sum)
+}
+
+fn resolved(record: &sam::Record, access_count: usize) -> io::Result<usize> {
+    let resolved = ResolvedRecord::new(record);
+    let mut sum = 0;
+
+    for _ in 0..access_count {
+        sum += resolved.alignment_span()?;
+    }
+
+    Ok(sum)
+}
+
+fn time<F>(mut f: F) -> io::Result<f64>
+where
+    F: FnMut() -> io::Result<usize>,
+{
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        black_box(f()?);
+    }
+
+    Ok(start.elapsed().as_nanos() as f64 / f64::from(ITERATIONS))
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let op_count = env::args()
+        .nth(1)
+        .map(|s| s.parse())
+        .transpose()?
+        .unwrap_or(7);
+
+    let record = build_record(op_count)?;
+
+    for access_count in [1, 2, 4] {
+        let reparse_time = time(|| reparse(black_box(&record), access_count))?;
+        let resolved_time = time(|| resolved(black_box(&record), access_count))?;
+
+        println!(
+            "{op_count} ops, {access_count} accesses: reparse {reparse_time:.0} ns/record, resolved {resolved_time:.0} ns/record"
+        );
+    }
+
+    Ok(())
+}
@@ -1,6 +1,11 @@
 //! Alignment record I/O.
 
 mod read;
+mod record_observer;
 mod write;
 
-pub use self::{read::Read, write::Write};
+pub use self::{
+    read::Read,
+    record_observer::{RecordMeta, RecordObserver},
+    write::Write,
+};
@@ -0,0 +1,146 @@
+use std::fmt;
+
+/// Metadata of a record read by an alignment reader.
+///
+/// This is given to a [`RecordObserver`] after each record is read.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RecordMeta {
+    byte_len: Option<usize>,
+    reference_sequence_id: Option<usize>,
+    is_mapped: bool,
+}
+
+impl RecordMeta {
+    /// Creates record metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::RecordMeta;
+    /// let meta = RecordMeta::new(Some(34), Some(0), true);
+    /// ```
+    pub fn new(
+        byte_len: Option<usize>,
+        reference_sequence_id: Option<usize>,
+        is_mapped: bool,
+    ) -> Self {
+        Self {
+            byte_len,
+            reference_sequence_id,
+            is_mapped,
+        }
+    }
+
+    /// Returns the number of bytes read for the record.
+    ///
+    /// This is the length of the encoded record in the stream, e.g., the line length of a SAM
+    /// record or the block size of a BAM record. It is `None` when records are not stored
+    /// contiguously, e.g., CRAM records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::RecordMeta;
+    /// let meta = RecordMeta::new(Some(34), Some(0), true);
+    /// assert_eq!(meta.byte_len(), Some(34));
+    /// ```
+    pub fn byte_len(&self) -> Option<usize> {
+        self.byte_len
+    }
+
+    /// Returns the reference sequence ID of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::RecordMeta;
+    /// let meta = RecordMeta::new(Some(34), Some(0), true);
+    /// assert_eq!(meta.reference_sequence_id(), Some(0));
+    /// ```
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        self.reference_sequence_id
+    }
+
+    /// Returns whether the record is mapped.
+    ///
+    /// This is the inverse of the unmapped flag (`0x04`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::RecordMeta;
+    /// let meta = RecordMeta::new(Some(34), Some(0), true);
+    /// assert!(meta.is_mapped());
+    /// ```
+    pub fn is_mapped(&self) -> bool {
+        self.is_mapped
+    }
+}
+
+/// A callback that is called by an alignment reader after each record is read.
+///
+/// This can be used to collect metrics, e.g., record and byte counts, without wrapping the record
+/// iterators of a reader. The callback is shared by reference, so state is kept using interior
+/// mutability, e.g., atomics.
+///
+/// Readers without an observer do not build record metadata.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{
+///     atomic::{AtomicUsize, Ordering},
+///     Arc,
+/// };
+///
+/// use noodles_sam::alignment::io::{RecordMeta, RecordObserver};
+///
+/// let count = Arc::new(AtomicUsize::new(0));
+///
+/// let observer = {
+///     let count = Arc::clone(&count);
+///     RecordObserver::new(move |_: &RecordMeta| {
+///         count.fetch_add(1, Ordering::Relaxed);
+///     })
+/// };
+///
+/// observer.observe(&RecordMeta::new(Some(34), Some(0), true));
+/// assert_eq!(count.load(Ordering::Relaxed), 1);
+/// ```
+pub struct RecordObserver(Box<dyn Fn(&RecordMeta) + Send + Sync>);
+
+impl RecordObserver {
+    /// Creates a record observer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::{RecordMeta, RecordObserver};
+    /// let observer = RecordObserver::new(|meta: &RecordMeta| println!("{:?}", meta.byte_len()));
+    /// ```
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(&RecordMeta) + Send + Sync + 'static,
+    {
+        Self(Box::new(f))
+    }
+
+    /// Calls the observer with the given record metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::io::{RecordMeta, RecordObserver};
+    /// let observer = RecordObserver::new(|meta: &RecordMeta| assert!(meta.is_mapped()));
+    /// observer.observe(&RecordMeta::new(Some(34), Some(0), true));
+    /// ```
+    pub fn observe(&self, meta: &RecordMeta) {
+        (self.0)(meta)
+    }
+}
+
+impl fmt::Debug for RecordObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordObserver").finish_non_exhaustive()
+    }
+}
@@ -2,11 +2,13 @@
 
 pub mod cigar;
 pub mod data;
+pub mod fields;
 mod flags;
 pub mod mapping_quality;
 mod name;
 mod quality_scores;
 mod sequence;
+mod validate_flags;
 
 use std::io;
 
@@ -14,8 +16,15 @@ use bstr::BStr;
 use noodles_core as core;
 
 pub use self::{
-    cigar::Cigar, data::Data, flags::Flags, mapping_quality::MappingQuality, name::Name,
-    quality_scores::QualityScores, sequence::Sequence,
+    cigar::Cigar,
+    data::Data,
+    fields::Fields,
+    flags::Flags,
+    mapping_quality::MappingQuality,
+    name::Name,
+    quality_scores::QualityScores,
+    sequence::Sequence,
+    validate_flags::{validate_flags, FlagViolation},
 };
 use crate::{
     header::{
@@ -67,6 +76,14 @@ pub trait Record {
     /// Returns the data.
     fn data(&self) -> Box<dyn Data + '_>;
 
+    /// Returns the raw bin, if the record has one.
+    ///
+    /// This is the legacy BAI bin (`bin`) of a record read from BAM. By default, a record has no
+    /// bin.
+    fn bin(&self) -> Option<io::Result<u16>> {
+        None
+    }
+
     /// Returns the associated reference sequence.
     fn reference_sequence<'h>(
         &self,
@@ -102,7 +119,15 @@ pub trait Record {
     }
 
     /// Calculates the end position.
+    ///
+    /// This returns `None` if the record is unmapped or has no alignment start.
     fn alignment_end(&self) -> Option<io::Result<core::Position>> {
+        match self.flags() {
+            Ok(flags) if flags.is_unmapped() => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(e)),
+        }
+
         let start = match self.alignment_start().transpose() {
             Ok(position) => position?,
             Err(e) => return Some(Err(e)),
@@ -117,6 +142,25 @@ pub trait Record {
             Err(e) => Some(Err(e)),
         }
     }
+
+    /// Formats the record as a SAM line.
+    ///
+    /// This is the tab-delimited SAM representation of the record, without a line ending, as
+    /// written by a SAM writer. The reference sequence IDs are resolved to their names using the
+    /// given header. Missing fields are written as `*` (or `0` for positions), and a mate
+    /// reference sequence name that is the same as the reference sequence name is written as
+    /// `=`.
+    ///
+    /// This is intended for debugging and logging. Use a SAM writer to write many records.
+    fn to_sam_line(&self, header: &Header) -> io::Result<String> {
+        use crate::io::writer::{record::write_record, LineEnding};
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, header, self, LineEnding::Lf)?;
+        buf.pop();
+
+        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
 }
 
 impl Record for Box<dyn Record> {
@@ -173,6 +217,10 @@ impl Record for Box<dyn Record> {
     fn data(&self) -> Box<dyn Data + '_> {
         (**self).data()
     }
+
+    fn bin(&self) -> Option<io::Result<u16>> {
+        (**self).bin()
+    }
 }
 
 fn get_reference_sequence(
@@ -201,6 +249,7 @@ mod tests {
         };
 
         let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
             .set_alignment_start(core::Position::try_from(8)?)
             .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
             .build();
@@ -209,6 +258,57 @@ mod tests {
         let expected = core::Position::new(12);
         assert_eq!(actual, expected);
 
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_alignment_start(core::Position::try_from(8)?)
+            .build();
+
+        assert!(Record::alignment_end(&record).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_sam_line() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::alignment::{
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let record = RecordBuf::default();
+        assert_eq!(
+            record.to_sam_line(&header)?,
+            "*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*"
+        );
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".as_slice().into())
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(core::Position::try_from(8)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(core::Position::try_from(5)?)
+            .set_sequence(b"ACGT".into())
+            .build();
+
+        assert_eq!(
+            record.to_sam_line(&header)?,
+            "r0\t1\tsq0\t8\t255\t4M\t=\t5\t0\tACGT\t*"
+        );
+
+        let record: Box<dyn Record> = Box::new(record);
+        assert!(record.to_sam_line(&header)?.starts_with("r0\t1\tsq0\t"));
+
         Ok(())
     }
 }
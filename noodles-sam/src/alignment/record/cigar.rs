@@ -4,10 +4,16 @@ pub mod op;
 
 #[doc(hidden)]
 pub mod iter;
+mod split;
+mod validate;
 
 use std::io;
 
-pub use self::op::Op;
+pub use self::{
+    op::Op,
+    split::split_at_reference_position,
+    validate::{validate_cigar_against_sequence, CigarLengthMismatch},
+};
 
 /// Alignment record CIGAR operations.
 pub trait Cigar {
@@ -20,6 +26,65 @@ pub trait Cigar {
     /// Returns an iterator over operations.
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Op>> + '_>;
 
+    /// Returns an iterator over operations with their reference and query offsets.
+    ///
+    /// Each item is `(op, reference_offset, query_offset)`, where the offsets are at the start of
+    /// the operation. The reference offset is relative to the alignment start, and the query
+    /// offset is relative to the start of the read sequence, both 0-based.
+    ///
+    /// The reference offset is advanced by operations that consume the reference: alignment match
+    /// (`M`), deletion (`D`), skip (`N`), sequence match (`=`), and sequence mismatch (`X`). The
+    /// query offset is advanced by operations that consume the read: alignment match (`M`),
+    /// insertion (`I`), soft clip (`S`), sequence match (`=`), and sequence mismatch (`X`). Hard
+    /// clip (`H`) and pad (`P`) operations advance neither.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{cigar::{op::Kind, Op}, Cigar as _},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::SoftClip, 2),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::Deletion, 1),
+    ///     Op::new(Kind::Match, 3),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let actual: Vec<_> = cigar.iter_with_offsets().collect::<std::io::Result<_>>()?;
+    ///
+    /// assert_eq!(actual, [
+    ///     (Op::new(Kind::SoftClip, 2), 0, 0),
+    ///     (Op::new(Kind::Match, 4), 0, 2),
+    ///     (Op::new(Kind::Deletion, 1), 4, 6),
+    ///     (Op::new(Kind::Match, 3), 5, 6),
+    /// ]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn iter_with_offsets(&self) -> Box<dyn Iterator<Item = io::Result<(Op, usize, usize)>> + '_> {
+        let mut reference_offset = 0;
+        let mut query_offset = 0;
+
+        Box::new(self.iter().map(move |result| {
+            let op = result?;
+            let item = (op, reference_offset, query_offset);
+
+            if op.kind().consumes_reference() {
+                reference_offset += op.len();
+            }
+
+            if op.kind().consumes_read() {
+                query_offset += op.len();
+            }
+
+            Ok(item)
+        }))
+    }
+
     /// Calculates the alignment span over the reference sequence.
     fn alignment_span(&self) -> io::Result<usize> {
         let mut span = 0;
@@ -49,6 +114,63 @@ pub trait Cigar {
 
         Ok(length)
     }
+
+    /// Calculates the number of aligned and total query bases.
+    ///
+    /// This returns `(aligned_query_bases, total_query_bases)`. The aligned query bases are the
+    /// bases of alignment match (`M`), sequence match (`=`), sequence mismatch (`X`), and
+    /// insertion (`I`) operations.
+    ///
+    /// The total query bases are the bases of the original read, i.e., the read length plus the
+    /// bases of hard clip (`H`) operations. Hard-clipped bases are absent from the sequence but
+    /// were still sequenced, so they count toward the total but never as aligned. Soft-clipped
+    /// (`S`) bases count toward the total and are also not aligned.
+    ///
+    /// The query coverage, i.e., the fraction of the read that is aligned, is the ratio of the
+    /// two. It is undefined when the total is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{cigar::{op::Kind, Op}, Cigar as _},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::HardClip, 2),
+    ///     Op::new(Kind::SoftClip, 3),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::Deletion, 1),
+    ///     Op::new(Kind::Insertion, 1),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(cigar.query_coverage()?, (5, 10));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn query_coverage(&self) -> io::Result<(usize, usize)> {
+        use self::op::Kind;
+
+        let mut aligned_len = 0;
+        let mut total_len = 0;
+
+        for result in self.iter() {
+            let op = result?;
+
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Insertion => {
+                    aligned_len += op.len();
+                    total_len += op.len();
+                }
+                Kind::SoftClip | Kind::HardClip => total_len += op.len(),
+                _ => {}
+            }
+        }
+
+        Ok((aligned_len, total_len))
+    }
 }
 
 impl<'a> IntoIterator for &'a dyn Cigar {
@@ -107,6 +229,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iter_with_offsets() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 8),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Deletion, 4),
+            Op::new(Kind::Skip, 100),
+            Op::new(Kind::SequenceMatch, 6),
+            Op::new(Kind::SequenceMismatch, 1),
+            Op::new(Kind::Pad, 1),
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::HardClip, 2),
+        ]);
+
+        let actual: Vec<_> = cigar.iter_with_offsets().collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Op::new(Kind::HardClip, 5), 0, 0),
+            (Op::new(Kind::SoftClip, 3), 0, 0),
+            (Op::new(Kind::Match, 8), 0, 3),
+            (Op::new(Kind::Insertion, 2), 8, 11),
+            (Op::new(Kind::Deletion, 4), 8, 13),
+            (Op::new(Kind::Skip, 100), 12, 13),
+            (Op::new(Kind::SequenceMatch, 6), 112, 13),
+            (Op::new(Kind::SequenceMismatch, 1), 118, 19),
+            (Op::new(Kind::Pad, 1), 119, 20),
+            (Op::new(Kind::SoftClip, 2), 119, 20),
+            (Op::new(Kind::HardClip, 2), 119, 22),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let cigar: &dyn Cigar = &T(Vec::new());
+        assert_eq!(cigar.iter_with_offsets().count(), 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_alignment_span() -> io::Result<()> {
         let cigar: &dyn Cigar = &T(vec![
@@ -132,4 +294,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_query_coverage() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 8),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Deletion, 4),
+            Op::new(Kind::Skip, 100),
+            Op::new(Kind::SequenceMatch, 6),
+            Op::new(Kind::SequenceMismatch, 1),
+            Op::new(Kind::Pad, 1),
+            Op::new(Kind::HardClip, 2),
+        ]);
+
+        assert_eq!(cigar.query_coverage()?, (17, 27));
+
+        let cigar: &dyn Cigar = &T(Vec::new());
+        assert_eq!(cigar.query_coverage()?, (0, 0));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,202 @@
+use std::io;
+
+use noodles_core::Position;
+
+use super::{Cigar, Op};
+use crate::alignment::record_buf;
+
+/// Splits CIGAR operations at a reference sequence position.
+///
+/// `start` is the alignment start, i.e., the reference sequence position of the first operation
+/// that consumes the reference. `split_pos` is the first reference sequence position of the right
+/// half. The left half covers the reference sequence interval [`start`, `split_pos`), and the
+/// right half covers the remainder.
+///
+/// An operation that consumes the reference, i.e., alignment match (`M`), deletion (`D`),
+/// skipped region (`N`), sequence match (`=`), and sequence mismatch (`X`), and spans the
+/// boundary is divided into two operations of the same kind.
+///
+/// Operations that do not consume the reference, i.e., insertion (`I`), soft clip (`S`), hard
+/// clip (`H`), and padding (`P`), are zero-width on the reference. They are assigned to the left
+/// half if they occur before the boundary is reached and to the right half otherwise. In
+/// particular, an insertion immediately before `split_pos` is assigned to the right half, i.e.,
+/// it stays attached to the base that follows it. As a consequence, a leading clip goes to the
+/// right half when `split_pos` is `start`, and a trailing clip goes to the right half when
+/// `split_pos` is the position after the alignment end.
+///
+/// If `split_pos` is at or before `start`, all operations are in the right half. If it is after
+/// the alignment end, all operations are in the left half, unless the above rule applies.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, split_at_reference_position, Op},
+///     record_buf::Cigar,
+/// };
+///
+/// let cigar: Cigar = [
+///     Op::new(Kind::SoftClip, 2),
+///     Op::new(Kind::Match, 4),
+///     Op::new(Kind::Insertion, 1),
+///     Op::new(Kind::Match, 3),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let start = Position::try_from(8)?;
+///
+/// let (left, right) = split_at_reference_position(&cigar, start, Position::try_from(10)?)?;
+/// assert_eq!(left.as_ref(), [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 2)]);
+/// assert_eq!(
+///     right.as_ref(),
+///     [
+///         Op::new(Kind::Match, 2),
+///         Op::new(Kind::Insertion, 1),
+///         Op::new(Kind::Match, 3),
+///     ]
+/// );
+///
+/// let (left, right) = split_at_reference_position(&cigar, start, Position::try_from(12)?)?;
+/// assert_eq!(left.as_ref(), [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 4)]);
+/// assert_eq!(right.as_ref(), [Op::new(Kind::Insertion, 1), Op::new(Kind::Match, 3)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn split_at_reference_position<C>(
+    cigar: &C,
+    start: Position,
+    split_pos: Position,
+) -> io::Result<(record_buf::Cigar, record_buf::Cigar)>
+where
+    C: Cigar + ?Sized,
+{
+    let split_pos = usize::from(split_pos);
+
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    let mut pos = usize::from(start);
+
+    for result in cigar.iter() {
+        let op = result?;
+        let kind = op.kind();
+
+        if !kind.consumes_reference() {
+            if pos < split_pos {
+                left.push(op);
+            } else {
+                right.push(op);
+            }
+
+            continue;
+        }
+
+        let end = pos
+            .checked_add(op.len())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid alignment span"))?;
+
+        if end <= split_pos {
+            left.push(op);
+        } else if pos >= split_pos {
+            right.push(op);
+        } else {
+            left.push(Op::new(kind, split_pos - pos));
+            right.push(Op::new(kind, end - split_pos));
+        }
+
+        pos = end;
+    }
+
+    Ok((left.into(), right.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record::cigar::op::Kind;
+
+    fn split(ops: &[Op], start: usize, split_pos: usize) -> (Vec<Op>, Vec<Op>) {
+        let cigar: record_buf::Cigar = ops.iter().copied().collect();
+
+        let (left, right) = split_at_reference_position(
+            &cigar,
+            Position::new(start).unwrap(),
+            Position::new(split_pos).unwrap(),
+        )
+        .unwrap();
+
+        (left.as_ref().to_vec(), right.as_ref().to_vec())
+    }
+
+    #[test]
+    fn test_split_at_reference_position() {
+        let ops = [
+            Op::new(Kind::HardClip, 1),
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 3),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Skip, 5),
+            Op::new(Kind::SequenceMatch, 2),
+            Op::new(Kind::SoftClip, 1),
+        ];
+
+        // Reference intervals: M [8, 11], D [12, 14], I before 15, N [15, 19], = [20, 21].
+
+        // inside M
+        let (left, right) = split(&ops, 8, 9);
+        assert_eq!(
+            left,
+            [
+                Op::new(Kind::HardClip, 1),
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::Match, 1),
+            ]
+        );
+        assert_eq!(right[0], Op::new(Kind::Match, 3));
+        assert_eq!(right[1..], ops[3..]);
+
+        // inside D
+        let (left, right) = split(&ops, 8, 13);
+        assert_eq!(
+            left,
+            [
+                Op::new(Kind::HardClip, 1),
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Deletion, 1),
+            ]
+        );
+        assert_eq!(right[0], Op::new(Kind::Deletion, 2));
+        assert_eq!(right[1..], ops[4..]);
+
+        // at the insertion
+        let (left, right) = split(&ops, 8, 15);
+        assert_eq!(left, ops[..4]);
+        assert_eq!(right, ops[4..]);
+
+        // inside N
+        let (left, right) = split(&ops, 8, 17);
+        assert_eq!(left[..5], ops[..5]);
+        assert_eq!(left[5..], [Op::new(Kind::Skip, 2)]);
+        assert_eq!(
+            right,
+            [
+                Op::new(Kind::Skip, 3),
+                Op::new(Kind::SequenceMatch, 2),
+                Op::new(Kind::SoftClip, 1),
+            ]
+        );
+
+        // at and before the alignment start
+        assert_eq!(split(&ops, 8, 8), (Vec::new(), ops.to_vec()));
+        assert_eq!(split(&ops, 8, 1), (Vec::new(), ops.to_vec()));
+
+        // at and after the alignment end
+        assert_eq!(split(&ops, 8, 22), (ops[..7].to_vec(), ops[7..].to_vec()));
+        assert_eq!(split(&ops, 8, 100), (ops.to_vec(), Vec::new()));
+
+        assert_eq!(split(&[], 8, 13), (Vec::new(), Vec::new()));
+    }
+}
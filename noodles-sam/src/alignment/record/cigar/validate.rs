@@ -0,0 +1,130 @@
+use std::{error, fmt, io};
+
+use super::Cigar;
+
+/// An error returned when the read length of CIGAR operations does not match the sequence length.
+///
+/// This is the inner error of the [`io::Error`] returned by [`validate_cigar_against_sequence`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CigarLengthMismatch {
+    read_length: usize,
+    sequence_length: usize,
+}
+
+impl CigarLengthMismatch {
+    /// Returns the read length calculated from the CIGAR operations.
+    pub fn read_length(&self) -> usize {
+        self.read_length
+    }
+
+    /// Returns the sequence length.
+    pub fn sequence_length(&self) -> usize {
+        self.sequence_length
+    }
+}
+
+impl error::Error for CigarLengthMismatch {}
+
+impl fmt::Display for CigarLengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CIGAR read length mismatch: expected {} (sequence length), got {} (sum of M/I/S/=/X operation lengths)",
+            self.sequence_length, self.read_length
+        )
+    }
+}
+
+/// Validates that the read length of CIGAR operations matches the sequence length.
+///
+/// The read length is the sum of the lengths of the operations that consume the read, i.e.,
+/// alignment match (`M`), insertion (`I`), soft clip (`S`), sequence match (`=`), and sequence
+/// mismatch (`X`).
+///
+/// The check is skipped if the sequence is missing (`*`), i.e., its length is 0, since its
+/// length is unknown, or if the CIGAR is missing (`*`).
+///
+/// On a mismatch, this returns an [`io::ErrorKind::InvalidData`] error with a
+/// [`CigarLengthMismatch`] as its inner error.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, validate_cigar_against_sequence, CigarLengthMismatch, Op},
+///     record_buf::Cigar,
+/// };
+///
+/// let cigar: Cigar = [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 4)]
+///     .into_iter()
+///     .collect();
+///
+/// assert!(validate_cigar_against_sequence(&cigar, 6).is_ok());
+/// assert!(validate_cigar_against_sequence(&cigar, 0).is_ok());
+///
+/// let e = validate_cigar_against_sequence(&cigar, 5).unwrap_err();
+/// let mismatch = e
+///     .get_ref()
+///     .and_then(|e| e.downcast_ref::<CigarLengthMismatch>())
+///     .expect("missing mismatch");
+/// assert_eq!((mismatch.read_length(), mismatch.sequence_length()), (6, 5));
+/// ```
+pub fn validate_cigar_against_sequence<C>(cigar: &C, sequence_length: usize) -> io::Result<()>
+where
+    C: Cigar + ?Sized,
+{
+    if sequence_length == 0 || cigar.is_empty() {
+        return Ok(());
+    }
+
+    let read_length = cigar.read_length()?;
+
+    if read_length == sequence_length {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            CigarLengthMismatch {
+                read_length,
+                sequence_length,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{
+        record::cigar::{op::Kind, Op},
+        record_buf,
+    };
+
+    #[test]
+    fn test_validate_cigar_against_sequence() {
+        let cigar: record_buf::Cigar = [
+            Op::new(Kind::HardClip, 3),
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::SequenceMatch, 2),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(validate_cigar_against_sequence(&cigar, 9).is_ok());
+        assert!(validate_cigar_against_sequence(&cigar, 0).is_ok());
+        assert!(validate_cigar_against_sequence(&record_buf::Cigar::default(), 4).is_ok());
+
+        let e = validate_cigar_against_sequence(&cigar, 8).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.get_ref().and_then(|e| e.downcast_ref()),
+            Some(&CigarLengthMismatch {
+                read_length: 9,
+                sequence_length: 8,
+            })
+        );
+    }
+}
@@ -7,6 +7,19 @@ use std::io;
 use self::field::{Tag, Value};
 
 /// Alignment record data.
+///
+/// This is a read-only view of the data fields. Presence can be tested with [`Self::get`], e.g.,
+/// `data.get(&tag).is_some()`.
+///
+/// Lazy records, e.g., [`crate::Record`], borrow their data from the raw record buffer and cannot
+/// add or remove fields. To edit fields, convert the record to a [`RecordBuf`] (see
+/// [`RecordBuf::try_from_alignment_record`]) and use its [`record_buf::Data`], e.g.,
+/// [`record_buf::Data::remove`].
+///
+/// [`RecordBuf`]: crate::alignment::RecordBuf
+/// [`RecordBuf::try_from_alignment_record`]: crate::alignment::RecordBuf::try_from_alignment_record
+/// [`record_buf::Data`]: crate::alignment::record_buf::Data
+/// [`record_buf::Data::remove`]: crate::alignment::record_buf::Data::remove
 pub trait Data {
     /// Returns whether there are any fields.
     fn is_empty(&self) -> bool;
@@ -222,6 +222,12 @@ impl fmt::Debug for Tag {
     }
 }
 
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_ref().as_bstr())
+    }
+}
+
 impl AsRef<[u8; 2]> for Tag {
     fn as_ref(&self) -> &[u8; 2] {
         &self.0
@@ -246,6 +252,18 @@ impl PartialEq<[u8; 2]> for Tag {
     }
 }
 
+impl PartialEq<str> for Tag {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq(other.as_bytes())
+    }
+}
+
+impl PartialEq<&str> for Tag {
+    fn eq(&self, other: &&str) -> bool {
+        self.eq(*other)
+    }
+}
+
 impl From<Tag> for [u8; 2] {
     fn from(tag: Tag) -> Self {
         tag.0
@@ -260,4 +278,20 @@ mod tests {
     fn test_fmt_debug() {
         assert_eq!(format!("{:?}", Tag::ALIGNMENT_HIT_COUNT), r#"Tag("NH")"#);
     }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Tag::ALIGNMENT_HIT_COUNT.to_string(), "NH");
+        assert_eq!(Tag::new(b'x', b'n').to_string(), "xn");
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Tag::ALIGNMENT_HIT_COUNT, *b"NH");
+        assert_eq!(Tag::ALIGNMENT_HIT_COUNT, "NH");
+        assert_eq!(Tag::ALIGNMENT_HIT_COUNT, *"NH");
+        assert_ne!(Tag::ALIGNMENT_HIT_COUNT, "nh");
+        assert_ne!(Tag::ALIGNMENT_HIT_COUNT, "N");
+        assert_ne!(Tag::ALIGNMENT_HIT_COUNT, "NHx");
+    }
 }
@@ -0,0 +1,295 @@
+//! Borrowed alignment record fields.
+
+mod builder;
+
+pub use self::builder::{BuildError, Builder};
+
+use std::io;
+
+use noodles_core::Position;
+
+use super::{
+    cigar::Op,
+    data::field::{
+        value::{array::Values, Array},
+        Tag, Value,
+    },
+    Cigar, Data, Flags, MappingQuality, Name, QualityScores, Record, Sequence,
+};
+use crate::Header;
+
+/// Borrowed alignment record fields.
+///
+/// This is an alignment record that borrows each of its fields from the caller, e.g., from
+/// columnar data or a simulator's buffers. It can be written by any alignment writer (see
+/// [`crate::alignment::io::Write::write_alignment_record`]) without assembling an owned
+/// [`crate::alignment::RecordBuf`].
+///
+/// Fields are created using a [`Builder`], which checks that the sequence and quality scores
+/// have the same length.
+#[derive(Clone, Copy, Debug)]
+pub struct Fields<'a, 'v> {
+    name: Option<&'a [u8]>,
+    flags: Flags,
+    reference_sequence_id: Option<usize>,
+    alignment_start: Option<Position>,
+    mapping_quality: Option<MappingQuality>,
+    cigar: &'a [Op],
+    mate_reference_sequence_id: Option<usize>,
+    mate_alignment_start: Option<Position>,
+    template_length: i32,
+    sequence: &'a [u8],
+    quality_scores: &'a [u8],
+    data: &'a [(Tag, Value<'v>)],
+}
+
+impl<'a, 'v> Fields<'a, 'v> {
+    /// Returns a builder to create fields from each of their parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Fields;
+    /// let builder = Fields::builder();
+    /// ```
+    pub fn builder() -> Builder<'a, 'v> {
+        Builder::default()
+    }
+}
+
+struct BorrowedName<'a>(&'a [u8]);
+
+impl Name for BorrowedName<'_> {
+    fn as_bytes(&self) -> &[u8] {
+        self.0
+    }
+}
+
+struct BorrowedCigar<'a>(&'a [Op]);
+
+impl Cigar for BorrowedCigar<'_> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Op>> + '_> {
+        Box::new(self.0.iter().copied().map(Ok))
+    }
+}
+
+struct BorrowedBytes<'a>(&'a [u8]);
+
+impl Sequence for BorrowedBytes<'_> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
+        Box::new(self.0.iter().copied())
+    }
+}
+
+impl QualityScores for BorrowedBytes<'_> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
+        Box::new(self.0.iter().copied())
+    }
+}
+
+struct BorrowedData<'a, 'v>(&'a [(Tag, Value<'v>)]);
+
+impl Data for BorrowedData<'_, '_> {
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn get(&self, tag: &Tag) -> Option<io::Result<Value<'_>>> {
+        self.0
+            .iter()
+            .find(|(t, _)| t == tag)
+            .map(|(_, value)| Ok(reborrow(value)))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_> {
+        Box::new(
+            self.0
+                .iter()
+                .map(|(tag, value)| Ok((*tag, reborrow(value)))),
+        )
+    }
+}
+
+struct BorrowedValues<'r, 'a, N>(&'r (dyn Values<'a, N> + 'a));
+
+impl<'r, N> Values<'r, N> for BorrowedValues<'r, '_, N> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<N>> + '_> {
+        self.0.iter()
+    }
+}
+
+fn reborrow<'r>(value: &'r Value<'_>) -> Value<'r> {
+    match value {
+        Value::Character(c) => Value::Character(*c),
+        Value::Int8(n) => Value::Int8(*n),
+        Value::UInt8(n) => Value::UInt8(*n),
+        Value::Int16(n) => Value::Int16(*n),
+        Value::UInt16(n) => Value::UInt16(*n),
+        Value::Int32(n) => Value::Int32(*n),
+        Value::UInt32(n) => Value::UInt32(*n),
+        Value::Float(n) => Value::Float(*n),
+        Value::String(s) => Value::String(s),
+        Value::Hex(s) => Value::Hex(s),
+        Value::Array(array) => Value::Array(match array {
+            Array::Int8(values) => Array::Int8(Box::new(BorrowedValues(values.as_ref()))),
+            Array::UInt8(values) => Array::UInt8(Box::new(BorrowedValues(values.as_ref()))),
+            Array::Int16(values) => Array::Int16(Box::new(BorrowedValues(values.as_ref()))),
+            Array::UInt16(values) => Array::UInt16(Box::new(BorrowedValues(values.as_ref()))),
+            Array::Int32(values) => Array::Int32(Box::new(BorrowedValues(values.as_ref()))),
+            Array::UInt32(values) => Array::UInt32(Box::new(BorrowedValues(values.as_ref()))),
+            Array::Float(values) => Array::Float(Box::new(BorrowedValues(values.as_ref()))),
+        }),
+    }
+}
+
+impl Record for Fields<'_, '_> {
+    fn name(&self) -> Option<Box<dyn Name + '_>> {
+        self.name
+            .map(|name| Box::new(BorrowedName(name)) as Box<dyn Name>)
+    }
+
+    fn flags(&self) -> io::Result<Flags> {
+        Ok(self.flags)
+    }
+
+    fn reference_sequence_id<'r, 'h: 'r>(&'r self, _: &'h Header) -> Option<io::Result<usize>> {
+        self.reference_sequence_id.map(Ok)
+    }
+
+    fn alignment_start(&self) -> Option<io::Result<Position>> {
+        self.alignment_start.map(Ok)
+    }
+
+    fn mapping_quality(&self) -> Option<io::Result<MappingQuality>> {
+        self.mapping_quality.map(Ok)
+    }
+
+    fn cigar(&self) -> Box<dyn Cigar + '_> {
+        Box::new(BorrowedCigar(self.cigar))
+    }
+
+    fn mate_reference_sequence_id<'r, 'h: 'r>(
+        &'r self,
+        _: &'h Header,
+    ) -> Option<io::Result<usize>> {
+        self.mate_reference_sequence_id.map(Ok)
+    }
+
+    fn mate_alignment_start(&self) -> Option<io::Result<Position>> {
+        self.mate_alignment_start.map(Ok)
+    }
+
+    fn template_length(&self) -> io::Result<i32> {
+        Ok(self.template_length)
+    }
+
+    fn sequence(&self) -> Box<dyn Sequence + '_> {
+        Box::new(BorrowedBytes(self.sequence))
+    }
+
+    fn quality_scores(&self) -> Box<dyn QualityScores + '_> {
+        Box::new(BorrowedBytes(self.quality_scores))
+    }
+
+    fn data(&self) -> Box<dyn Data + '_> {
+        Box::new(BorrowedData(self.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{
+        record::cigar::op::Kind,
+        record_buf::data::field::{value::Array as ArrayBuf, Value as ValueBuf},
+        RecordBuf,
+    };
+
+    #[test]
+    fn test_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let cigar = [Op::new(Kind::Match, 4)];
+        let hit_count = ValueBuf::UInt8(1);
+        let comment = ValueBuf::from("ndls");
+        let base_modification_probabilities = ValueBuf::Array(ArrayBuf::UInt8(vec![0, 1]));
+
+        let data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(&hit_count)),
+            (Tag::COMMENT, Value::from(&comment)),
+            (
+                Tag::BASE_MODIFICATION_PROBABILITIES,
+                Value::from(&base_modification_probabilities),
+            ),
+        ];
+
+        let fields = Fields::builder()
+            .set_name(b"r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar(&cigar)
+            .set_sequence(b"ACGT")
+            .set_quality_scores(&[45, 35, 43, 50])
+            .set_data(&data)
+            .build()?;
+
+        let actual = RecordBuf::try_from_alignment_record(&header, &fields)?;
+
+        let expected = RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar(cigar.into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![45, 35, 43, 50].into())
+            .set_data(
+                [
+                    (Tag::ALIGNMENT_HIT_COUNT, hit_count.clone()),
+                    (Tag::COMMENT, comment.clone()),
+                    (
+                        Tag::BASE_MODIFICATION_PROBABILITIES,
+                        base_modification_probabilities.clone(),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+use std::{error, fmt};
+
+use noodles_core::Position;
+
+use super::Fields;
+use crate::alignment::record::{
+    cigar::Op,
+    data::field::{Tag, Value},
+    Flags, MappingQuality,
+};
+
+/// A borrowed alignment record fields builder.
+#[derive(Debug)]
+pub struct Builder<'a, 'v> {
+    name: Option<&'a [u8]>,
+    flags: Flags,
+    reference_sequence_id: Option<usize>,
+    alignment_start: Option<Position>,
+    mapping_quality: Option<MappingQuality>,
+    cigar: &'a [Op],
+    mate_reference_sequence_id: Option<usize>,
+    mate_alignment_start: Option<Position>,
+    template_length: i32,
+    sequence: &'a [u8],
+    quality_scores: &'a [u8],
+    data: &'a [(Tag, Value<'v>)],
+}
+
+/// An error returned when borrowed alignment record fields fail to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The quality scores are not empty and have a different length than the sequence.
+    SequenceQualityScoresLengthMismatch {
+        /// The sequence length.
+        sequence_len: usize,
+        /// The quality scores length.
+        quality_scores_len: usize,
+    },
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SequenceQualityScoresLengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            } => write!(
+                f,
+                "sequence and quality scores length mismatch: expected {sequence_len}, got {quality_scores_len}"
+            ),
+        }
+    }
+}
+
+impl<'a, 'v> Builder<'a, 'v> {
+    /// Sets the name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder().set_name(b"r0").build()?;
+    ///
+    /// assert_eq!(fields.name().map(|name| name.as_bytes().to_vec()), Some(b"r0".to_vec()));
+    /// # Ok::<_, noodles_sam::alignment::record::fields::BuildError>(())
+    /// ```
+    pub fn set_name(mut self, name: &'a [u8]) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Sets the flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{Fields, Flags},
+    ///     Record,
+    /// };
+    ///
+    /// let fields = Fields::builder().set_flags(Flags::empty()).build()?;
+    ///
+    /// assert_eq!(fields.flags()?, Flags::empty());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_flags(mut self, flags: Flags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the reference sequence ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{record::Fields, Record},
+    /// };
+    ///
+    /// let header = sam::Header::default();
+    /// let fields = Fields::builder().set_reference_sequence_id(0).build()?;
+    ///
+    /// assert_eq!(fields.reference_sequence_id(&header).transpose()?, Some(0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_sequence_id(mut self, reference_sequence_id: usize) -> Self {
+        self.reference_sequence_id = Some(reference_sequence_id);
+        self
+    }
+
+    /// Sets the alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder().set_alignment_start(Position::MIN).build()?;
+    ///
+    /// assert_eq!(fields.alignment_start().transpose()?, Some(Position::MIN));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_alignment_start(mut self, alignment_start: Position) -> Self {
+        self.alignment_start = Some(alignment_start);
+        self
+    }
+
+    /// Sets the mapping quality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{Fields, MappingQuality},
+    ///     Record,
+    /// };
+    ///
+    /// let fields = Fields::builder()
+    ///     .set_mapping_quality(MappingQuality::MIN)
+    ///     .build()?;
+    ///
+    /// assert_eq!(fields.mapping_quality().transpose()?, Some(MappingQuality::MIN));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_mapping_quality(mut self, mapping_quality: MappingQuality) -> Self {
+        self.mapping_quality = Some(mapping_quality);
+        self
+    }
+
+    /// Sets the CIGAR operations.
+    ///
+    /// Writers may read the operations more than once, e.g., BAM writes the operation count and
+    /// the bin, which is calculated from the alignment span, before the operations, so they are
+    /// given as a slice rather than an iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{
+    ///         cigar::{op::Kind, Op},
+    ///         Fields,
+    ///     },
+    ///     Record,
+    /// };
+    ///
+    /// let cigar = [Op::new(Kind::Match, 4)];
+    /// let fields = Fields::builder().set_cigar(&cigar).build()?;
+    ///
+    /// let actual: Vec<_> = fields.cigar().iter().collect::<Result<_, _>>()?;
+    /// assert_eq!(actual, cigar);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_cigar(mut self, cigar: &'a [Op]) -> Self {
+        self.cigar = cigar;
+        self
+    }
+
+    /// Sets the mate reference sequence ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{record::Fields, Record},
+    /// };
+    ///
+    /// let header = sam::Header::default();
+    /// let fields = Fields::builder().set_mate_reference_sequence_id(0).build()?;
+    ///
+    /// assert_eq!(fields.mate_reference_sequence_id(&header).transpose()?, Some(0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_mate_reference_sequence_id(mut self, mate_reference_sequence_id: usize) -> Self {
+        self.mate_reference_sequence_id = Some(mate_reference_sequence_id);
+        self
+    }
+
+    /// Sets the mate alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder()
+    ///     .set_mate_alignment_start(Position::MIN)
+    ///     .build()?;
+    ///
+    /// assert_eq!(fields.mate_alignment_start().transpose()?, Some(Position::MIN));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_mate_alignment_start(mut self, mate_alignment_start: Position) -> Self {
+        self.mate_alignment_start = Some(mate_alignment_start);
+        self
+    }
+
+    /// Sets the template length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder().set_template_length(4).build()?;
+    ///
+    /// assert_eq!(fields.template_length()?, 4);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_template_length(mut self, template_length: i32) -> Self {
+        self.template_length = template_length;
+        self
+    }
+
+    /// Sets the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder().set_sequence(b"ACGT").build()?;
+    ///
+    /// let actual: Vec<_> = fields.sequence().iter().collect();
+    /// assert_eq!(actual, b"ACGT");
+    /// # Ok::<_, noodles_sam::alignment::record::fields::BuildError>(())
+    /// ```
+    pub fn set_sequence(mut self, sequence: &'a [u8]) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// Sets the quality scores.
+    ///
+    /// These are raw scores, i.e., not offset by 33.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Fields, Record};
+    ///
+    /// let fields = Fields::builder()
+    ///     .set_sequence(b"ACGT")
+    ///     .set_quality_scores(&[45, 35, 43, 50])
+    ///     .build()?;
+    ///
+    /// let actual: Vec<_> = fields.quality_scores().iter().collect();
+    /// assert_eq!(actual, [45, 35, 43, 50]);
+    /// # Ok::<_, noodles_sam::alignment::record::fields::BuildError>(())
+    /// ```
+    pub fn set_quality_scores(mut self, quality_scores: &'a [u8]) -> Self {
+        self.quality_scores = quality_scores;
+        self
+    }
+
+    /// Sets the data fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{
+    ///         data::field::{Tag, Value},
+    ///         Fields,
+    ///     },
+    ///     Record,
+    /// };
+    ///
+    /// let data = [(Tag::COMMENT, Value::String(b"ndls".into()))];
+    /// let fields = Fields::builder().set_data(&data).build()?;
+    ///
+    /// assert!(matches!(
+    ///     fields.data().get(&Tag::COMMENT).transpose()?,
+    ///     Some(Value::String(s)) if s == "ndls"
+    /// ));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_data(mut self, data: &'a [(Tag, Value<'v>)]) -> Self {
+        self.data = data;
+        self
+    }
+
+    /// Builds borrowed alignment record fields.
+    ///
+    /// This fails if the quality scores are not empty and have a different length than the
+    /// sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Fields;
+    ///
+    /// let fields = Fields::builder()
+    ///     .set_sequence(b"ACGT")
+    ///     .set_quality_scores(&[45, 35, 43, 50])
+    ///     .build()?;
+    ///
+    /// assert!(Fields::builder().set_sequence(b"ACGT").set_quality_scores(&[45]).build().is_err());
+    /// # Ok::<_, noodles_sam::alignment::record::fields::BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<Fields<'a, 'v>, BuildError> {
+        if !self.quality_scores.is_empty() && self.quality_scores.len() != self.sequence.len() {
+            return Err(BuildError::SequenceQualityScoresLengthMismatch {
+                sequence_len: self.sequence.len(),
+                quality_scores_len: self.quality_scores.len(),
+            });
+        }
+
+        Ok(Fields {
+            name: self.name,
+            flags: self.flags,
+            reference_sequence_id: self.reference_sequence_id,
+            alignment_start: self.alignment_start,
+            mapping_quality: self.mapping_quality,
+            cigar: self.cigar,
+            mate_reference_sequence_id: self.mate_reference_sequence_id,
+            mate_alignment_start: self.mate_alignment_start,
+            template_length: self.template_length,
+            sequence: self.sequence,
+            quality_scores: self.quality_scores,
+            data: self.data,
+        })
+    }
+}
+
+impl Default for Builder<'_, '_> {
+    fn default() -> Self {
+        Self {
+            name: None,
+            flags: Flags::UNMAPPED,
+            reference_sequence_id: None,
+            alignment_start: None,
+            mapping_quality: None,
+            cigar: &[],
+            mate_reference_sequence_id: None,
+            mate_alignment_start: None,
+            template_length: 0,
+            sequence: &[],
+            quality_scores: &[],
+            data: &[],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        assert!(Builder::default().build().is_ok());
+
+        assert_eq!(
+            Builder::default()
+                .set_sequence(b"ACGT")
+                .set_quality_scores(&[45, 35])
+                .build()
+                .err(),
+            Some(BuildError::SequenceQualityScoresLengthMismatch {
+                sequence_len: 4,
+                quality_scores_len: 2
+            })
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use noodles_core::sequence::BaseCounts;
+
 /// An alignment record sequence.
 pub trait Sequence {
     /// Returns whether there are any bases.
@@ -8,6 +10,43 @@ pub trait Sequence {
 
     /// Returns an iterator over bases.
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_>;
+
+    /// Counts the bases.
+    ///
+    /// The bases are counted in a single pass over the sequence. Lowercase bases are counted as
+    /// their uppercase base, and the BAM `=` code is counted as other (see [`BaseCounts`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sequence::BaseCounts;
+    /// use noodles_sam::alignment::{record::Sequence as _, record_buf::Sequence};
+    ///
+    /// let sequence = &Sequence::from(b"ACgtN=");
+    ///
+    /// assert_eq!(
+    ///     sequence.base_composition(),
+    ///     BaseCounts { a: 1, c: 1, g: 1, t: 1, n: 1, other: 1 }
+    /// );
+    /// ```
+    fn base_composition(&self) -> BaseCounts {
+        self.iter().collect()
+    }
+
+    /// Calculates the GC content.
+    ///
+    /// See [`BaseCounts::gc_content`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Sequence as _, record_buf::Sequence};
+    /// let sequence = &Sequence::from(b"ACGGNN");
+    /// assert_eq!(sequence.gc_content(), 0.75);
+    /// ```
+    fn gc_content(&self) -> f64 {
+        self.base_composition().gc_content()
+    }
 }
 
 impl<'a> IntoIterator for &'a dyn Sequence {
@@ -0,0 +1,243 @@
+use std::{fmt, io};
+
+use super::{Flags, Record};
+
+/// A contradiction between the flags of an alignment record and its other fields or flags.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum FlagViolation {
+    /// The record is unmapped (`0x04`) but has a CIGAR.
+    UnmappedWithCigar,
+    /// The record is unmapped (`0x04`) but has a nonzero mapping quality.
+    UnmappedWithMappingQuality,
+    /// The record is unmapped (`0x04`) but is a secondary alignment (`0x100`).
+    UnmappedSecondary,
+    /// The record is unmapped (`0x04`) but is a supplementary alignment (`0x800`).
+    UnmappedSupplementary,
+    /// The record is not segmented (`0x01`) but is properly segmented (`0x02`).
+    ProperlySegmentedWithoutMultipleSegments,
+    /// The record is not segmented (`0x01`) but its mate is unmapped (`0x08`).
+    MateUnmappedWithoutMultipleSegments,
+    /// The record is not segmented (`0x01`) but its mate is reverse complemented (`0x20`).
+    MateReverseComplementedWithoutMultipleSegments,
+    /// The record is not segmented (`0x01`) but is the first segment (`0x40`).
+    FirstSegmentWithoutMultipleSegments,
+    /// The record is not segmented (`0x01`) but is the last segment (`0x80`).
+    LastSegmentWithoutMultipleSegments,
+    /// The record is segmented (`0x01`) but is neither the first (`0x40`) nor the last (`0x80`)
+    /// segment.
+    MissingSegmentIndex,
+}
+
+impl fmt::Display for FlagViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnmappedWithCigar => write!(f, "unmapped record has a CIGAR"),
+            Self::UnmappedWithMappingQuality => {
+                write!(f, "unmapped record has a nonzero mapping quality")
+            }
+            Self::UnmappedSecondary => write!(f, "unmapped record is a secondary alignment"),
+            Self::UnmappedSupplementary => {
+                write!(f, "unmapped record is a supplementary alignment")
+            }
+            Self::ProperlySegmentedWithoutMultipleSegments => {
+                write!(f, "properly segmented flag is set on an unsegmented record")
+            }
+            Self::MateUnmappedWithoutMultipleSegments => {
+                write!(f, "mate unmapped flag is set on an unsegmented record")
+            }
+            Self::MateReverseComplementedWithoutMultipleSegments => write!(
+                f,
+                "mate reverse complemented flag is set on an unsegmented record"
+            ),
+            Self::FirstSegmentWithoutMultipleSegments => {
+                write!(f, "first segment flag is set on an unsegmented record")
+            }
+            Self::LastSegmentWithoutMultipleSegments => {
+                write!(f, "last segment flag is set on an unsegmented record")
+            }
+            Self::MissingSegmentIndex => write!(
+                f,
+                "segmented record is neither the first nor the last segment"
+            ),
+        }
+    }
+}
+
+/// Validates the flags of an alignment record against its other fields and flags.
+///
+/// This returns a list of all contradictions found, in the order of the [`FlagViolation`]
+/// variants. The list is empty if the flags are consistent. Each contradiction is a distinct
+/// variant, so callers can ignore specific ones, e.g., [`FlagViolation::MissingSegmentIndex`],
+/// which is allowed for templates that have lost their segment order.
+///
+/// A missing mapping quality (255) is not a contradiction for an unmapped record.
+///
+/// This only returns an error if a field fails to decode.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         validate_flags, FlagViolation, Flags,
+///     },
+///     RecordBuf,
+/// };
+///
+/// let record = RecordBuf::default();
+/// assert!(validate_flags(&record)?.is_empty());
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::UNMAPPED | Flags::FIRST_SEGMENT)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// assert_eq!(
+///     validate_flags(&record)?,
+///     [
+///         FlagViolation::UnmappedWithCigar,
+///         FlagViolation::FirstSegmentWithoutMultipleSegments,
+///     ]
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn validate_flags<R>(record: &R) -> io::Result<Vec<FlagViolation>>
+where
+    R: Record + ?Sized,
+{
+    let flags = record.flags()?;
+    let mut violations = Vec::new();
+
+    if flags.is_unmapped() {
+        if !record.cigar().is_empty() {
+            violations.push(FlagViolation::UnmappedWithCigar);
+        }
+
+        if let Some(mapping_quality) = record.mapping_quality().transpose()? {
+            if mapping_quality.get() != 0 {
+                violations.push(FlagViolation::UnmappedWithMappingQuality);
+            }
+        }
+
+        if flags.is_secondary() {
+            violations.push(FlagViolation::UnmappedSecondary);
+        }
+
+        if flags.is_supplementary() {
+            violations.push(FlagViolation::UnmappedSupplementary);
+        }
+    }
+
+    if flags.is_segmented() {
+        if !flags.intersects(Flags::FIRST_SEGMENT | Flags::LAST_SEGMENT) {
+            violations.push(FlagViolation::MissingSegmentIndex);
+        }
+    } else {
+        const SEGMENT_VIOLATIONS: [(Flags, FlagViolation); 5] = [
+            (
+                Flags::PROPERLY_SEGMENTED,
+                FlagViolation::ProperlySegmentedWithoutMultipleSegments,
+            ),
+            (
+                Flags::MATE_UNMAPPED,
+                FlagViolation::MateUnmappedWithoutMultipleSegments,
+            ),
+            (
+                Flags::MATE_REVERSE_COMPLEMENTED,
+                FlagViolation::MateReverseComplementedWithoutMultipleSegments,
+            ),
+            (
+                Flags::FIRST_SEGMENT,
+                FlagViolation::FirstSegmentWithoutMultipleSegments,
+            ),
+            (
+                Flags::LAST_SEGMENT,
+                FlagViolation::LastSegmentWithoutMultipleSegments,
+            ),
+        ];
+
+        for (flag, violation) in SEGMENT_VIOLATIONS {
+            if flags.contains(flag) {
+                violations.push(violation);
+            }
+        }
+    }
+
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            MappingQuality,
+        },
+        RecordBuf,
+    };
+
+    #[test]
+    fn test_validate_flags() -> io::Result<()> {
+        fn t(record: &RecordBuf, expected: &[FlagViolation]) -> io::Result<()> {
+            assert_eq!(validate_flags(record)?, expected);
+            Ok(())
+        }
+
+        t(&RecordBuf::default(), &[])?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::LAST_SEGMENT)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mapping_quality(MappingQuality::new(60).unwrap())
+            .build();
+        t(&record, &[])?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED | Flags::SECONDARY | Flags::SUPPLEMENTARY)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mapping_quality(MappingQuality::new(60).unwrap())
+            .build();
+        t(
+            &record,
+            &[
+                FlagViolation::UnmappedWithCigar,
+                FlagViolation::UnmappedWithMappingQuality,
+                FlagViolation::UnmappedSecondary,
+                FlagViolation::UnmappedSupplementary,
+            ],
+        )?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_mapping_quality(MappingQuality::MIN)
+            .build();
+        t(&record, &[])?;
+
+        let record = RecordBuf::builder()
+            .set_flags(
+                Flags::PROPERLY_SEGMENTED
+                    | Flags::MATE_UNMAPPED
+                    | Flags::MATE_REVERSE_COMPLEMENTED
+                    | Flags::FIRST_SEGMENT
+                    | Flags::LAST_SEGMENT,
+            )
+            .build();
+        t(
+            &record,
+            &[
+                FlagViolation::ProperlySegmentedWithoutMultipleSegments,
+                FlagViolation::MateUnmappedWithoutMultipleSegments,
+                FlagViolation::MateReverseComplementedWithoutMultipleSegments,
+                FlagViolation::FirstSegmentWithoutMultipleSegments,
+                FlagViolation::LastSegmentWithoutMultipleSegments,
+            ],
+        )?;
+
+        let record = RecordBuf::builder().set_flags(Flags::SEGMENTED).build();
+        t(&record, &[FlagViolation::MissingSegmentIndex])?;
+
+        Ok(())
+    }
+}
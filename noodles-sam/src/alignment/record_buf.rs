@@ -43,6 +43,7 @@ pub struct RecordBuf {
     sequence: Sequence,
     quality_scores: QualityScores,
     data: Data,
+    bin: Option<u16>,
 }
 
 impl RecordBuf {
@@ -418,6 +419,41 @@ impl RecordBuf {
         &mut self.data
     }
 
+    /// Returns the raw bin.
+    ///
+    /// This is the legacy BAI bin (`bin`) of a record read from BAM. It is not validated and may
+    /// differ from the bin calculated from the alignment span. It is not updated when the
+    /// alignment start or CIGAR changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::RecordBuf;
+    ///
+    /// let record = RecordBuf::default();
+    /// assert!(record.bin().is_none());
+    ///
+    /// let record = RecordBuf::builder().set_bin(0).build();
+    /// assert_eq!(record.bin(), Some(0));
+    /// ```
+    pub fn bin(&self) -> Option<u16> {
+        self.bin
+    }
+
+    /// Returns a mutable reference to the raw bin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let mut record = sam::alignment::RecordBuf::default();
+    /// *record.bin_mut() = Some(4680);
+    /// assert_eq!(record.bin(), Some(4680));
+    /// ```
+    pub fn bin_mut(&mut self) -> &mut Option<u16> {
+        &mut self.bin
+    }
+
     /// Returns the associated reference sequence.
     ///
     /// # Examples
@@ -473,16 +509,22 @@ impl RecordBuf {
 
     /// Calculates the end position.
     ///
+    /// This returns `None` if the record is unmapped or has no alignment start.
+    ///
     /// # Examples
     ///
     /// ```
     /// use noodles_core::Position;
     /// use noodles_sam::{
     ///     self as sam,
-    ///     alignment::record::cigar::{op::Kind, Op},
+    ///     alignment::record::{
+    ///         cigar::{op::Kind, Op},
+    ///         Flags,
+    ///     },
     /// };
     ///
     /// let record = sam::alignment::RecordBuf::builder()
+    ///     .set_flags(Flags::empty())
     ///     .set_alignment_start(Position::try_from(8)?)
     ///     .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
     ///     .build();
@@ -491,6 +533,10 @@ impl RecordBuf {
     /// # Ok::<_, noodles_core::position::TryFromIntError>(())
     /// ```
     pub fn alignment_end(&self) -> Option<Position> {
+        if self.flags().is_unmapped() {
+            return None;
+        }
+
         self.alignment_start()
             .and_then(|start| match self.alignment_span() {
                 Some(span) => {
@@ -554,6 +600,10 @@ impl Record for RecordBuf {
     fn data(&self) -> Box<dyn super::record::Data + '_> {
         Box::new(self.data())
     }
+
+    fn bin(&self) -> Option<io::Result<u16>> {
+        self.bin().map(Ok)
+    }
 }
 
 impl Default for RecordBuf {
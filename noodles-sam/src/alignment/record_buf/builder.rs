@@ -17,6 +17,7 @@ pub struct Builder {
     sequence: Sequence,
     quality_scores: QualityScores,
     data: Data,
+    bin: Option<u16>,
 }
 
 impl Builder {
@@ -262,6 +263,20 @@ impl Builder {
         self
     }
 
+    /// Sets the raw bin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let record = sam::alignment::RecordBuf::builder().set_bin(4680).build();
+    /// assert_eq!(record.bin(), Some(4680));
+    /// ```
+    pub fn set_bin(mut self, bin: u16) -> Self {
+        self.bin = Some(bin);
+        self
+    }
+
     /// Builds the alignment record.
     ///
     /// # Examples
@@ -285,6 +300,7 @@ impl Builder {
             sequence: self.sequence,
             quality_scores: self.quality_scores,
             data: self.data,
+            bin: self.bin,
         }
     }
 }
@@ -304,6 +320,7 @@ impl Default for Builder {
             sequence: Sequence::default(),
             quality_scores: QualityScores::default(),
             data: Data::default(),
+            bin: None,
         }
     }
 }
@@ -328,5 +345,6 @@ mod tests {
         assert!(builder.sequence.is_empty());
         assert!(builder.quality_scores.is_empty());
         assert!(builder.data.is_empty());
+        assert!(builder.bin.is_none());
     }
 }
@@ -49,6 +49,8 @@ impl RecordBuf {
 
         *record_buf.data_mut() = data_buf;
 
+        *record_buf.bin_mut() = record.bin().transpose()?;
+
         Ok(record_buf)
     }
 }
@@ -86,6 +86,30 @@ impl Data {
             .map(|(_, v)| v)
     }
 
+    /// Returns whether a field with the given tag exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let data: Data = [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(data.contains_key(&Tag::ALIGNMENT_HIT_COUNT));
+    /// assert!(!data.contains_key(&Tag::READ_GROUP));
+    /// ```
+    pub fn contains_key<K>(&self, tag: &K) -> bool
+    where
+        K: indexmap::Equivalent<Tag>,
+    {
+        self.get_index_of(tag).is_some()
+    }
+
     /// Returns a mutable reference to the value of the given tag.
     ///
     /// # Examples
@@ -235,8 +259,8 @@ impl Data {
     ///
     /// The field is returned if it exists.
     ///
-    /// This works like [`Vec::swap_remove`]; it does not preserve the order but has a constant
-    /// time complexity.
+    /// This works like [`Vec::remove`]; it preserves the order of the remaining fields but has a
+    /// linear time complexity.
     ///
     /// # Examples
     ///
@@ -254,21 +278,43 @@ impl Data {
     /// assert_eq!(data.remove(&Tag::ALIGNMENT_HIT_COUNT), Some(nh));
     /// assert!(data.remove(&Tag::COMMENT).is_none());
     ///
-    /// let expected = [md, rg].into_iter().collect();
+    /// let expected = [rg, md].into_iter().collect();
     /// assert_eq!(data, expected);
     /// ```
     pub fn remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
     where
         K: indexmap::Equivalent<Tag>,
     {
-        self.swap_remove(tag)
+        self.get_index_of(tag).map(|i| self.0.remove(i))
     }
 
-    fn swap_remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
+    /// Retains only the fields for which the predicate returns `true`.
+    ///
+    /// This works like [`Vec::retain`]; it preserves the order of the remaining fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let nh = (Tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let rg = (Tag::READ_GROUP, Value::from("rg0"));
+    /// let md = (Tag::ALIGNMENT_SCORE, Value::from(98));
+    /// let mut data: Data = [nh, rg.clone(), md.clone()].into_iter().collect();
+    ///
+    /// data.retain(|tag, _| *tag != Tag::ALIGNMENT_HIT_COUNT);
+    ///
+    /// let expected = [rg, md].into_iter().collect();
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
     where
-        K: indexmap::Equivalent<Tag>,
+        F: FnMut(&Tag, &Value) -> bool,
     {
-        self.get_index_of(tag).map(|i| self.0.swap_remove(i))
+        self.0.retain(|(tag, value)| f(tag, value));
     }
 }
 
@@ -354,4 +400,30 @@ mod tests {
 
         assert!(data.is_empty());
     }
+
+    #[test]
+    fn test_remove_preserves_order() {
+        let mut data: Data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(2)),
+            (Tag::ORIGINAL_ALIGNMENT, Value::from("sq0,8,+,4M,255,0;")),
+            (Tag::EDIT_DISTANCE, Value::from(1)),
+            (Tag::READ_GROUP, Value::from("rg0")),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            data.remove(&Tag::ORIGINAL_ALIGNMENT),
+            Some((Tag::ORIGINAL_ALIGNMENT, Value::from("sq0,8,+,4M,255,0;")))
+        );
+
+        assert_eq!(
+            data.keys().collect::<Vec<_>>(),
+            [
+                Tag::ALIGNMENT_HIT_COUNT,
+                Tag::EDIT_DISTANCE,
+                Tag::READ_GROUP
+            ]
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 mod array;
 
+use std::{error, fmt};
+
 use bstr::BString;
 
 pub use self::array::Array;
@@ -35,6 +37,27 @@ pub enum Value {
 }
 
 impl Value {
+    /// Creates a character value.
+    ///
+    /// A character (`A`) must be a printable ASCII character (`[!-~]`). This returns an error if
+    /// the given byte is a control character, a space, or not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    /// assert_eq!(Value::try_character(b'n'), Ok(Value::Character(b'n')));
+    /// assert!(Value::try_character(b'\n').is_err());
+    /// ```
+    pub fn try_character(b: u8) -> Result<Self, InvalidCharacterError> {
+        // § 1.5 "The alignment section: optional fields" (2023-05-24): "`[!-~]`".
+        if b.is_ascii_graphic() {
+            Ok(Self::Character(b))
+        } else {
+            Err(InvalidCharacterError(b))
+        }
+    }
+
     /// Returns the type of the value.
     ///
     /// # Examples
@@ -105,6 +128,18 @@ impl Value {
     }
 }
 
+/// An error returned when a byte is not a valid character value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvalidCharacterError(u8);
+
+impl error::Error for InvalidCharacterError {}
+
+impl fmt::Display for InvalidCharacterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid character: 0x{:02x}", self.0)
+    }
+}
+
 impl From<i8> for Value {
     fn from(n: i8) -> Self {
         if n >= 0 {
@@ -274,6 +309,16 @@ impl<'a> From<&'a Value> for crate::alignment::record::data::field::Value<'a> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_try_character() {
+        assert_eq!(Value::try_character(b'n'), Ok(Value::Character(b'n')));
+        assert_eq!(Value::try_character(b'~'), Ok(Value::Character(b'~')));
+
+        for b in [0x00, b'\t', b' ', 0x7f, 0x80, 0xff] {
+            assert_eq!(Value::try_character(b), Err(InvalidCharacterError(b)));
+        }
+    }
+
     #[test]
     fn test_ty() {
         assert_eq!(Value::Character(b'n').ty(), Type::Character);
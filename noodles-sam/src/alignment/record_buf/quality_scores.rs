@@ -11,6 +11,23 @@ impl QualityScores {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Applies Illumina 8-level binning to the quality scores in place.
+    ///
+    /// This is a lossy transform. See [`noodles_core::quality_scores::bin_illumina8`] for the bin
+    /// boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::QualityScores;
+    /// let mut quality_scores = QualityScores::from(vec![1, 8, 13, 21, 45]);
+    /// quality_scores.bin_illumina8();
+    /// assert_eq!(quality_scores.as_ref(), [1, 6, 15, 22, 40]);
+    /// ```
+    pub fn bin_illumina8(&mut self) {
+        noodles_core::quality_scores::bin_illumina8(&mut self.0);
+    }
 }
 
 impl AsRef<[u8]> for QualityScores {
@@ -1,7 +1,14 @@
-use std::ops::{Index, IndexMut};
+use std::{
+    io,
+    ops::{Index, IndexMut},
+};
 
 use noodles_core::position::SequenceIndex;
 
+use crate::alignment::record::{cigar::op::Kind, Cigar};
+
+const EQ: u8 = b'=';
+
 /// An alignment record sequence buffer.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Sequence(Vec<u8>);
@@ -16,6 +23,193 @@ impl Sequence {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Replaces `=` bases with the reference bases they match.
+    ///
+    /// A `=` base means the base is identical to the reference base it is aligned to. The CIGAR
+    /// operations align the bases to `reference_sequence`, which must be the reference bases of
+    /// the alignment span, i.e., starting at the alignment start.
+    ///
+    /// This returns an error if a `=` base is not aligned to the reference, e.g., in an insertion
+    /// or soft clip, if the sequence is shorter than the read length of the CIGAR operations, or if
+    /// `reference_sequence` is shorter than the alignment span. A missing sequence (`*`) is left
+    /// as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::{Cigar, Sequence},
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::Match, 2),
+    ///     Op::new(Kind::Deletion, 1),
+    ///     Op::new(Kind::Match, 2),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut sequence = Sequence::from(b"=T=A");
+    /// sequence.resolve_matches(&cigar, b"ACGTA")?;
+    /// assert_eq!(sequence.as_ref(), b"ATTA");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn resolve_matches<C>(&mut self, cigar: &C, reference_sequence: &[u8]) -> io::Result<()>
+    where
+        C: Cigar + ?Sized,
+    {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        for_each_aligned_base(
+            self,
+            cigar,
+            reference_sequence,
+            |base, reference_base, kind| {
+                if *base != EQ {
+                    return Ok(());
+                }
+
+                match reference_base {
+                    Some(reference_base) => {
+                        *base = reference_base;
+                        Ok(())
+                    }
+                    None => Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("`=` base in operation not aligned to the reference: {kind:?}"),
+                    )),
+                }
+            },
+        )
+    }
+
+    /// Replaces bases that match the reference with `=`.
+    ///
+    /// This is the inverse of [`Self::resolve_matches`]. Bases in alignment match (`M`),
+    /// sequence match (`=`), and sequence mismatch (`X`) operations that are identical to their
+    /// reference bases, ignoring case, are replaced. `N` bases are never replaced.
+    ///
+    /// This returns an error if the sequence is shorter than the read length of the CIGAR
+    /// operations or if `reference_sequence` is shorter than the alignment span. A missing
+    /// sequence (`*`) is left as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::{Cigar, Sequence},
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::Match, 2),
+    ///     Op::new(Kind::Deletion, 1),
+    ///     Op::new(Kind::Match, 2),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut sequence = Sequence::from(b"ATTA");
+    /// sequence.mask_matches(&cigar, b"ACGTA")?;
+    /// assert_eq!(sequence.as_ref(), b"=T==");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn mask_matches<C>(&mut self, cigar: &C, reference_sequence: &[u8]) -> io::Result<()>
+    where
+        C: Cigar + ?Sized,
+    {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        for_each_aligned_base(
+            self,
+            cigar,
+            reference_sequence,
+            |base, reference_base, _| {
+                if let Some(reference_base) = reference_base {
+                    if base.eq_ignore_ascii_case(&reference_base)
+                        && !base.eq_ignore_ascii_case(&b'N')
+                    {
+                        *base = EQ;
+                    }
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+// Calls `f` with each base that is in an operation that consumes the read and its reference base,
+// if the operation also consumes the reference.
+fn for_each_aligned_base<C, F>(
+    sequence: &mut Sequence,
+    cigar: &C,
+    reference_sequence: &[u8],
+    mut f: F,
+) -> io::Result<()>
+where
+    C: Cigar + ?Sized,
+    F: FnMut(&mut u8, Option<u8>, Kind) -> io::Result<()>,
+{
+    let mut read_position = 0;
+    let mut reference_position = 0;
+
+    for result in cigar.iter() {
+        let op = result?;
+        let kind = op.kind();
+        let len = op.len();
+
+        match (kind.consumes_read(), kind.consumes_reference()) {
+            (true, true) => {
+                let bases = get_mut(&mut sequence.0, read_position, len)?;
+
+                let reference_bases = reference_sequence
+                    .get(reference_position..reference_position + len)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            "reference sequence is shorter than the alignment span",
+                        )
+                    })?;
+
+                for (base, &reference_base) in bases.iter_mut().zip(reference_bases) {
+                    f(base, Some(reference_base), kind)?;
+                }
+
+                read_position += len;
+                reference_position += len;
+            }
+            (true, false) => {
+                for base in get_mut(&mut sequence.0, read_position, len)? {
+                    f(base, None, kind)?;
+                }
+
+                read_position += len;
+            }
+            (false, true) => reference_position += len,
+            (false, false) => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn get_mut(bases: &mut [u8], start: usize, len: usize) -> io::Result<&mut [u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| bases.get_mut(start..end))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "sequence is shorter than the read length",
+            )
+        })
 }
 
 impl AsRef<[u8]> for Sequence {
@@ -87,3 +281,71 @@ impl crate::alignment::record::Sequence for &Sequence {
         Box::new(self.0.iter().copied())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{record::cigar::Op, record_buf};
+
+    #[test]
+    fn test_resolve_matches() -> io::Result<()> {
+        let cigar: record_buf::Cigar = [
+            Op::new(Kind::SoftClip, 1),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Deletion, 2),
+            Op::new(Kind::Skip, 3),
+            Op::new(Kind::SequenceMatch, 2),
+            Op::new(Kind::SequenceMismatch, 1),
+            Op::new(Kind::HardClip, 4),
+        ]
+        .into_iter()
+        .collect();
+
+        // Reference [1, 11]: ACGTTGCAACG, aligned: M [1, 2], D [3, 4], N [5, 7], = [8, 9], X [10].
+        let reference_sequence = b"ACGTTGCAACG";
+
+        let mut sequence = Sequence::from(b"NA=G==T");
+        sequence.resolve_matches(&cigar, reference_sequence)?;
+        assert_eq!(sequence.as_ref(), b"NACGAAT");
+
+        let mut sequence = Sequence::from(b"NACGAAT");
+        sequence.mask_matches(&cigar, reference_sequence)?;
+        assert_eq!(sequence.as_ref(), b"N==G==T");
+
+        let mut sequence = Sequence::from(b"=ACGAAT");
+        assert!(matches!(
+            sequence.resolve_matches(&cigar, reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut sequence = Sequence::from(b"NACGAA");
+        assert!(matches!(
+            sequence.resolve_matches(&cigar, reference_sequence),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut sequence = Sequence::from(b"NACGAAT");
+        assert!(matches!(
+            sequence.mask_matches(&cigar, &reference_sequence[..9]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut sequence = Sequence::default();
+        sequence.resolve_matches(&cigar, reference_sequence)?;
+        assert!(sequence.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mask_matches() -> io::Result<()> {
+        let cigar: record_buf::Cigar = [Op::new(Kind::Match, 4)].into_iter().collect();
+
+        let mut sequence = Sequence::from(b"aCNT");
+        sequence.mask_matches(&cigar, b"AGNT")?;
+        assert_eq!(sequence.as_ref(), b"=CN=");
+
+        Ok(())
+    }
+}
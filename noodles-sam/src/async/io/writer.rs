@@ -146,10 +146,10 @@ where
         header: &Header,
         record: &dyn crate::alignment::Record,
     ) -> io::Result<()> {
-        use crate::io::writer::write_record;
+        use crate::io::writer::{write_record, LineEnding};
 
         let mut buf = Vec::new();
-        write_record(&mut buf, header, record)?;
+        write_record(&mut buf, header, record, LineEnding::default())?;
         self.inner.write_all(&buf).await
     }
 }
@@ -81,10 +81,15 @@ pub use self::{
     record::Record,
 };
 
-use std::str::{self, FromStr};
+use std::{
+    io, mem,
+    num::NonZeroUsize,
+    str::{self, FromStr},
+};
 
-use bstr::BString;
+use bstr::{BStr, BString};
 use indexmap::IndexMap;
+use noodles_core::{region::ResolveError, Region, SequenceDictionary};
 
 pub use self::programs::Programs;
 use self::record::value::{
@@ -225,6 +230,95 @@ impl Header {
         &mut self.reference_sequences
     }
 
+    /// Reorders the reference sequences by the given names.
+    ///
+    /// This is useful to write the `@SQ` records in a canonical order, e.g., karyotypic order.
+    /// The ordering must list every reference sequence in the header exactly once. Otherwise, this
+    /// returns an error, and the header is left unchanged.
+    ///
+    /// Reference sequence IDs are indices into the reference sequence dictionary, so this changes
+    /// the reference sequence IDs of any records associated with this header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let mut header = sam::Header::builder()
+    ///     .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))
+    ///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))
+    ///     .build();
+    ///
+    /// header.reorder_reference_sequences(["sq0", "sq1"])?;
+    ///
+    /// let names: Vec<_> = header.reference_sequences().keys().collect();
+    /// assert_eq!(names, ["sq0", "sq1"]);
+    ///
+    /// assert!(header.reorder_reference_sequences(["sq0"]).is_err());
+    /// assert!(header.reorder_reference_sequences(["sq0", "sq1", "sq2"]).is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reorder_reference_sequences<I, N>(&mut self, names: I) -> io::Result<()>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<[u8]>,
+    {
+        let len = self.reference_sequences.len();
+
+        let mut indices = Vec::with_capacity(len);
+        let mut is_listed = vec![false; len];
+
+        for name in names {
+            let name = BStr::new(name.as_ref());
+
+            let i = self.reference_sequences.get_index_of(name).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {name}"),
+                )
+            })?;
+
+            if mem::replace(&mut is_listed[i], true) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("duplicate reference sequence name in ordering: {name}"),
+                ));
+            }
+
+            indices.push(i);
+        }
+
+        if let Some(i) = is_listed.iter().position(|&is_listed| !is_listed) {
+            let name = self
+                .reference_sequences
+                .get_index(i)
+                .map(|(name, _)| name.clone())
+                .unwrap_or_default();
+
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("reference sequence not in ordering: {name}"),
+            ));
+        }
+
+        let mut entries: Vec<_> = mem::take(&mut self.reference_sequences)
+            .into_iter()
+            .map(Some)
+            .collect();
+
+        self.reference_sequences = indices
+            .into_iter()
+            .filter_map(|i| entries[i].take())
+            .collect();
+
+        Ok(())
+    }
+
     /// Returns the SAM header read groups.
     ///
     /// # Examples
@@ -434,3 +528,152 @@ impl FromStr for Header {
         parser::parse(s)
     }
 }
+
+impl From<&Header> for SequenceDictionary {
+    /// Builds a sequence dictionary from the reference sequences of a SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::{region::ResolveError, Region, SequenceDictionary};
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let sequence_dictionary = SequenceDictionary::from(&header);
+    /// assert_eq!(sequence_dictionary.get_index_of(b"sq0"), Some(0));
+    /// assert_eq!(sequence_dictionary.get_length(0), Some(8));
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    fn from(header: &Header) -> Self {
+        let mut sequence_dictionary = Self::default();
+
+        for (name, reference_sequence) in header.reference_sequences() {
+            // SAFETY: Reference sequence names in a header are unique.
+            sequence_dictionary
+                .push(name.clone(), usize::from(reference_sequence.length()))
+                .unwrap();
+        }
+
+        sequence_dictionary
+    }
+}
+
+impl TryFrom<&SequenceDictionary> for Header {
+    type Error = io::Error;
+
+    /// Builds a SAM header with the reference sequences of a sequence dictionary.
+    ///
+    /// The header has no other records. This fails if a reference sequence length is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::ResolveError, Region, SequenceDictionary};
+    /// use noodles_sam as sam;
+    ///
+    /// let sequence_dictionary = SequenceDictionary::try_from(vec![("sq0", 8)])?;
+    /// let header = sam::Header::try_from(&sequence_dictionary)?;
+    ///
+    /// let reference_sequences = header.reference_sequences();
+    /// assert_eq!(reference_sequences.len(), 1);
+    /// assert!(reference_sequences.contains_key(&b"sq0"[..]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn try_from(sequence_dictionary: &SequenceDictionary) -> Result<Self, Self::Error> {
+        let reference_sequences = sequence_dictionary
+            .iter()
+            .map(|(name, length)| {
+                NonZeroUsize::try_from(length)
+                    .map(|length| (name.into(), Map::<ReferenceSequence>::new(length)))
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self::builder()
+            .set_reference_sequences(reference_sequences)
+            .build())
+    }
+}
+
+/// Resolves the reference sequence of a region to its index in the reference sequences.
+///
+/// # Errors
+///
+/// An [`io::ErrorKind::InvalidInput`] error with a [`ResolveError`] is returned if the region
+/// name is not a reference sequence name.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Region;
+/// use noodles_sam::{
+///     self as sam,
+///     header::{record::value::{map::ReferenceSequence, Map}, resolve_region},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?))
+///     .build();
+///
+/// let reference_sequences = header.reference_sequences();
+/// assert_eq!(resolve_region(reference_sequences, &"sq0:2-5".parse()?)?, 0);
+/// assert!(resolve_region(reference_sequences, &"sq1".parse()?).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn resolve_region(
+    reference_sequences: &ReferenceSequences,
+    region: &Region,
+) -> io::Result<usize> {
+    reference_sequences
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                ResolveError::UnknownReferenceSequence(region.name().into()),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let region = "sq0:8-13".parse()?;
+        assert_eq!(resolve_region(header.reference_sequences(), &region)?, 0);
+
+        let region = "sq1".parse()?;
+
+        for reference_sequences in [
+            header.reference_sequences(),
+            Header::default().reference_sequences(),
+        ] {
+            let e = resolve_region(reference_sequences, &region).unwrap_err();
+            assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+            assert_eq!(e.to_string(), "unknown reference sequence: sq1");
+        }
+
+        Ok(())
+    }
+}
@@ -26,7 +26,6 @@ pub enum ParseError {
     InvalidTag(super::field::tag::ParseError),
     InvalidValue(value::ParseError),
     MissingVersion,
-    InvalidVersion(version::ParseError),
     InvalidOther(Other<tag::Standard>, value::ParseError),
     DuplicateTag(Tag),
 }
@@ -49,7 +48,6 @@ impl fmt::Display for ParseError {
             Self::InvalidTag(_) => write!(f, "invalid tag"),
             Self::InvalidValue(_) => write!(f, "invalid value"),
             Self::MissingVersion => write!(f, "missing version ({}) field", tag::VERSION),
-            Self::InvalidVersion(_) => write!(f, "invalid version ({})", tag::VERSION),
             Self::InvalidOther(tag, _) => write!(f, "invalid other ({tag})"),
             Self::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
         }
@@ -70,14 +68,14 @@ pub(crate) fn parse_header(src: &mut &[u8], ctx: &Context) -> Result<Map<Header>
             tag::VERSION => {
                 parse_value(src)
                     .map_err(ParseError::InvalidValue)
-                    .and_then(|buf| parse_version(buf).map_err(ParseError::InvalidVersion))
-                    .and_then(|v| try_replace(&mut version, ctx, tag::VERSION, v))?;
+                    .and_then(|v| try_replace(&mut version, ctx, tag::VERSION, v.into()))?;
             }
             Tag::Other(t) => parse_other(src, t)
                 .and_then(|value| try_insert(&mut other_fields, ctx, t, value))?,
         }
     }
 
+    // The raw version is kept verbatim, even if it is malformed. See `Map<Header>::version`.
     let version = version.ok_or(ParseError::MissingVersion)?;
 
     Ok(Map {
@@ -134,6 +132,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_header_with_malformed_version() -> Result<(), ParseError> {
+        let mut src = &b"\tVN:1.x"[..];
+        let ctx = Context::default();
+
+        let header = parse_header(&mut src, &ctx)?;
+        assert!(header.version().is_none());
+        assert_eq!(header.raw_version(), "1.x");
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_header_with_missing_version() {
         let mut src = &b"\tSO:coordinate"[..];
@@ -190,6 +190,93 @@ impl Programs {
                 .unwrap()
         }))
     }
+
+    /// Removes programs that are identical to their previous program.
+    ///
+    /// Repeatedly processing a file with the same tool appends a near-identical program to each
+    /// program chain, e.g., `pg0 -> pg0-pg0 -> pg0-pg0-pg0`. This collapses consecutive
+    /// identical programs, keeping only the first (oldest) of each run.
+    ///
+    /// Two programs are identical if they have the same program name (`PN`), version (`VN`),
+    /// and command line (`CL`), where a missing field only equals a missing field. The program ID
+    /// (`ID`) and previous program ID (`PP`), which are generated, and all other fields, e.g.,
+    /// description (`DS`), are ignored.
+    ///
+    /// Programs that link to a removed program are relinked to the kept program. This can be
+    /// called after [`Self::add`] to keep headers from growing in iterative pipelines. The order
+    /// of the remaining programs is preserved. This returns the number of programs removed.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if any program chain has a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::{program::tag, Program}, Map},
+    /// };
+    ///
+    /// let mut header = sam::Header::default();
+    /// let programs = header.programs_mut();
+    ///
+    /// let program = Map::<Program>::builder()
+    ///     .insert(tag::NAME, "noodles")
+    ///     .insert(tag::COMMAND_LINE, "noodles sort")
+    ///     .build()?;
+    ///
+    /// programs.add("noodles", program.clone())?;
+    /// programs.add("noodles", program)?;
+    /// assert_eq!(programs.as_ref().len(), 2);
+    ///
+    /// assert_eq!(programs.dedup_consecutive()?, 1);
+    /// assert_eq!(programs.as_ref().len(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn dedup_consecutive(&mut self) -> io::Result<usize> {
+        // Validates that there are no cycles.
+        self.leaves()?.for_each(drop);
+
+        let mut removed_count = 0;
+
+        while let Some((id, previous_program_id)) = self.find_duplicate() {
+            self.0.shift_remove(&id);
+
+            for map in self.0.values_mut() {
+                if let Some(value) = map.other_fields_mut().get_mut(&tag::PREVIOUS_PROGRAM_ID) {
+                    if *value == id {
+                        *value = previous_program_id.clone();
+                    }
+                }
+            }
+
+            removed_count += 1;
+        }
+
+        Ok(removed_count)
+    }
+
+    // Returns the first program that is identical to its previous program and its previous
+    // program ID.
+    fn find_duplicate(&self) -> Option<(BString, BString)> {
+        self.0.iter().find_map(|(id, map)| {
+            let previous_program_id = map.other_fields().get(&tag::PREVIOUS_PROGRAM_ID)?;
+            let previous_program = self.0.get(previous_program_id)?;
+
+            if is_identical(map, previous_program) {
+                Some((id.clone(), previous_program_id.clone()))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+fn is_identical(a: &Map<Program>, b: &Map<Program>) -> bool {
+    [tag::NAME, tag::VERSION, tag::COMMAND_LINE]
+        .iter()
+        .all(|tag| a.other_fields().get(tag) == b.other_fields().get(tag))
 }
 
 fn has_cycle<'a>(graph: &'a Inner, mut parent_id: &'a BStr, node_id: &'a BStr) -> bool {
@@ -325,6 +412,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_dedup_consecutive() -> Result<(), Box<dyn std::error::Error>> {
+        fn build_program(
+            name: &str,
+            version: Option<&str>,
+            previous_program_id: Option<&str>,
+        ) -> Result<Map<Program>, crate::header::record::value::map::builder::BuildError> {
+            let mut builder = Map::builder()
+                .insert(tag::NAME, name)
+                .insert(tag::COMMAND_LINE, format!("{name} view"));
+
+            if let Some(version) = version {
+                builder = builder.insert(tag::VERSION, version);
+            }
+
+            if let Some(id) = previous_program_id {
+                builder = builder.insert(tag::PREVIOUS_PROGRAM_ID, id);
+            }
+
+            builder.build()
+        }
+
+        // pg0 -> pg1 -> pg2 -> pg3 -> pg4 -> pg5 and ndls0 -> ndls1
+        let mut header = Header::builder()
+            .add_program("pg0", build_program("samtools", Some("1.19"), None)?)
+            .add_program("pg1", build_program("samtools", Some("1.19"), Some("pg0"))?)
+            .add_program("pg2", build_program("samtools", Some("1.19"), Some("pg1"))?)
+            .add_program("pg3", build_program("noodles", None, Some("pg2"))?)
+            .add_program("pg4", build_program("samtools", Some("1.20"), Some("pg3"))?)
+            .add_program("pg5", build_program("samtools", Some("1.20"), Some("pg4"))?)
+            .add_program("ndls0", build_program("noodles", None, None)?)
+            .add_program("ndls1", build_program("noodles", None, Some("ndls0"))?)
+            .build();
+
+        let programs = header.programs_mut();
+        assert_eq!(programs.dedup_consecutive()?, 4);
+
+        let expected = Header::builder()
+            .add_program("pg0", build_program("samtools", Some("1.19"), None)?)
+            .add_program("pg3", build_program("noodles", None, Some("pg0"))?)
+            .add_program("pg4", build_program("samtools", Some("1.20"), Some("pg3"))?)
+            .add_program("ndls0", build_program("noodles", None, None)?)
+            .build();
+
+        assert_eq!(programs, expected.programs());
+
+        assert_eq!(programs.dedup_consecutive()?, 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_leaves_with_cycle() -> Result<(), crate::header::record::value::map::builder::BuildError>
     {
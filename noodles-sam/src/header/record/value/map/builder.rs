@@ -13,6 +13,10 @@ pub enum BuildError {
     MissingField(&'static str),
     /// A value is invalid.
     InvalidValue(&'static str),
+    /// An other field value is invalid.
+    ///
+    /// This is the tag of the field whose value contains a tab or line break.
+    InvalidOtherFieldValue([u8; 2]),
 }
 
 impl error::Error for BuildError {}
@@ -22,6 +26,9 @@ impl fmt::Display for BuildError {
         match self {
             Self::MissingField(tag) => write!(f, "missing field: {tag}"),
             Self::InvalidValue(tag) => write!(f, "invalid value: {tag}"),
+            Self::InvalidOtherFieldValue([a, b]) => {
+                write!(f, "invalid value: {}{}", char::from(*a), char::from(*b))
+            }
         }
     }
 }
@@ -76,6 +83,11 @@ where
 
     /// Builds a SAM header record map value.
     ///
+    /// Header record fields are tab-delimited, and header records are newline-delimited, so this
+    /// returns an error if a value in the other fields contains a tab or line break (`\n` or
+    /// `\r`). This includes free-text fields, e.g., the program command line (`@PG` `CL`) and
+    /// description (`DS`), which commonly come from user input.
+    ///
     /// # Examples
     ///
     /// ```
@@ -85,6 +97,12 @@ where
     pub fn build(self) -> Result<Map<I>, BuildError> {
         let inner = self.inner.build()?;
 
+        for (tag, value) in &self.other_fields {
+            if value.iter().any(|&b| matches!(b, b'\t' | b'\n' | b'\r')) {
+                return Err(BuildError::InvalidOtherFieldValue(*tag.as_ref()));
+            }
+        }
+
         Ok(Map {
             inner,
             other_fields: self.other_fields,
@@ -103,3 +121,30 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::map::{program::tag, Program};
+
+    #[test]
+    fn test_build_with_invalid_other_field_value() {
+        let result = Map::<Program>::builder()
+            .insert(tag::NAME, "noodles")
+            .insert(tag::COMMAND_LINE, "noodles view\t--verbose")
+            .build();
+        assert_eq!(result, Err(BuildError::InvalidOtherFieldValue(*b"CL")));
+
+        for value in ["noodles\n", "noodles\r"] {
+            let result = Map::<Program>::builder()
+                .insert(tag::DESCRIPTION, value)
+                .build();
+            assert_eq!(result, Err(BuildError::InvalidOtherFieldValue(*b"DS")));
+        }
+
+        assert!(Map::<Program>::builder()
+            .insert(tag::COMMAND_LINE, "noodles view --output=\"out file.sam\"")
+            .build()
+            .is_ok());
+    }
+}
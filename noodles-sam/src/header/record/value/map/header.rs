@@ -6,6 +6,8 @@ pub mod sort_order;
 pub mod tag;
 pub mod version;
 
+use bstr::{BStr, BString};
+
 pub use self::{tag::Tag, version::Version};
 
 use self::builder::Builder;
@@ -14,9 +16,26 @@ use super::{Inner, Map, OtherFields};
 /// A SAM header record header map value.
 ///
 /// The header describes file-level metadata. The format version is guaranteed to be set.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+///
+/// The format version is stored as it was read, e.g., it may be malformed. This allows it to be
+/// written back verbatim.
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
-    pub(crate) version: Version,
+    pub(crate) version: BString,
+}
+
+impl Header {
+    pub(crate) fn from_version(version: Version) -> Self {
+        Self {
+            version: version.to_string().into(),
+        }
+    }
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self::from_version(Version::default())
+    }
 }
 
 impl Inner for Header {
@@ -39,13 +58,15 @@ impl Map<Header> {
     /// ```
     pub fn new(version: Version) -> Self {
         Self {
-            inner: Header { version },
+            inner: Header::from_version(version),
             other_fields: OtherFields::new(),
         }
     }
 
     /// Returns the format version.
     ///
+    /// This returns `None` if the raw format version is malformed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -55,24 +76,43 @@ impl Map<Header> {
     /// };
     ///
     /// let header = Map::<map::Header>::new(Version::new(1, 6));
-    /// assert_eq!(header.version(), Version::new(1, 6));
+    /// assert_eq!(header.version(), Some(Version::new(1, 6)));
     /// ```
-    pub fn version(&self) -> Version {
-        self.inner.version
+    pub fn version(&self) -> Option<Version> {
+        std::str::from_utf8(&self.inner.version)
+            .ok()
+            .and_then(|s| s.parse().ok())
     }
 
-    /// Returns a mutable reference to the format version.
+    /// Returns the raw format version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{self, header::Version},
+    ///     Map,
+    /// };
+    ///
+    /// let header = Map::<map::Header>::new(Version::new(1, 6));
+    /// assert_eq!(header.raw_version(), "1.6");
+    /// ```
+    pub fn raw_version(&self) -> &BStr {
+        self.inner.version.as_ref()
+    }
+
+    /// Sets the format version.
     ///
     /// # Examples
     ///
     /// ```
     /// use noodles_sam::header::record::value::{map::{self, header::Version}, Map};
     /// let mut header = Map::<map::Header>::default();
-    /// *header.version_mut() = Version::new(1, 5);
-    /// assert_eq!(header.version(), Version::new(1, 5));
+    /// header.set_version(Version::new(1, 5));
+    /// assert_eq!(header.version(), Some(Version::new(1, 5)));
     /// ```
-    pub fn version_mut(&mut self) -> &mut Version {
-        &mut self.inner.version
+    pub fn set_version(&mut self, version: Version) {
+        self.inner = Header::from_version(version);
     }
 }
 
@@ -83,6 +123,19 @@ mod tests {
     #[test]
     fn test_default() {
         let header = Map::<Header>::default();
-        assert_eq!(header.version(), Version::default());
+        assert_eq!(header.version(), Some(Version::default()));
+    }
+
+    #[test]
+    fn test_version_with_malformed_raw_version() {
+        let header = Map {
+            inner: Header {
+                version: BString::from("1.x"),
+            },
+            other_fields: OtherFields::new(),
+        };
+
+        assert!(header.version().is_none());
+        assert_eq!(header.raw_version(), "1.x");
     }
 }
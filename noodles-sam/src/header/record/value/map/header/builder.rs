@@ -20,7 +20,7 @@ impl map::Builder<Header> {
     ///
     /// let version = Version::new(1, 6);
     /// let header = Map::<map::Header>::builder().set_version(version).build()?;
-    /// assert_eq!(header.version(), version);
+    /// assert_eq!(header.version(), Some(version));
     /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
     /// ```
     pub fn set_version(mut self, version: Version) -> Self {
@@ -31,9 +31,7 @@ impl map::Builder<Header> {
 
 impl map::builder::Inner<Header> for Builder {
     fn build(self) -> Result<Header, BuildError> {
-        Ok(Header {
-            version: self.version.unwrap_or_default(),
-        })
+        Ok(Header::from_version(self.version.unwrap_or_default()))
     }
 }
 
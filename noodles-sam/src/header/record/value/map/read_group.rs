@@ -6,8 +6,8 @@ pub mod tag;
 
 pub(crate) use self::tag::Tag;
 
-use self::builder::Builder;
-use super::Inner;
+use self::{builder::Builder, platform::Platform};
+use super::{Inner, Map};
 
 /// A SAM header record read group map value.
 ///
@@ -20,3 +20,59 @@ impl Inner for ReadGroup {
     type StandardTag = tag::Standard;
     type Builder = Builder;
 }
+
+impl Map<ReadGroup> {
+    /// Returns the platform (`PL`).
+    ///
+    /// The raw value is parsed case-insensitively. An unknown platform is returned as
+    /// [`Platform::Other`]. The raw value in the other fields is not changed, so it is written
+    /// with its original casing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{read_group::{platform::Platform, tag}, ReadGroup},
+    ///     Map,
+    /// };
+    ///
+    /// let mut read_group = Map::<ReadGroup>::default();
+    /// assert!(read_group.platform().is_none());
+    ///
+    /// read_group
+    ///     .other_fields_mut()
+    ///     .insert(tag::PLATFORM, "illumina".into());
+    /// assert_eq!(read_group.platform(), Some(Platform::Illumina));
+    /// ```
+    pub fn platform(&self) -> Option<Platform> {
+        self.other_fields()
+            .get(&tag::PLATFORM)
+            .map(|raw_platform| Platform::from(raw_platform.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bstr::BString;
+
+    use super::*;
+
+    #[test]
+    fn test_platform() {
+        let mut read_group = Map::<ReadGroup>::default();
+        assert!(read_group.platform().is_none());
+
+        read_group
+            .other_fields_mut()
+            .insert(tag::PLATFORM, "ONT".into());
+        assert_eq!(read_group.platform(), Some(Platform::Ont));
+
+        read_group
+            .other_fields_mut()
+            .insert(tag::PLATFORM, "noodles".into());
+        assert_eq!(
+            read_group.platform(),
+            Some(Platform::Other(BString::from("noodles")))
+        );
+    }
+}
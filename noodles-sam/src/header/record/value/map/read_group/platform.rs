@@ -1,5 +1,7 @@
 //! SAM header read group platform.
 
+use bstr::{BStr, BString};
+
 /// Capillary electrophoresis sequencing (`CAPILLARY`).
 pub const CAPILLARY: &[u8] = b"CAPILLARY";
 
@@ -35,3 +37,125 @@ pub const SOLID: &[u8] = b"SOLID";
 
 /// Ultima Genomics (`ULTIMA`).
 pub const ULTIMA: &[u8] = b"ULTIMA";
+
+/// A SAM header read group platform (`PL`).
+///
+/// Known platforms are parsed case-insensitively. Unknown platforms are kept as is
+/// ([`Self::Other`]).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Platform {
+    /// Capillary electrophoresis sequencing (`CAPILLARY`).
+    Capillary,
+    /// DNBseq (`DNBSEQ`).
+    DnbSeq,
+    /// Element Biosciences (`ELEMENT`).
+    Element,
+    /// Helicos (`HELICOS`).
+    Helicos,
+    /// Illumina (`ILLUMINA`).
+    Illumina,
+    /// Ion Torrent (`IONTORRENT`).
+    IonTorrent,
+    /// 454 Life Sciences (`LS454`).
+    Ls454,
+    /// Oxford Nanopore Technologies (ONT) (`ONT`).
+    Ont,
+    /// Pacific Biosciences (PacBio) (`PACBIO`).
+    PacBio,
+    /// Singular Genomics (`SINGULAR`).
+    Singular,
+    /// SOLiD (`SOLID`).
+    Solid,
+    /// Ultima Genomics (`ULTIMA`).
+    Ultima,
+    /// Any other platform.
+    Other(BString),
+}
+
+impl From<&[u8]> for Platform {
+    fn from(src: &[u8]) -> Self {
+        const PLATFORMS: [(&[u8], Platform); 12] = [
+            (CAPILLARY, Platform::Capillary),
+            (DNB_SEQ, Platform::DnbSeq),
+            (ELEMENT, Platform::Element),
+            (HELICOS, Platform::Helicos),
+            (ILLUMINA, Platform::Illumina),
+            (ION_TORRENT, Platform::IonTorrent),
+            (LS454, Platform::Ls454),
+            (ONT, Platform::Ont),
+            (PAC_BIO, Platform::PacBio),
+            (SINGULAR, Platform::Singular),
+            (SOLID, Platform::Solid),
+            (ULTIMA, Platform::Ultima),
+        ];
+
+        PLATFORMS
+            .into_iter()
+            .find(|(raw_platform, _)| raw_platform.eq_ignore_ascii_case(src))
+            .map(|(_, platform)| platform)
+            .unwrap_or_else(|| Self::Other(src.into()))
+    }
+}
+
+impl AsRef<BStr> for Platform {
+    fn as_ref(&self) -> &BStr {
+        let raw_platform = match self {
+            Self::Capillary => CAPILLARY,
+            Self::DnbSeq => DNB_SEQ,
+            Self::Element => ELEMENT,
+            Self::Helicos => HELICOS,
+            Self::Illumina => ILLUMINA,
+            Self::IonTorrent => ION_TORRENT,
+            Self::Ls454 => LS454,
+            Self::Ont => ONT,
+            Self::PacBio => PAC_BIO,
+            Self::Singular => SINGULAR,
+            Self::Solid => SOLID,
+            Self::Ultima => ULTIMA,
+            Self::Other(raw_platform) => return raw_platform.as_ref(),
+        };
+
+        raw_platform.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_u8_slice_for_platform() {
+        fn t(src: &[u8], expected: Platform) {
+            assert_eq!(Platform::from(src), expected);
+        }
+
+        t(b"CAPILLARY", Platform::Capillary);
+        t(b"DNBSEQ", Platform::DnbSeq);
+        t(b"ELEMENT", Platform::Element);
+        t(b"HELICOS", Platform::Helicos);
+        t(b"ILLUMINA", Platform::Illumina);
+        t(b"IONTORRENT", Platform::IonTorrent);
+        t(b"LS454", Platform::Ls454);
+        t(b"ONT", Platform::Ont);
+        t(b"PACBIO", Platform::PacBio);
+        t(b"SINGULAR", Platform::Singular);
+        t(b"SOLID", Platform::Solid);
+        t(b"ULTIMA", Platform::Ultima);
+
+        t(b"illumina", Platform::Illumina);
+        t(b"PacBio", Platform::PacBio);
+
+        t(b"NOODLES", Platform::Other(BString::from("NOODLES")));
+        t(b"", Platform::Other(BString::default()));
+    }
+
+    #[test]
+    fn test_as_ref_bstr_for_platform() {
+        let platform: &BStr = Platform::Illumina.as_ref();
+        assert_eq!(platform, "ILLUMINA");
+
+        let platform = Platform::Other(BString::from("Noodles"));
+        let raw_platform: &BStr = platform.as_ref();
+        assert_eq!(raw_platform, "Noodles");
+    }
+}
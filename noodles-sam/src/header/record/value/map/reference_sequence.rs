@@ -7,6 +7,8 @@ pub mod tag;
 
 use std::num::NonZeroUsize;
 
+use bstr::{BStr, ByteSlice};
+
 pub use self::md5_checksum::Md5Checksum;
 pub(crate) use self::tag::Tag;
 
@@ -83,4 +85,42 @@ impl Map<ReferenceSequence> {
     pub fn length_mut(&mut self) -> &mut NonZeroUsize {
         &mut self.inner.length
     }
+
+    /// Returns the alternate reference sequence names (`AN`).
+    ///
+    /// `AN` is a comma-separated list of alternate names of the reference sequence, e.g., `1` for
+    /// `chr1`. This is read from the other fields, where it is stored as is. It is empty if the
+    /// field is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use bstr::BString;
+    /// use noodles_sam::header::record::value::{
+    ///     map::{reference_sequence::tag, ReferenceSequence},
+    ///     Map,
+    /// };
+    ///
+    /// let mut reference_sequence = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?);
+    /// assert_eq!(reference_sequence.alternative_names().count(), 0);
+    ///
+    /// reference_sequence
+    ///     .other_fields_mut()
+    ///     .insert(tag::ALTERNATIVE_NAMES, BString::from("1,NC_000001.11"));
+    ///
+    /// let names: Vec<_> = reference_sequence.alternative_names().collect();
+    /// assert_eq!(names, ["1", "NC_000001.11"]);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn alternative_names(&self) -> impl Iterator<Item = &BStr> {
+        const DELIMITER: u8 = b',';
+
+        self.other_fields()
+            .get(&tag::ALTERNATIVE_NAMES)
+            .filter(|value| !value.is_empty())
+            .into_iter()
+            .flat_map(|value| value.split_str(&[DELIMITER]).map(|name| name.as_bstr()))
+    }
 }
@@ -2,6 +2,7 @@
 
 mod builder;
 mod header;
+mod line_kind;
 mod query;
 mod record;
 pub(crate) mod record_buf;
@@ -12,14 +13,23 @@ use std::{
     iter,
 };
 
+use bstr::{BStr, BString};
+use indexmap::IndexSet;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 pub(crate) use self::record::read_record;
-pub use self::{builder::Builder, record_bufs::RecordBufs};
+pub use self::{builder::Builder, line_kind::LineKind, record_bufs::RecordBufs};
 use self::{header::read_header, query::Query, record_buf::read_record_buf};
-use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
+use crate::{
+    alignment::{
+        io::{RecordMeta, RecordObserver},
+        RecordBuf,
+    },
+    header::resolve_region,
+    Header, Record,
+};
 
 /// A SAM reader.
 ///
@@ -48,6 +58,11 @@ use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    header: Option<Box<Header>>,
+    record_observer: Option<RecordObserver>,
+    // The reference sequence names used to resolve reference sequence IDs for the record
+    // observer.
+    record_observer_reference_sequence_names: IndexSet<BString>,
 }
 
 impl<R> Reader<R> {
@@ -121,6 +136,9 @@ where
     ///
     /// The SAM header is optional, and if it is missing, an empty [`Header`] is returned.
     ///
+    /// If the reader was built with a header (see [`Builder::set_header`]), the input is treated
+    /// as headerless: nothing is read, and this returns a copy of the given header.
+    ///
     /// # Examples
     ///
     /// ```
@@ -142,7 +160,17 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner)
+        let header = match &self.header {
+            Some(header) => Header::clone(header),
+            None => read_header(&mut self.inner)?,
+        };
+
+        if self.record_observer.is_some() {
+            self.record_observer_reference_sequence_names =
+                header.reference_sequences().keys().cloned().collect();
+        }
+
+        Ok(header)
     }
 
     /// Reads a record into an alignment record buffer.
@@ -181,7 +209,17 @@ where
         header: &Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, &mut self.buf, header, record)
+        let n = read_record_buf(&mut self.inner, &mut self.buf, header, record)?;
+
+        if let Some(record_observer) = self.record_observer.as_ref().filter(|_| n > 0) {
+            record_observer.observe(&RecordMeta::new(
+                Some(n),
+                record.reference_sequence_id(),
+                !record.flags().is_unmapped(),
+            ));
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over alignment record buffers starting from the current stream
@@ -221,6 +259,12 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// If the reader has a record observer (see [`Builder::set_record_observer`]), the flags and
+    /// reference sequence ID are decoded for the observer. The reference sequence ID is resolved
+    /// using the header read by [`Self::read_header`]. The observer is always called: if no header
+    /// was read or decoding these fields fails, the observer is given no reference sequence ID
+    /// and an unmapped record, and the result of this method is unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -239,7 +283,23 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if let Some(record_observer) = self.record_observer.as_ref().filter(|_| n > 0) {
+            let is_mapped = record
+                .flags()
+                .map(|flags| !flags.is_unmapped())
+                .unwrap_or_default();
+
+            let reference_sequence_id = record.reference_sequence_name().and_then(|name| {
+                self.record_observer_reference_sequence_names
+                    .get_index_of(BStr::new(name.as_ref()))
+            });
+
+            record_observer.observe(&RecordMeta::new(Some(n), reference_sequence_id, is_mapped));
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records.
@@ -274,6 +334,50 @@ where
             Err(e) => Some(Err(e)),
         })
     }
+
+    /// Returns an iterator over raw lines.
+    ///
+    /// Each line is returned as is, without its line ending (`\n` or `\r\n`), and with the kind of
+    /// line, determined only by its first byte: a line prefixed with an `@` (at sign) is a header
+    /// line, and any other line is a record line. Lines are not parsed or validated, and comments
+    /// and nonstandard lines are passed through.
+    ///
+    /// This reads from the current position of the stream, e.g., the start of the file to read
+    /// both header and record lines. It is a low-level alternative to the typed readers, e.g.,
+    /// for passthrough tools or diagnosing malformed input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, io::reader::LineKind};
+    ///
+    /// let data = b"@HD\tVN:1.6\r
+    /// *\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = sam::io::Reader::new(&data[..]);
+    /// let lines: Vec<_> = reader.raw_lines().collect::<std::io::Result<_>>()?;
+    ///
+    /// assert_eq!(
+    ///     lines,
+    ///     [
+    ///         (LineKind::Header, b"@HD\tVN:1.6".to_vec()),
+    ///         (LineKind::Record, b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*".to_vec()),
+    ///     ]
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn raw_lines(&mut self) -> impl Iterator<Item = io::Result<(LineKind, Vec<u8>)>> + '_ {
+        iter::from_fn(move || {
+            let mut line = Vec::new();
+
+            match read_line(&mut self.inner, &mut line) {
+                Ok(0) => None,
+                Ok(_) => Some(Ok((LineKind::from_line(&line), line))),
+                Err(e) => Some(Err(e)),
+            }
+        })
+    }
 }
 
 impl<R> Reader<R>
@@ -342,6 +446,19 @@ where
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
+    /// This only returns unplaced, unmapped records, i.e., records without a reference sequence
+    /// name. These are at the end of a coordinate-sorted file. Unmapped records placed next to
+    /// their mate are returned by [`Self::query`] instead.
+    ///
+    /// The reader is moved to the start of the unplaced, unmapped records, as recorded in the
+    /// index metadata. If the index has no metadata, this falls back to the first record. Records
+    /// before the first unplaced, unmapped record are skipped.
+    ///
+    /// If the index records that there are no unplaced, unmapped records, this returns an empty
+    /// iterator without reading.
+    ///
+    /// # Examples
+    ///
     /// ```no_run
     /// # use std::{fs::File, io};
     /// use noodles_bgzf as bgzf;
@@ -370,28 +487,23 @@ where
     where
         I: BinningIndex,
     {
-        if let Some(pos) = index.last_first_record_start_position() {
-            self.get_mut().seek_to_virtual_position(pos)?;
+        let records = if index.unplaced_unmapped_record_count() == Some(0) {
+            None
         } else {
-            self.seek_to_first_record()?;
-        }
+            if let Some(pos) = index.unplaced_unmapped_record_start_position() {
+                self.get_mut().seek_to_virtual_position(pos)?;
+            } else {
+                self.seek_to_first_record()?;
+            }
 
-        let mut record = Record::default();
+            Some(self.records())
+        };
 
-        Ok(iter::from_fn(move || loop {
-            match self.read_record(&mut record) {
-                Ok(0) => return None,
-                Ok(_) => {
-                    let result = record.flags().map(|flags| flags.is_unmapped());
-
-                    match result {
-                        Ok(true) => return Some(Ok(record.clone())),
-                        Ok(false) => {}
-                        Err(e) => return Some(Err(e)),
-                    }
-                }
-                Err(e) => return Some(Err(e)),
-            }
+        Ok(records.into_iter().flatten().filter(|result| {
+            result
+                .as_ref()
+                .map(|record| record.reference_sequence_name().is_none())
+                .unwrap_or(true)
         }))
     }
 }
@@ -404,6 +516,9 @@ where
         Self {
             inner,
             buf: Vec::new(),
+            header: None,
+            record_observer: None,
+            record_observer_reference_sequence_names: IndexSet::new(),
         }
     }
 }
@@ -449,19 +564,6 @@ where
     }
 }
 
-fn resolve_region(reference_sequences: &ReferenceSequences, region: &Region) -> io::Result<usize> {
-    reference_sequences
-        .get_index_of(region.name())
-        .ok_or_else(|| {
-            io::Error::new(
-                io::ErrorKind::InvalidInput,
-                format!(
-                    "region reference sequence does not exist in reference sequences: {region:?}"
-                ),
-            )
-        })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -483,4 +585,207 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_header_with_headerless_input() -> io::Result<()> {
+        use std::num::NonZeroUsize;
+
+        use crate::header::record::value::{map::ReferenceSequence, Map};
+
+        const SQ0_LENGTH: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(length) => length,
+            None => unreachable!(),
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LENGTH))
+            .build();
+
+        let data = b"r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS\n";
+
+        let mut reader = Builder::default()
+            .set_header(header.clone())
+            .build_from_reader(&data[..])?;
+
+        assert_eq!(reader.read_header()?, header);
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, data.len());
+        assert_eq!(
+            record.name().map(|name| name.as_ref().to_vec()),
+            Some(b"r0".to_vec())
+        );
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_record_observer() -> io::Result<()> {
+        use std::{
+            num::NonZeroUsize,
+            sync::{Arc, Mutex},
+        };
+
+        use crate::header::record::value::{map::ReferenceSequence, Map};
+
+        const SQ0_LENGTH: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(length) => length,
+            None => unreachable!(),
+        };
+
+        let data = b"@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+";
+
+        let metas = Arc::new(Mutex::new(Vec::new()));
+
+        let build_reader = || {
+            let metas = Arc::clone(&metas);
+
+            Builder::default()
+                .set_record_observer(move |meta: &RecordMeta| metas.lock().unwrap().push(*meta))
+                .build_from_reader(&data[..])
+        };
+
+        let mut reader = build_reader()?;
+        let header = reader.read_header()?;
+
+        assert_eq!(
+            header,
+            Header::builder()
+                .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LENGTH))
+                .build()
+        );
+
+        for result in reader.record_bufs(&header) {
+            result?;
+        }
+
+        assert_eq!(
+            metas.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            [
+                RecordMeta::new(Some(34), Some(0), true),
+                RecordMeta::new(Some(25), None, false),
+            ]
+        );
+
+        let mut reader = build_reader()?;
+        reader.read_header()?;
+
+        for result in reader.records() {
+            result?;
+        }
+
+        assert_eq!(
+            metas.lock().unwrap().drain(..).collect::<Vec<_>>(),
+            [
+                RecordMeta::new(Some(34), Some(0), true),
+                RecordMeta::new(Some(25), None, false),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_record_observer_and_invalid_fields() -> io::Result<()> {
+        use std::sync::{Arc, Mutex};
+
+        let data = b"@SQ\tSN:sq0\tLN:8
+r0\tn\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t0\tsq1\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS
+";
+
+        let metas = Arc::new(Mutex::new(Vec::new()));
+        let observer_metas = Arc::clone(&metas);
+
+        let mut reader = Builder::default()
+            .set_record_observer(move |meta: &RecordMeta| {
+                observer_metas.lock().unwrap().push(*meta)
+            })
+            .build_from_reader(&data[..])?;
+
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, 34);
+        assert_eq!(reader.read_record(&mut record)?, 34);
+
+        assert_eq!(
+            *metas.lock().unwrap(),
+            [
+                RecordMeta::new(Some(34), Some(0), false),
+                RecordMeta::new(Some(34), None, true),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use noodles_csi as csi;
+
+        let data = b"@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS
+r1\t4\tsq0\t1\t255\t*\t*\t0\t0\tACGT\tNDLS
+r2\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+r3\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS
+";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        let src = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(src)));
+        reader.read_header()?;
+
+        let index = csi::Index::default();
+
+        let names: Vec<_> = reader
+            .query_unmapped(&index)?
+            .map(|result| result.map(|record| record.name().map(|name| name.as_ref().to_vec())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some(b"r2".to_vec()), Some(b"r3".to_vec())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_raw_lines() -> io::Result<()> {
+        let data = b"@HD\tVN:1.6
+@CO\tnoodles\r
+#comment
+*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+
+@r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*";
+
+        let mut reader = Reader::new(&data[..]);
+        let actual: Vec<_> = reader.raw_lines().collect::<io::Result<_>>()?;
+
+        let expected = [
+            (LineKind::Header, b"@HD\tVN:1.6".to_vec()),
+            (LineKind::Header, b"@CO\tnoodles".to_vec()),
+            (LineKind::Record, b"#comment".to_vec()),
+            (
+                LineKind::Record,
+                b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*".to_vec(),
+            ),
+            (LineKind::Record, Vec::new()),
+            (
+                LineKind::Header,
+                b"@r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*".to_vec(),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
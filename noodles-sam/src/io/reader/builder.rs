@@ -7,12 +7,18 @@ use std::{
 use noodles_bgzf as bgzf;
 
 use super::Reader;
-use crate::io::CompressionMethod;
+use crate::{
+    alignment::io::{RecordMeta, RecordObserver},
+    io::CompressionMethod,
+    Header,
+};
 
 /// A SAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    header: Option<Header>,
+    record_observer: Option<RecordObserver>,
 }
 
 impl Builder {
@@ -29,6 +35,84 @@ impl Builder {
         self
     }
 
+    /// Sets the header of a headerless input.
+    ///
+    /// The input is treated as a record stream without a header, and records are read from the
+    /// start of the stream, i.e., lines that start with `@` are not consumed as header records.
+    /// The given header is used instead, e.g., from a sidecar file, and [`Reader::read_header`]
+    /// returns a copy of it without reading from the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, io::reader::Builder};
+    ///
+    /// let data = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+    ///
+    /// let mut reader = Builder::default()
+    ///     .set_header(sam::Header::default())
+    ///     .build_from_reader(&data[..])?;
+    ///
+    /// let header = reader.read_header()?;
+    /// assert!(header.is_empty());
+    /// assert_eq!(reader.records().count(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_header(mut self, header: Header) -> Self {
+        self.header = Some(header);
+        self
+    }
+
+    /// Sets a record observer.
+    ///
+    /// The observer is called after each record is read, with the line length, reference
+    /// sequence ID, and whether the record is mapped. Records read using
+    /// [`Reader::read_record`] (e.g., [`Reader::records`]) resolve their reference sequence ID
+    /// using the header read by [`Reader::read_header`].
+    ///
+    /// Only the synchronous reader calls the observer. The async reader does not support record
+    /// observers.
+    ///
+    /// By default, no observer is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use std::sync::{
+    ///     atomic::{AtomicUsize, Ordering},
+    ///     Arc,
+    /// };
+    ///
+    /// use noodles_sam::{self as sam, alignment::io::RecordMeta, io::reader::Builder};
+    ///
+    /// let data = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+    /// let byte_count = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let mut reader = {
+    ///     let byte_count = Arc::clone(&byte_count);
+    ///
+    ///     Builder::default()
+    ///         .set_record_observer(move |meta: &RecordMeta| {
+    ///             byte_count.fetch_add(meta.byte_len().unwrap_or_default(), Ordering::Relaxed);
+    ///         })
+    ///         .build_from_reader(&data[..])?
+    /// };
+    ///
+    /// let header = reader.read_header()?;
+    /// assert_eq!(reader.record_bufs(&header).count(), 1);
+    /// assert_eq!(byte_count.load(Ordering::Relaxed), data.len());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_record_observer<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&RecordMeta) + Send + Sync + 'static,
+    {
+        self.record_observer = Some(RecordObserver::new(f));
+        self
+    }
+
     /// Builds a SAM reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -77,6 +161,10 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+        reader.header = self.header.map(Box::new);
+        reader.record_observer = self.record_observer;
+
+        Ok(reader)
     }
 }
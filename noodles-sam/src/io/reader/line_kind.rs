@@ -0,0 +1,38 @@
+const HEADER_PREFIX: u8 = b'@';
+
+/// A kind of raw SAM line.
+///
+/// This is determined only by the first byte of the line, i.e., the line is not parsed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LineKind {
+    /// A header line, i.e., a line prefixed with an `@` (at sign).
+    Header,
+    /// Any other line, including an empty line.
+    Record,
+}
+
+impl LineKind {
+    pub(super) fn from_line(line: &[u8]) -> Self {
+        if line.first() == Some(&HEADER_PREFIX) {
+            Self::Header
+        } else {
+            Self::Record
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_line() {
+        assert_eq!(LineKind::from_line(b"@HD\tVN:1.6"), LineKind::Header);
+        assert_eq!(LineKind::from_line(b"@CO"), LineKind::Header);
+        assert_eq!(
+            LineKind::from_line(b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*"),
+            LineKind::Record
+        );
+        assert_eq!(LineKind::from_line(b""), LineKind::Record);
+    }
+}
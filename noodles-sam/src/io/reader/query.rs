@@ -68,7 +68,8 @@ fn intersects(
         record.alignment_start().transpose()?,
         record.alignment_end().transpose()?,
     ) {
-        (Some(id), Some(start), Some(end)) => {
+        (Some(id), Some(start), end) => {
+            let end = end.unwrap_or(start);
             let alignment_interval = (start..=end).into();
             Ok(id == reference_sequence_id && region_interval.intersects(alignment_interval))
         }
@@ -77,7 +77,7 @@ fn parse_char(src: &[u8]) -> Result<Value, ParseError> {
     let (n, rest) = src.split_first().ok_or(ParseError::UnexpectedEof)?;
 
     if rest.is_empty() {
-        Ok(Value::Character(*n))
+        Value::try_character(*n).map_err(|_| ParseError::InvalidCharacter)
     } else {
         Err(ParseError::InvalidCharacter)
     }
@@ -140,6 +140,14 @@ mod tests {
             parse_value(&mut &b"ndls"[..], Type::Character),
             Err(ParseError::InvalidCharacter)
         );
+        assert_eq!(
+            parse_value(&mut &[0x07][..], Type::Character),
+            Err(ParseError::InvalidCharacter)
+        );
+        assert_eq!(
+            parse_value(&mut &[0xe9][..], Type::Character),
+            Err(ParseError::InvalidCharacter)
+        );
 
         t(b"0", Type::Int32, Value::UInt8(0));
         assert!(matches!(
@@ -2,14 +2,15 @@
 
 mod builder;
 mod header;
+mod line_ending;
 mod num;
 pub mod record;
 
 use std::io::{self, Write};
 
-pub use self::builder::Builder;
 use self::header::write_header;
 pub(crate) use self::record::write_record;
+pub use self::{builder::Builder, line_ending::LineEnding};
 use crate::{Header, Record};
 
 /// A SAM writer.
@@ -47,6 +48,9 @@ where
     W: Write,
 {
     inner: W,
+    line_ending: LineEnding,
+    ensure_header_line: bool,
+    records_written: u64,
 }
 
 impl<W> Writer<W>
@@ -62,7 +66,12 @@ where
     /// let writer = sam::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_ending: LineEnding::default(),
+            ensure_header_line: false,
+            records_written: 0,
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -104,11 +113,62 @@ where
         self.inner
     }
 
+    /// Returns the number of records written.
+    ///
+    /// This is reset to 0 when the writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = sam::io::Writer::new(Vec::new());
+    /// assert_eq!(writer.records_written(), 0);
+    ///
+    /// let header = sam::Header::default();
+    /// let record = sam::Record::default();
+    /// writer.write_record(&header, &record)?;
+    /// assert_eq!(writer.records_written(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records_written(&self) -> u64 {
+        self.records_written
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// Buffered output is written to the destination, so a reader can read all records written
+    /// up to this point, e.g., when writing to a pipe. For a BGZF writer, any buffered data is
+    /// written as a complete block, but the BGZF EOF marker is not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = sam::io::writer::Builder::default().build_from_writer(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// let record = sam::Record::default();
+    /// writer.write_record(&header, &record)?;
+    ///
+    /// writer.flush()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
     /// Writes a SAM header.
     ///
     /// The SAM header is optional, though recommended to include. A call to this method can be
     /// omitted if it is empty.
     ///
+    /// If the writer is set to ensure a header line (see [`Builder::set_ensure_header_line`]) and
+    /// the header does not have one, a default header line (`@HD\tVN:1.6`) is written first.
+    ///
     /// # Examples
     ///
     /// ```
@@ -121,7 +181,12 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write_header(&mut self.inner, header)
+        write_header(
+            &mut self.inner,
+            header,
+            self.line_ending,
+            self.ensure_header_line,
+        )
     }
 
     /// Writes a SAM record.
@@ -142,7 +207,9 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, self.line_ending)?;
+        self.records_written += 1;
+        Ok(())
     }
 }
 
@@ -159,10 +226,167 @@ where
         header: &Header,
         record: &dyn crate::alignment::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, self.line_ending)?;
+        self.records_written += 1;
+        Ok(())
     }
 
     fn finish(&mut self, _: &Header) -> io::Result<()> {
+        self.records_written = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_with_crlf_line_ending() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::{
+            alignment::{io::Write as _, RecordBuf},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_comment("noodles-sam")
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_reference_sequence_id(0)
+            .build();
+
+        let mut writer = Writer {
+            inner: Vec::new(),
+            line_ending: LineEnding::CrLf,
+            ensure_header_line: false,
+            records_written: 0,
+        };
+
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.write_alignment_record(&header, &record)?;
+
+        let src = writer.into_inner();
+
+        let expected = b"@SQ\tSN:sq0\tLN:8\r
+@CO\tnoodles-sam\r
+r0\t4\tsq0\t0\t255\t*\t*\t0\t0\t*\t*\r
+r0\t4\tsq0\t0\t255\t*\t*\t0\t0\t*\t*\r
+";
+        assert_eq!(src, expected);
+
+        let mut reader = crate::io::Reader::new(&src[..]);
+        let actual_header = reader.read_header()?;
+        assert_eq!(actual_header, header);
+
+        let records: Vec<_> = reader
+            .record_bufs(&actual_header)
+            .collect::<io::Result<_>>()?;
+        assert_eq!(records, [record.clone(), record]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_borrowed_fields() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+
+        use crate::{
+            alignment::{
+                io::Write as _,
+                record::{
+                    cigar::{op::Kind, Op},
+                    data::field::{Tag, Value},
+                    Fields, Flags, MappingQuality,
+                },
+                record_buf::data::field::{value::Array as ArrayBuf, Value as ValueBuf},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let cigar = [Op::new(Kind::Match, 4)];
+        let comment = ValueBuf::from("ndls");
+        let base_modification_probabilities = ValueBuf::Array(ArrayBuf::UInt8(vec![0, 1]));
+
+        let data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::UInt8(1)),
+            (Tag::COMMENT, Value::from(&comment)),
+            (
+                Tag::BASE_MODIFICATION_PROBABILITIES,
+                Value::from(&base_modification_probabilities),
+            ),
+        ];
+
+        let fields = Fields::builder()
+            .set_name(b"r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar(&cigar)
+            .set_sequence(b"ACGT")
+            .set_quality_scores(&[45, 35, 43, 50])
+            .set_data(&data)
+            .build()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &fields)?;
+
+        assert_eq!(
+            writer.get_ref(),
+            b"r0\t0\tsq0\t2\t13\t4M\t*\t0\t0\tACGT\tNDLS\tNH:i:1\tCO:Z:ndls\tML:B:C,0,1\n"
+        );
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+
+        let expected = RecordBuf::try_from_alignment_record(&header, &fields)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_ensure_header_line() -> io::Result<()> {
+        use crate::header::record::value::{map, Map};
+
+        fn t(header: &Header, ensure_header_line: bool, expected: &[u8]) -> io::Result<()> {
+            let mut writer = Writer::new(Vec::new());
+            writer.ensure_header_line = ensure_header_line;
+            writer.write_header(header)?;
+            assert_eq!(writer.get_ref(), expected);
+            Ok(())
+        }
+
+        let header = Header::builder().add_comment("noodles-sam").build();
+        t(&header, false, b"@CO\tnoodles-sam\n")?;
+        t(&header, true, b"@HD\tVN:1.6\n@CO\tnoodles-sam\n")?;
+
+        let header = Header::builder()
+            .set_header(Map::<map::Header>::new(map::header::Version::new(1, 5)))
+            .build();
+        t(&header, true, b"@HD\tVN:1.5\n")?;
+
         Ok(())
     }
 }
@@ -6,13 +6,16 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Writer;
+use super::{LineEnding, Writer};
 use crate::io::CompressionMethod;
 
 /// A SAM writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    line_ending: LineEnding,
+    buffer_size: Option<usize>,
+    ensure_header_line: bool,
 }
 
 impl Builder {
@@ -29,6 +32,68 @@ impl Builder {
         self
     }
 
+    /// Sets the line ending.
+    ///
+    /// This is used to terminate both header and record lines. By default, lines are terminated
+    /// with a line feed (`\n`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::io::writer::{Builder, LineEnding};
+    /// let builder = Builder::default().set_line_ending(LineEnding::CrLf);
+    /// ```
+    pub fn set_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets the output buffer size.
+    ///
+    /// This is the capacity of the buffer used for uncompressed output. Output is written to the
+    /// destination when the buffer is full or the writer is flushed (see [`Writer::flush`]). A
+    /// large buffer favors throughput; a small buffer, or flushing after each record, favors
+    /// latency. By default, the buffer size is 8 KiB.
+    ///
+    /// This is not used with BGZF compression, which buffers data in blocks of up to 64 KiB
+    /// (uncompressed) and writes each block directly to the destination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::io::writer::Builder;
+    /// let builder = Builder::default().set_buffer_size(1 << 20);
+    /// ```
+    pub fn set_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
+
+    /// Sets whether to ensure the header has a header line (`@HD`).
+    ///
+    /// When this is set and a header without a header line is written, a default header line with
+    /// the current format version (`@HD\tVN:1.6`) is written first. A header line that is set is
+    /// always written as is.
+    ///
+    /// The default is `false`, i.e., the header is written as given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, io::writer::Builder};
+    ///
+    /// let mut writer = Builder::default()
+    ///     .set_ensure_header_line(true)
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// writer.write_header(&sam::Header::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn set_ensure_header_line(mut self, ensure_header_line: bool) -> Self {
+        self.ensure_header_line = ensure_header_line;
+        self
+    }
+
     /// Builds a SAM writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -74,9 +139,17 @@ impl Builder {
     {
         let inner: Box<dyn Write> = match self.compression_method {
             Some(CompressionMethod::Bgzf) => Box::new(bgzf::Writer::new(writer)),
-            Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
+            Some(CompressionMethod::None) | None => match self.buffer_size {
+                Some(buffer_size) => Box::new(BufWriter::with_capacity(buffer_size, writer)),
+                None => Box::new(BufWriter::new(writer)),
+            },
         };
 
-        Writer::new(inner)
+        Writer {
+            inner,
+            line_ending: self.line_ending,
+            ensure_header_line: self.ensure_header_line,
+            records_written: 0,
+        }
     }
 }
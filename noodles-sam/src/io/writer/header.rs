@@ -2,31 +2,44 @@ mod record;
 
 use std::io::{self, Write};
 
-use crate::Header;
+use super::LineEnding;
+use crate::{
+    header::record::value::{map, Map},
+    Header,
+};
 use record::{write_comment, write_program, write_read_group, write_reference_sequence};
 
-pub(super) fn write_header<W>(writer: &mut W, header: &Header) -> io::Result<()>
+pub(super) fn write_header<W>(
+    writer: &mut W,
+    header: &Header,
+    line_ending: LineEnding,
+    ensure_header_line: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
-    if let Some(header) = header.header() {
-        record::write_header(writer, header)?;
+    match header.header() {
+        Some(header) => record::write_header(writer, header, line_ending)?,
+        None if ensure_header_line => {
+            record::write_header(writer, &Map::<map::Header>::default(), line_ending)?
+        }
+        None => {}
     }
 
     for (name, reference_sequence) in header.reference_sequences() {
-        write_reference_sequence(writer, name, reference_sequence)?;
+        write_reference_sequence(writer, name, reference_sequence, line_ending)?;
     }
 
     for (id, read_group) in header.read_groups() {
-        write_read_group(writer, id, read_group)?;
+        write_read_group(writer, id, read_group, line_ending)?;
     }
 
     for (id, program) in header.programs().as_ref() {
-        write_program(writer, id, program)?;
+        write_program(writer, id, program, line_ending)?;
     }
 
     for comment in header.comments() {
-        write_comment(writer, comment)?;
+        write_comment(writer, comment, line_ending)?;
     }
 
     Ok(())
@@ -68,7 +81,7 @@ mod tests {
             .build();
 
         let mut buf = Vec::new();
-        write_header(&mut buf, &header)?;
+        write_header(&mut buf, &header, LineEnding::Lf, false)?;
 
         let expected = b"@HD\tVN:1.6
 @SQ\tSN:sq0\tLN:8
@@ -85,4 +98,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_header_with_extended_reference_sequence_fields(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let src = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:chr1\tLN:248956422\tAN:1,NC_000001.11\tAS:GRCh38\tM5:6aef897c3d6ff0c78aff06ac189178dd\tSP:Homo sapiens\tUR:file:/ref/GRCh38.fa\tTP:linear
+@SQ\tSN:chr1_KI270706v1_random\tLN:175055\tAH:*\tDS:unlocalized
+@SQ\tSN:HLA-A*01:01:01:01\tLN:3503\tAH:chr6:29941260-29945884\tAN:HLA-A
+";
+
+        let header: Header = std::str::from_utf8(src)?.parse()?;
+
+        let reference_sequence = header
+            .reference_sequences()
+            .get(&b"chr1"[..])
+            .expect("missing chr1");
+
+        let names: Vec<_> = reference_sequence.alternative_names().collect();
+        assert_eq!(names, ["1", "NC_000001.11"]);
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header, LineEnding::Lf, false)?;
+        assert_eq!(buf, src);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_crlf_line_ending() -> io::Result<()> {
+        let header = Header::builder()
+            .add_comment("noodles")
+            .add_comment("sam")
+            .build();
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header, LineEnding::CrLf, false)?;
+
+        let expected = b"@CO\tnoodles\r\n@CO\tsam\r\n";
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
 }
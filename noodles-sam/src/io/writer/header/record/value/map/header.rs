@@ -10,7 +10,7 @@ pub(crate) fn write_header<W>(writer: &mut W, header: &Map<Header>) -> io::Resul
 where
     W: Write,
 {
-    write_version_field(writer, header.version())?;
+    write_version_field(writer, header.raw_version())?;
     write_other_fields(writer, header.other_fields())?;
     Ok(())
 }
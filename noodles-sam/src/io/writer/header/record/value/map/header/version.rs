@@ -1,8 +1,8 @@
 use std::io::{self, Write};
 
-use crate::header::record::value::map::header::{tag, Version};
+use crate::header::record::value::map::header::tag;
 
-pub(super) fn write_version_field<W>(writer: &mut W, version: Version) -> io::Result<()>
+pub(super) fn write_version_field<W>(writer: &mut W, version: &[u8]) -> io::Result<()>
 where
     W: Write,
 {
@@ -11,22 +11,7 @@ where
     write_delimiter(writer)?;
     writer.write_all(tag::VERSION.as_ref())?;
     write_separator(writer)?;
-    write_version(writer, version)?;
-
-    Ok(())
-}
-
-fn write_version<W>(writer: &mut W, version: Version) -> io::Result<()>
-where
-    W: Write,
-{
-    use crate::io::writer::num;
-
-    const DELIMITER: u8 = b'.';
-
-    num::write_u32(writer, version.major())?;
-    writer.write_all(&[DELIMITER])?;
-    num::write_u32(writer, version.minor())?;
+    writer.write_all(version)?;
 
     Ok(())
 }
@@ -38,8 +23,13 @@ mod tests {
     #[test]
     fn test_write_version_field() -> io::Result<()> {
         let mut buf = Vec::new();
-        write_version_field(&mut buf, Version::new(1, 6))?;
+        write_version_field(&mut buf, b"1.6")?;
         assert_eq!(buf, b"\tVN:1.6");
+
+        buf.clear();
+        write_version_field(&mut buf, b"1.x")?;
+        assert_eq!(buf, b"\tVN:1.x");
+
         Ok(())
     }
 }
@@ -14,3 +14,32 @@ where
     write_other_fields(writer, program.other_fields())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_program() -> io::Result<()> {
+        let mut program = Map::<Program>::builder()
+            .insert(tag::COMMAND_LINE, "noodles view --verbose")
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut buf = Vec::new();
+        write_program(&mut buf, b"pg0", &program)?;
+        assert_eq!(buf, b"\tID:pg0\tCL:noodles view --verbose");
+
+        program
+            .other_fields_mut()
+            .insert(tag::COMMAND_LINE, "noodles\tview".into());
+
+        buf.clear();
+        assert!(matches!(
+            write_program(&mut buf, b"pg0", &program),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+/// A SAM writer line ending.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum LineEnding {
+    /// Line feed (`\n`).
+    #[default]
+    Lf,
+    /// Carriage return followed by a line feed (`\r\n`).
+    CrLf,
+}
+
+impl LineEnding {
+    pub(super) fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::Lf => b"\n",
+            Self::CrLf => b"\r\n",
+        }
+    }
+}
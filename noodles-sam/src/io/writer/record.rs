@@ -25,11 +25,17 @@ use self::{
     sequence::write_sequence,
     template_length::write_template_length,
 };
+use super::LineEnding;
 use crate::{alignment::Record, Header};
 
 const MISSING: u8 = b'*';
 
-pub(crate) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+pub(crate) fn write_record<W, R>(
+    writer: &mut W,
+    header: &Header,
+    record: &R,
+    line_ending: LineEnding,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -97,7 +103,7 @@ where
 
     write_data(writer, record.data())?;
 
-    writeln!(writer)?;
+    writer.write_all(line_ending.as_bytes())?;
 
     Ok(())
 }
@@ -120,7 +126,7 @@ mod tests {
             .collect();
         let record = RecordBuf::builder().set_data(data).build();
 
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, LineEnding::Lf)?;
 
         let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\n";
         assert_eq!(buf, expected);
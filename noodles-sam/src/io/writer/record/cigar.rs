@@ -64,7 +64,11 @@ fn write_kind<W>(writer: &mut W, kind: Kind) -> io::Result<()>
 where
     W: Write,
 {
-    let c = match kind {
+    writer.write_all(&[encode_op_kind(kind)])
+}
+
+pub(crate) fn encode_op_kind(kind: Kind) -> u8 {
+    match kind {
         Kind::Match => b'M',
         Kind::Insertion => b'I',
         Kind::Deletion => b'D',
@@ -74,9 +78,7 @@ where
         Kind::Pad => b'P',
         Kind::SequenceMatch => b'=',
         Kind::SequenceMismatch => b'X',
-    };
-
-    writer.write_all(&[c])
+    }
 }
 
 #[cfg(test)]
@@ -6,6 +6,7 @@ pub mod fields;
 mod name;
 mod quality_scores;
 mod reference_sequence_name;
+mod resolved_record;
 mod sequence;
 
 use std::{fmt, io};
@@ -15,10 +16,11 @@ use noodles_core::Position;
 pub(crate) use self::fields::Fields;
 pub use self::{
     cigar::Cigar, data::Data, name::Name, quality_scores::QualityScores,
-    reference_sequence_name::ReferenceSequenceName, sequence::Sequence,
+    reference_sequence_name::ReferenceSequenceName, resolved_record::ResolvedRecord,
+    sequence::Sequence,
 };
 use crate::{
-    alignment::record::{Flags, MappingQuality},
+    alignment::record::{data::field::Tag, Flags, MappingQuality},
     Header,
 };
 
@@ -227,6 +229,22 @@ impl Record {
     pub fn data(&self) -> Data<'_> {
         self.0.data()
     }
+
+    /// Returns an iterator over the data field tags.
+    ///
+    /// This is a quick way to check which data fields are present. Only the tags are parsed; the
+    /// values are skipped. See [`Data::tags`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let record = sam::Record::default();
+    /// assert!(record.data_tags().next().is_none());
+    /// ```
+    pub fn data_tags(&self) -> impl Iterator<Item = io::Result<Tag>> + '_ {
+        self.data().tags()
+    }
 }
 
 impl fmt::Debug for Record {
@@ -4,7 +4,7 @@ pub mod field;
 
 use std::{fmt, io, iter};
 
-use self::field::parse_field;
+use self::field::{parse_field, parse_field_tag};
 use crate::alignment::record::data::field::{Tag, Value};
 
 /// Raw SAM record data.
@@ -32,6 +32,30 @@ impl<'a> Data<'a> {
             }
         })
     }
+
+    /// Returns an iterator over all tags.
+    ///
+    /// This only parses the tags. The types and values are skipped without being parsed or
+    /// validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let record = sam::Record::default();
+    /// assert!(record.data().tags().next().is_none());
+    /// ```
+    pub fn tags(&self) -> impl Iterator<Item = io::Result<Tag>> + 'a {
+        let mut src = self.0;
+
+        iter::from_fn(move || {
+            if src.is_empty() {
+                None
+            } else {
+                Some(parse_field_tag(&mut src))
+            }
+        })
+    }
 }
 
 impl<'a> fmt::Debug for Data<'a> {
@@ -98,4 +122,29 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tags() -> io::Result<()> {
+        let data = Data::new(b"");
+        assert!(data.tags().next().is_none());
+
+        let data = Data::new(b"NH:i:1\tCO:Z:ndls\tzz:B:c,0,1");
+        let actual: Vec<_> = data.tags().collect::<io::Result<_>>()?;
+        let expected = [
+            Tag::ALIGNMENT_HIT_COUNT,
+            Tag::COMMENT,
+            Tag::from([b'z', b'z']),
+        ];
+        assert_eq!(actual, expected);
+
+        let data = Data::new(b"NH:i:1\tC");
+        let mut tags = data.tags();
+        assert_eq!(tags.next().transpose()?, Some(Tag::ALIGNMENT_HIT_COUNT));
+        assert!(matches!(
+            tags.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
@@ -21,6 +21,21 @@ pub(super) fn parse_field<'a>(src: &mut &'a [u8]) -> io::Result<(Tag, Value<'a>)
     Ok((tag, value))
 }
 
+// Parses the tag of a field and skips its type and value.
+pub(super) fn parse_field_tag(src: &mut &[u8]) -> io::Result<Tag> {
+    const TERMINATOR: u8 = b'\t';
+
+    let tag = parse_tag(src)?;
+    consume_delimiter(src)?;
+
+    *src = match memchr::memchr(TERMINATOR, src) {
+        Some(i) => &src[i + 1..],
+        None => &[],
+    };
+
+    Ok(tag)
+}
+
 fn consume_delimiter(src: &mut &[u8]) -> io::Result<()> {
     const DELIMITER: u8 = b':';
 
@@ -60,6 +75,29 @@ fn maybe_consume_terminator(src: &mut &[u8]) -> io::Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_field_tag() -> io::Result<()> {
+        let mut src = &b"NH:i:1\tCO:Z:ndls"[..];
+        assert_eq!(parse_field_tag(&mut src)?, Tag::ALIGNMENT_HIT_COUNT);
+        assert_eq!(src, b"CO:Z:ndls");
+        assert_eq!(parse_field_tag(&mut src)?, Tag::COMMENT);
+        assert!(src.is_empty());
+
+        let mut src = &b"N"[..];
+        assert!(matches!(
+            parse_field_tag(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut src = &b"NH_i:1"[..];
+        assert!(matches!(
+            parse_field_tag(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_field() -> io::Result<()> {
         let mut src = &b"NH:i:1"[..];
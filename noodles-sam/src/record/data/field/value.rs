@@ -11,7 +11,7 @@ use bstr::{BStr, ByteSlice};
 
 use self::array::parse_array;
 use super::Type;
-use crate::alignment::record::data::field::Value;
+use crate::alignment::{record::data::field::Value, record_buf::data::field::Value as ValueBuf};
 
 pub(super) fn parse_value<'a>(src: &mut &'a [u8], ty: Type) -> io::Result<Value<'a>> {
     match ty {
@@ -27,7 +27,10 @@ pub(super) fn parse_value<'a>(src: &mut &'a [u8], ty: Type) -> io::Result<Value<
 fn parse_character_value<'a>(src: &mut &'a [u8]) -> io::Result<Value<'a>> {
     if let Some((b, rest)) = src.split_first() {
         *src = rest;
-        Ok(Value::Character(*b))
+
+        ValueBuf::try_character(*b)
+            .map(|_| Value::Character(*b))
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     } else {
         Err(io::Error::from(io::ErrorKind::UnexpectedEof))
     }
@@ -81,6 +84,14 @@ mod tests {
             Value::Character(b'n')
         ));
 
+        for src in [&[0x07][..], &[0xe9][..]] {
+            let mut src = src;
+            assert!(matches!(
+                parse_value(&mut src, Type::Character),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
         let mut src = &b"0"[..];
         assert!(matches!(
             parse_value(&mut src, Type::Int32)?,
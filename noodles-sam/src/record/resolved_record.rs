@@ -0,0 +1,186 @@
+use std::{cell::OnceCell, io};
+
+use noodles_core::Position;
+
+use super::Record;
+use crate::alignment::record::Cigar;
+
+/// A SAM record with cached CIGAR-derived values.
+///
+/// A [`Record`] parses its fields on each access. This wraps a record and calculates the
+/// alignment span and read length from the CIGAR operations once, on first access, and caches
+/// them for the lifetime of the wrapper. Both values are calculated in a single pass over the
+/// CIGAR operations.
+///
+/// The cache holds two integers, which only pays off when the values are read more than once per
+/// record. Create a new wrapper for each record read.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{self as sam, record::ResolvedRecord};
+///
+/// let record = sam::Record::default();
+/// let resolved = ResolvedRecord::new(&record);
+///
+/// assert_eq!(resolved.alignment_span()?, 0);
+/// assert_eq!(resolved.read_length()?, 0);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResolvedRecord<'r> {
+    record: &'r Record,
+    cigar_lengths: OnceCell<(usize, usize)>,
+}
+
+impl<'r> ResolvedRecord<'r> {
+    /// Creates a resolved record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::ResolvedRecord};
+    /// let record = sam::Record::default();
+    /// let resolved = ResolvedRecord::new(&record);
+    /// ```
+    pub fn new(record: &'r Record) -> Self {
+        Self {
+            record,
+            cigar_lengths: OnceCell::new(),
+        }
+    }
+
+    /// Returns the underlying record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::ResolvedRecord};
+    /// let record = sam::Record::default();
+    /// let resolved = ResolvedRecord::new(&record);
+    /// assert_eq!(resolved.record(), &record);
+    /// ```
+    pub fn record(&self) -> &'r Record {
+        self.record
+    }
+
+    /// Returns the alignment span over the reference sequence.
+    ///
+    /// This is calculated from the CIGAR operations on first access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::ResolvedRecord};
+    /// let record = sam::Record::default();
+    /// let resolved = ResolvedRecord::new(&record);
+    /// assert_eq!(resolved.alignment_span()?, 0);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn alignment_span(&self) -> io::Result<usize> {
+        self.cigar_lengths().map(|(span, _)| span)
+    }
+
+    /// Returns the read length.
+    ///
+    /// This is calculated from the CIGAR operations on first access.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::ResolvedRecord};
+    /// let record = sam::Record::default();
+    /// let resolved = ResolvedRecord::new(&record);
+    /// assert_eq!(resolved.read_length()?, 0);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read_length(&self) -> io::Result<usize> {
+        self.cigar_lengths().map(|(_, len)| len)
+    }
+
+    /// Returns the alignment end.
+    ///
+    /// This uses the cached alignment span. If the span is 0, the alignment end is the alignment
+    /// start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, record::ResolvedRecord};
+    /// let record = sam::Record::default();
+    /// let resolved = ResolvedRecord::new(&record);
+    /// assert!(resolved.alignment_end().is_none());
+    /// ```
+    pub fn alignment_end(&self) -> Option<io::Result<Position>> {
+        let start = match self.record.alignment_start()? {
+            Ok(start) => start,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let span = match self.alignment_span() {
+            Ok(span) => span,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let end = match span.checked_sub(1) {
+            Some(len) => usize::from(start).checked_add(len).and_then(Position::new),
+            None => Some(start),
+        };
+
+        Some(end.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid alignment end")))
+    }
+
+    fn cigar_lengths(&self) -> io::Result<(usize, usize)> {
+        if let Some(lengths) = self.cigar_lengths.get() {
+            return Ok(*lengths);
+        }
+
+        let mut alignment_span = 0;
+        let mut read_length = 0;
+
+        let cigar = self.record.cigar();
+
+        for result in Cigar::iter(&cigar) {
+            let op = result?;
+
+            if op.kind().consumes_reference() {
+                alignment_span += op.len();
+            }
+
+            if op.kind().consumes_read() {
+                read_length += op.len();
+            }
+        }
+
+        let lengths = (alignment_span, read_length);
+        // The cell is empty, so this always succeeds.
+        let _ = self.cigar_lengths.set(lengths);
+
+        Ok(lengths)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cigar_lengths() -> io::Result<()> {
+        let src = b"r0\t0\tsq0\t8\t255\t2S4M1D3M1I\t*\t0\t0\tACGTACGTAC\t*";
+        let mut reader = crate::io::Reader::new(&src[..]);
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        let resolved = ResolvedRecord::new(&record);
+
+        assert!(resolved.cigar_lengths.get().is_none());
+        assert_eq!(resolved.alignment_span()?, 8);
+        assert_eq!(resolved.cigar_lengths.get(), Some(&(8, 10)));
+        assert_eq!(resolved.read_length()?, 10);
+
+        assert_eq!(resolved.alignment_end().transpose()?, Position::new(15));
+
+        Ok(())
+    }
+}
@@ -1,4 +1,26 @@
 //! Alignment format utilities.
 
+pub mod anonymizer;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+mod bed12;
+pub mod cmp;
+mod count;
+pub mod fastq;
+mod format_alignment;
+pub mod insert_size_histogram;
 pub mod io;
 pub mod iter;
+pub mod pileup_events;
+pub mod record_filter;
+pub mod soft_clips;
+pub mod sort;
+pub mod sorted_set;
+pub mod split_read;
+mod verify_index;
+
+pub use self::{
+    bed12::write_bed12, count::count, format_alignment::format_alignment,
+    insert_size_histogram::InsertSizeHistogram, pileup_events::pileup_events,
+    record_filter::RecordFilter, sorted_set::SortedSet, verify_index::verify_index,
+};
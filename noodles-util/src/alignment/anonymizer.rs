@@ -0,0 +1,289 @@
+//! Alignment record anonymizer.
+
+use std::{collections::HashMap, io::Write};
+
+use noodles_sam::alignment::RecordBuf;
+
+/// An alignment record anonymizer.
+///
+/// This replaces read names with anonymous names and optionally masks sequences and quality
+/// scores.
+///
+/// Names are mapped consistently across a stream, i.e., records with the same name, e.g., mates
+/// and secondary and supplementary alignments, get the same anonymous name. Each distinct name is
+/// given the next number in order of first appearance, which is then scrambled by a bijective
+/// mix with the seed and written as 16 hexadecimal digits. Anonymous names are therefore unique,
+/// do not reveal the input order, and are reproducible: the same seed and input produce the same
+/// names.
+///
+/// The anonymizer holds the name map, which has an entry for every distinct name seen. Memory
+/// usage grows with the number of templates in the input. Use [`Self::clear`] to release it when
+/// no more records of the seen templates are expected, e.g., between chunks of a
+/// queryname-grouped stream.
+///
+/// Other fields that may identify a read, e.g., barcode data fields, are not changed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::anonymizer::Anonymizer;
+///
+/// let mut anonymizer = Anonymizer::new(8);
+///
+/// let mut r1 = RecordBuf::builder()
+///     .set_name(b"r0".as_slice().into())
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+///     .build();
+///
+/// let mut r2 = RecordBuf::builder()
+///     .set_name(b"r0".as_slice().into())
+///     .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT)
+///     .build();
+///
+/// anonymizer.anonymize(&mut r1);
+/// anonymizer.anonymize(&mut r2);
+///
+/// assert_ne!(r1.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+/// assert_eq!(r1.name(), r2.name());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Anonymizer {
+    key: u64,
+    next_id: u64,
+    names: HashMap<Vec<u8>, u64>,
+    mask_sequence: bool,
+    mask_quality_scores: bool,
+}
+
+impl Anonymizer {
+    /// Creates an anonymizer with a seed.
+    ///
+    /// By default, sequences and quality scores are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    /// let anonymizer = Anonymizer::new(8);
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        Self {
+            key: mix(seed),
+            next_id: 0,
+            names: HashMap::new(),
+            mask_sequence: false,
+            mask_quality_scores: false,
+        }
+    }
+
+    /// Sets whether to mask sequences.
+    ///
+    /// Each base is replaced with `N`. The sequence length is kept, so it still matches the CIGAR
+    /// operations and quality scores.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    /// let anonymizer = Anonymizer::new(8).set_mask_sequence(true);
+    /// ```
+    pub fn set_mask_sequence(mut self, mask_sequence: bool) -> Self {
+        self.mask_sequence = mask_sequence;
+        self
+    }
+
+    /// Sets whether to mask quality scores.
+    ///
+    /// The quality scores are removed, i.e., they are missing (`*`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    /// let anonymizer = Anonymizer::new(8).set_mask_quality_scores(true);
+    /// ```
+    pub fn set_mask_quality_scores(mut self, mask_quality_scores: bool) -> Self {
+        self.mask_quality_scores = mask_quality_scores;
+        self
+    }
+
+    /// Returns the number of distinct names seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    /// let anonymizer = Anonymizer::new(8);
+    /// assert_eq!(anonymizer.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns whether no names have been seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    /// let anonymizer = Anonymizer::new(8);
+    /// assert!(anonymizer.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Clears the name map.
+    ///
+    /// Names seen after this are given new anonymous names, even if they were seen before.
+    /// Anonymous names continue to be unique.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::RecordBuf;
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    ///
+    /// let mut anonymizer = Anonymizer::new(8);
+    ///
+    /// let mut record = RecordBuf::builder().set_name(b"r0".as_slice().into()).build();
+    /// anonymizer.anonymize(&mut record);
+    /// assert_eq!(anonymizer.len(), 1);
+    ///
+    /// anonymizer.clear();
+    /// assert!(anonymizer.is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.names.clear();
+    }
+
+    /// Anonymizes a record.
+    ///
+    /// The name, if any, is replaced with its anonymous name. The sequence and quality scores are
+    /// masked if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::RecordBuf;
+    /// use noodles_util::alignment::anonymizer::Anonymizer;
+    ///
+    /// let mut anonymizer = Anonymizer::new(8)
+    ///     .set_mask_sequence(true)
+    ///     .set_mask_quality_scores(true);
+    ///
+    /// let mut record = RecordBuf::builder()
+    ///     .set_name(b"r0".as_slice().into())
+    ///     .set_sequence(b"ACGT".to_vec().into())
+    ///     .set_quality_scores(vec![45, 35, 43, 50].into())
+    ///     .build();
+    ///
+    /// anonymizer.anonymize(&mut record);
+    ///
+    /// assert_eq!(record.sequence().as_ref(), b"NNNN");
+    /// assert!(record.quality_scores().is_empty());
+    /// ```
+    pub fn anonymize(&mut self, record: &mut RecordBuf) {
+        if let Some(name) = record.name_mut() {
+            let id = *self.names.entry(name.as_ref().to_vec()).or_insert_with(|| {
+                let id = self.next_id;
+                self.next_id += 1;
+                id
+            });
+
+            let buf = name.as_mut();
+            buf.clear();
+            // Writing to a `Vec` does not fail.
+            let _ = write!(buf, "{:016x}", mix(id ^ self.key));
+        }
+
+        if self.mask_sequence {
+            for base in record.sequence_mut().as_mut() {
+                *base = b'N';
+            }
+        }
+
+        if self.mask_quality_scores {
+            record.quality_scores_mut().as_mut().clear();
+        }
+    }
+}
+
+// The splitmix64 finalizer, which is a bijection on `u64`.
+fn mix(mut n: u64) -> u64 {
+    n = (n ^ (n >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    n = (n ^ (n >> 27)).wrapping_mul(0x94d049bb133111eb);
+    n ^ (n >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(name: &str) -> RecordBuf {
+        RecordBuf::builder()
+            .set_name(name.as_bytes().into())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![45, 35, 43, 50].into())
+            .build()
+    }
+
+    fn anonymize(anonymizer: &mut Anonymizer, names: &[&str]) -> Vec<Vec<u8>> {
+        names
+            .iter()
+            .map(|name| {
+                let mut record = build_record(name);
+                anonymizer.anonymize(&mut record);
+                record.name().unwrap().as_ref().to_vec()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_anonymize() {
+        let names = ["r0", "r1", "r0", "r2", "r1"];
+
+        let mut anonymizer = Anonymizer::new(8);
+        let actual = anonymize(&mut anonymizer, &names);
+
+        assert_eq!(anonymizer.len(), 3);
+
+        assert_eq!(actual[0], actual[2]);
+        assert_eq!(actual[1], actual[4]);
+        assert_ne!(actual[0], actual[1]);
+        assert_ne!(actual[0], actual[3]);
+        assert_ne!(actual[1], actual[3]);
+
+        for name in &actual {
+            assert_eq!(name.len(), 16);
+            assert!(name.iter().all(|b| b.is_ascii_hexdigit()));
+        }
+
+        // Reruns with the same seed are identical.
+        assert_eq!(anonymize(&mut Anonymizer::new(8), &names), actual);
+        assert_ne!(anonymize(&mut Anonymizer::new(13), &names), actual);
+
+        let mut record = build_record("r0");
+        Anonymizer::new(8).anonymize(&mut record);
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+        assert_eq!(record.quality_scores().as_ref(), [45, 35, 43, 50]);
+
+        let mut record = RecordBuf::default();
+        Anonymizer::new(8).anonymize(&mut record);
+        assert!(record.name().is_none());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut anonymizer = Anonymizer::new(8);
+
+        let a = anonymize(&mut anonymizer, &["r0", "r1"]);
+        anonymizer.clear();
+        let b = anonymize(&mut anonymizer, &["r0", "r2"]);
+
+        assert_eq!(anonymizer.len(), 2);
+        assert!(!a.contains(&b[0]));
+        assert!(!a.contains(&b[1]));
+    }
+}
@@ -0,0 +1,553 @@
+//! Alignment record conversion to Apache Arrow.
+//!
+//! [`RecordBatchBuilder`] appends alignment record fields directly to Arrow column builders, and
+//! [`RecordBatches`] reads a record stream as record batches of a given size.
+
+use std::{io, num::NonZeroUsize, sync::Arc};
+
+use arrow_array::{
+    builder::{Float32Builder, Int64Builder, StringBuilder, UInt16Builder, UInt8Builder},
+    ArrayRef, RecordBatch,
+};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::data::field::{Tag, Value},
+        Record,
+    },
+    io::writer::record::write_cigar,
+};
+
+/// The default number of records per batch.
+pub const DEFAULT_BATCH_SIZE: NonZeroUsize = match NonZeroUsize::new(8192) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// The column type of a data field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TagType {
+    /// An integer, i.e., a `c`, `C`, `s`, `S`, `i`, or `I` value, as an `Int64` column.
+    Int,
+    /// A single-precision floating-point number, i.e., an `f` value, as a `Float32` column.
+    Float,
+    /// A string, i.e., an `A`, `Z`, or `H` value, as a `Utf8` column.
+    String,
+}
+
+impl From<TagType> for DataType {
+    fn from(ty: TagType) -> Self {
+        match ty {
+            TagType::Int => Self::Int64,
+            TagType::Float => Self::Float32,
+            TagType::String => Self::Utf8,
+        }
+    }
+}
+
+enum TagValue {
+    Int(i64),
+    Float(f32),
+    String(String),
+}
+
+enum TagBuilder {
+    Int(Int64Builder),
+    Float(Float32Builder),
+    String(StringBuilder),
+}
+
+impl TagBuilder {
+    fn new(ty: TagType) -> Self {
+        match ty {
+            TagType::Int => Self::Int(Int64Builder::new()),
+            TagType::Float => Self::Float(Float32Builder::new()),
+            TagType::String => Self::String(StringBuilder::new()),
+        }
+    }
+
+    // Converts a data field value to the type of the column.
+    fn parse(&self, tag: Tag, value: Option<Value<'_>>) -> io::Result<Option<TagValue>> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+
+        let value = match (self, value) {
+            (Self::Int(_), value) => value
+                .as_int()
+                .map(TagValue::Int)
+                .ok_or_else(|| type_mismatch_error(tag))?,
+            (Self::Float(_), Value::Float(n)) => TagValue::Float(n),
+            (Self::String(_), Value::Character(c)) => TagValue::String(char::from(c).to_string()),
+            (Self::String(_), Value::String(s) | Value::Hex(s)) => {
+                TagValue::String(to_str(s)?.into())
+            }
+            _ => return Err(type_mismatch_error(tag)),
+        };
+
+        Ok(Some(value))
+    }
+
+    // Appends a value parsed by `Self::parse`.
+    fn append(&mut self, value: Option<TagValue>) {
+        match (self, value) {
+            (Self::Int(builder), Some(TagValue::Int(n))) => builder.append_value(n),
+            (Self::Float(builder), Some(TagValue::Float(n))) => builder.append_value(n),
+            (Self::String(builder), Some(TagValue::String(s))) => builder.append_value(s),
+            (Self::Int(builder), None) => builder.append_null(),
+            (Self::Float(builder), None) => builder.append_null(),
+            (Self::String(builder), None) => builder.append_null(),
+            _ => unreachable!("tag value type mismatch"),
+        }
+    }
+
+    fn finish(&mut self) -> ArrayRef {
+        match self {
+            Self::Int(builder) => Arc::new(builder.finish()),
+            Self::Float(builder) => Arc::new(builder.finish()),
+            Self::String(builder) => Arc::new(builder.finish()),
+        }
+    }
+}
+
+/// An Arrow record batch builder for alignment records.
+///
+/// The schema has the columns
+///
+///   1. `qname` (`Utf8`): the read name;
+///   2. `flag` (`UInt16`): the flags;
+///   3. `rname` (`Utf8`): the reference sequence name;
+///   4. `pos` (`Int64`): the alignment start (1-based);
+///   5. `mapq` (`UInt8`): the mapping quality;
+///   6. `cigar` (`Utf8`): the CIGAR operations as a SAM CIGAR string; and
+///   7. a column for each of the given tags, named by the tag, e.g., `NM`.
+///
+/// All columns except `flag` are nullable. A missing field is null, e.g., a missing read name
+/// (`*`) or a record without the tag.
+///
+/// A data field must have the type of its column. Otherwise, appending the record returns an
+/// [`io::ErrorKind::InvalidData`] error. Array values are not supported.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::data::field::Tag,
+///         record_buf::data::field::Value,
+///         RecordBuf,
+///     },
+/// };
+/// use noodles_util::alignment::arrow::{RecordBatchBuilder, TagType};
+///
+/// let header = sam::Header::default();
+///
+/// let mut builder = RecordBatchBuilder::new(vec![(Tag::ALIGNMENT_HIT_COUNT, TagType::Int)]);
+///
+/// let record = RecordBuf::builder()
+///     .set_name(b"r0".into())
+///     .set_data([(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))].into_iter().collect())
+///     .build();
+///
+/// builder.append(&header, &record)?;
+/// builder.append(&header, &RecordBuf::default())?;
+///
+/// let batch = builder.finish()?;
+/// assert_eq!(batch.num_rows(), 2);
+/// assert_eq!(batch.num_columns(), 7);
+/// assert_eq!(batch.schema().field(6).name(), "NH");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct RecordBatchBuilder {
+    schema: SchemaRef,
+    names: StringBuilder,
+    flags: UInt16Builder,
+    reference_sequence_names: StringBuilder,
+    alignment_starts: Int64Builder,
+    mapping_qualities: UInt8Builder,
+    cigars: StringBuilder,
+    tags: Vec<(Tag, TagBuilder)>,
+    len: usize,
+}
+
+impl RecordBatchBuilder {
+    /// Creates a record batch builder with the given tag columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::arrow::RecordBatchBuilder;
+    /// let builder = RecordBatchBuilder::new(Vec::new());
+    /// assert_eq!(builder.schema().fields().len(), 6);
+    /// ```
+    pub fn new(tags: Vec<(Tag, TagType)>) -> Self {
+        let mut fields = vec![
+            Field::new("qname", DataType::Utf8, true),
+            Field::new("flag", DataType::UInt16, false),
+            Field::new("rname", DataType::Utf8, true),
+            Field::new("pos", DataType::Int64, true),
+            Field::new("mapq", DataType::UInt8, true),
+            Field::new("cigar", DataType::Utf8, true),
+        ];
+
+        for (tag, ty) in &tags {
+            fields.push(Field::new(tag.to_string(), DataType::from(*ty), true));
+        }
+
+        Self {
+            schema: Arc::new(Schema::new(fields)),
+            names: StringBuilder::new(),
+            flags: UInt16Builder::new(),
+            reference_sequence_names: StringBuilder::new(),
+            alignment_starts: Int64Builder::new(),
+            mapping_qualities: UInt8Builder::new(),
+            cigars: StringBuilder::new(),
+            tags: tags
+                .into_iter()
+                .map(|(tag, ty)| (tag, TagBuilder::new(ty)))
+                .collect(),
+            len: 0,
+        }
+    }
+
+    /// Returns the schema of the record batches.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of appended records since the last batch.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether no records were appended since the last batch.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends an alignment record.
+    ///
+    /// All fields are read and converted before any are appended, so if this returns an error,
+    /// the builder is not changed.
+    pub fn append<R>(&mut self, header: &sam::Header, record: &R) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        let name = record.name();
+        let name = name
+            .as_ref()
+            .map(|name| to_str(name.as_bytes()))
+            .transpose()?;
+
+        let flags = record.flags()?;
+
+        let reference_sequence_name = match record.reference_sequence(header).transpose()? {
+            Some((name, _)) => Some(to_str(name)?),
+            None => None,
+        };
+
+        let alignment_start = record.alignment_start().transpose()?;
+        let mapping_quality = record.mapping_quality().transpose()?;
+
+        let cigar = record.cigar();
+
+        let cigar = if cigar.is_empty() {
+            None
+        } else {
+            let mut buf = Vec::new();
+            write_cigar(&mut buf, &cigar)?;
+            Some(to_str(&buf)?.to_string())
+        };
+
+        let data = record.data();
+
+        let tag_values = self
+            .tags
+            .iter()
+            .map(|(tag, builder)| {
+                let value = data.get(tag).transpose()?;
+                builder.parse(*tag, value)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        self.names.append_option(name);
+        self.flags.append_value(u16::from(flags));
+        self.reference_sequence_names
+            .append_option(reference_sequence_name);
+        self.alignment_starts
+            .append_option(alignment_start.map(|position| usize::from(position) as i64));
+        self.mapping_qualities
+            .append_option(mapping_quality.map(u8::from));
+        self.cigars.append_option(cigar);
+
+        for ((_, builder), value) in self.tags.iter_mut().zip(tag_values) {
+            builder.append(value);
+        }
+
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Builds a record batch from the appended records and resets the builder.
+    pub fn finish(&mut self) -> io::Result<RecordBatch> {
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(self.names.finish()),
+            Arc::new(self.flags.finish()),
+            Arc::new(self.reference_sequence_names.finish()),
+            Arc::new(self.alignment_starts.finish()),
+            Arc::new(self.mapping_qualities.finish()),
+            Arc::new(self.cigars.finish()),
+        ];
+
+        for (_, builder) in &mut self.tags {
+            columns.push(builder.finish());
+        }
+
+        self.len = 0;
+
+        RecordBatch::try_new(self.schema.clone(), columns)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// An iterator over record batches of an alignment record stream.
+///
+/// Each batch has the configured number of records, except the last, which has the remaining
+/// records. An empty stream has no batches.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::arrow::{RecordBatchBuilder, RecordBatches};
+///
+/// let header = sam::Header::default();
+/// let records = (0..5).map(|_| Ok(RecordBuf::default()));
+/// let builder = RecordBatchBuilder::new(Vec::new());
+/// let batch_size = NonZeroUsize::try_from(2)?;
+///
+/// let row_counts: Vec<_> = RecordBatches::new(&header, records, builder, batch_size)
+///     .map(|result| result.map(|batch| batch.num_rows()))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(row_counts, [2, 2, 1]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct RecordBatches<'h, I> {
+    header: &'h sam::Header,
+    records: I,
+    builder: RecordBatchBuilder,
+    batch_size: NonZeroUsize,
+    is_eof: bool,
+    is_done: bool,
+}
+
+impl<'h, I, R> RecordBatches<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates an iterator over record batches.
+    pub fn new(
+        header: &'h sam::Header,
+        records: I,
+        builder: RecordBatchBuilder,
+        batch_size: NonZeroUsize,
+    ) -> Self {
+        Self {
+            header,
+            records,
+            builder,
+            batch_size,
+            is_eof: false,
+            is_done: false,
+        }
+    }
+
+    fn next_batch(&mut self) -> io::Result<Option<RecordBatch>> {
+        while !self.is_eof && self.builder.len() < self.batch_size.get() {
+            match self.records.next().transpose()? {
+                Some(record) => self.builder.append(self.header, &record)?,
+                None => self.is_eof = true,
+            }
+        }
+
+        if self.builder.is_empty() {
+            Ok(None)
+        } else {
+            self.builder.finish().map(Some)
+        }
+    }
+}
+
+impl<I, R> Iterator for RecordBatches<'_, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.next_batch() {
+            Ok(Some(batch)) => Some(Ok(batch)),
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn to_str(buf: &[u8]) -> io::Result<&str> {
+    std::str::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn type_mismatch_error(tag: Tag) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("data field type mismatch: {tag}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use arrow_array::{Array, Int64Array, StringArray, UInt16Array, UInt8Array};
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags, MappingQuality,
+            },
+            record_buf::data::field::Value as ValueBuf,
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn column<T: 'static>(batch: &RecordBatch, i: usize) -> &T {
+        batch.column(i).as_any().downcast_ref::<T>().unwrap()
+    }
+
+    #[test]
+    fn test_append() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .build();
+
+        let mut builder = RecordBatchBuilder::new(vec![
+            (Tag::ALIGNMENT_HIT_COUNT, TagType::Int),
+            (Tag::COMMENT, TagType::String),
+        ]);
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mapping_quality(MappingQuality::new(13).unwrap())
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 4)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_data(
+                [
+                    (Tag::ALIGNMENT_HIT_COUNT, ValueBuf::from(2)),
+                    (Tag::COMMENT, ValueBuf::from("noodles")),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        builder.append(&header, &record)?;
+        builder.append(&header, &RecordBuf::default())?;
+
+        let batch = builder.finish()?;
+        assert_eq!(batch.num_rows(), 2);
+        assert!(builder.is_empty());
+
+        let names: &StringArray = column(&batch, 0);
+        assert_eq!(names.value(0), "r0");
+        assert!(names.is_null(1));
+
+        let flags: &UInt16Array = column(&batch, 1);
+        assert_eq!(flags.values(), &[0, 4]);
+
+        let reference_sequence_names: &StringArray = column(&batch, 2);
+        assert_eq!(reference_sequence_names.value(0), "sq0");
+        assert!(reference_sequence_names.is_null(1));
+
+        let alignment_starts: &Int64Array = column(&batch, 3);
+        assert_eq!(alignment_starts.value(0), 8);
+        assert!(alignment_starts.is_null(1));
+
+        let mapping_qualities: &UInt8Array = column(&batch, 4);
+        assert_eq!(mapping_qualities.value(0), 13);
+        assert!(mapping_qualities.is_null(1));
+
+        let cigars: &StringArray = column(&batch, 5);
+        assert_eq!(cigars.value(0), "2S4M");
+        assert!(cigars.is_null(1));
+
+        let hit_counts: &Int64Array = column(&batch, 6);
+        assert_eq!(hit_counts.value(0), 2);
+        assert!(hit_counts.is_null(1));
+
+        let comments: &StringArray = column(&batch, 7);
+        assert_eq!(comments.value(0), "noodles");
+        assert!(comments.is_null(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_with_tag_type_mismatch() -> io::Result<()> {
+        let header = sam::Header::default();
+        let mut builder = RecordBatchBuilder::new(vec![(Tag::COMMENT, TagType::Int)]);
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [(Tag::COMMENT, ValueBuf::from("noodles"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert!(matches!(
+            builder.append(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // The builder is not changed.
+        assert!(builder.is_empty());
+
+        builder.append(&header, &RecordBuf::default())?;
+
+        let batch = builder.finish()?;
+        assert_eq!(batch.num_rows(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,393 @@
+use std::io::{self, Write};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{record::cigar::op::Kind, Record},
+};
+
+const MISSING: &[u8] = b".";
+
+// A block start relative to `chromStart` and its size.
+type Block = (usize, usize);
+
+/// Writes an alignment record as a BED12 line.
+///
+/// The aligned segments of the record, i.e., the CIGAR operations split by skipped regions (`N`),
+/// are written as blocks. Deletions (`D`) do not split a block, but a segment without aligned
+/// bases, e.g., a deletion between two skipped regions, is not a block and is merged into the
+/// surrounding skipped regions. The feature starts at the first block and ends at the last block,
+/// i.e., leading and trailing skipped regions are trimmed.
+///
+/// The fields are
+///
+///   1. `chrom`: the reference sequence name;
+///   2. `chromStart`: the start of the first block (0-based);
+///   3. `chromEnd`: the end of the last block (0-based, exclusive);
+///   4. `name`: the read name (or `.` if missing);
+///   5. `score`: the mapping quality (or 0 if missing);
+///   6. `strand`: `-` if the record is reverse complemented, otherwise `+`;
+///   7. `thickStart` / 8. `thickEnd`: `chromStart` and `chromEnd`;
+///   9. `itemRgb`: `0`;
+///   10. `blockCount`: the number of blocks; and
+///   11. `blockSizes` / 12. `blockStarts`: comma-separated block sizes and starts relative to
+///       `chromStart`, each with a trailing comma.
+///
+/// The record must be mapped and have at least one aligned base. Otherwise, this returns an
+/// [`io::ErrorKind::InvalidInput`] error.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             Flags,
+///         },
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_name(b"r0".as_slice().into())
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(101)?)
+///     .set_cigar(
+///         [
+///             Op::new(Kind::Match, 10),
+///             Op::new(Kind::Skip, 90),
+///             Op::new(Kind::Match, 20),
+///         ]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .build();
+///
+/// let mut buf = Vec::new();
+/// alignment::write_bed12(&mut buf, &header, &record)?;
+///
+/// assert_eq!(buf, b"sq0\t100\t220\tr0\t0\t+\t100\t220\t0\t2\t10,20,\t0,100,\n");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn write_bed12<W, R>(writer: &mut W, header: &sam::Header, record: &R) -> io::Result<()>
+where
+    W: Write,
+    R: Record + ?Sized,
+{
+    let flags = record.flags()?;
+
+    if flags.is_unmapped() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "record is unmapped",
+        ));
+    }
+
+    let (reference_sequence_name, _) =
+        record
+            .reference_sequence(header)
+            .transpose()?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "record is missing reference sequence",
+                )
+            })?;
+
+    let alignment_start = record.alignment_start().transpose()?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "record is missing alignment start",
+        )
+    })?;
+
+    let blocks = build_blocks(record)?;
+
+    let (first_block_start, last_block_end) = blocks
+        .first()
+        .zip(blocks.last())
+        .map(|(&(first_start, _), &(last_start, last_size))| (first_start, last_start + last_size))
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "record has no aligned bases")
+        })?;
+
+    let alignment_offset = usize::from(alignment_start) - 1;
+    let chrom_start = alignment_offset + first_block_start;
+    let chrom_end = alignment_offset + last_block_end;
+
+    writer.write_all(reference_sequence_name)?;
+    write!(writer, "\t{chrom_start}\t{chrom_end}\t")?;
+
+    match record.name() {
+        Some(name) => writer.write_all(name.as_bytes())?,
+        None => writer.write_all(MISSING)?,
+    }
+
+    let score = record
+        .mapping_quality()
+        .transpose()?
+        .map(u8::from)
+        .unwrap_or(0);
+
+    let strand = if flags.is_reverse_complemented() {
+        '-'
+    } else {
+        '+'
+    };
+
+    write!(
+        writer,
+        "\t{score}\t{strand}\t{chrom_start}\t{chrom_end}\t0\t{}\t",
+        blocks.len()
+    )?;
+
+    for (_, size) in &blocks {
+        write!(writer, "{size},")?;
+    }
+
+    writer.write_all(b"\t")?;
+
+    for (start, _) in &blocks {
+        write!(writer, "{},", start - first_block_start)?;
+    }
+
+    writer.write_all(b"\n")?;
+
+    Ok(())
+}
+
+// Block starts are relative to the alignment start. Segments without aligned bases are skipped.
+fn build_blocks<R>(record: &R) -> io::Result<Vec<Block>>
+where
+    R: Record + ?Sized,
+{
+    let mut blocks = Vec::new();
+    let mut block_start = 0;
+    let mut position = 0;
+    let mut is_aligned = false;
+
+    for result in record.cigar().iter() {
+        let op = result?;
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                position += op.len();
+                is_aligned |= !op.is_empty();
+            }
+            Kind::Deletion => position += op.len(),
+            Kind::Skip => {
+                if is_aligned {
+                    blocks.push((block_start, position - block_start));
+                }
+
+                position += op.len();
+                block_start = position;
+                is_aligned = false;
+            }
+            Kind::Insertion | Kind::SoftClip | Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    if is_aligned {
+        blocks.push((block_start, position - block_start));
+    }
+
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            record::{cigar::Op, Flags, MappingQuality},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(10000).unwrap()),
+            )
+            .build()
+    }
+
+    fn write(record: &RecordBuf) -> io::Result<String> {
+        let mut buf = Vec::new();
+        write_bed12(&mut buf, &build_header(), record)?;
+        Ok(String::from_utf8(buf).unwrap())
+    }
+
+    #[test]
+    fn test_write_bed12() -> io::Result<()> {
+        // A spliced read with a soft clip, an insertion, and a deletion:
+        //
+        //   5S 20M 1I 30M 2D 10M 500N 40M 1000N 25M 3S
+        let ops = [
+            (Kind::SoftClip, 5),
+            (Kind::Match, 20),
+            (Kind::Insertion, 1),
+            (Kind::Match, 30),
+            (Kind::Deletion, 2),
+            (Kind::Match, 10),
+            (Kind::Skip, 500),
+            (Kind::Match, 40),
+            (Kind::Skip, 1000),
+            (Kind::Match, 25),
+            (Kind::SoftClip, 3),
+        ];
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".as_slice().into())
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(1001).unwrap())
+            .set_mapping_quality(MappingQuality::new(60).unwrap())
+            .set_cigar(
+                ops.into_iter()
+                    .map(|(kind, len)| Op::new(kind, len))
+                    .collect(),
+            )
+            .build();
+
+        // chromStart = 1001 - 1 = 1000
+        // blocks: [1000, 1062) (20M 30M 2D 10M), [1562, 1602), [2602, 2627)
+        assert_eq!(
+            write(&record)?,
+            "sq0\t1000\t2627\tr0\t60\t-\t1000\t2627\t0\t3\t62,40,25,\t0,562,1602,\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_bed12_with_unspliced_record() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build();
+
+        assert_eq!(write(&record)?, "sq0\t0\t4\t.\t0\t+\t0\t4\t0\t1\t4,\t0,\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_bed12_with_leading_and_trailing_skips() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(101).unwrap())
+            .set_cigar(
+                [
+                    Op::new(Kind::Skip, 50),
+                    Op::new(Kind::Match, 10),
+                    Op::new(Kind::Skip, 20),
+                    Op::new(Kind::Match, 5),
+                    Op::new(Kind::Skip, 30),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        // blocks: [150, 160), [180, 185)
+        assert_eq!(
+            write(&record)?,
+            "sq0\t150\t185\t.\t0\t+\t150\t185\t0\t2\t10,5,\t0,30,\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_bed12_with_deletion_only_segments() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(
+                [
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Skip, 10),
+                    Op::new(Kind::Match, 10),
+                    Op::new(Kind::Skip, 20),
+                    Op::new(Kind::Deletion, 3),
+                    Op::new(Kind::Skip, 20),
+                    Op::new(Kind::Match, 5),
+                    Op::new(Kind::Skip, 5),
+                    Op::new(Kind::Deletion, 4),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        // blocks: [12, 22), [65, 70)
+        assert_eq!(
+            write(&record)?,
+            "sq0\t12\t70\t.\t0\t+\t12\t70\t0\t2\t10,5,\t0,53,\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_bed12_with_invalid_record() {
+        fn t(record: &RecordBuf) {
+            assert!(matches!(
+                write(record),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        t(&RecordBuf::default());
+
+        t(&RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .build());
+
+        t(&RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Skip, 4)].into_iter().collect())
+            .build());
+
+        t(&RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(
+                [Op::new(Kind::Deletion, 2), Op::new(Kind::Skip, 4)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build());
+    }
+}
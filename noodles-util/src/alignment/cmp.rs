@@ -0,0 +1,276 @@
+//! Alignment record comparators.
+
+use std::cmp::Ordering;
+
+use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+/// Compares two alignment records by coordinate.
+///
+/// This matches the coordinate order of `samtools sort`: records are ordered by reference
+/// sequence ID, i.e., the order of the reference sequences in the header; then by alignment
+/// start; and then by strand, with the forward strand first. Records without a reference
+/// sequence ID are ordered last, and records without an alignment start are ordered first within
+/// their reference sequence.
+///
+/// Records that compare equal are not further ordered. Use a stable sort to keep their input
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::RecordBuf;
+/// use noodles_util::alignment::cmp;
+///
+/// let a = RecordBuf::builder()
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .build();
+///
+/// let b = RecordBuf::builder()
+///     .set_reference_sequence_id(1)
+///     .set_alignment_start(Position::try_from(5)?)
+///     .build();
+///
+/// let c = RecordBuf::default();
+///
+/// assert_eq!(cmp::coordinate(&a, &b), Ordering::Less);
+/// assert_eq!(cmp::coordinate(&c, &b), Ordering::Greater);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn coordinate(a: &RecordBuf, b: &RecordBuf) -> Ordering {
+    coordinate_key(a).cmp(&coordinate_key(b))
+}
+
+fn coordinate_key(record: &RecordBuf) -> (usize, usize, bool) {
+    (
+        record.reference_sequence_id().unwrap_or(usize::MAX),
+        record.alignment_start().map(usize::from).unwrap_or(0),
+        record.flags().is_reverse_complemented(),
+    )
+}
+
+/// Compares two alignment records by name.
+///
+/// This matches the queryname order of `samtools sort -n`: records are ordered by name using a
+/// natural order, i.e., runs of digits are compared by numeric value rather than lexicographically
+/// (e.g., `r2` < `r10`); then by segment, with the first segment before the last segment; and then
+/// by alignment type, with primary alignments before secondary and supplementary alignments.
+/// Records without a name are ordered first.
+///
+/// Records that compare equal are not further ordered. Use a stable sort to keep their input
+/// order.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::cmp;
+///
+/// let a = RecordBuf::builder().set_name(b"r2".as_slice().into()).build();
+/// let b = RecordBuf::builder().set_name(b"r10".as_slice().into()).build();
+/// assert_eq!(cmp::queryname(&a, &b), Ordering::Less);
+///
+/// let c = RecordBuf::builder()
+///     .set_name(b"r2".as_slice().into())
+///     .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT)
+///     .build();
+/// let d = RecordBuf::builder()
+///     .set_name(b"r2".as_slice().into())
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+///     .build();
+/// assert_eq!(cmp::queryname(&c, &d), Ordering::Greater);
+/// ```
+pub fn queryname(a: &RecordBuf, b: &RecordBuf) -> Ordering {
+    const SEGMENT_FLAGS: Flags = Flags::FIRST_SEGMENT.union(Flags::LAST_SEGMENT);
+    const ALIGNMENT_TYPE_FLAGS: Flags = Flags::SECONDARY.union(Flags::SUPPLEMENTARY);
+
+    fn name(record: &RecordBuf) -> &[u8] {
+        record.name().map(|name| name.as_ref()).unwrap_or_default()
+    }
+
+    natural_cmp(name(a), name(b))
+        .then_with(|| {
+            let a_flags = a.flags().intersection(SEGMENT_FLAGS);
+            let b_flags = b.flags().intersection(SEGMENT_FLAGS);
+            a_flags.bits().cmp(&b_flags.bits())
+        })
+        .then_with(|| {
+            let a_flags = a.flags().intersection(ALIGNMENT_TYPE_FLAGS);
+            let b_flags = b.flags().intersection(ALIGNMENT_TYPE_FLAGS);
+            a_flags.bits().cmp(&b_flags.bits())
+        })
+}
+
+// Compares two strings, treating runs of digits as numbers (`strnum_cmp` in samtools).
+//
+// Leading zeros are ignored, so, e.g., `r01` and `r1` compare equal.
+fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    fn split_digits(src: &[u8]) -> (&[u8], &[u8]) {
+        let i = src.iter().position(|b| *b != b'0').unwrap_or(src.len());
+        let src = &src[i..];
+        let j = src
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(src.len());
+        src.split_at(j)
+    }
+
+    let (mut a, mut b) = (a, b);
+
+    while let (Some(&x), Some(&y)) = (a.first(), b.first()) {
+        if x.is_ascii_digit() && y.is_ascii_digit() {
+            let (m, a_rest) = split_digits(a);
+            let (n, b_rest) = split_digits(b);
+
+            match m.len().cmp(&n.len()).then_with(|| m.cmp(n)) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+
+            (a, b) = (a_rest, b_rest);
+        } else {
+            match x.cmp(&y) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+
+            (a, b) = (&a[1..], &b[1..]);
+        }
+    }
+
+    a.len().cmp(&b.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    fn build_record(
+        reference_sequence_id: Option<usize>,
+        alignment_start: Option<usize>,
+        flags: Flags,
+    ) -> RecordBuf {
+        let mut builder = RecordBuf::builder().set_flags(flags);
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder.set_reference_sequence_id(id);
+        }
+
+        if let Some(start) = alignment_start {
+            builder = builder.set_alignment_start(Position::new(start).unwrap());
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_coordinate() {
+        let mut records = [
+            build_record(None, None, Flags::UNMAPPED),
+            build_record(Some(1), Some(2), Flags::empty()),
+            build_record(Some(0), Some(8), Flags::REVERSE_COMPLEMENTED),
+            build_record(Some(0), Some(8), Flags::empty()),
+            build_record(Some(0), None, Flags::UNMAPPED),
+            build_record(Some(0), Some(13), Flags::empty()),
+        ];
+
+        records.sort_by(coordinate);
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                (
+                    record.reference_sequence_id(),
+                    record.alignment_start().map(usize::from),
+                    record.flags().is_reverse_complemented(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                (Some(0), None, false),
+                (Some(0), Some(8), false),
+                (Some(0), Some(8), true),
+                (Some(0), Some(13), false),
+                (Some(1), Some(2), false),
+                (None, None, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_queryname() {
+        fn build_record(name: Option<&str>, flags: Flags) -> RecordBuf {
+            let mut builder = RecordBuf::builder().set_flags(flags);
+
+            if let Some(name) = name {
+                builder = builder.set_name(name.as_bytes().into());
+            }
+
+            builder.build()
+        }
+
+        let mut records = [
+            build_record(Some("r10"), Flags::empty()),
+            build_record(Some("r2"), Flags::SEGMENTED | Flags::LAST_SEGMENT),
+            build_record(
+                Some("r2"),
+                Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::SUPPLEMENTARY,
+            ),
+            build_record(Some("r2"), Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build_record(Some("q9"), Flags::empty()),
+            build_record(None, Flags::empty()),
+        ];
+
+        records.sort_by(queryname);
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                (
+                    record.name().map(|name| name.as_ref().to_vec()),
+                    record.flags(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                (None, Flags::empty()),
+                (Some(b"q9".to_vec()), Flags::empty()),
+                (
+                    Some(b"r2".to_vec()),
+                    Flags::SEGMENTED | Flags::FIRST_SEGMENT
+                ),
+                (
+                    Some(b"r2".to_vec()),
+                    Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::SUPPLEMENTARY
+                ),
+                (Some(b"r2".to_vec()), Flags::SEGMENTED | Flags::LAST_SEGMENT),
+                (Some(b"r10".to_vec()), Flags::empty()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp(b"r2", b"r10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r10", b"r10"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r01", b"r1"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r1:2", b"r1:10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r1", b"r1a"), Ordering::Less);
+        assert_eq!(natural_cmp(b"ra", b"r1"), Ordering::Greater);
+        assert_eq!(natural_cmp(b"", b"r1"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r0", b"r"), Ordering::Greater);
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use noodles_bam::{self as bam, bai};
+use noodles_bgzf as bgzf;
+use noodles_cram as cram;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence};
+use noodles_sam as sam;
+
+use super::io::{
+    reader::builder::{detect_compression_method, detect_format},
+    CompressionMethod, Format,
+};
+
+/// Counts the number of records in an alignment file.
+///
+/// The compression method and format are autodetected.
+///
+/// For bgzip-compressed SAM and BAM, the record count is read from an associated index, if one
+/// exists and it has the record counts: the mapped and unmapped record counts in the metadata
+/// pseudo-bin of each reference sequence and the number of unplaced, unmapped records. The
+/// associated index is `<src>.csi` for SAM and `<src>.bai` or `<src>.csi`, in that order, for BAM.
+/// This does not read any records.
+///
+/// Otherwise, the records are counted by reading the entire file. Records are not decoded: SAM
+/// and BAM records are only read, and CRAM record counts are read from the container headers,
+/// which does not require the reference sequences.
+///
+/// The index is trusted. If it is out of date with the file, the count is from the index.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment;
+/// let n = alignment::count("sample.bam")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn count<P>(src: P) -> io::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+    let reader = File::open(src).map(BufReader::new)?;
+    count_records(reader, |format| count_from_associated_index(src, format))
+}
+
+// Counts the records in an alignment stream.
+//
+// For bgzip-compressed SAM and BAM, `count_from_associated_index` is called first and its count,
+// if any, is returned.
+fn count_records<R, F>(mut reader: R, count_from_associated_index: F) -> io::Result<u64>
+where
+    R: BufRead,
+    F: FnOnce(Format) -> io::Result<Option<u64>>,
+{
+    let compression_method = detect_compression_method(&mut reader)?;
+    let format = detect_format(&mut reader, compression_method)?;
+
+    if compression_method == Some(CompressionMethod::Bgzf) {
+        if let Some(n) = count_from_associated_index(format)? {
+            return Ok(n);
+        }
+    }
+
+    match (format, compression_method) {
+        (Format::Sam, None) => count_sam_records(reader),
+        (Format::Sam, Some(CompressionMethod::Bgzf)) => {
+            count_sam_records(bgzf::Reader::new(reader))
+        }
+        (Format::Bam, None) => count_bam_records(reader),
+        (Format::Bam, Some(CompressionMethod::Bgzf)) => {
+            count_bam_records(bgzf::Reader::new(reader))
+        }
+        (Format::Cram, None) => count_cram_records(reader),
+        (Format::Cram, Some(CompressionMethod::Bgzf)) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "CRAM cannot be bgzip-compressed",
+        )),
+    }
+}
+
+fn count_from_associated_index(src: &Path, format: Format) -> io::Result<Option<u64>> {
+    if format == Format::Bam {
+        match bai::read(push_ext(src, "bai")) {
+            Ok(index) => return Ok(count_from_index(&index)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    if matches!(format, Format::Sam | Format::Bam) {
+        match csi::read(push_ext(src, "csi")) {
+            Ok(index) => return Ok(count_from_index(&index)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(None)
+}
+
+// Returns `None` if the index does not have all the record counts.
+fn count_from_index<I>(index: &csi::binning_index::Index<I>) -> Option<u64>
+where
+    I: reference_sequence::Index,
+{
+    use csi::{binning_index::ReferenceSequence as _, BinningIndex};
+
+    let mut n = index.unplaced_unmapped_record_count()?;
+
+    for reference_sequence in index.reference_sequences() {
+        match reference_sequence.metadata() {
+            Some(metadata) => {
+                n += metadata.mapped_record_count() + metadata.unmapped_record_count();
+            }
+            // A reference sequence without records has neither bins nor metadata.
+            None if reference_sequence.bins().is_empty() => {}
+            None => return None,
+        }
+    }
+
+    Some(n)
+}
+
+fn count_sam_records<R>(reader: R) -> io::Result<u64>
+where
+    R: BufRead,
+{
+    let mut reader = sam::io::Reader::new(reader);
+    reader.read_header()?;
+
+    let mut record = sam::Record::default();
+    let mut n = 0;
+
+    while reader.read_record(&mut record)? != 0 {
+        n += 1;
+    }
+
+    Ok(n)
+}
+
+fn count_bam_records<R>(reader: R) -> io::Result<u64>
+where
+    R: BufRead,
+{
+    let mut reader = bam::io::Reader::from(reader);
+    reader.read_header()?;
+
+    let mut record = bam::Record::default();
+    let mut n = 0;
+
+    while reader.read_record(&mut record)? != 0 {
+        n += 1;
+    }
+
+    Ok(n)
+}
+
+fn count_cram_records<R>(reader: R) -> io::Result<u64>
+where
+    R: BufRead,
+{
+    let mut reader = cram::io::Reader::new(reader);
+    reader.read_header()?;
+
+    let mut n = 0;
+
+    while let Some(container) = reader.read_raw_container()? {
+        let record_count = u64::try_from(container.header().record_count())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        n += record_count;
+    }
+
+    Ok(n)
+}
+
+fn push_ext(src: &Path, ext: &str) -> PathBuf {
+    let mut s = src.as_os_str().to_owned();
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{header::Version, Header, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .set_header(
+                Map::<Header>::builder()
+                    .set_version(Version::new(1, 6))
+                    .insert(
+                        sam::header::record::value::map::header::tag::SORT_ORDER,
+                        "coordinate",
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_records() -> Vec<RecordBuf> {
+        let mapped = |reference_sequence_id, start| {
+            RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::new(start).unwrap())
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .build()
+        };
+
+        vec![
+            mapped(0, 8),
+            mapped(0, 13),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(13).unwrap())
+                .build(),
+            // sq1 has no records.
+            mapped(2, 21),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+        ]
+    }
+
+    fn write_records<W>(writer: &mut W, header: &sam::Header) -> io::Result<()>
+    where
+        W: Write,
+    {
+        writer.write_alignment_header(header)?;
+
+        for record in build_records() {
+            writer.write_alignment_record(header, &record)?;
+        }
+
+        writer.finish(header)
+    }
+
+    fn no_index(_: Format) -> io::Result<Option<u64>> {
+        Ok(None)
+    }
+
+    #[test]
+    fn test_count_records() -> io::Result<()> {
+        let mut header = build_header();
+        let expected = build_records().len() as u64;
+
+        let mut writer = bam::io::Writer::new(Vec::new());
+        write_records(&mut writer, &header)?;
+        let bam_src = writer.into_inner().finish()?;
+
+        assert_eq!(count_records(&bam_src[..], no_index)?, expected);
+
+        let index = bam::index_from_reader(&bam_src[..])?;
+        assert_eq!(count_from_index(&index), Some(expected));
+
+        // The associated index is preferred.
+        let actual = count_records(&bam_src[..], |format| {
+            assert_eq!(format, Format::Bam);
+            Ok(count_from_index(&index).map(|n| n + 1))
+        })?;
+        assert_eq!(actual, expected + 1);
+
+        let mut writer = sam::io::Writer::new(Vec::new());
+        write_records(&mut writer, &header)?;
+        let sam_src = writer.into_inner();
+
+        assert_eq!(count_records(&sam_src[..], no_index)?, expected);
+
+        // Set the reference sequence MD5 checksums to allow writing CRAM without reference
+        // sequences.
+        for reference_sequence in header.reference_sequences_mut().values_mut() {
+            reference_sequence.other_fields_mut().insert(
+                sam::header::record::value::map::reference_sequence::tag::MD5_CHECKSUM,
+                "d41d8cd98f00b204e9800998ecf8427e".into(),
+            );
+        }
+
+        let mut writer = cram::io::writer::Builder::default()
+            .set_reference_required(false)
+            .build_with_writer(Vec::new());
+        write_records(&mut writer, &header)?;
+        let cram_src = writer.get_ref().clone();
+
+        // CRAM is never bgzip-compressed, so an associated index is not read.
+        let actual = count_records(&cram_src[..], |_| unreachable!())?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_from_index() {
+        let index = bai::Index::default();
+        assert!(count_from_index(&index).is_none());
+    }
+}
@@ -0,0 +1,410 @@
+//! Alignment record and FASTQ record conversion.
+
+use std::io;
+
+use noodles_fasta as fasta;
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{record::Flags, Record, RecordBuf};
+
+pub(super) const MISSING_NAME: &[u8] = b"*";
+
+const FIRST_SEGMENT_SUFFIX: &[u8] = b"/1";
+const LAST_SEGMENT_SUFFIX: &[u8] = b"/2";
+
+// § 1.4.11 "QUAL: ASCII of Phred-scaled base QUALity+33" (2023-05-24)
+const QUALITY_SCORE_OFFSET: u8 = b'!';
+
+/// Converts an alignment record to a FASTQ record.
+///
+/// This drops all alignment information. The FASTQ record has the read name, sequence, and
+/// quality scores of the original read:
+///
+///   * the name is the read name (or `*` if missing). If the record is segmented, `/1` is
+///     appended for the first segment and `/2`, for the last segment;
+///   * if the record is mapped and reverse complemented, the sequence is reverse complemented and
+///     the quality scores are reversed. Unmapped records are never reoriented; and
+///   * if the record has no quality scores, the FASTQ quality scores are set to 0.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         Flags,
+///     },
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::fastq;
+///
+/// let record = RecordBuf::builder()
+///     .set_name(b"r0".into())
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::REVERSE_COMPLEMENTED)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"AACG".to_vec().into())
+///     .set_quality_scores(vec![40, 30, 20, 10].into())
+///     .build();
+///
+/// let fastq_record = fastq::from_alignment_record(&record)?;
+///
+/// assert_eq!(fastq_record.name(), b"r0/1");
+/// assert_eq!(fastq_record.sequence(), b"CGTT");
+/// assert_eq!(fastq_record.quality_scores(), b"+5?I");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn from_alignment_record<R>(record: &R) -> io::Result<fastq::Record>
+where
+    R: Record + ?Sized,
+{
+    let flags = record.flags()?;
+
+    let sequence: Vec<u8> = record.sequence().iter().collect();
+    let quality_scores: Vec<u8> = record.quality_scores().iter().collect();
+
+    let quality_scores = if quality_scores.is_empty() {
+        None
+    } else if quality_scores.len() == sequence.len() {
+        Some(&quality_scores[..])
+    } else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    };
+
+    let is_reverse_complemented = !flags.is_unmapped() && flags.is_reverse_complemented();
+    let segment = Segment::new(&sequence, quality_scores, is_reverse_complemented)?;
+
+    let name = record.name();
+    let name = name.as_ref().map(|n| n.as_bytes()).unwrap_or(MISSING_NAME);
+
+    let suffix: &[u8] = if !flags.is_segmented() {
+        b""
+    } else if flags.is_first_segment() {
+        FIRST_SEGMENT_SUFFIX
+    } else if flags.is_last_segment() {
+        LAST_SEGMENT_SUFFIX
+    } else {
+        b""
+    };
+
+    Ok(segment.into_fastq_record(&[name, suffix]))
+}
+
+/// Converts a single-end FASTQ record to an unmapped alignment record.
+///
+/// The alignment record is unmapped (`0x04`) and not segmented. The name is the FASTQ record
+/// name, with the sequence and quality scores copied as is. The FASTQ record description is
+/// dropped.
+///
+/// # Errors
+///
+/// This returns an error if a quality score is below `!` (Phred+33).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_sam::alignment::record::Flags;
+/// use noodles_util::alignment::fastq::into_unmapped_record;
+///
+/// let fastq_record = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "NDLS");
+/// let record = into_unmapped_record(&fastq_record)?;
+///
+/// assert_eq!(record.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+/// assert_eq!(record.flags(), Flags::UNMAPPED);
+/// assert_eq!(record.sequence().as_ref(), b"ACGT");
+/// assert_eq!(record.quality_scores().as_ref(), [45, 35, 43, 50]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn into_unmapped_record(record: &fastq::Record) -> io::Result<RecordBuf> {
+    build_unmapped_record(record.name(), record, Flags::UNMAPPED)
+}
+
+/// Converts a pair of FASTQ records to a pair of unmapped alignment records.
+///
+/// This is the conversion of paired FASTQ records, e.g., from interleaved or split read files, to
+/// unmapped SAM/BAM, as done by `samtools import`. Both alignment records are segmented (`0x01`),
+/// unmapped (`0x04`), and mate unmapped (`0x08`). The first record is the first segment (`0x40`),
+/// and the second record, the last segment (`0x80`). The mate reference sequence and position are
+/// unset (`*` and 0), as neither segment is mapped.
+///
+/// The read names must have the same stem, i.e., the name without a trailing `/1` or `/2`,
+/// which is the name given to both alignment records. The sequences and quality scores are copied
+/// as is, and the FASTQ record descriptions are dropped.
+///
+/// Use [`into_unmapped_record`] for single-end reads.
+///
+/// # Errors
+///
+/// This returns an error if the name stems differ or a quality score is below `!` (Phred+33).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_sam::alignment::record::Flags;
+/// use noodles_util::alignment::fastq::into_unmapped_records;
+///
+/// let r1 = fastq::Record::new(fastq::record::Definition::new("r0/1", ""), "ACGT", "NDLS");
+/// let r2 = fastq::Record::new(fastq::record::Definition::new("r0/2", ""), "TGCA", "SLDN");
+///
+/// let (a, b) = into_unmapped_records(&r1, &r2)?;
+///
+/// assert_eq!(a.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+/// assert_eq!(
+///     a.flags(),
+///     Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::FIRST_SEGMENT
+/// );
+///
+/// assert_eq!(b.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+/// assert_eq!(
+///     b.flags(),
+///     Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::LAST_SEGMENT
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn into_unmapped_records(
+    r1: &fastq::Record,
+    r2: &fastq::Record,
+) -> io::Result<(RecordBuf, RecordBuf)> {
+    const PAIRED_FLAGS: Flags = Flags::SEGMENTED
+        .union(Flags::UNMAPPED)
+        .union(Flags::MATE_UNMAPPED);
+
+    let name = name_stem(r1.name());
+
+    if name_stem(r2.name()) != name {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "read name mismatch",
+        ));
+    }
+
+    let a = build_unmapped_record(name, r1, PAIRED_FLAGS | Flags::FIRST_SEGMENT)?;
+    let b = build_unmapped_record(name, r2, PAIRED_FLAGS | Flags::LAST_SEGMENT)?;
+
+    Ok((a, b))
+}
+
+fn name_stem(name: &[u8]) -> &[u8] {
+    name.strip_suffix(FIRST_SEGMENT_SUFFIX)
+        .or_else(|| name.strip_suffix(LAST_SEGMENT_SUFFIX))
+        .unwrap_or(name)
+}
+
+fn build_unmapped_record(
+    name: &[u8],
+    record: &fastq::Record,
+    flags: Flags,
+) -> io::Result<RecordBuf> {
+    let quality_scores = record
+        .quality_scores()
+        .iter()
+        .map(|&b| {
+            b.checked_sub(QUALITY_SCORE_OFFSET)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid quality score"))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    Ok(RecordBuf::builder()
+        .set_name(name.into())
+        .set_flags(flags)
+        .set_sequence(record.sequence().to_vec().into())
+        .set_quality_scores(quality_scores.into())
+        .build())
+}
+
+// A read sequence and its quality scores in the original read orientation.
+pub(super) struct Segment {
+    sequence: Vec<u8>,
+    quality_scores: Vec<u8>,
+}
+
+impl Segment {
+    pub(super) fn new(
+        sequence: &[u8],
+        quality_scores: Option<&[u8]>,
+        is_reverse_complemented: bool,
+    ) -> io::Result<Self> {
+        let mut quality_scores: Vec<u8> = match quality_scores {
+            Some(scores) => scores
+                .iter()
+                .map(|&n| n.saturating_add(QUALITY_SCORE_OFFSET))
+                .collect(),
+            None => vec![QUALITY_SCORE_OFFSET; sequence.len()],
+        };
+
+        let sequence = if is_reverse_complemented {
+            quality_scores.reverse();
+
+            fasta::record::Sequence::from(sequence.to_vec())
+                .complement()
+                .rev()
+                .collect::<Result<_, _>>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+        } else {
+            sequence.to_vec()
+        };
+
+        Ok(Self {
+            sequence,
+            quality_scores,
+        })
+    }
+
+    pub(super) fn into_fastq_record(self, name_parts: &[&[u8]]) -> fastq::Record {
+        let name = name_parts.concat();
+        let definition = fastq::record::Definition::new(name, "");
+        fastq::Record::new(definition, self.sequence, self.quality_scores)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    fn build_record(flags: Flags) -> RecordBuf {
+        let builder = RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_flags(flags)
+            .set_sequence(b"AACG".to_vec().into())
+            .set_quality_scores(vec![40, 30, 20, 10].into());
+
+        if flags.is_unmapped() {
+            builder.build()
+        } else {
+            builder
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .build()
+        }
+    }
+
+    fn build_fastq_record(name: &str, sequence: &str, quality_scores: &str) -> fastq::Record {
+        fastq::Record::new(
+            fastq::record::Definition::new(name, ""),
+            sequence,
+            quality_scores,
+        )
+    }
+
+    #[test]
+    fn test_from_alignment_record() -> io::Result<()> {
+        let record = build_record(Flags::empty());
+        assert_eq!(
+            from_alignment_record(&record)?,
+            build_fastq_record("r0", "AACG", "I?5+")
+        );
+
+        let record = build_record(Flags::REVERSE_COMPLEMENTED);
+        assert_eq!(
+            from_alignment_record(&record)?,
+            build_fastq_record("r0", "CGTT", "+5?I")
+        );
+
+        let record = build_record(Flags::UNMAPPED | Flags::REVERSE_COMPLEMENTED);
+        assert_eq!(
+            from_alignment_record(&record)?,
+            build_fastq_record("r0", "AACG", "I?5+")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_alignment_record_with_segments() -> io::Result<()> {
+        let record = build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT);
+        assert_eq!(from_alignment_record(&record)?.name(), b"r0/1");
+
+        let record = build_record(Flags::SEGMENTED | Flags::LAST_SEGMENT);
+        assert_eq!(from_alignment_record(&record)?.name(), b"r0/2");
+
+        let record = build_record(Flags::SEGMENTED);
+        assert_eq!(from_alignment_record(&record)?.name(), b"r0");
+
+        let record = build_record(Flags::FIRST_SEGMENT);
+        assert_eq!(from_alignment_record(&record)?.name(), b"r0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_alignment_record_with_missing_name_and_quality_scores() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        assert_eq!(
+            from_alignment_record(&record)?,
+            build_fastq_record("*", "ACGT", "!!!!")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_unmapped_record() -> io::Result<()> {
+        let fastq_record = build_fastq_record("r0/1", "AACG", "I?5+");
+        let record = into_unmapped_record(&fastq_record)?;
+
+        assert_eq!(record.name().map(|name| name.as_ref()), Some(&b"r0/1"[..]));
+        assert_eq!(record.flags(), Flags::UNMAPPED);
+        assert_eq!(record.sequence().as_ref(), b"AACG");
+        assert_eq!(record.quality_scores().as_ref(), [40, 30, 20, 10]);
+
+        assert_eq!(from_alignment_record(&record)?, fastq_record);
+
+        let fastq_record = build_fastq_record("r0", "A", " ");
+        assert!(matches!(
+            into_unmapped_record(&fastq_record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_unmapped_records() -> io::Result<()> {
+        const FLAGS: Flags = Flags::SEGMENTED
+            .union(Flags::UNMAPPED)
+            .union(Flags::MATE_UNMAPPED);
+
+        let r1 = build_fastq_record("r0/1", "AACG", "I?5+");
+        let r2 = build_fastq_record("r0/2", "TTGC", "+5?I");
+
+        let (a, b) = into_unmapped_records(&r1, &r2)?;
+
+        assert_eq!(a.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+        assert_eq!(a.flags(), FLAGS | Flags::FIRST_SEGMENT);
+        assert_eq!(a.sequence().as_ref(), b"AACG");
+        assert!(a.mate_reference_sequence_id().is_none());
+        assert!(a.mate_alignment_start().is_none());
+
+        assert_eq!(b.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+        assert_eq!(b.flags(), FLAGS | Flags::LAST_SEGMENT);
+        assert_eq!(b.sequence().as_ref(), b"TTGC");
+
+        // The conversion is reversible.
+        assert_eq!(from_alignment_record(&a)?, r1);
+        assert_eq!(from_alignment_record(&b)?, r2);
+
+        let r1 = build_fastq_record("r0", "AACG", "I?5+");
+        let r2 = build_fastq_record("r0", "TTGC", "+5?I");
+        let (a, b) = into_unmapped_records(&r1, &r2)?;
+        assert_eq!(a.name(), b.name());
+
+        let r2 = build_fastq_record("r1/2", "TTGC", "+5?I");
+        assert!(matches!(
+            into_unmapped_records(&r1, &r2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use std::{io, iter};
+
+use noodles_sam::alignment::{
+    record::{cigar::op::Kind, Sequence},
+    Record,
+};
+
+const GAP: u8 = b'-';
+const PAD: u8 = b'*';
+const MATCH: u8 = b'|';
+const BLANK: u8 = b' ';
+const UNKNOWN: u8 = b'?';
+
+/// Formats an alignment record as a human-readable alignment view.
+///
+/// This is intended for debugging, e.g., clipping, `MD`, or `NM` logic.
+///
+/// `reference_bases` are the reference sequence bases starting at the alignment start. They must
+/// cover at least the alignment span of the record.
+///
+/// When reference bases are given, the view has three lines: the read, a match line, and the
+/// reference. The match line has `|` where the read and reference bases are equal (compared
+/// case-insensitively) and a space otherwise. When reference bases are not given, the view has
+/// two lines: the read and the CIGAR operation of each column.
+///
+/// Insertions (`I`) are gaps (`-`) in the reference, and deletions (`D`) and skips (`N`) are gaps
+/// in the read. Soft clips (`S`) are shown in lowercase in the read line and are blank in the
+/// other lines. Pads (`P`) are shown as `*` in both the read and reference lines. Hard clips (`H`)
+/// are omitted. A record without CIGAR operations, e.g., an unmapped record, is shown as its
+/// sequence with a blank line below it.
+///
+/// The lines are separated by a newline, and the last line does not have a trailing newline.
+///
+/// A record without a sequence (`*`) has no read bases, so each read base is shown as `?` and is
+/// never a match. Otherwise, the sequence length must be the read length of the CIGAR operations,
+/// or this returns an [`io::ErrorKind::InvalidInput`] error.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment;
+///
+/// let record = RecordBuf::builder()
+///     .set_cigar(
+///         [
+///             Op::new(Kind::SoftClip, 1),
+///             Op::new(Kind::Match, 3),
+///             Op::new(Kind::Insertion, 1),
+///             Op::new(Kind::Deletion, 2),
+///             Op::new(Kind::Match, 2),
+///         ]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .set_sequence(b"TACGTAT".to_vec().into())
+///     .build();
+///
+/// let view = alignment::format_alignment(&record, Some(b"ACCCAAT"))?;
+/// assert_eq!(view, "tACGT--AT\n ||    ||\n ACC-CAAT");
+///
+/// let view = alignment::format_alignment(&record, None)?;
+/// assert_eq!(view, "tACGT--AT\nSMMMIDDMM");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn format_alignment<R>(record: &R, reference_bases: Option<&[u8]>) -> io::Result<String>
+where
+    R: Record + ?Sized,
+{
+    let cigar = record.cigar();
+    let sequence = record.sequence();
+
+    let read_length = cigar.read_length()?;
+
+    if cigar.is_empty() {
+        let read_line: Vec<_> = sequence.iter().collect();
+        let blank_line = vec![BLANK; read_line.len()];
+        return Ok(join_lines(&[read_line, blank_line]));
+    }
+
+    if !sequence.is_empty() && sequence.len() != read_length {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "sequence length mismatch: expected {read_length}, got {}",
+                sequence.len()
+            ),
+        ));
+    }
+
+    if let Some(reference_bases) = reference_bases {
+        let alignment_span = cigar.alignment_span()?;
+
+        if reference_bases.len() < alignment_span {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference bases length mismatch: expected >= {alignment_span}, got {}",
+                    reference_bases.len()
+                ),
+            ));
+        }
+    }
+
+    let mut read_bases: Box<dyn Iterator<Item = u8>> = if sequence.is_empty() {
+        Box::new(iter::repeat(UNKNOWN))
+    } else {
+        sequence.iter()
+    };
+    let mut reference_bases = reference_bases.map(|bases| bases.iter().copied());
+
+    let mut read_line = Vec::new();
+    let mut match_line = Vec::new();
+    let mut reference_line = Vec::new();
+    let mut op_line = Vec::new();
+
+    for result in cigar.iter() {
+        let op = result?;
+
+        let kind = op.kind();
+        let op_char = kind_to_char(kind);
+
+        for _ in 0..op.len() {
+            let (read_base, reference_base) = match kind {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => (
+                    next_base(&mut read_bases),
+                    reference_bases.as_mut().map(next_base),
+                ),
+                Kind::Insertion => (
+                    next_base(&mut read_bases),
+                    reference_bases.as_ref().map(|_| GAP),
+                ),
+                Kind::Deletion | Kind::Skip => (GAP, reference_bases.as_mut().map(next_base)),
+                Kind::SoftClip => (
+                    next_base(&mut read_bases).to_ascii_lowercase(),
+                    reference_bases.as_ref().map(|_| BLANK),
+                ),
+                Kind::Pad => (PAD, reference_bases.as_ref().map(|_| PAD)),
+                Kind::HardClip => break,
+            };
+
+            read_line.push(read_base);
+            op_line.push(op_char);
+
+            if let Some(reference_base) = reference_base {
+                let is_match = is_base(read_base)
+                    && is_base(reference_base)
+                    && read_base.eq_ignore_ascii_case(&reference_base);
+
+                match_line.push(if is_match { MATCH } else { BLANK });
+                reference_line.push(reference_base);
+            }
+        }
+    }
+
+    if reference_bases.is_some() {
+        Ok(join_lines(&[read_line, match_line, reference_line]))
+    } else {
+        Ok(join_lines(&[read_line, op_line]))
+    }
+}
+
+// SAFETY: the caller checks that the bases cover the CIGAR operations.
+fn next_base<I>(bases: &mut I) -> u8
+where
+    I: Iterator<Item = u8>,
+{
+    bases.next().unwrap()
+}
+
+fn is_base(b: u8) -> bool {
+    !matches!(b, GAP | PAD | BLANK | UNKNOWN)
+}
+
+fn kind_to_char(kind: Kind) -> u8 {
+    match kind {
+        Kind::Match => b'M',
+        Kind::Insertion => b'I',
+        Kind::Deletion => b'D',
+        Kind::Skip => b'N',
+        Kind::SoftClip => b'S',
+        Kind::HardClip => b'H',
+        Kind::Pad => b'P',
+        Kind::SequenceMatch => b'=',
+        Kind::SequenceMismatch => b'X',
+    }
+}
+
+fn join_lines(lines: &[Vec<u8>]) -> String {
+    let lines: Vec<_> = lines
+        .iter()
+        .map(|line| String::from_utf8_lossy(line))
+        .collect();
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::cigar::Op, RecordBuf};
+
+    use super::*;
+
+    fn build_record(ops: &[(Kind, usize)], sequence: &[u8]) -> RecordBuf {
+        RecordBuf::builder()
+            .set_cigar(ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect())
+            .set_sequence(sequence.to_vec().into())
+            .build()
+    }
+
+    #[test]
+    fn test_format_alignment() -> io::Result<()> {
+        // 2H 1S 2M 1I 1M 2N 2= 1X 1P 1D 2H
+        let record = build_record(
+            &[
+                (Kind::HardClip, 2),
+                (Kind::SoftClip, 1),
+                (Kind::Match, 2),
+                (Kind::Insertion, 1),
+                (Kind::Match, 1),
+                (Kind::Skip, 2),
+                (Kind::SequenceMatch, 2),
+                (Kind::SequenceMismatch, 1),
+                (Kind::Pad, 1),
+                (Kind::Deletion, 1),
+                (Kind::HardClip, 2),
+            ],
+            b"GACTGACT",
+        );
+
+        assert_eq!(
+            format_alignment(&record, Some(b"aggttacgt"))?,
+            "gACTG--ACT*-\n |  |  ||   \n ag-gttacg*t"
+        );
+
+        assert_eq!(
+            format_alignment(&record, None)?,
+            "gACTG--ACT*-\nSMMIMNN==XPD"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_alignment_without_sequence() -> io::Result<()> {
+        let record = build_record(
+            &[
+                (Kind::SoftClip, 1),
+                (Kind::Match, 2),
+                (Kind::Insertion, 1),
+                (Kind::Deletion, 1),
+                (Kind::Match, 1),
+            ],
+            b"",
+        );
+
+        assert_eq!(
+            format_alignment(&record, Some(b"ACGT"))?,
+            "????-?\n      \n AC-GT"
+        );
+
+        assert_eq!(format_alignment(&record, None)?, "????-?\nSMMIDM");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_alignment_with_unmapped_record() -> io::Result<()> {
+        let record = build_record(&[], b"ACGT");
+        assert_eq!(format_alignment(&record, None)?, "ACGT\n    ");
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_alignment_with_invalid_input() {
+        fn t(record: &RecordBuf, reference_bases: Option<&[u8]>) {
+            assert!(matches!(
+                format_alignment(record, reference_bases),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        let record = build_record(&[(Kind::Match, 4)], b"ACG");
+        t(&record, None);
+
+        let record = build_record(&[(Kind::Match, 4)], b"ACGT");
+        t(&record, Some(b"ACG"));
+    }
+}
@@ -0,0 +1,419 @@
+//! Alignment insert size histogram.
+
+use std::io;
+
+use noodles_sam::alignment::{record::Flags, Record};
+
+/// The default maximum insert size.
+pub const DEFAULT_MAX_INSERT_SIZE: usize = 10000;
+
+/// An insert size histogram.
+///
+/// This accumulates the distribution of insert sizes, i.e., the absolute template lengths (TLEN),
+/// of paired alignment records.
+///
+/// A record is counted if
+///
+///   * it is properly segmented (`0x02`), and neither it nor its mate are unmapped;
+///   * it is a primary alignment, i.e., not secondary (`0x100`) or supplementary (`0x800`);
+///   * it is not a QC fail (`0x200`) or duplicate (`0x400`);
+///   * it is the first segment (`0x40`); and
+///   * its template length is not 0.
+///
+/// Each pair is counted once, by its first segment. Since the template length of the first
+/// segment is negative when it is the rightmost segment, the absolute value is used. (Requiring
+/// a positive template length as well would drop pairs where the first segment is on the reverse
+/// strand of a forward-reverse library.) A template length of 0 is unknown, e.g., when the
+/// segments are on different reference sequences.
+///
+/// Counted insert sizes larger than the maximum insert size are not added to the histogram but
+/// are counted separately (see [`Self::overflow_count`]). They are not included in the metrics.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::InsertSizeHistogram;
+///
+/// let mut histogram = InsertSizeHistogram::default();
+///
+/// for template_length in [300, 300, 350, -400] {
+///     let record = RecordBuf::builder()
+///         .set_flags(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::FIRST_SEGMENT)
+///         .set_template_length(template_length)
+///         .build();
+///
+///     histogram.add(&record)?;
+/// }
+///
+/// assert_eq!(histogram.count(), 4);
+/// assert_eq!(histogram.counts()[300], 2);
+///
+/// let metrics = histogram.metrics().unwrap();
+/// assert_eq!(metrics.median(), 325.0);
+/// assert_eq!(metrics.mean(), 337.5);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsertSizeHistogram {
+    counts: Vec<u64>,
+    overflow_count: u64,
+}
+
+impl InsertSizeHistogram {
+    /// Creates an insert size histogram with a maximum insert size.
+    ///
+    /// The histogram has a bucket for each insert size from 0 to the maximum insert size,
+    /// inclusive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    /// let histogram = InsertSizeHistogram::new(1000);
+    /// assert_eq!(histogram.max_insert_size(), 1000);
+    /// ```
+    pub fn new(max_insert_size: usize) -> Self {
+        Self {
+            counts: vec![0; max_insert_size + 1],
+            overflow_count: 0,
+        }
+    }
+
+    /// Returns the maximum insert size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{insert_size_histogram::DEFAULT_MAX_INSERT_SIZE, InsertSizeHistogram};
+    /// let histogram = InsertSizeHistogram::default();
+    /// assert_eq!(histogram.max_insert_size(), DEFAULT_MAX_INSERT_SIZE);
+    /// ```
+    pub fn max_insert_size(&self) -> usize {
+        self.counts.len() - 1
+    }
+
+    /// Returns the raw histogram.
+    ///
+    /// The value at index `i` is the number of counted records with insert size `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    /// let histogram = InsertSizeHistogram::new(1000);
+    /// assert_eq!(histogram.counts().len(), 1001);
+    /// ```
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of records in the histogram.
+    ///
+    /// This does not include the records with insert sizes larger than the maximum insert size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    /// let histogram = InsertSizeHistogram::default();
+    /// assert_eq!(histogram.count(), 0);
+    /// ```
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the number of counted records with insert sizes larger than the maximum insert
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    /// let histogram = InsertSizeHistogram::default();
+    /// assert_eq!(histogram.overflow_count(), 0);
+    /// ```
+    pub fn overflow_count(&self) -> u64 {
+        self.overflow_count
+    }
+
+    /// Adds a record to the histogram.
+    ///
+    /// This returns whether the record is counted (see [`InsertSizeHistogram`] for the criteria).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf};
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    ///
+    /// let mut histogram = InsertSizeHistogram::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_flags(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::FIRST_SEGMENT)
+    ///     .set_template_length(300)
+    ///     .build();
+    /// assert!(histogram.add(&record)?);
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_flags(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::LAST_SEGMENT)
+    ///     .set_template_length(-300)
+    ///     .build();
+    /// assert!(!histogram.add(&record)?);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add<R>(&mut self, record: &R) -> io::Result<bool>
+    where
+        R: Record + ?Sized,
+    {
+        let flags = record.flags()?;
+
+        if !is_counted(flags) {
+            return Ok(false);
+        }
+
+        let template_length = record.template_length()?;
+
+        if template_length == 0 {
+            return Ok(false);
+        }
+
+        let insert_size = usize::try_from(template_length.unsigned_abs())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        match self.counts.get_mut(insert_size) {
+            Some(count) => *count += 1,
+            None => self.overflow_count += 1,
+        }
+
+        Ok(true)
+    }
+
+    /// Computes the metrics of the histogram.
+    ///
+    /// This returns `None` if the histogram is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::InsertSizeHistogram;
+    /// let histogram = InsertSizeHistogram::default();
+    /// assert!(histogram.metrics().is_none());
+    /// ```
+    pub fn metrics(&self) -> Option<Metrics> {
+        let n = self.count();
+
+        if n == 0 {
+            return None;
+        }
+
+        let buckets = || {
+            self.counts
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| **count > 0)
+                .map(|(insert_size, count)| (insert_size as f64, *count))
+        };
+
+        let median = median(buckets(), n);
+
+        let mut deviations: Vec<_> = buckets()
+            .map(|(insert_size, count)| ((insert_size - median).abs(), count))
+            .collect();
+        deviations.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let median_absolute_deviation = self::median(deviations, n);
+
+        let sum: f64 = buckets()
+            .map(|(insert_size, count)| insert_size * count as f64)
+            .sum();
+        let mean = sum / n as f64;
+
+        let standard_deviation = if n > 1 {
+            let sum_of_squares: f64 = buckets()
+                .map(|(insert_size, count)| (insert_size - mean).powi(2) * count as f64)
+                .sum();
+
+            (sum_of_squares / (n - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Some(Metrics {
+            median,
+            median_absolute_deviation,
+            mean,
+            standard_deviation,
+        })
+    }
+}
+
+impl Default for InsertSizeHistogram {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_INSERT_SIZE)
+    }
+}
+
+/// Insert size metrics.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Metrics {
+    median: f64,
+    median_absolute_deviation: f64,
+    mean: f64,
+    standard_deviation: f64,
+}
+
+impl Metrics {
+    /// Returns the median insert size.
+    ///
+    /// If there is an even number of insert sizes, this is the mean of the two middle values.
+    pub fn median(&self) -> f64 {
+        self.median
+    }
+
+    /// Returns the median absolute deviation of the insert sizes.
+    ///
+    /// This is the median of the absolute deviations from the median. It is not scaled.
+    pub fn median_absolute_deviation(&self) -> f64 {
+        self.median_absolute_deviation
+    }
+
+    /// Returns the mean insert size.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the sample standard deviation of the insert sizes.
+    ///
+    /// This is 0 if there is only one insert size.
+    pub fn standard_deviation(&self) -> f64 {
+        self.standard_deviation
+    }
+}
+
+fn is_counted(flags: Flags) -> bool {
+    const REQUIRED: Flags = Flags::SEGMENTED
+        .union(Flags::PROPERLY_SEGMENTED)
+        .union(Flags::FIRST_SEGMENT);
+
+    const EXCLUDED: Flags = Flags::UNMAPPED
+        .union(Flags::MATE_UNMAPPED)
+        .union(Flags::SECONDARY)
+        .union(Flags::QC_FAIL)
+        .union(Flags::DUPLICATE)
+        .union(Flags::SUPPLEMENTARY);
+
+    flags.contains(REQUIRED) && !flags.intersects(EXCLUDED)
+}
+
+// Returns the median of sorted `(value, count)` pairs with a total count of `n`.
+fn median<I>(buckets: I, n: u64) -> f64
+where
+    I: IntoIterator<Item = (f64, u64)>,
+{
+    // The 0-based ranks of the middle values.
+    let (i, j) = ((n - 1) / 2, n / 2);
+
+    let mut a = None;
+    let mut seen = 0;
+
+    for (value, count) in buckets {
+        seen += count;
+
+        if a.is_none() && i < seen {
+            a = Some(value);
+        }
+
+        if j < seen {
+            // SAFETY: `i <= j`.
+            return (a.unwrap() + value) / 2.0;
+        }
+    }
+
+    unreachable!("n is the total count");
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::RecordBuf;
+
+    use super::*;
+
+    const PAIRED: Flags = Flags::SEGMENTED
+        .union(Flags::PROPERLY_SEGMENTED)
+        .union(Flags::FIRST_SEGMENT);
+
+    fn build_record(flags: Flags, template_length: i32) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(flags)
+            .set_template_length(template_length)
+            .build()
+    }
+
+    #[test]
+    fn test_add() -> io::Result<()> {
+        let mut histogram = InsertSizeHistogram::new(500);
+
+        assert!(histogram.add(&build_record(PAIRED, 300))?);
+        assert!(histogram.add(&build_record(PAIRED, -300))?);
+        assert!(histogram.add(&build_record(PAIRED, 501))?);
+
+        assert!(!histogram.add(&build_record(PAIRED, 0))?);
+        assert!(!histogram.add(&build_record(Flags::SEGMENTED | Flags::FIRST_SEGMENT, 300))?);
+        assert!(!histogram.add(&build_record(
+            Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::LAST_SEGMENT,
+            300
+        ))?);
+
+        for flags in [
+            Flags::UNMAPPED,
+            Flags::MATE_UNMAPPED,
+            Flags::SECONDARY,
+            Flags::QC_FAIL,
+            Flags::DUPLICATE,
+            Flags::SUPPLEMENTARY,
+        ] {
+            assert!(!histogram.add(&build_record(PAIRED | flags, 300))?);
+        }
+
+        assert_eq!(histogram.count(), 2);
+        assert_eq!(histogram.counts()[300], 2);
+        assert_eq!(histogram.overflow_count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_metrics() -> io::Result<()> {
+        let mut histogram = InsertSizeHistogram::default();
+
+        for template_length in [100, 200, 200, 300, 1000] {
+            histogram.add(&build_record(PAIRED, template_length))?;
+        }
+
+        let metrics = histogram.metrics().unwrap();
+
+        assert_eq!(metrics.median(), 200.0);
+        // deviations: 100, 0, 0, 100, 800
+        assert_eq!(metrics.median_absolute_deviation(), 100.0);
+        assert_eq!(metrics.mean(), 360.0);
+        // (260^2 + 160^2 + 160^2 + 60^2 + 640^2) / 4 = 133000
+        assert!((metrics.standard_deviation() - 133000.0f64.sqrt()).abs() < 1e-9);
+
+        let mut histogram = InsertSizeHistogram::default();
+        histogram.add(&build_record(PAIRED, 300))?;
+        let metrics = histogram.metrics().unwrap();
+        assert_eq!(metrics.median(), 300.0);
+        assert_eq!(metrics.median_absolute_deviation(), 0.0);
+        assert_eq!(metrics.standard_deviation(), 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median([(1.0, 1)], 1), 1.0);
+        assert_eq!(median([(1.0, 1), (2.0, 1)], 2), 1.5);
+        assert_eq!(median([(1.0, 2), (4.0, 1)], 3), 1.0);
+        assert_eq!(median([(1.0, 2), (4.0, 2)], 4), 2.5);
+    }
+}
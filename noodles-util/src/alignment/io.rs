@@ -1,12 +1,14 @@
 //! Alignment format I/O.
 
+pub mod chain_reader;
 mod compression_method;
 mod format;
 pub mod indexed_reader;
 pub mod reader;
+pub mod split_writer;
 pub mod writer;
 
 pub use self::{
-    compression_method::CompressionMethod, format::Format, indexed_reader::IndexedReader,
-    reader::Reader, writer::Writer,
+    chain_reader::ChainReader, compression_method::CompressionMethod, format::Format,
+    indexed_reader::IndexedReader, reader::Reader, split_writer::SplitWriter, writer::Writer,
 };
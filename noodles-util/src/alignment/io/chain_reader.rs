@@ -0,0 +1,411 @@
+//! Alignment chain reader.
+
+use std::{
+    error, fmt,
+    io::{self, BufRead, Read},
+    iter, mem,
+    path::{Path, PathBuf},
+};
+
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::{reader::Builder, Reader};
+
+/// An alignment chain reader.
+///
+/// This reads multiple alignment sources as one continuous record stream, i.e., `cat` at the
+/// record level. Records are read from each source in order, without sorting or merging.
+///
+/// The header is read from the first source. Each subsequent source is opened lazily, when the
+/// records of the previous source have been exhausted, and its header must be compatible with the
+/// first, i.e., it must have the same reference sequences (names and lengths) in the same order.
+/// Otherwise, the reference sequence IDs of the records would be ambiguous.
+///
+/// Errors from a source are wrapped in a [`SourceError`], which identifies the source.
+pub struct ChainReader {
+    builder: Builder,
+    sources: Vec<Source>,
+}
+
+impl ChainReader {
+    /// Creates an alignment chain reader with a reader builder.
+    ///
+    /// The builder is used to open each source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::io::{reader::Builder, ChainReader};
+    /// let reader = ChainReader::new(Builder::default());
+    /// ```
+    pub fn new(builder: Builder) -> Self {
+        Self {
+            builder,
+            sources: Vec::new(),
+        }
+    }
+
+    /// Creates an alignment chain reader from paths.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment::io::ChainReader;
+    /// let reader = ChainReader::from_paths(["sample.0.bam", "sample.1.bam"]);
+    /// ```
+    pub fn from_paths<I, P>(paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        let mut reader = Self::new(Builder::default());
+
+        for path in paths {
+            reader.push_path(path);
+        }
+
+        reader
+    }
+
+    /// Adds a source from a path.
+    ///
+    /// The file is not opened until its records are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::io::{reader::Builder, ChainReader};
+    /// let mut reader = ChainReader::new(Builder::default());
+    /// reader.push_path("sample.bam");
+    /// ```
+    pub fn push_path<P>(&mut self, path: P)
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+
+        self.sources.push(Source {
+            path: Some(path.clone()),
+            state: State::Path(path),
+        });
+    }
+
+    /// Adds a source from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_util::alignment::io::{reader::Builder, ChainReader};
+    /// let mut reader = ChainReader::new(Builder::default());
+    /// reader.push_reader(io::empty());
+    /// ```
+    pub fn push_reader<R>(&mut self, reader: R)
+    where
+        R: Read + 'static,
+    {
+        self.sources.push(Source {
+            path: None,
+            state: State::Reader(Box::new(reader)),
+        });
+    }
+
+    /// Reads and parses the alignment header of the first source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::io::{reader::Builder, ChainReader};
+    ///
+    /// let mut reader = ChainReader::new(Builder::default());
+    /// reader.push_reader(Cursor::new(b"@SQ\tSN:sq0\tLN:8\n".to_vec()));
+    /// reader.push_reader(Cursor::new(b"@SQ\tSN:sq0\tLN:8\n".to_vec()));
+    ///
+    /// let header = reader.read_header()?;
+    /// assert_eq!(header.reference_sequences().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<sam::Header> {
+        let source = self.sources.first_mut().ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "chain reader has no sources")
+        })?;
+
+        source
+            .open(&self.builder)
+            .map_err(|e| SourceError::wrap(0, source.path.clone(), e))
+    }
+
+    /// Returns an iterator over records from all sources.
+    ///
+    /// If the header of the first source has not already been read, it is read and validated
+    /// against the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::alignment::io::{reader::Builder, ChainReader};
+    ///
+    /// let data = b"@SQ\tSN:sq0\tLN:8
+    /// r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = ChainReader::new(Builder::default());
+    /// reader.push_reader(Cursor::new(data.to_vec()));
+    /// reader.push_reader(Cursor::new(data.to_vec()));
+    ///
+    /// let header = reader.read_header()?;
+    /// let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+    /// assert_eq!(records.len(), 2);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+    ) -> impl Iterator<Item = io::Result<Box<dyn Record>>> + 'a {
+        let builder = &self.builder;
+
+        self.sources.iter_mut().enumerate().flat_map(
+            move |(i, Source { path, state })| -> Box<dyn Iterator<Item = _> + 'a> {
+                let path = &*path;
+
+                match state.open_with_header(builder, header) {
+                    Ok(reader) => Box::new(reader.records(header).map(move |result| {
+                        result.map_err(|e| SourceError::wrap(i, path.clone(), e))
+                    })),
+                    Err(e) => Box::new(iter::once(Err(SourceError::wrap(i, path.clone(), e)))),
+                }
+            },
+        )
+    }
+}
+
+struct Source {
+    path: Option<PathBuf>,
+    state: State,
+}
+
+impl Source {
+    fn open(&mut self, builder: &Builder) -> io::Result<sam::Header> {
+        self.state.open(builder)
+    }
+}
+
+enum State {
+    Path(PathBuf),
+    Reader(Box<dyn Read>),
+    Open(Reader<Box<dyn BufRead>>),
+    Failed,
+}
+
+impl State {
+    fn open(&mut self, builder: &Builder) -> io::Result<sam::Header> {
+        let mut reader = match mem::replace(self, Self::Failed) {
+            Self::Path(path) => builder.clone().build_from_path(path)?,
+            Self::Reader(reader) => builder.clone().build_from_reader(reader)?,
+            Self::Open(reader) => {
+                *self = Self::Open(reader);
+
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "source is already open",
+                ));
+            }
+            Self::Failed => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "source failed to open",
+                ))
+            }
+        };
+
+        let header = reader.read_header()?;
+        *self = Self::Open(reader);
+
+        Ok(header)
+    }
+
+    fn open_with_header(
+        &mut self,
+        builder: &Builder,
+        header: &sam::Header,
+    ) -> io::Result<&mut Reader<Box<dyn BufRead>>> {
+        if !matches!(self, Self::Open(_)) {
+            let source_header = self.open(builder)?;
+
+            if let Err(e) = validate_header(header, &source_header) {
+                *self = Self::Failed;
+                return Err(e);
+            }
+        }
+
+        match self {
+            Self::Open(reader) => Ok(reader),
+            _ => unreachable!(),
+        }
+    }
+}
+
+fn validate_header(header: &sam::Header, source_header: &sam::Header) -> io::Result<()> {
+    let reference_sequences = header.reference_sequences();
+    let source_reference_sequences = source_header.reference_sequences();
+
+    let is_compatible = reference_sequences.len() == source_reference_sequences.len()
+        && reference_sequences
+            .iter()
+            .zip(source_reference_sequences)
+            .all(|((name, map), (source_name, source_map))| {
+                name == source_name && map.length() == source_map.length()
+            });
+
+    if is_compatible {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "incompatible header: reference sequences do not match",
+        ))
+    }
+}
+
+/// An error returned when a chain reader source fails.
+///
+/// This is the inner error of the [`io::Error`] returned by a [`ChainReader`].
+#[derive(Debug)]
+pub struct SourceError {
+    index: usize,
+    path: Option<PathBuf>,
+    source: io::Error,
+}
+
+impl SourceError {
+    fn wrap(index: usize, path: Option<PathBuf>, source: io::Error) -> io::Error {
+        let kind = source.kind();
+        io::Error::new(
+            kind,
+            Self {
+                index,
+                path,
+                source,
+            },
+        )
+    }
+
+    /// Returns the index of the source.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the path of the source, if the source was added from a path.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+impl error::Error for SourceError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for SourceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "source {}", self.index)?;
+
+        if let Some(path) = &self.path {
+            write!(f, " ({})", path.display())?;
+        }
+
+        write!(f, ": {}", self.source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    fn source_error(e: &io::Error) -> Option<&SourceError> {
+        e.get_ref().and_then(|e| e.downcast_ref())
+    }
+
+    #[test]
+    fn test_records() -> io::Result<()> {
+        let mut reader = ChainReader::new(Builder::default());
+        reader.push_reader(Cursor::new(
+            b"@SQ\tSN:sq0\tLN:8\nr0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n".to_vec(),
+        ));
+        reader.push_reader(Cursor::new(b"@SQ\tSN:sq0\tLN:8\n".to_vec()));
+        reader.push_reader(Cursor::new(
+            b"@SQ\tSN:sq0\tLN:8\nr1\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\nr2\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n"
+                .to_vec(),
+        ));
+
+        let header = reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.name().map(|name| name.as_bytes().to_vec())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            names,
+            [
+                Some(b"r0".to_vec()),
+                Some(b"r1".to_vec()),
+                Some(b"r2".to_vec())
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_incompatible_header() -> io::Result<()> {
+        let mut reader = ChainReader::new(Builder::default());
+        reader.push_reader(Cursor::new(
+            b"@SQ\tSN:sq0\tLN:8\nr0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n".to_vec(),
+        ));
+        reader.push_reader(Cursor::new(b"@SQ\tSN:sq0\tLN:13\n".to_vec()));
+
+        let header = reader.read_header()?;
+        let mut records = reader.records(&header);
+
+        assert!(records.next().transpose()?.is_some());
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+                && source_error(&e).map(|e| e.index()) == Some(1)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_missing_path() {
+        let mut reader = ChainReader::from_paths(["/noodles/missing.bam"]);
+
+        let e = reader.read_header().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::NotFound);
+
+        let source_error = source_error(&e);
+        assert_eq!(source_error.map(|e| e.index()), Some(0));
+        assert_eq!(
+            source_error.and_then(|e| e.path()),
+            Some(Path::new("/noodles/missing.bam"))
+        );
+    }
+
+    #[test]
+    fn test_read_header_with_no_sources() {
+        let mut reader = ChainReader::new(Builder::default());
+
+        assert!(matches!(
+            reader.read_header(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -4,12 +4,16 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, Read, Seek};
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Seek, SeekFrom},
+};
 
 use noodles_bam as bam;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_cram as cram;
+use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::Record};
 
 /// An indexed alignment reader.
@@ -36,6 +40,26 @@ where
     }
 
     /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// Records are read lazily, so the first `n` records (`head`) are
+    /// `reader.records(&header).take(n)`, which reads nothing after the `n`th record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.records(&header).take(10) {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
     pub fn records<'r, 'h: 'r>(
         &'r mut self,
         header: &'h sam::Header,
@@ -108,4 +132,348 @@ where
 
         Ok(records)
     }
+
+    /// Returns the last `n` records.
+    ///
+    /// This is `tail` for alignment records. The stream must be positioned at the first record,
+    /// i.e., directly after reading the header, and it requires a seekable source.
+    ///
+    /// Rather than reading the entire file, this seeks near the end of the file and buffers the
+    /// last `n` records read from there to EOF. The start is taken from the index: for SAM and
+    /// BAM, the start of the unplaced, unmapped records (see
+    /// [`BinningIndex::unplaced_unmapped_record_start_position`]) and the first record in the last
+    /// linear bin; and for CRAM, the last container. If fewer than `n` records follow a start, the
+    /// next earlier one is tried, ending with the first record. The result is therefore exact,
+    /// i.e., the last `min(n, record count)` records in file order, but if `n` exceeds the
+    /// records after the indexed starts, the entire file is read.
+    ///
+    /// The stream position is undefined afterward.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// for record in reader.tail(&header, 10)? {
+    ///     // ...
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn tail(&mut self, header: &sam::Header, n: usize) -> io::Result<Vec<Box<dyn Record>>> {
+        if n == 0 {
+            return Ok(Vec::new());
+        }
+
+        match self {
+            Self::Sam(reader) => {
+                let start = reader.get_ref().virtual_position();
+                let candidates = binning_index_tail_starts(reader.index(), start);
+
+                for pos in candidates {
+                    reader.get_mut().seek(pos)?;
+
+                    let records = last_records(reader.records(), n)?;
+
+                    if records.len() == n || pos == start {
+                        return Ok(records);
+                    }
+                }
+            }
+            Self::Bam(reader) => {
+                let start = reader.get_ref().virtual_position();
+                let candidates = binning_index_tail_starts(reader.index(), start);
+
+                for pos in candidates {
+                    reader.get_mut().seek(pos)?;
+
+                    let records = last_records(reader.records(), n)?;
+
+                    if records.len() == n || pos == start {
+                        return Ok(records);
+                    }
+                }
+            }
+            Self::Cram(reader) => {
+                let start = reader.get_mut().stream_position()?;
+
+                let mut candidates: Vec<_> = reader
+                    .index()
+                    .iter()
+                    .map(|record| record.offset())
+                    .max()
+                    .filter(|&pos| pos > start)
+                    .into_iter()
+                    .collect();
+
+                candidates.push(start);
+
+                for pos in candidates {
+                    reader.get_mut().seek(SeekFrom::Start(pos))?;
+
+                    let records = reader.records(header).map(|result| {
+                        result.and_then(|record| record.try_into_alignment_record(header))
+                    });
+
+                    let records = last_records(records, n)?;
+
+                    if records.len() == n || pos == start {
+                        return Ok(records);
+                    }
+                }
+            }
+        }
+
+        unreachable!("the start position is always a candidate")
+    }
+}
+
+// Returns the positions to scan from for the tail, in descending order, ending with `start`.
+fn binning_index_tail_starts(
+    index: &dyn BinningIndex,
+    start: bgzf::VirtualPosition,
+) -> Vec<bgzf::VirtualPosition> {
+    let mut candidates: Vec<_> = [
+        index.unplaced_unmapped_record_start_position(),
+        index.last_first_record_start_position(),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|&pos| pos > start)
+    .collect();
+
+    candidates.sort_unstable_by(|a, b| b.cmp(a));
+    candidates.dedup();
+    candidates.push(start);
+
+    candidates
+}
+
+fn last_records<I, R>(records: I, n: usize) -> io::Result<Vec<Box<dyn Record>>>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record + 'static,
+{
+    let mut buf = VecDeque::with_capacity(n);
+
+    for result in records {
+        let record = result?;
+
+        if buf.len() == n {
+            buf.pop_front();
+        }
+
+        buf.push_back(Box::new(record) as Box<dyn Record>);
+    }
+
+    Ok(buf.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io::Cursor, num::NonZeroUsize, rc::Rc};
+
+    use noodles_core::Position;
+    use noodles_csi::{self as csi, binning_index::index::reference_sequence::index::LinearIndex};
+    use noodles_sam::{
+        alignment::{
+            io::Write as _,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{self, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    const MAPPED_RECORD_COUNT: usize = 8;
+    const UNMAPPED_RECORD_COUNT: usize = 3;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(map::header::tag::SORT_ORDER, "coordinate")
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 20)?),
+            )
+            .build())
+    }
+
+    fn build_bam() -> Result<(sam::Header, Vec<u8>), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut writer = bam::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..MAPPED_RECORD_COUNT {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}").into_bytes().into())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1 + (i << 16))?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        for i in 0..UNMAPPED_RECORD_COUNT {
+            let record = RecordBuf::builder()
+                .set_name(format!("u{i}").into_bytes().into())
+                .set_sequence(b"ACGT".to_vec().into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        Ok((header, writer.into_inner().into_inner()))
+    }
+
+    fn tail_names<R>(reader: &mut IndexedReader<R>, n: usize) -> io::Result<Vec<Vec<u8>>>
+    where
+        R: Read + Seek,
+    {
+        let header = reader.read_header()?;
+
+        reader.tail(&header, n).map(|records| {
+            records
+                .iter()
+                .map(|record| record.name().map(|name| name.as_bytes().to_vec()))
+                .collect::<Option<_>>()
+                .unwrap_or_default()
+        })
+    }
+
+    #[test]
+    fn test_tail() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, src) = build_bam()?;
+        let index = bam::index_from_reader(&src[..])?;
+
+        let build_reader = || {
+            IndexedReader::Bam(bam::io::IndexedReader::new(
+                Cursor::new(src.clone()),
+                index.clone(),
+            ))
+        };
+
+        assert!(tail_names(&mut build_reader(), 0)?.is_empty());
+
+        let actual = tail_names(&mut build_reader(), 2)?;
+        assert_eq!(actual, [b"u1".to_vec(), b"u2".to_vec()]);
+
+        let actual = tail_names(&mut build_reader(), 4)?;
+        let expected = [
+            b"r7".to_vec(),
+            b"u0".to_vec(),
+            b"u1".to_vec(),
+            b"u2".to_vec(),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = tail_names(&mut build_reader(), 64)?;
+        assert_eq!(actual.len(), MAPPED_RECORD_COUNT + UNMAPPED_RECORD_COUNT);
+        assert_eq!(actual[0], b"r0");
+
+        Ok(())
+    }
+
+    // A reader that records the positions it seeks to.
+    struct SeekRecorder {
+        inner: Cursor<Vec<u8>>,
+        positions: Rc<RefCell<Vec<u64>>>,
+    }
+
+    impl Read for SeekRecorder {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.inner.read(buf)
+        }
+    }
+
+    impl Seek for SeekRecorder {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            let position = self.inner.seek(pos)?;
+            self.positions.borrow_mut().push(position);
+            Ok(position)
+        }
+    }
+
+    #[test]
+    fn test_tail_without_unplaced_unmapped_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut writer = bam::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        // Each record is larger than half a BGZF block, so the records span several blocks.
+        const READ_LENGTH: usize = 1 << 15;
+
+        for i in 0..MAPPED_RECORD_COUNT {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}").into_bytes().into())
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1 + (i << 16))?)
+                .set_cigar([Op::new(Kind::Match, READ_LENGTH)].into_iter().collect())
+                .set_sequence(vec![b'A'; READ_LENGTH].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+        let index = bam::index_from_reader(&src[..])?;
+
+        // The unplaced, unmapped record start is EOF, so the next candidate is the start of the
+        // first record in the last linear bin, i.e., the last record.
+        let last_record_start = index.last_first_record_start_position().unwrap();
+
+        let positions = Rc::new(RefCell::new(Vec::new()));
+
+        let inner = SeekRecorder {
+            inner: Cursor::new(src),
+            positions: positions.clone(),
+        };
+
+        let mut reader = IndexedReader::Bam(bam::io::IndexedReader::new(inner, index));
+
+        let actual = tail_names(&mut reader, 1)?;
+        assert_eq!(actual, [b"r7".to_vec()]);
+
+        assert!(!positions.borrow().is_empty());
+        assert!(positions
+            .borrow()
+            .iter()
+            .all(|&position| position >= last_record_start.compressed()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tail_without_index_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let (_, src) = build_bam()?;
+        let index = csi::binning_index::Index::<LinearIndex>::default();
+
+        let mut reader = IndexedReader::Bam(bam::io::IndexedReader::new(Cursor::new(src), index));
+
+        let actual = tail_names(&mut reader, 1)?;
+        assert_eq!(actual, [b"u2".to_vec()]);
+
+        Ok(())
+    }
 }
@@ -14,7 +14,7 @@ use super::Reader;
 use crate::alignment::io::{CompressionMethod, Format};
 
 /// An alignment reader builder.
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct Builder {
     compression_method: Option<Option<CompressionMethod>>,
     format: Option<Format>,
@@ -103,6 +103,12 @@ impl Builder {
     /// By default, the format will be autodetected. This can be overridden by using
     /// [`Self::set_format`].
     ///
+    /// Detection is by content, not by name, so this works with streams, e.g., stdin or sockets.
+    /// The input is buffered, and the first bytes are peeked without being consumed. A gzip magic
+    /// number sets the compression method to BGZF, and the first block is inflated to check for
+    /// the BAM magic number (`BAM\x01`). Otherwise, the CRAM (`CRAM`) and BAM magic numbers are
+    /// checked. Any other input is read as SAM.
+    ///
     /// # Examples
     ///
     /// ```
@@ -170,17 +176,9 @@ pub(crate) fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<
 where
     R: BufRead,
 {
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-
-    let src = reader.fill_buf()?;
-
-    if let Some(buf) = src.get(..GZIP_MAGIC_NUMBER.len()) {
-        if buf == GZIP_MAGIC_NUMBER {
-            return Ok(Some(CompressionMethod::Bgzf));
-        }
-    }
-
-    Ok(None)
+    // Any gzip stream is read as BGZF.
+    bgzf::io::detect_compression_method(reader)
+        .map(|method| method.map(|_| CompressionMethod::Bgzf))
 }
 
 pub(crate) fn detect_format<R>(
@@ -198,9 +196,11 @@ where
     let src = reader.fill_buf()?;
 
     if matches!(compression_method, Some(CompressionMethod::Bgzf)) {
-        let mut decoder = MultiGzDecoder::new(src);
-        let mut buf = [0; BAM_MAGIC_NUMBER.len()];
-        decoder.read_exact(&mut buf)?;
+        // An empty or short stream, e.g., bgzipped SAM with only an EOF block, is not BAM.
+        let mut buf = Vec::with_capacity(BAM_MAGIC_NUMBER.len());
+        MultiGzDecoder::new(src)
+            .take(BAM_MAGIC_NUMBER.len() as u64)
+            .read_to_end(&mut buf)?;
 
         if buf == BAM_MAGIC_NUMBER {
             return Ok(Format::Bam);
@@ -263,6 +263,53 @@ mod tests {
 
         t(b"CRAM", Format::Cram, None);
 
+        let src = bgzf::Writer::new(Vec::new()).finish()?;
+        t(&src, Format::Sam, Some(CompressionMethod::Bgzf));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_bgzf_compressed_stream() -> io::Result<()> {
+        use std::io::Write;
+
+        use sam::alignment::io::Write as _;
+
+        let header = sam::Header::builder()
+            .set_header(Default::default())
+            .build();
+        let record = sam::alignment::RecordBuf::default();
+
+        let mut writer = bam::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        let src = writer.into_inner().finish()?;
+
+        // A non-seekable source.
+        struct Stream(io::Cursor<Vec<u8>>);
+
+        impl Read for Stream {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.0.read(buf)
+            }
+        }
+
+        let mut reader = Builder::default().build_from_reader(Stream(io::Cursor::new(src)))?;
+        let actual_header = reader.read_header()?;
+        assert_eq!(actual_header, header);
+
+        let mut records = reader.records(&actual_header);
+        assert!(records.next().transpose()?.is_some());
+        assert!(records.next().is_none());
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@HD\tVN:1.6\n")?;
+        let src = writer.finish()?;
+
+        let mut reader = Builder::default().build_from_reader(Stream(io::Cursor::new(src)))?;
+        let header = reader.read_header()?;
+        assert!(header.header().is_some());
+
         Ok(())
     }
 }
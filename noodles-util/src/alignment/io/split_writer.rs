@@ -0,0 +1,572 @@
+//! Alignment split writer.
+
+pub mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::Format;
+
+const REFERENCE_SEQUENCE_NAME_PLACEHOLDER: &str = "{ref}";
+
+/// An alignment writer that splits records by reference sequence.
+///
+/// This is the core of `bamtools split -reference`. Each record is written to the output of its
+/// reference sequence, and records without a reference sequence (i.e., unplaced unmapped records)
+/// are written to a separate output. Placed unmapped records are written to the output of their
+/// reference sequence.
+///
+/// An output file is created when its first record is written. Each output gets the full header,
+/// so it is a complete SAM or BAM file on its own.
+///
+/// A call to [`Self::finish`] must be made before the writer is dropped. This finalizes all
+/// outputs, e.g., writes the BGZF EOF marker of each BAM file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::io::split_writer;
+///
+/// let header = sam::Header::default();
+///
+/// let mut writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+/// writer.write_record(&RecordBuf::default())?;
+/// writer.finish()?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct SplitWriter {
+    header: sam::Header,
+    format: Format,
+    max_open_files: Option<NonZeroUsize>,
+    files: Box<dyn Files>,
+    // One output per reference sequence, followed by the unmapped output.
+    outputs: Vec<Output>,
+    open_file_count: usize,
+    clock: u64,
+    is_finished: bool,
+}
+
+impl SplitWriter {
+    /// Returns the SAM header written to each output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::io::split_writer;
+    ///
+    /// let header = sam::Header::default();
+    /// let writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+    /// assert!(writer.header().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Returns the paths of the files that have been created.
+    ///
+    /// These are in the order of the reference sequences in the header, followed by the path of
+    /// the unmapped output, if records were written to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::io::split_writer;
+    ///
+    /// let header = sam::Header::default();
+    /// let writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+    /// assert_eq!(writer.paths().count(), 0);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.outputs
+            .iter()
+            .filter(|output| output.is_created)
+            .map(|output| output.path.as_path())
+    }
+
+    /// Writes an alignment record.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record reference sequence ID is not in the header or the
+    /// writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::io::split_writer;
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let mut writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+    /// writer.write_record(&RecordBuf::default())?;
+    /// writer.finish()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: Record,
+    {
+        if self.is_finished {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "split writer is finished",
+            ));
+        }
+
+        let reference_sequence_count = self.header.reference_sequences().len();
+
+        let i = match record.reference_sequence_id(&self.header).transpose()? {
+            Some(id) if id < reference_sequence_count => id,
+            Some(id) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "invalid reference sequence ID: expected < {reference_sequence_count}, got {id}"
+                    ),
+                ))
+            }
+            None => reference_sequence_count,
+        };
+
+        self.open(i)?;
+
+        self.clock += 1;
+
+        let output = &mut self.outputs[i];
+        output.last_used = self.clock;
+
+        let writer = output.writer.as_mut().expect("output is not open");
+        writer.write_record(&self.header, record)
+    }
+
+    /// Finishes all outputs.
+    ///
+    /// Each output that was closed because of the open file limit is reopened to be finalized.
+    ///
+    /// The outputs are only finalized once. Calling this again does nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::io::split_writer;
+    ///
+    /// let header = sam::Header::default();
+    /// let mut writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+    /// writer.finish()?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(&mut self) -> io::Result<()> {
+        if self.is_finished {
+            return Ok(());
+        }
+
+        self.is_finished = true;
+
+        for output in self.outputs.iter_mut().filter(|output| output.is_created) {
+            let writer = match output.writer.take() {
+                Some(writer) => {
+                    self.open_file_count -= 1;
+                    writer
+                }
+                None => output.open(&*self.files, self.format, &self.header)?,
+            };
+
+            writer.finish()?;
+        }
+
+        Ok(())
+    }
+
+    fn open(&mut self, i: usize) -> io::Result<()> {
+        if self.outputs[i].writer.is_some() {
+            return Ok(());
+        }
+
+        if let Some(max_open_files) = self.max_open_files {
+            if self.open_file_count >= max_open_files.get() {
+                self.close_least_recently_used()?;
+            }
+        }
+
+        let writer = self.outputs[i].open(&*self.files, self.format, &self.header)?;
+        self.outputs[i].writer = Some(writer);
+        self.open_file_count += 1;
+
+        Ok(())
+    }
+
+    fn close_least_recently_used(&mut self) -> io::Result<()> {
+        let output = self
+            .outputs
+            .iter_mut()
+            .filter(|output| output.writer.is_some())
+            .min_by_key(|output| output.last_used);
+
+        if let Some(writer) = output.and_then(|output| output.writer.take()) {
+            self.open_file_count -= 1;
+            writer.close()?;
+        }
+
+        Ok(())
+    }
+}
+
+type OutputFile = Box<dyn Write + Send>;
+
+// Creates and opens output files.
+trait Files: Send {
+    fn create(&self, path: &Path) -> io::Result<OutputFile>;
+    fn append(&self, path: &Path) -> io::Result<OutputFile>;
+}
+
+struct FileSystem;
+
+impl Files for FileSystem {
+    fn create(&self, path: &Path) -> io::Result<OutputFile> {
+        File::create(path).map(|file| Box::new(file) as OutputFile)
+    }
+
+    fn append(&self, path: &Path) -> io::Result<OutputFile> {
+        OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map(|file| Box::new(file) as OutputFile)
+    }
+}
+
+struct Output {
+    path: PathBuf,
+    writer: Option<OutputWriter>,
+    is_created: bool,
+    last_used: u64,
+}
+
+impl Output {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            writer: None,
+            is_created: false,
+            last_used: 0,
+        }
+    }
+
+    // Creates the file and writes the header, or opens an existing file for appending.
+    fn open(
+        &mut self,
+        files: &dyn Files,
+        format: Format,
+        header: &sam::Header,
+    ) -> io::Result<OutputWriter> {
+        if self.is_created {
+            let file = files.append(&self.path)?;
+            return Ok(OutputWriter::new(format, file));
+        }
+
+        let file = files.create(&self.path)?;
+        self.is_created = true;
+
+        let mut writer = OutputWriter::new(format, file);
+        writer.write_header(header)?;
+
+        Ok(writer)
+    }
+}
+
+enum OutputWriter {
+    Sam(sam::io::Writer<BufWriter<OutputFile>>),
+    Bam(bam::io::Writer<bgzf::Writer<OutputFile>>),
+}
+
+impl OutputWriter {
+    fn new(format: Format, file: OutputFile) -> Self {
+        match format {
+            Format::Sam => Self::Sam(sam::io::Writer::new(BufWriter::new(file))),
+            Format::Bam => Self::Bam(bam::io::Writer::new(file)),
+            Format::Cram => unreachable!("CRAM is not supported"),
+        }
+    }
+
+    fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        match self {
+            Self::Sam(writer) => writer.write_header(header),
+            Self::Bam(writer) => writer.write_header(header),
+        }
+    }
+
+    fn write_record<R>(&mut self, header: &sam::Header, record: &R) -> io::Result<()>
+    where
+        R: Record,
+    {
+        use sam::alignment::io::Write;
+
+        match self {
+            Self::Sam(writer) => writer.write_alignment_record(header, record),
+            Self::Bam(writer) => writer.write_alignment_record(header, record),
+        }
+    }
+
+    // Flushes and closes the file without finalizing the output, so it can be appended to.
+    //
+    // For BAM, this ends the current BGZF block but does not write the EOF marker.
+    fn close(self) -> io::Result<()> {
+        match self {
+            Self::Sam(writer) => writer.into_inner().flush(),
+            Self::Bam(writer) => {
+                let mut inner = writer.into_inner();
+                inner.flush()?;
+                drop(inner.into_inner());
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(self) -> io::Result<()> {
+        match self {
+            Self::Sam(writer) => writer.into_inner().flush(),
+            Self::Bam(writer) => writer.into_inner().finish().map(drop),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        sync::{Arc, Mutex},
+    };
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{record::Flags, RecordBuf},
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    // In-memory output files.
+    #[derive(Clone, Default)]
+    struct MemoryFiles(Arc<Mutex<HashMap<PathBuf, Vec<u8>>>>);
+
+    impl MemoryFiles {
+        fn get(&self, path: &Path) -> Option<Vec<u8>> {
+            self.0.lock().unwrap().get(path).cloned()
+        }
+
+        fn open(&self, path: &Path) -> OutputFile {
+            Box::new(MemoryFile {
+                files: self.clone(),
+                path: path.into(),
+            })
+        }
+    }
+
+    impl Files for MemoryFiles {
+        fn create(&self, path: &Path) -> io::Result<OutputFile> {
+            self.0.lock().unwrap().insert(path.into(), Vec::new());
+            Ok(self.open(path))
+        }
+
+        fn append(&self, path: &Path) -> io::Result<OutputFile> {
+            if self.0.lock().unwrap().contains_key(path) {
+                Ok(self.open(path))
+            } else {
+                Err(io::Error::from(io::ErrorKind::NotFound))
+            }
+        }
+    }
+
+    struct MemoryFile {
+        files: MemoryFiles,
+        path: PathBuf,
+    }
+
+    impl Write for MemoryFile {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let mut files = self.files.0.lock().unwrap();
+            let file = files
+                .get_mut(&self.path)
+                .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))?;
+            file.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn build_record(name: &str, reference_sequence_id: Option<usize>) -> RecordBuf {
+        let mut builder = RecordBuf::builder().set_name(name.as_bytes().into());
+
+        if let Some(id) = reference_sequence_id {
+            builder = builder
+                .set_reference_sequence_id(id)
+                .set_alignment_start(Position::MIN);
+        } else {
+            builder = builder.set_flags(Flags::UNMAPPED);
+        }
+
+        builder.build()
+    }
+
+    fn read_names(src: &[u8], header: &sam::Header) -> io::Result<Vec<String>> {
+        let mut reader = bam::io::Reader::new(src);
+
+        // The BAM writer adds a default header line.
+        let mut expected_header = header.clone();
+        *expected_header.header_mut() = Some(Default::default());
+        assert_eq!(reader.read_header()?, expected_header);
+
+        reader
+            .record_bufs(header)
+            .map(|result| {
+                result.map(|record| {
+                    String::from_utf8_lossy(record.name().unwrap().as_ref()).into_owned()
+                })
+            })
+            .collect()
+    }
+
+    fn run(max_open_files: Option<NonZeroUsize>) -> io::Result<()> {
+        let header = build_header();
+
+        let mut builder = Builder::default();
+
+        if let Some(n) = max_open_files {
+            builder = builder.set_max_open_files(n);
+        }
+
+        let files = MemoryFiles::default();
+        let mut writer = builder.build("out.{ref}.bam", header.clone())?;
+        writer.files = Box::new(files.clone());
+
+        for (name, reference_sequence_id) in [
+            ("r0", Some(0)),
+            ("r1", Some(2)),
+            ("r2", None),
+            ("r3", Some(0)),
+            ("r4", Some(2)),
+            ("r5", None),
+            ("r6", Some(0)),
+        ] {
+            writer.write_record(&build_record(name, reference_sequence_id))?;
+        }
+
+        writer.finish()?;
+
+        let paths: Vec<_> = writer.paths().collect();
+        assert_eq!(
+            paths,
+            [
+                Path::new("out.sq0.bam"),
+                Path::new("out.sq2.bam"),
+                Path::new("out.unmapped.bam"),
+            ]
+        );
+
+        assert!(files.get(Path::new("out.sq1.bam")).is_none());
+
+        let data: Vec<_> = paths.iter().map(|path| files.get(path).unwrap()).collect();
+
+        assert_eq!(read_names(&data[0], &header)?, ["r0", "r3", "r6"]);
+        assert_eq!(read_names(&data[1], &header)?, ["r1", "r4"]);
+        assert_eq!(read_names(&data[2], &header)?, ["r2", "r5"]);
+
+        // The last block of each output is the BGZF EOF marker.
+        for buf in &data {
+            assert!(buf.ends_with(&[
+                0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+                0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ]));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record() -> io::Result<()> {
+        run(None)
+    }
+
+    #[test]
+    fn test_write_record_with_max_open_files() -> io::Result<()> {
+        run(NonZeroUsize::new(1))
+    }
+
+    #[test]
+    fn test_finish() -> io::Result<()> {
+        let files = MemoryFiles::default();
+        let mut writer = Builder::default().build("out.{ref}.bam", build_header())?;
+        writer.files = Box::new(files.clone());
+
+        writer.write_record(&build_record("r0", Some(0)))?;
+        writer.finish()?;
+
+        let path = Path::new("out.sq0.bam");
+        let expected = files.get(path);
+
+        // The output is not finalized again.
+        writer.finish()?;
+        assert_eq!(files.get(path), expected);
+
+        assert!(matches!(
+            writer.write_record(&build_record("r1", Some(0))),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_invalid_reference_sequence_id() -> io::Result<()> {
+        let files = MemoryFiles::default();
+        let mut writer = Builder::default().build("out.{ref}.sam", build_header())?;
+        writer.files = Box::new(files.clone());
+
+        assert!(matches!(
+            writer.write_record(&build_record("r0", Some(3))),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        writer.finish()?;
+        assert_eq!(writer.paths().count(), 0);
+        assert!(files.0.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+}
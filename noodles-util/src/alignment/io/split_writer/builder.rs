@@ -0,0 +1,291 @@
+//! Alignment split writer builder.
+
+use std::{collections::HashSet, io, num::NonZeroUsize, path::Path};
+
+use noodles_sam as sam;
+
+use super::{FileSystem, Output, SplitWriter, REFERENCE_SEQUENCE_NAME_PLACEHOLDER};
+use crate::alignment::io::Format;
+
+const DEFAULT_UNMAPPED_NAME: &str = "unmapped";
+
+/// An alignment split writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    format: Option<Format>,
+    max_open_files: Option<NonZeroUsize>,
+    unmapped_name: Option<String>,
+}
+
+impl Builder {
+    /// Sets the format of the outputs.
+    ///
+    /// Only SAM and BAM are supported. If not set, the format is detected from the path template
+    /// extension, or SAM is used if it cannot be detected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::io::{split_writer, Format};
+    /// let builder = split_writer::Builder::default().set_format(Format::Bam);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the maximum number of open files.
+    ///
+    /// When a record is written to an output that is not open and the limit is reached, the least
+    /// recently used output is closed. It is reopened (appended to) when it is written to again.
+    /// By default, there is no limit, i.e., each output stays open until the writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::io::split_writer;
+    /// let builder = split_writer::Builder::default()
+    ///     .set_max_open_files(NonZeroUsize::try_from(64)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_max_open_files(mut self, max_open_files: NonZeroUsize) -> Self {
+        self.max_open_files = Some(max_open_files);
+        self
+    }
+
+    /// Sets the name used in the path of the output for unplaced unmapped records.
+    ///
+    /// By default, this is `unmapped`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::io::split_writer;
+    /// let builder = split_writer::Builder::default().set_unmapped_name("unplaced");
+    /// ```
+    pub fn set_unmapped_name<N>(mut self, unmapped_name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.unmapped_name = Some(unmapped_name.into());
+        self
+    }
+
+    /// Builds an alignment split writer.
+    ///
+    /// The path template must contain the placeholder `{ref}`, which is replaced by the reference
+    /// sequence name (or the unmapped name) to build the path of each output, e.g., `out.{ref}.bam`
+    /// writes the records on `sq0` to `out.sq0.bam`. No files are created until a record is
+    /// written.
+    ///
+    /// Path separators (`/` and `\`) in a name are replaced by `_`, so each output is in the
+    /// directory given by the path template, e.g., the records on `chrUn/1` are written to
+    /// `out.chrUn_1.bam`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the path template does not contain `{ref}`, the format is CRAM, the
+    /// unmapped name is also a reference sequence name, or two outputs have the same path after
+    /// replacing path separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::io::split_writer;
+    ///
+    /// let header = sam::Header::default();
+    /// let writer = split_writer::Builder::default().build("out.{ref}.bam", header)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build<S>(self, path_template: S, header: sam::Header) -> io::Result<SplitWriter>
+    where
+        S: AsRef<str>,
+    {
+        let path_template = path_template.as_ref();
+
+        if !path_template.contains(REFERENCE_SEQUENCE_NAME_PLACEHOLDER) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("path template is missing {REFERENCE_SEQUENCE_NAME_PLACEHOLDER}"),
+            ));
+        }
+
+        let format = self
+            .format
+            .or_else(|| detect_format_from_path_extension(path_template))
+            .unwrap_or(Format::Sam);
+
+        if format == Format::Cram {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "CRAM is not supported",
+            ));
+        }
+
+        let unmapped_name = self
+            .unmapped_name
+            .unwrap_or_else(|| DEFAULT_UNMAPPED_NAME.into());
+
+        if header
+            .reference_sequences()
+            .contains_key(unmapped_name.as_bytes())
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unmapped name is a reference sequence name: {unmapped_name}"),
+            ));
+        }
+
+        let mut paths = HashSet::new();
+
+        let mut build_output = |name: &str| {
+            let path =
+                path_template.replace(REFERENCE_SEQUENCE_NAME_PLACEHOLDER, &escape_name(name));
+
+            if paths.insert(path.clone()) {
+                Ok(Output::new(path.into()))
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("duplicate output path: {path}"),
+                ))
+            }
+        };
+
+        let mut outputs = Vec::with_capacity(header.reference_sequences().len() + 1);
+
+        for name in header.reference_sequences().keys() {
+            let name = String::from_utf8_lossy(name);
+            outputs.push(build_output(&name)?);
+        }
+
+        outputs.push(build_output(&unmapped_name)?);
+
+        Ok(SplitWriter {
+            header,
+            format,
+            max_open_files: self.max_open_files,
+            files: Box::new(FileSystem),
+            outputs,
+            open_file_count: 0,
+            clock: 0,
+            is_finished: false,
+        })
+    }
+}
+
+// Replaces path separators, so the name cannot change the directory of the output.
+fn escape_name(name: &str) -> String {
+    name.replace(['/', '\\'], "_")
+}
+
+fn detect_format_from_path_extension<P>(path: P) -> Option<Format>
+where
+    P: AsRef<Path>,
+{
+    match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+        Some("sam") => Some(Format::Sam),
+        Some("bam") => Some(Format::Bam),
+        Some("cram") => Some(Format::Cram),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_build() -> io::Result<()> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8).unwrap()),
+            )
+            .add_reference_sequence(
+                "unmapped",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8).unwrap()),
+            )
+            .build();
+
+        let writer = Builder::default()
+            .set_unmapped_name("unplaced")
+            .build("out.{ref}.bam", header.clone())?;
+
+        assert_eq!(writer.format, Format::Bam);
+
+        let paths: Vec<_> = writer
+            .outputs
+            .iter()
+            .map(|output| output.path.as_path())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                Path::new("out.sq0.bam"),
+                Path::new("out.unmapped.bam"),
+                Path::new("out.unplaced.bam"),
+            ]
+        );
+
+        assert!(matches!(
+            Builder::default().build("out.bam", header.clone()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Builder::default().build("out.{ref}.cram", header.clone()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Builder::default().build("out.{ref}.sam", header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_path_separators_in_names() -> io::Result<()> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "chrUn/1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8).unwrap()),
+            )
+            .add_reference_sequence(
+                "../sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8).unwrap()),
+            )
+            .build();
+
+        let writer = Builder::default().build("out/{ref}.bam", header.clone())?;
+
+        let paths: Vec<_> = writer
+            .outputs
+            .iter()
+            .map(|output| output.path.as_path())
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                Path::new("out/chrUn_1.bam"),
+                Path::new("out/.._sq1.bam"),
+                Path::new("out/unmapped.bam"),
+            ]
+        );
+
+        assert!(matches!(
+            Builder::default()
+                .set_unmapped_name("chrUn_1")
+                .build("out/{ref}.bam", header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
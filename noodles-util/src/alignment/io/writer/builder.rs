@@ -22,6 +22,7 @@ pub struct Builder {
     format: Option<Format>,
     reference_sequence_repository: fasta::Repository,
     block_content_encoder_map: BlockContentEncoderMap,
+    buffer_size: Option<usize>,
 }
 
 impl Builder {
@@ -102,6 +103,23 @@ impl Builder {
         self
     }
 
+    /// Sets the output buffer size.
+    ///
+    /// When building from a path, this is the capacity of the buffer used for the file, for any
+    /// format. When building from a writer, this is only used for uncompressed SAM output (see
+    /// [`sam::io::writer::Builder::set_buffer_size`]). By default, the buffer size is 8 KiB.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    /// let builder = alignment::io::writer::Builder::default().set_buffer_size(1 << 20);
+    /// ```
+    pub fn set_buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = Some(buffer_size);
+        self
+    }
+
     /// Builds an alignment writer from a path.
     ///
     /// If the format or compression method is not set, it is detected from the path extension.
@@ -129,8 +147,14 @@ impl Builder {
             self.format = detect_format_from_path_extension(src);
         }
 
+        // The file is already buffered, so the buffer size is not used again for SAM output.
+        let buffer_size = self.buffer_size.take();
+
         File::create(src)
-            .map(BufWriter::new)
+            .map(|file| match buffer_size {
+                Some(buffer_size) => BufWriter::with_capacity(buffer_size, file),
+                None => BufWriter::new(file),
+            })
             .and_then(|writer| self.build_from_writer(writer))
     }
 
@@ -162,7 +186,13 @@ impl Builder {
         };
 
         let inner: Box<dyn sam::alignment::io::Write> = match (format, compression_method) {
-            (Format::Sam, None) => Box::new(sam::io::Writer::new(writer)),
+            (Format::Sam, None) => match self.buffer_size {
+                Some(buffer_size) => Box::new(sam::io::Writer::new(BufWriter::with_capacity(
+                    buffer_size,
+                    writer,
+                ))),
+                None => Box::new(sam::io::Writer::new(writer)),
+            },
             (Format::Sam, Some(CompressionMethod::Bgzf)) => {
                 Box::new(sam::io::Writer::new(bgzf::Writer::new(writer)))
             }
@@ -1,5 +1,13 @@
 //! Composable iterators for alignment records.
 
+#[cfg(feature = "annotation")]
+mod feature_overlaps;
 mod pileup;
+pub mod projection;
+mod records_in_interval;
 
-pub use self::pileup::Pileup as Depth;
+#[cfg(feature = "annotation")]
+pub use self::feature_overlaps::FeatureOverlaps;
+pub use self::{
+    pileup::Pileup as Depth, projection::Projection, records_in_interval::RecordsInInterval,
+};
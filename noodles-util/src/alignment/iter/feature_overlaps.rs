@@ -0,0 +1,429 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_gff as gff;
+use noodles_sam::{alignment::Record, Header};
+
+/// An iterator over records in a coordinate-sorted stream paired with the GFF features they
+/// overlap.
+///
+/// This is a sweep-line merge join of an alignment record stream and a GFF feature stream. Both
+/// streams are read once and in lockstep, and only the active features, i.e., the features that
+/// start at or before the current record end and have not ended before the current record start,
+/// are kept in memory. A feature is added to the active set when the alignment records reach its
+/// start and dropped once a record starts after its end.
+///
+/// Each record is returned with the features that overlap its alignment interval, in feature
+/// stream order. All intervals are 1-based and inclusive.
+///
+/// Both streams must be sorted by the reference sequence order of the header and then by start
+/// position. Records or features that are out of order result in an
+/// [`io::ErrorKind::InvalidData`] error. Features on reference sequences that are not in the
+/// header are skipped.
+///
+/// Records without a reference sequence ID are last in coordinate order and are returned with no
+/// features. Unmapped records that are placed, i.e., that have a reference sequence ID and
+/// alignment start, have a span of 1 at their alignment start.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io, num::NonZeroUsize};
+///
+/// use noodles_core::Position;
+/// use noodles_gff as gff;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             Flags,
+///         },
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::iter::FeatureOverlaps;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+///     )
+///     .build();
+///
+/// let records = [(1, 4), (8, 8)].into_iter().map(|(start, len)| {
+///     Ok(RecordBuf::builder()
+///         .set_flags(Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::try_from(start).unwrap())
+///         .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+///         .build())
+/// });
+///
+/// let features = [(3, 10), (13, 21)].into_iter().map(|(start, end)| {
+///     Ok(gff::Record::builder()
+///         .set_reference_sequence_name(String::from("sq0"))
+///         .set_start(Position::try_from(start).unwrap())
+///         .set_end(Position::try_from(end).unwrap())
+///         .build())
+/// });
+///
+/// let overlaps: Vec<_> = FeatureOverlaps::new(&header, records, features)
+///     .map(|result| {
+///         result.map(|(_, features)| {
+///             features
+///                 .iter()
+///                 .map(|feature| usize::from(feature.start()))
+///                 .collect::<Vec<_>>()
+///         })
+///     })
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(overlaps, [vec![3], vec![3, 13]]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct FeatureOverlaps<'h, I, F> {
+    header: &'h Header,
+    records: I,
+    features: F,
+    pending_feature: Option<(usize, gff::Record)>,
+    active_features: Vec<(usize, gff::Record)>,
+    last_record_key: Option<(usize, Position)>,
+    last_feature_key: Option<(usize, Position)>,
+    is_unplaced: bool,
+    is_done: bool,
+}
+
+impl<'h, I, F, R> FeatureOverlaps<'h, I, F>
+where
+    I: Iterator<Item = io::Result<R>>,
+    F: Iterator<Item = io::Result<gff::Record>>,
+    R: Record,
+{
+    /// Creates an iterator over records paired with the GFF features they overlap.
+    ///
+    /// The reference sequence names of the features are resolved using the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use noodles_gff as gff;
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::iter::FeatureOverlaps;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = std::iter::empty::<io::Result<RecordBuf>>();
+    /// let features = std::iter::empty::<io::Result<gff::Record>>();
+    /// let mut overlaps = FeatureOverlaps::new(&header, records, features);
+    ///
+    /// assert!(overlaps.next().is_none());
+    /// ```
+    pub fn new(header: &'h Header, records: I, features: F) -> Self {
+        Self {
+            header,
+            records,
+            features,
+            pending_feature: None,
+            active_features: Vec::new(),
+            last_record_key: None,
+            last_feature_key: None,
+            is_unplaced: false,
+            is_done: false,
+        }
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<(R, Vec<gff::Record>)>> {
+        let Some(record) = self.records.next().transpose()? else {
+            return Ok(None);
+        };
+
+        let Some(reference_sequence_id) = record.reference_sequence_id(self.header).transpose()?
+        else {
+            // Records without a reference sequence ID are last in coordinate order.
+            self.is_unplaced = true;
+            self.active_features.clear();
+            return Ok(Some((record, Vec::new())));
+        };
+
+        let start = record
+            .alignment_start()
+            .transpose()?
+            .unwrap_or(Position::MIN);
+
+        self.check_record_order(reference_sequence_id, start)?;
+
+        let end = record.alignment_end().transpose()?.unwrap_or(start);
+
+        self.active_features
+            .retain(|(id, feature)| *id == reference_sequence_id && feature.end() >= start);
+
+        self.add_active_features(reference_sequence_id, start, end)?;
+
+        let features = self
+            .active_features
+            .iter()
+            .filter(|(_, feature)| feature.start() <= end)
+            .map(|(_, feature)| feature.clone())
+            .collect();
+
+        Ok(Some((record, features)))
+    }
+
+    // Adds the features that start at or before the given end.
+    fn add_active_features(
+        &mut self,
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+    ) -> io::Result<()> {
+        loop {
+            let pending_feature = match self.pending_feature.take() {
+                Some(pending_feature) => pending_feature,
+                None => match self.next_feature()? {
+                    Some(feature) => feature,
+                    None => return Ok(()),
+                },
+            };
+
+            let (id, feature) = &pending_feature;
+
+            if (*id, feature.start()) > (reference_sequence_id, end) {
+                self.pending_feature = Some(pending_feature);
+                return Ok(());
+            }
+
+            // Features on earlier reference sequences or that end before the record are passed.
+            if *id == reference_sequence_id && feature.end() >= start {
+                self.active_features.push(pending_feature);
+            }
+        }
+    }
+
+    fn next_feature(&mut self) -> io::Result<Option<(usize, gff::Record)>> {
+        while let Some(feature) = self.features.next().transpose()? {
+            let Some(reference_sequence_id) = self
+                .header
+                .reference_sequences()
+                .get_index_of(feature.reference_sequence_name().as_bytes())
+            else {
+                continue;
+            };
+
+            let key = (reference_sequence_id, feature.start());
+
+            if is_unsorted(self.last_feature_key, key) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "features are not coordinate-sorted",
+                ));
+            }
+
+            self.last_feature_key = Some(key);
+
+            return Ok(Some((reference_sequence_id, feature)));
+        }
+
+        Ok(None)
+    }
+
+    fn check_record_order(
+        &mut self,
+        reference_sequence_id: usize,
+        start: Position,
+    ) -> io::Result<()> {
+        let key = (reference_sequence_id, start);
+
+        if self.is_unplaced || is_unsorted(self.last_record_key, key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "records are not coordinate-sorted",
+            ));
+        }
+
+        self.last_record_key = Some(key);
+
+        Ok(())
+    }
+}
+
+impl<I, F, R> Iterator for FeatureOverlaps<'_, I, F>
+where
+    I: Iterator<Item = io::Result<R>>,
+    F: Iterator<Item = io::Result<gff::Record>>,
+    R: Record,
+{
+    type Item = io::Result<(R, Vec<gff::Record>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.next_record() {
+            Ok(Some(item)) => Some(Ok(item)),
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+fn is_unsorted(last_key: Option<(usize, Position)>, key: (usize, Position)) -> bool {
+    last_key.map(|last_key| key < last_key).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> Header {
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_record(reference_sequence_id: usize, start: usize, len: usize) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::new(start).unwrap())
+            .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+            .build()
+    }
+
+    fn build_feature(reference_sequence_name: &str, start: usize, end: usize) -> gff::Record {
+        gff::Record::builder()
+            .set_reference_sequence_name(reference_sequence_name.into())
+            .set_start(Position::new(start).unwrap())
+            .set_end(Position::new(end).unwrap())
+            .build()
+    }
+
+    type Overlaps = Vec<(Option<usize>, Vec<(String, usize)>)>;
+
+    fn join(records: Vec<RecordBuf>, features: Vec<gff::Record>) -> io::Result<Overlaps> {
+        let header = build_header();
+
+        FeatureOverlaps::new(
+            &header,
+            records.into_iter().map(Ok),
+            features.into_iter().map(Ok),
+        )
+        .map(|result| {
+            result.map(|(record, features)| {
+                let start = record.alignment_start().map(usize::from);
+
+                let features = features
+                    .iter()
+                    .map(|feature| {
+                        (
+                            feature.reference_sequence_name().into(),
+                            usize::from(feature.start()),
+                        )
+                    })
+                    .collect();
+
+                (start, features)
+            })
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records = vec![
+            build_record(0, 1, 100),
+            build_record(0, 50, 10),
+            build_record(0, 150, 10),
+            build_record(1, 1, 10),
+            RecordBuf::default(),
+        ];
+
+        let features = vec![
+            build_feature("sq0", 5, 200),
+            build_feature("sq0", 55, 60),
+            build_feature("sq0", 80, 90),
+            build_feature("sqX", 1, 1000),
+            build_feature("sq1", 1, 5),
+            build_feature("sq1", 11, 20),
+        ];
+
+        assert_eq!(
+            join(records, features)?,
+            [
+                (
+                    Some(1),
+                    vec![
+                        (String::from("sq0"), 5),
+                        (String::from("sq0"), 55),
+                        (String::from("sq0"), 80)
+                    ]
+                ),
+                (
+                    Some(50),
+                    vec![(String::from("sq0"), 5), (String::from("sq0"), 55)]
+                ),
+                (Some(150), vec![(String::from("sq0"), 5)]),
+                (Some(1), vec![(String::from("sq1"), 1)]),
+                (None, vec![]),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() {
+        let records = vec![build_record(0, 13, 4), build_record(0, 8, 4)];
+        assert!(matches!(
+            join(records, Vec::new()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = vec![RecordBuf::default(), build_record(0, 8, 4)];
+        assert!(matches!(
+            join(records, Vec::new()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_next_with_unsorted_features() {
+        let records = vec![build_record(1, 1, 100)];
+        let features = vec![build_feature("sq1", 5, 10), build_feature("sq0", 1, 10)];
+
+        assert!(matches!(
+            join(records, features),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
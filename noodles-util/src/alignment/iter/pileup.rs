@@ -1,11 +1,9 @@
 use std::{collections::VecDeque, io};
 
 use noodles_core::Position;
-use noodles_sam::{
-    self as sam,
-    alignment::{record::Flags, Record},
-    Header,
-};
+use noodles_sam::{self as sam, alignment::Record, Header};
+
+use crate::alignment::RecordFilter;
 
 type ActiveWindowRange = (Position, Position);
 
@@ -25,6 +23,7 @@ enum State {
 pub struct Pileup<'h, I> {
     header: &'h Header,
     records: I,
+    filter: RecordFilter,
     state: State,
     position: Position,
     window: VecDeque<u64>,
@@ -38,10 +37,36 @@ where
     /// Creates a pileup iterator.
     ///
     /// The given iterator must be coordinate-sorted on a single reference sequence.
+    ///
+    /// Records are filtered by [`RecordFilter::depth`].
     pub fn new(header: &'h Header, records: I) -> Self {
+        Self::with_filter(header, records, RecordFilter::depth())
+    }
+
+    /// Creates a pileup iterator with a record filter.
+    ///
+    /// The given iterator must be coordinate-sorted on a single reference sequence. Unmapped
+    /// records are always excluded, regardless of the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::{iter::Depth, RecordFilter};
+    ///
+    /// let header = sam::Header::default();
+    /// let records = std::iter::empty::<io::Result<Box<dyn sam::alignment::Record>>>();
+    /// let filter = RecordFilter::depth().set_exclude_supplementary(true);
+    ///
+    /// let mut depth = Depth::with_filter(&header, records, filter);
+    /// assert!(depth.next().is_none());
+    /// ```
+    pub fn with_filter(header: &'h Header, records: I, filter: RecordFilter) -> Self {
         Self {
             header,
             records,
+            filter: filter.set_exclude_unmapped(true),
             state: State::Empty,
             position: Position::MIN,
             window: VecDeque::new(),
@@ -53,9 +78,8 @@ where
         if self.next_record.is_none() {
             for result in &mut self.records {
                 let record = result?;
-                let flags = record.flags()?;
 
-                if filter(flags) {
+                if !self.filter.matches(&record)? {
                     continue;
                 }
 
@@ -88,9 +112,7 @@ where
         }
 
         while let Some(record) = self.records.next().transpose()? {
-            let flags = record.flags()?;
-
-            if filter(flags) {
+            if !self.filter.matches(&record)? {
                 continue;
             }
 
@@ -180,10 +202,6 @@ where
     }
 }
 
-fn filter(flags: Flags) -> bool {
-    flags.is_unmapped() || flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate()
-}
-
 fn pile_record<R>(
     window: &mut VecDeque<u64>,
     start: Position,
@@ -244,7 +262,7 @@ mod tests {
     use std::num::NonZeroUsize;
 
     use super::*;
-    use sam::alignment::RecordBuf;
+    use sam::alignment::{record::Flags, RecordBuf};
 
     #[test]
     fn test_next() -> Result<(), Box<dyn std::error::Error>> {
@@ -325,4 +343,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            alignment::record::cigar::{op::Kind, Op},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let build_records = || {
+            [Flags::empty(), Flags::SUPPLEMENTARY, Flags::DUPLICATE]
+                .into_iter()
+                .map(|flags| {
+                    RecordBuf::builder()
+                        .set_flags(flags)
+                        .set_reference_sequence_id(0)
+                        .set_alignment_start(Position::MIN)
+                        .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+                        .build()
+                })
+                .map(|record| Ok(Box::new(record) as Box<dyn Record>))
+        };
+
+        let actual: Vec<_> = Pileup::new(&header, build_records()).collect::<Result<_, _>>()?;
+        assert_eq!(actual, [(Position::MIN, 2)]);
+
+        let filter = RecordFilter::depth().set_exclude_supplementary(true);
+        let actual: Vec<_> =
+            Pileup::with_filter(&header, build_records(), filter).collect::<Result<_, _>>()?;
+        assert_eq!(actual, [(Position::MIN, 1)]);
+
+        let filter = RecordFilter::default();
+        let actual: Vec<_> =
+            Pileup::with_filter(&header, build_records(), filter).collect::<Result<_, _>>()?;
+        assert_eq!(actual, [(Position::MIN, 3)]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,254 @@
+//! Alignment record field projection.
+
+use std::io;
+
+use bstr::{BStr, BString};
+use noodles_core::Position;
+use noodles_sam::{
+    alignment::{
+        record::{data::field::Tag, Flags, MappingQuality},
+        record_buf::data::field::Value as DataValue,
+        Record,
+    },
+    Header,
+};
+
+/// An alignment record field selector.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    /// The read name (`QNAME`).
+    Name,
+    /// The flags (`FLAG`).
+    Flags,
+    /// The reference sequence name (`RNAME`).
+    ReferenceSequenceName,
+    /// The alignment start (`POS`).
+    AlignmentStart,
+    /// The mapping quality (`MAPQ`).
+    MappingQuality,
+    /// The mate reference sequence name (`RNEXT`).
+    MateReferenceSequenceName,
+    /// The mate alignment start (`PNEXT`).
+    MateAlignmentStart,
+    /// The template length (`TLEN`).
+    TemplateLength,
+    /// A data field value with the given tag.
+    Data(Tag),
+}
+
+/// A projected alignment record field value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'h> {
+    /// The read name.
+    Name(BString),
+    /// The flags.
+    Flags(Flags),
+    /// A reference sequence name.
+    ReferenceSequenceName(&'h BStr),
+    /// A position.
+    Position(Position),
+    /// The mapping quality.
+    MappingQuality(MappingQuality),
+    /// The template length.
+    TemplateLength(i32),
+    /// A data field value.
+    Data(DataValue),
+}
+
+/// A projected alignment record.
+///
+/// Each value corresponds to the field selector at the same index. A missing value is `None`.
+pub type Row<'h> = Vec<Option<Value<'h>>>;
+
+/// An alignment record field projection iterator.
+///
+/// This takes an iterator of records and emits only the selected fields. Fields that are not
+/// selected are never read, which avoids decoding, e.g., the sequence, quality scores, or data
+/// of lazy records.
+pub struct Projection<'h, I> {
+    header: &'h Header,
+    records: I,
+    fields: Vec<Field>,
+}
+
+impl<'h, I> Projection<'h, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates a field projection iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::iter::{projection::Field, Projection};
+    ///
+    /// let header = sam::Header::default();
+    /// let records = std::iter::empty::<io::Result<Box<dyn sam::alignment::Record>>>();
+    /// let mut projection = Projection::new(&header, records, [Field::Name, Field::Flags]);
+    /// assert!(projection.next().is_none());
+    /// ```
+    pub fn new<F>(header: &'h Header, records: I, fields: F) -> Self
+    where
+        F: IntoIterator<Item = Field>,
+    {
+        Self {
+            header,
+            records,
+            fields: fields.into_iter().collect(),
+        }
+    }
+
+    /// Returns the field selectors.
+    pub fn fields(&self) -> &[Field] {
+        &self.fields
+    }
+}
+
+impl<'h, I> Iterator for Projection<'h, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Row<'h>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some(project(self.header, &self.fields, &record))
+    }
+}
+
+fn project<'h, R>(header: &'h Header, fields: &[Field], record: &R) -> io::Result<Row<'h>>
+where
+    R: Record + ?Sized,
+{
+    fields
+        .iter()
+        .map(|field| project_field(header, *field, record))
+        .collect()
+}
+
+fn project_field<'h, R>(
+    header: &'h Header,
+    field: Field,
+    record: &R,
+) -> io::Result<Option<Value<'h>>>
+where
+    R: Record + ?Sized,
+{
+    match field {
+        Field::Name => Ok(record
+            .name()
+            .map(|name| Value::Name(name.as_bytes().into()))),
+        Field::Flags => record.flags().map(Value::Flags).map(Some),
+        Field::ReferenceSequenceName => record
+            .reference_sequence(header)
+            .transpose()
+            .map(|rs| rs.map(|(name, _)| Value::ReferenceSequenceName(name))),
+        Field::AlignmentStart => record
+            .alignment_start()
+            .transpose()
+            .map(|position| position.map(Value::Position)),
+        Field::MappingQuality => record
+            .mapping_quality()
+            .transpose()
+            .map(|mapping_quality| mapping_quality.map(Value::MappingQuality)),
+        Field::MateReferenceSequenceName => record
+            .mate_reference_sequence(header)
+            .transpose()
+            .map(|rs| rs.map(|(name, _)| Value::ReferenceSequenceName(name))),
+        Field::MateAlignmentStart => record
+            .mate_alignment_start()
+            .transpose()
+            .map(|position| position.map(Value::Position)),
+        Field::TemplateLength => record
+            .template_length()
+            .map(|len| Some(Value::TemplateLength(len))),
+        Field::Data(tag) => {
+            let data = record.data();
+
+            let value = match data.get(&tag).transpose()? {
+                Some(value) => DataValue::try_from(value).map(|value| Some(Value::Data(value)))?,
+                None => None,
+            };
+
+            Ok(value)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::RecordBuf,
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_data(
+                [(Tag::READ_GROUP, DataValue::from("rg0"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let records = [Ok(Box::new(record) as Box<dyn Record>)].into_iter();
+
+        let mut projection = Projection::new(
+            &header,
+            records,
+            [
+                Field::Name,
+                Field::Flags,
+                Field::ReferenceSequenceName,
+                Field::AlignmentStart,
+                Field::MappingQuality,
+                Field::MateReferenceSequenceName,
+                Field::TemplateLength,
+                Field::Data(Tag::READ_GROUP),
+                Field::Data(Tag::ALIGNMENT_HIT_COUNT),
+            ],
+        );
+
+        let actual = projection.next().transpose()?;
+
+        let expected = vec![
+            Some(Value::Name(BString::from("r0"))),
+            Some(Value::Flags(Flags::empty())),
+            Some(Value::ReferenceSequenceName(BStr::new("sq0"))),
+            Some(Value::Position(Position::MIN)),
+            None,
+            None,
+            Some(Value::TemplateLength(0)),
+            Some(Value::Data(DataValue::from("rg0"))),
+            None,
+        ];
+
+        assert_eq!(actual, Some(expected));
+        assert!(projection.next().is_none());
+
+        Ok(())
+    }
+}
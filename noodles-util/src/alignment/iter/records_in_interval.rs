@@ -0,0 +1,351 @@
+use std::io;
+
+use noodles_core::{Position, Region};
+use noodles_sam::{alignment::Record, header::resolve_region, Header};
+
+/// An iterator over records in a coordinate-sorted stream that overlap a region.
+///
+/// This is a region query without an index. The input is scanned from its current position:
+/// records before the region are skipped, records that overlap the region are returned, and
+/// iteration stops at the first record that starts after the region end or is on a later
+/// reference sequence. Nothing after that record is read.
+///
+/// The input must be coordinate-sorted, i.e., by reference sequence ID and then by alignment
+/// start. Records that are out of order result in an [`io::ErrorKind::InvalidData`] error, since
+/// the early stop would otherwise silently drop overlapping records.
+///
+/// In coordinate order, a record that starts before the region can still overlap it, e.g., a long
+/// read or a spliced alignment. Records are therefore not skipped by alignment start alone. The
+/// alignment end of each record before the region on the same reference sequence is calculated
+/// from its CIGAR operations, and it is returned if the end reaches the region start. There is no
+/// bound on how far back an overlapping record can start. The cost is a scan of all records up to
+/// the region end, which makes this suitable for streams that cannot be indexed or seeked, e.g.,
+/// pipes. Use an indexed reader for random access.
+///
+/// Unmapped records that are placed, i.e., that have a reference sequence ID and alignment
+/// start, have a span of 1 at their alignment start.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io, num::NonZeroUsize};
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             Flags,
+///         },
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::iter::RecordsInInterval;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+///     )
+///     .build();
+///
+/// let records = [(1, 13), (8, 2), (13, 4), (21, 4)].into_iter().map(|(start, len)| {
+///     Ok(RecordBuf::builder()
+///         .set_flags(Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::try_from(start).unwrap())
+///         .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+///         .build())
+/// });
+///
+/// let region = "sq0:10-15".parse()?;
+/// let starts: Vec<_> = RecordsInInterval::new(&header, records, &region)?
+///     .map(|result| result.map(|record| record.alignment_start().map(usize::from)))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(starts, [Some(1), Some(13)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct RecordsInInterval<'h, I> {
+    header: &'h Header,
+    records: I,
+    reference_sequence_id: usize,
+    start: Position,
+    end: Position,
+    last_key: Option<(usize, Position)>,
+    is_done: bool,
+}
+
+impl<'h, I, R> RecordsInInterval<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates an iterator over records that overlap a region.
+    ///
+    /// The region reference sequence name is resolved using the given header. This returns an
+    /// error if it is not in the header reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io, num::NonZeroUsize};
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::RecordBuf,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    /// use noodles_util::alignment::iter::RecordsInInterval;
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let records = std::iter::empty::<io::Result<RecordBuf>>();
+    /// let region = "sq0".parse()?;
+    /// let mut records = RecordsInInterval::new(&header, records, &region)?;
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(header: &'h Header, records: I, region: &Region) -> io::Result<Self> {
+        let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
+
+        let interval = region.interval();
+
+        Ok(Self {
+            header,
+            records,
+            reference_sequence_id,
+            start: interval.start().unwrap_or(Position::MIN),
+            end: interval.end().unwrap_or(Position::MAX),
+            last_key: None,
+            is_done: false,
+        })
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<R>> {
+        while let Some(record) = self.records.next().transpose()? {
+            let Some(reference_sequence_id) =
+                record.reference_sequence_id(self.header).transpose()?
+            else {
+                // Records without a reference sequence ID are last in coordinate order.
+                return Ok(None);
+            };
+
+            let start = record
+                .alignment_start()
+                .transpose()?
+                .unwrap_or(Position::MIN);
+
+            self.check_order(reference_sequence_id, start)?;
+
+            if reference_sequence_id < self.reference_sequence_id {
+                continue;
+            } else if reference_sequence_id > self.reference_sequence_id || start > self.end {
+                return Ok(None);
+            }
+
+            let end = record.alignment_end().transpose()?.unwrap_or(start);
+
+            if end >= self.start {
+                return Ok(Some(record));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn check_order(&mut self, reference_sequence_id: usize, start: Position) -> io::Result<()> {
+        let key = (reference_sequence_id, start);
+
+        if self
+            .last_key
+            .map(|last_key| key < last_key)
+            .unwrap_or(false)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "records are not coordinate-sorted",
+            ));
+        }
+
+        self.last_key = Some(key);
+
+        Ok(())
+    }
+}
+
+impl<I, R> Iterator for RecordsInInterval<'_, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+
+        match self.next_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(e) => {
+                self.is_done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> Header {
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_record(reference_sequence_id: usize, start: usize, len: usize) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::new(start).unwrap())
+            .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+            .build()
+    }
+
+    fn query<I>(header: &Header, records: I, region: &str) -> io::Result<Vec<(usize, usize)>>
+    where
+        I: IntoIterator<Item = io::Result<RecordBuf>>,
+    {
+        let region = region.parse().unwrap();
+
+        RecordsInInterval::new(header, records.into_iter(), &region)?
+            .map(|result| {
+                result.map(|record| {
+                    let id = record.reference_sequence_id().unwrap();
+                    let start = record.alignment_start().map(usize::from).unwrap();
+                    (id, start)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let header = build_header();
+
+        let records = [
+            build_record(0, 8, 4),
+            build_record(1, 1, 100),
+            build_record(1, 5, 4),
+            build_record(1, 20, 5),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::new(30).unwrap())
+                .build(),
+            build_record(1, 60, 4),
+            build_record(2, 1, 4),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+        ];
+
+        let t = |region| query(&header, records.iter().cloned().map(Ok), region);
+
+        assert_eq!(t("sq1:10-30")?, [(1, 1), (1, 20), (1, 30)]);
+        assert_eq!(t("sq1:101-110")?, []);
+        assert_eq!(t("sq1:23-24")?, [(1, 1), (1, 20)]);
+        assert_eq!(t("sq0")?, [(0, 8)]);
+        assert_eq!(t("sq2")?, [(2, 1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_stops_after_region() -> io::Result<()> {
+        let header = build_header();
+
+        // Any read after the region end fails.
+        let records = [
+            Ok(build_record(0, 8, 4)),
+            Ok(build_record(0, 21, 4)),
+            Err(io::Error::from(io::ErrorKind::UnexpectedEof)),
+        ];
+
+        assert_eq!(query(&header, records, "sq0:1-13")?, [(0, 8)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() {
+        let header = build_header();
+
+        let records = [
+            build_record(0, 13, 4),
+            build_record(0, 8, 4),
+            build_record(0, 21, 4),
+        ];
+
+        assert!(matches!(
+            query(&header, records.into_iter().map(Ok), "sq0:10-30"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = [build_record(1, 8, 4), build_record(0, 8, 4)];
+
+        assert!(matches!(
+            query(&header, records.into_iter().map(Ok), "sq1"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_new_with_invalid_region() {
+        let header = build_header();
+        let region = "sq3".parse().unwrap();
+
+        assert!(matches!(
+            RecordsInInterval::new(&header, std::iter::empty::<io::Result<RecordBuf>>(), &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -0,0 +1,423 @@
+//! Alignment record pileup events.
+
+use std::{io, vec};
+
+use noodles_core::Position;
+use noodles_sam::alignment::{
+    record::cigar::{op::Kind, Op},
+    Record,
+};
+
+const UNKNOWN_BASE: u8 = b'N';
+
+/// A pileup event of an alignment record at a reference position.
+///
+/// Positions are 1-based reference positions.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Event {
+    /// A read base that is the same as the reference base.
+    Match(Position),
+    /// A read base that differs from the reference base: (position, reference base, read base,
+    /// quality score).
+    Mismatch(Position, u8, u8, Option<u8>),
+    /// A read base aligned to the reference base that is unknown because the record has no
+    /// sequence (`*`).
+    Unknown(Position),
+    /// Read bases not in the reference: (position, bases, quality scores).
+    ///
+    /// The position is of the reference base preceding the insertion.
+    Insertion(Position, Vec<u8>, Option<Vec<u8>>),
+    /// Read bases not in the reference before the first aligned reference base, e.g., `2I4M`:
+    /// (position, bases, quality scores).
+    ///
+    /// There is no preceding reference base, so the position is of the reference base following
+    /// the insertion, i.e., the alignment start.
+    LeadingInsertion(Position, Vec<u8>, Option<Vec<u8>>),
+    /// Reference bases not in the read: (start position, length).
+    Deletion(Position, usize),
+    /// Skipped reference bases, e.g., an intron: (start position, length).
+    RefSkip(Position, usize),
+}
+
+/// Returns the pileup events of an alignment record.
+///
+/// `reference_bases` are the reference sequence bases starting at the alignment start of the
+/// record. It must cover at least the alignment span.
+///
+/// Events are emitted in CIGAR order. Each read base aligned to the reference, i.e., by an
+/// alignment match (`M`), sequence match (`=`), or sequence mismatch (`X`) operation, is a
+/// [`Event::Match`] or an [`Event::Mismatch`], as determined by a case-insensitive comparison of
+/// the read and reference bases, regardless of the operation kind.
+///
+/// An insertion (`I`) is attached to the preceding reference position, i.e., the last reference
+/// base before the insertion, and it is emitted after the event at that position. An insertion
+/// before the first aligned reference base has no preceding reference position and is emitted as
+/// an [`Event::LeadingInsertion`] at the alignment start. Adjacent insertion operations are
+/// emitted as separate events.
+///
+/// A deletion (`D`) or skip (`N`) is a single event at its first reference position. Soft clip
+/// (`S`), hard clip (`H`), and pad (`P`) operations emit no events.
+///
+/// A record without a sequence (`*`) has no read bases: each aligned read base is an
+/// [`Event::Unknown`], and inserted bases are `N`. Deletions and skips are emitted as usual.
+///
+/// Quality scores are `None` if the record has no quality scores.
+///
+/// Unmapped records, i.e., records without an alignment start, have no events.
+///
+/// The record fields are read and validated by this function, but the events are built lazily
+/// by the returned iterator.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::pileup_events::{self, Event};
+///
+/// let record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar(
+///         [
+///             Op::new(Kind::Match, 2),
+///             Op::new(Kind::Insertion, 1),
+///             Op::new(Kind::Deletion, 1),
+///             Op::new(Kind::Match, 1),
+///         ]
+///         .into_iter()
+///         .collect(),
+///     )
+///     .set_sequence(b"ATGT".to_vec().into())
+///     .set_quality_scores(vec![30, 31, 32, 33].into())
+///     .build();
+///
+/// let events: Vec<_> = pileup_events::pileup_events(&record, b"ACGT")?.collect();
+///
+/// assert_eq!(
+///     events,
+///     [
+///         Event::Match(Position::try_from(8)?),
+///         Event::Mismatch(Position::try_from(9)?, b'C', b'T', Some(31)),
+///         Event::Insertion(Position::try_from(9)?, b"G".to_vec(), Some(vec![32])),
+///         Event::Deletion(Position::try_from(10)?, 1),
+///         Event::Match(Position::try_from(11)?),
+///     ]
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn pileup_events<'r, R>(
+    record: &R,
+    reference_bases: &'r [u8],
+) -> io::Result<impl Iterator<Item = Event> + 'r>
+where
+    R: Record + ?Sized,
+{
+    let Some(alignment_start) = record.alignment_start().transpose()? else {
+        return Ok(Events::new(
+            Position::MIN,
+            Vec::new(),
+            reference_bases,
+            Vec::new(),
+            Vec::new(),
+        ));
+    };
+
+    let ops: Vec<Op> = record.cigar().iter().collect::<io::Result<_>>()?;
+    let sequence: Vec<u8> = record.sequence().iter().collect();
+    let quality_scores: Vec<u8> = record.quality_scores().iter().collect();
+
+    if !quality_scores.is_empty() && quality_scores.len() != sequence.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    let (alignment_span, read_length) = ops.iter().fold((0, 0), |(span, len), op| {
+        let kind = op.kind();
+
+        (
+            span + if kind.consumes_reference() {
+                op.len()
+            } else {
+                0
+            },
+            len + if kind.consumes_read() { op.len() } else { 0 },
+        )
+    });
+
+    if alignment_span > reference_bases.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "reference bases do not cover the alignment span",
+        ));
+    }
+
+    if !sequence.is_empty() && read_length > sequence.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "CIGAR read length exceeds sequence length",
+        ));
+    }
+
+    if alignment_start
+        .checked_add(alignment_span.saturating_sub(1))
+        .is_none()
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "position overflow",
+        ));
+    }
+
+    Ok(Events::new(
+        alignment_start,
+        ops,
+        reference_bases,
+        sequence,
+        quality_scores,
+    ))
+}
+
+struct Events<'r> {
+    alignment_start: Position,
+    ops: vec::IntoIter<Op>,
+    reference_bases: &'r [u8],
+    sequence: Vec<u8>,
+    quality_scores: Vec<u8>,
+    reference_offset: usize,
+    query_offset: usize,
+    // The number of read bases left in the current match operation.
+    remaining_aligned_bases: usize,
+}
+
+impl<'r> Events<'r> {
+    fn new(
+        alignment_start: Position,
+        ops: Vec<Op>,
+        reference_bases: &'r [u8],
+        sequence: Vec<u8>,
+        quality_scores: Vec<u8>,
+    ) -> Self {
+        Self {
+            alignment_start,
+            ops: ops.into_iter(),
+            reference_bases,
+            sequence,
+            quality_scores,
+            reference_offset: 0,
+            query_offset: 0,
+            remaining_aligned_bases: 0,
+        }
+    }
+
+    fn reference_position(&self, reference_offset: usize) -> Position {
+        // SAFETY: `pileup_events` checks that the positions in the alignment span do not
+        // overflow.
+        self.alignment_start
+            .checked_add(reference_offset)
+            .expect("position overflow")
+    }
+
+    fn aligned_event(&self) -> Event {
+        let position = self.reference_position(self.reference_offset);
+
+        let Some(&read_base) = self.sequence.get(self.query_offset) else {
+            return Event::Unknown(position);
+        };
+
+        let reference_base = self.reference_bases[self.reference_offset];
+
+        if reference_base.eq_ignore_ascii_case(&read_base) {
+            Event::Match(position)
+        } else {
+            Event::Mismatch(
+                position,
+                reference_base,
+                read_base,
+                self.quality_scores.get(self.query_offset).copied(),
+            )
+        }
+    }
+
+    fn inserted_bases(&self, len: usize) -> (Vec<u8>, Option<Vec<u8>>) {
+        let range = self.query_offset..self.query_offset + len;
+
+        let bases = self
+            .sequence
+            .get(range.clone())
+            .map(|bases| bases.to_vec())
+            .unwrap_or_else(|| vec![UNKNOWN_BASE; len]);
+
+        let scores = self.quality_scores.get(range).map(|scores| scores.to_vec());
+
+        (bases, scores)
+    }
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.remaining_aligned_bases > 0 {
+                let event = self.aligned_event();
+
+                self.remaining_aligned_bases -= 1;
+                self.reference_offset += 1;
+                self.query_offset += 1;
+
+                return Some(event);
+            }
+
+            let op = self.ops.next()?;
+            let len = op.len();
+
+            match op.kind() {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                    self.remaining_aligned_bases = len;
+                }
+                Kind::Insertion => {
+                    let (bases, scores) = self.inserted_bases(len);
+
+                    let event = match self.reference_offset.checked_sub(1) {
+                        Some(preceding_offset) => Event::Insertion(
+                            self.reference_position(preceding_offset),
+                            bases,
+                            scores,
+                        ),
+                        None => Event::LeadingInsertion(self.alignment_start, bases, scores),
+                    };
+
+                    self.query_offset += len;
+
+                    return Some(event);
+                }
+                Kind::Deletion | Kind::Skip => {
+                    let position = self.reference_position(self.reference_offset);
+                    self.reference_offset += len;
+
+                    return Some(if op.kind() == Kind::Deletion {
+                        Event::Deletion(position, len)
+                    } else {
+                        Event::RefSkip(position, len)
+                    });
+                }
+                Kind::SoftClip => self.query_offset += len,
+                Kind::HardClip | Kind::Pad => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::cigar::Op, RecordBuf};
+
+    use super::*;
+
+    fn build_record(ops: &[Op], sequence: &[u8], quality_scores: &[u8]) -> RecordBuf {
+        RecordBuf::builder()
+            .set_alignment_start(Position::MIN)
+            .set_cigar(ops.iter().copied().collect())
+            .set_sequence(sequence.to_vec().into())
+            .set_quality_scores(quality_scores.to_vec().into())
+            .build()
+    }
+
+    #[test]
+    fn test_pileup_events() -> Result<(), Box<dyn std::error::Error>> {
+        let record = build_record(
+            &[
+                Op::new(Kind::HardClip, 3),
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::Insertion, 1),
+                Op::new(Kind::SequenceMatch, 1),
+                Op::new(Kind::SequenceMismatch, 1),
+                Op::new(Kind::Insertion, 2),
+                Op::new(Kind::Skip, 3),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::SoftClip, 1),
+            ],
+            b"NTagTTAtT",
+            &[],
+        );
+
+        let actual: Vec<_> = pileup_events(&record, b"ACGTAGTC")?.collect();
+
+        let expected = [
+            Event::LeadingInsertion(Position::try_from(1)?, b"T".to_vec(), None),
+            Event::Match(Position::try_from(1)?),
+            Event::Mismatch(Position::try_from(2)?, b'C', b'g', None),
+            Event::Insertion(Position::try_from(2)?, b"TT".to_vec(), None),
+            Event::RefSkip(Position::try_from(3)?, 3),
+            Event::Mismatch(Position::try_from(6)?, b'G', b'A', None),
+            Event::Match(Position::try_from(7)?),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_events_without_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let record = build_record(
+            &[
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::Insertion, 2),
+                Op::new(Kind::Deletion, 1),
+                Op::new(Kind::Skip, 2),
+                Op::new(Kind::Match, 1),
+            ],
+            b"",
+            &[],
+        );
+
+        let actual: Vec<_> = pileup_events(&record, b"ACGTAG")?.collect();
+
+        let expected = [
+            Event::Unknown(Position::try_from(1)?),
+            Event::Unknown(Position::try_from(2)?),
+            Event::Insertion(Position::try_from(2)?, b"NN".to_vec(), None),
+            Event::Deletion(Position::try_from(3)?, 1),
+            Event::RefSkip(Position::try_from(4)?, 2),
+            Event::Unknown(Position::try_from(6)?),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_events_with_unmapped_record() -> io::Result<()> {
+        let record = RecordBuf::default();
+        assert_eq!(pileup_events(&record, b"")?.count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pileup_events_with_short_reference_bases() {
+        let record = build_record(&[Op::new(Kind::Match, 4)], b"ACGT", &[]);
+
+        assert!(matches!(
+            pileup_events(&record, b"ACG"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_pileup_events_with_sequence_and_quality_scores_length_mismatch() {
+        let record = build_record(&[Op::new(Kind::Match, 4)], b"ACGT", &[30, 30]);
+
+        assert!(matches!(
+            pileup_events(&record, b"ACGT"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,245 @@
+//! Alignment record filter.
+
+use std::io;
+
+use noodles_sam::alignment::{
+    record::{Flags, MappingQuality},
+    Record,
+};
+
+/// An alignment record filter.
+///
+/// This is a policy shared by alignment tools to decide which records are included in a
+/// computation. Each option excludes records by a flag bit, except the minimum mapping quality.
+/// Options combine conjunctively, i.e., a record is included only if no option excludes it.
+///
+/// The default filter includes all records, which suits counting tools, e.g., flagstat, that
+/// report secondary and supplementary alignments separately. [`Self::depth`] is the default for
+/// coverage.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::RecordFilter;
+///
+/// let filter = RecordFilter::default()
+///     .set_exclude_secondary(true)
+///     .set_exclude_supplementary(true);
+///
+/// let record = RecordBuf::builder().set_flags(Flags::SECONDARY).build();
+/// assert!(!filter.matches(&record)?);
+///
+/// let record = RecordBuf::builder().set_flags(Flags::empty()).build();
+/// assert!(filter.matches(&record)?);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct RecordFilter {
+    excluded_flags: Flags,
+    min_mapping_quality: Option<MappingQuality>,
+}
+
+impl RecordFilter {
+    /// Creates a record filter for coverage (depth).
+    ///
+    /// This excludes unmapped, secondary, QC fail, and duplicate records, which matches the
+    /// defaults of `samtools depth`. Supplementary alignments are included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::depth();
+    /// ```
+    pub fn depth() -> Self {
+        Self::default()
+            .set_exclude_unmapped(true)
+            .set_exclude_secondary(true)
+            .set_exclude_qc_fail(true)
+            .set_exclude_duplicates(true)
+    }
+
+    /// Sets whether to exclude unmapped records (`0x04`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::default().set_exclude_unmapped(true);
+    /// ```
+    pub fn set_exclude_unmapped(self, value: bool) -> Self {
+        self.set_excluded_flag(Flags::UNMAPPED, value)
+    }
+
+    /// Sets whether to exclude secondary alignments (`0x100`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::default().set_exclude_secondary(true);
+    /// ```
+    pub fn set_exclude_secondary(self, value: bool) -> Self {
+        self.set_excluded_flag(Flags::SECONDARY, value)
+    }
+
+    /// Sets whether to exclude records that fail quality control (`0x200`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::default().set_exclude_qc_fail(true);
+    /// ```
+    pub fn set_exclude_qc_fail(self, value: bool) -> Self {
+        self.set_excluded_flag(Flags::QC_FAIL, value)
+    }
+
+    /// Sets whether to exclude duplicates (`0x400`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::default().set_exclude_duplicates(true);
+    /// ```
+    pub fn set_exclude_duplicates(self, value: bool) -> Self {
+        self.set_excluded_flag(Flags::DUPLICATE, value)
+    }
+
+    /// Sets whether to exclude supplementary alignments (`0x800`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::RecordFilter;
+    /// let filter = RecordFilter::default().set_exclude_supplementary(true);
+    /// ```
+    pub fn set_exclude_supplementary(self, value: bool) -> Self {
+        self.set_excluded_flag(Flags::SUPPLEMENTARY, value)
+    }
+
+    /// Sets the minimum mapping quality.
+    ///
+    /// Records with a lower mapping quality are excluded. As in `samtools`, a missing mapping
+    /// quality (255) is treated as the value 255, i.e., it always passes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::MappingQuality;
+    /// use noodles_util::alignment::RecordFilter;
+    ///
+    /// let filter = RecordFilter::default().set_min_mapping_quality(MappingQuality::new(30));
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: Option<MappingQuality>) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Returns whether the given flags pass the flag options.
+    ///
+    /// This ignores the minimum mapping quality.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::RecordFilter;
+    ///
+    /// let filter = RecordFilter::depth();
+    /// assert!(filter.matches_flags(Flags::SUPPLEMENTARY));
+    /// assert!(!filter.matches_flags(Flags::DUPLICATE));
+    /// ```
+    pub fn matches_flags(&self, flags: Flags) -> bool {
+        !flags.intersects(self.excluded_flags)
+    }
+
+    /// Returns whether the given record passes the filter.
+    ///
+    /// The mapping quality is only read if a minimum is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf};
+    /// use noodles_util::alignment::RecordFilter;
+    ///
+    /// let filter = RecordFilter::depth();
+    /// let record = RecordBuf::builder().set_flags(Flags::UNMAPPED).build();
+    /// assert!(!filter.matches(&record)?);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn matches<R>(&self, record: &R) -> io::Result<bool>
+    where
+        R: Record + ?Sized,
+    {
+        if !self.matches_flags(record.flags()?) {
+            return Ok(false);
+        }
+
+        if let Some(min_mapping_quality) = self.min_mapping_quality {
+            if let Some(mapping_quality) = record.mapping_quality().transpose()? {
+                return Ok(mapping_quality >= min_mapping_quality);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn set_excluded_flag(mut self, flag: Flags, value: bool) -> Self {
+        self.excluded_flags.set(flag, value);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::RecordBuf;
+
+    use super::*;
+
+    fn build_record(flags: Flags, mapping_quality: Option<u8>) -> RecordBuf {
+        let mut builder = RecordBuf::builder().set_flags(flags);
+
+        if let Some(mapping_quality) = mapping_quality.and_then(MappingQuality::new) {
+            builder = builder.set_mapping_quality(mapping_quality);
+        }
+
+        builder.build()
+    }
+
+    #[test]
+    fn test_matches() -> io::Result<()> {
+        let filter = RecordFilter::default();
+        assert!(filter.matches(&build_record(Flags::all(), None))?);
+
+        let filter = RecordFilter::depth();
+        assert!(filter.matches(&build_record(Flags::empty(), Some(0)))?);
+        assert!(filter.matches(&build_record(Flags::SUPPLEMENTARY, None))?);
+
+        for flags in [
+            Flags::UNMAPPED,
+            Flags::SECONDARY,
+            Flags::QC_FAIL,
+            Flags::DUPLICATE,
+        ] {
+            assert!(!filter.matches(&build_record(flags, None))?);
+        }
+
+        let filter = RecordFilter::default()
+            .set_exclude_supplementary(true)
+            .set_exclude_secondary(true)
+            .set_exclude_secondary(false);
+        assert!(filter.matches(&build_record(Flags::SECONDARY, None))?);
+        assert!(!filter.matches(&build_record(Flags::SUPPLEMENTARY, None))?);
+
+        let filter = RecordFilter::default().set_min_mapping_quality(MappingQuality::new(30));
+        assert!(filter.matches(&build_record(Flags::empty(), Some(30)))?);
+        assert!(!filter.matches(&build_record(Flags::empty(), Some(29)))?);
+        assert!(filter.matches(&build_record(Flags::empty(), None))?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,318 @@
+//! Alignment record soft clip extraction.
+
+use std::io;
+
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{
+    record::cigar::{op::Kind, Op},
+    Record,
+};
+
+use super::fastq::{Segment, MISSING_NAME};
+
+const LEADING_SUFFIX: &[u8] = b"_leading_clip";
+const TRAILING_SUFFIX: &[u8] = b"_trailing_clip";
+
+/// The soft-clipped segments of an alignment record as FASTQ records.
+///
+/// Leading and trailing are relative to the original read orientation, i.e., as the read was
+/// sequenced, not as it is stored in the alignment record.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SoftClips {
+    leading: Option<fastq::Record>,
+    trailing: Option<fastq::Record>,
+}
+
+impl SoftClips {
+    /// Returns the soft-clipped segment at the start of the original read.
+    pub fn leading(&self) -> Option<&fastq::Record> {
+        self.leading.as_ref()
+    }
+
+    /// Returns the soft-clipped segment at the end of the original read.
+    pub fn trailing(&self) -> Option<&fastq::Record> {
+        self.trailing.as_ref()
+    }
+}
+
+/// Extracts the soft-clipped segments of an alignment record as FASTQ records.
+///
+/// The soft clip lengths are the sum of the consecutive soft clip (`S`) operations at each end of
+/// the CIGAR, ignoring any outer hard clip (`H`) operations. The sequence and quality scores of
+/// each segment are sliced from `SEQ` and `QUAL` by these lengths.
+///
+/// If the record is reverse complemented, each segment is reverse complemented (and its quality
+/// scores reversed) to restore the original read orientation. This also means the segment at the
+/// start of the CIGAR is the trailing segment of the original read.
+///
+/// The FASTQ record names are the read name (or `*` if missing) with the suffix `_leading_clip` or
+/// `_trailing_clip`. If the record has no quality scores, the FASTQ quality scores are set to 0.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::soft_clips;
+///
+/// let record = RecordBuf::builder()
+///     .set_name(b"r0".into())
+///     .set_cigar([Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACGTAC".to_vec().into())
+///     .set_quality_scores(vec![40, 30, 20, 20, 20, 20].into())
+///     .build();
+///
+/// let clips = soft_clips::extract(&record)?;
+///
+/// let leading = clips.leading().expect("missing leading clip");
+/// assert_eq!(leading.name(), b"r0_leading_clip");
+/// assert_eq!(leading.sequence(), b"AC");
+/// assert_eq!(leading.quality_scores(), b"I?");
+///
+/// assert!(clips.trailing().is_none());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn extract<R>(record: &R) -> io::Result<SoftClips>
+where
+    R: Record + ?Sized,
+{
+    let ops: Vec<Op> = record.cigar().iter().collect::<io::Result<_>>()?;
+    let (start_len, end_len) = soft_clip_lens(&ops);
+
+    let sequence: Vec<u8> = record.sequence().iter().collect();
+
+    if start_len + end_len > sequence.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "soft clip lengths exceed sequence length",
+        ));
+    }
+
+    let quality_scores: Vec<u8> = record.quality_scores().iter().collect();
+
+    if !quality_scores.is_empty() && quality_scores.len() != sequence.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+
+    let name = record.name();
+    let name = name.as_ref().map(|n| n.as_bytes()).unwrap_or(MISSING_NAME);
+
+    let end_start = sequence.len() - end_len;
+
+    let build = |range: std::ops::Range<usize>, suffix: &[u8]| -> io::Result<_> {
+        if range.is_empty() {
+            return Ok(None);
+        }
+
+        let quals = quality_scores.get(range.clone());
+        let segment = Segment::new(&sequence[range], quals, is_reverse_complemented)?;
+        Ok(Some(segment.into_fastq_record(&[name, suffix])))
+    };
+
+    let (leading, trailing) = if is_reverse_complemented {
+        (
+            build(end_start..sequence.len(), LEADING_SUFFIX)?,
+            build(0..start_len, TRAILING_SUFFIX)?,
+        )
+    } else {
+        (
+            build(0..start_len, LEADING_SUFFIX)?,
+            build(end_start..sequence.len(), TRAILING_SUFFIX)?,
+        )
+    };
+
+    Ok(SoftClips { leading, trailing })
+}
+
+// Returns the soft clip lengths at the start and end of the CIGAR.
+fn soft_clip_lens(ops: &[Op]) -> (usize, usize) {
+    fn sum_soft_clips<'a, I>(iter: I) -> (usize, usize)
+    where
+        I: Iterator<Item = &'a Op>,
+    {
+        let mut op_count = 0;
+        let mut len = 0;
+
+        for op in iter.skip_while(|op| op.kind() == Kind::HardClip) {
+            if op.kind() != Kind::SoftClip {
+                break;
+            }
+
+            op_count += 1;
+            len += op.len();
+        }
+
+        (op_count, len)
+    }
+
+    let (start_op_count, start_len) = sum_soft_clips(ops.iter());
+
+    // A CIGAR that only has clip operations is entirely counted as the start soft clip.
+    if ops.iter().filter(|op| op.kind() != Kind::HardClip).count() == start_op_count {
+        return (start_len, 0);
+    }
+
+    let (_, end_len) = sum_soft_clips(ops.iter().rev());
+
+    (start_len, end_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    fn build_record(flags: Flags, ops: &[Op]) -> RecordBuf {
+        RecordBuf::builder()
+            .set_name(b"r0".into())
+            .set_flags(flags)
+            .set_cigar(ops.iter().copied().collect())
+            .set_sequence(b"ACGTTAGC".to_vec().into())
+            .set_quality_scores(vec![0, 1, 2, 3, 4, 5, 6, 7].into())
+            .build()
+    }
+
+    #[test]
+    fn test_soft_clip_lens() {
+        assert_eq!(soft_clip_lens(&[]), (0, 0));
+        assert_eq!(soft_clip_lens(&[Op::new(Kind::Match, 8)]), (0, 0));
+
+        assert_eq!(
+            soft_clip_lens(&[
+                Op::new(Kind::HardClip, 5),
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::HardClip, 3),
+            ]),
+            (3, 1)
+        );
+
+        assert_eq!(soft_clip_lens(&[Op::new(Kind::SoftClip, 8)]), (8, 0));
+    }
+
+    #[test]
+    fn test_extract() -> io::Result<()> {
+        let ops = [
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 3),
+            Op::new(Kind::SoftClip, 3),
+        ];
+
+        let record = build_record(Flags::empty(), &ops);
+        let clips = extract(&record)?;
+
+        assert_eq!(
+            clips.leading(),
+            Some(&fastq::Record::new(
+                fastq::record::Definition::new("r0_leading_clip", ""),
+                "AC",
+                "!\"",
+            ))
+        );
+
+        assert_eq!(
+            clips.trailing(),
+            Some(&fastq::Record::new(
+                fastq::record::Definition::new("r0_trailing_clip", ""),
+                "AGC",
+                "&'(",
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_with_reverse_complemented_record() -> io::Result<()> {
+        let ops = [
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 3),
+            Op::new(Kind::SoftClip, 3),
+        ];
+
+        let record = build_record(Flags::REVERSE_COMPLEMENTED, &ops);
+        let clips = extract(&record)?;
+
+        // SEQ[5..8] = AGC, QUAL[5..8] = [5, 6, 7]
+        assert_eq!(
+            clips.leading(),
+            Some(&fastq::Record::new(
+                fastq::record::Definition::new("r0_leading_clip", ""),
+                "GCT",
+                "('&",
+            ))
+        );
+
+        // SEQ[0..2] = AC, QUAL[0..2] = [0, 1]
+        assert_eq!(
+            clips.trailing(),
+            Some(&fastq::Record::new(
+                fastq::record::Definition::new("r0_trailing_clip", ""),
+                "GT",
+                "\"!",
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_without_soft_clips() -> io::Result<()> {
+        let record = build_record(Flags::empty(), &[Op::new(Kind::Match, 8)]);
+        assert_eq!(extract(&record)?, SoftClips::default());
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_with_missing_quality_scores() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 2)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        let clips = extract(&record)?;
+
+        assert_eq!(
+            clips.leading(),
+            Some(&fastq::Record::new(
+                fastq::record::Definition::new("*_leading_clip", ""),
+                "AC",
+                "!!",
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_with_invalid_soft_clip_lengths() {
+        let record = RecordBuf::builder()
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 8), Op::new(Kind::Match, 2)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        assert!(matches!(
+            extract(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,662 @@
+//! External alignment record sort.
+
+use std::{
+    cmp::Ordering,
+    collections::BinaryHeap,
+    fs::{self, File, OpenOptions},
+    io::{self, BufWriter, Write as _},
+    mem,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{self, AtomicUsize},
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        io::Write,
+        record_buf::data::field::{value::Array, Value},
+        RecordBuf,
+    },
+    header::record::value::{
+        map::{self, header::tag},
+        Map,
+    },
+};
+
+use super::cmp;
+
+const DEFAULT_MEMORY_LIMIT: NonZeroUsize = match NonZeroUsize::new(768 << 20) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+// The maximum number of runs merged at once.
+const MAX_FAN_IN: usize = 64;
+
+static RUN_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Comparator = fn(&RecordBuf, &RecordBuf) -> Ordering;
+
+/// A sort order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// By coordinate ([`cmp::coordinate`]).
+    #[default]
+    Coordinate,
+    /// By name ([`cmp::queryname`]).
+    QueryName,
+}
+
+impl SortOrder {
+    fn comparator(&self) -> Comparator {
+        match self {
+            Self::Coordinate => cmp::coordinate,
+            Self::QueryName => cmp::queryname,
+        }
+    }
+
+    fn as_bytes(&self) -> &'static [u8] {
+        use sam::header::record::value::map::header::sort_order;
+
+        match self {
+            Self::Coordinate => sort_order::COORDINATE,
+            Self::QueryName => sort_order::QUERY_NAME,
+        }
+    }
+}
+
+/// An external alignment record sorter.
+///
+/// This is the core of `samtools sort`. Records are buffered in memory until the estimated size
+/// of the buffer reaches the memory limit. The buffer is then sorted and spilled to a temporary
+/// file as a BAM, i.e., a sorted run. After all input records are read, the runs are merged
+/// (k-way) and written. If the input fits in memory, no temporary files are created.
+///
+/// At most 64 runs are open at once. When there are more, they are first merged in groups of 64
+/// into larger runs, in one or more passes.
+///
+/// The memory limit is an estimate of the heap size of the buffered records. It excludes the
+/// memory used for reading and writing, e.g., a BGZF block buffer per run during the merge.
+///
+/// Temporary files are created in the temporary directory and are removed when the sort finishes,
+/// including on error.
+///
+/// The sort is stable. Records that compare equal keep their input order.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_sam as sam;
+/// use noodles_util::alignment::sort::{SortOrder, Sorter};
+///
+/// let sorter = Sorter::default()
+///     .set_memory_limit(NonZeroUsize::try_from(64 << 20)?)
+///     .set_sort_order(SortOrder::QueryName);
+///
+/// let header = sam::Header::default();
+/// let records = Vec::new();
+///
+/// let mut writer = sam::io::Writer::new(Vec::new());
+/// sorter.sort(&header, records, &mut writer)?;
+///
+/// assert_eq!(writer.get_ref(), b"@HD\tVN:1.6\tSO:queryname\n");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sorter {
+    memory_limit: NonZeroUsize,
+    temp_dir: PathBuf,
+    sort_order: SortOrder,
+    max_fan_in: usize,
+}
+
+impl Sorter {
+    /// Sets the memory limit in bytes.
+    ///
+    /// This is the estimated size of the records buffered before a sorted run is spilled to disk.
+    /// By default, it is 768 MiB, which matches `samtools sort -m`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::sort::Sorter;
+    /// let sorter = Sorter::default().set_memory_limit(NonZeroUsize::try_from(64 << 20)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_memory_limit(mut self, memory_limit: NonZeroUsize) -> Self {
+        self.memory_limit = memory_limit;
+        self
+    }
+
+    /// Sets the directory for temporary files.
+    ///
+    /// By default, this is the system temporary directory ([`std::env::temp_dir`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort::Sorter;
+    /// let sorter = Sorter::default().set_temp_dir("tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = temp_dir.into();
+        self
+    }
+
+    /// Sets the sort order.
+    ///
+    /// By default, records are sorted by coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort::{SortOrder, Sorter};
+    /// let sorter = Sorter::default().set_sort_order(SortOrder::QueryName);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sorts records and writes them.
+    ///
+    /// This writes the header, the sorted records, and finishes the writer. The header written
+    /// has the sort order (`@HD` `SO`) set to the chosen order, and its subsort order (`SS`), if
+    /// any, is removed. A header record (`@HD`) is added if it is missing.
+    ///
+    /// The reference sequence IDs of the records are expected to refer to the reference
+    /// sequences of the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::sort::{SortOrder, Sorter};
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let records = [&b"r10"[..], b"r2"]
+    ///     .into_iter()
+    ///     .map(|name| Ok(RecordBuf::builder().set_name(name.into()).build()));
+    ///
+    /// let mut writer = sam::io::Writer::new(Vec::new());
+    ///
+    /// Sorter::default()
+    ///     .set_sort_order(SortOrder::QueryName)
+    ///     .sort(&header, records, &mut writer)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn sort<I, W>(&self, header: &sam::Header, records: I, writer: &mut W) -> io::Result<()>
+    where
+        I: IntoIterator<Item = io::Result<RecordBuf>>,
+        W: Write,
+    {
+        let cmp = self.sort_order.comparator();
+
+        let header = sorted_header(header, self.sort_order);
+
+        let mut runs = Runs::new(&self.temp_dir);
+        let mut buf = Vec::new();
+        let mut buf_size = 0;
+
+        for result in records {
+            let record = result?;
+
+            buf_size += estimate_record_size(&record);
+            buf.push(record);
+
+            if buf_size >= self.memory_limit.get() {
+                buf.sort_by(cmp);
+                runs.spill(&header, &buf)?;
+                buf.clear();
+                buf_size = 0;
+            }
+        }
+
+        buf.sort_by(cmp);
+
+        writer.write_alignment_header(&header)?;
+
+        if runs.is_empty() {
+            for record in &buf {
+                writer.write_alignment_record(&header, record)?;
+            }
+        } else {
+            if !buf.is_empty() {
+                runs.spill(&header, &buf)?;
+            }
+
+            drop(mem::take(&mut buf));
+
+            runs.merge(&header, cmp, self.max_fan_in, writer)?;
+        }
+
+        writer.finish(&header)?;
+
+        Ok(())
+    }
+}
+
+impl Default for Sorter {
+    fn default() -> Self {
+        Self {
+            memory_limit: DEFAULT_MEMORY_LIMIT,
+            temp_dir: std::env::temp_dir(),
+            sort_order: SortOrder::default(),
+            max_fan_in: MAX_FAN_IN,
+        }
+    }
+}
+
+fn sorted_header(header: &sam::Header, sort_order: SortOrder) -> sam::Header {
+    let mut header = header.clone();
+
+    let hd = header
+        .header_mut()
+        .get_or_insert_with(Map::<map::Header>::default);
+
+    let other_fields = hd.other_fields_mut();
+    other_fields.insert(tag::SORT_ORDER, sort_order.as_bytes().into());
+    other_fields.shift_remove(&tag::SUBSORT_ORDER);
+
+    header
+}
+
+// Estimates the heap size of a record, including the record itself.
+fn estimate_record_size(record: &RecordBuf) -> usize {
+    fn value_size(value: &Value) -> usize {
+        match value {
+            Value::String(s) | Value::Hex(s) => s.len(),
+            Value::Array(Array::Int8(values)) => values.len(),
+            Value::Array(Array::UInt8(values)) => values.len(),
+            Value::Array(Array::Int16(values)) => values.len() * mem::size_of::<i16>(),
+            Value::Array(Array::UInt16(values)) => values.len() * mem::size_of::<u16>(),
+            Value::Array(Array::Int32(values)) => values.len() * mem::size_of::<i32>(),
+            Value::Array(Array::UInt32(values)) => values.len() * mem::size_of::<u32>(),
+            Value::Array(Array::Float(values)) => values.len() * mem::size_of::<f32>(),
+            _ => 0,
+        }
+    }
+
+    let data_size: usize = record
+        .data()
+        .iter()
+        .map(|(_, value)| {
+            mem::size_of::<(sam::alignment::record::data::field::Tag, Value)>() + value_size(value)
+        })
+        .sum();
+
+    mem::size_of::<RecordBuf>()
+        + record.name().map(|name| name.as_ref().len()).unwrap_or(0)
+        + mem::size_of_val(record.cigar().as_ref())
+        + record.sequence().as_ref().len()
+        + record.quality_scores().as_ref().len()
+        + data_size
+}
+
+// Sorted runs spilled to temporary files.
+//
+// The files are removed on drop.
+struct Runs<'a> {
+    temp_dir: &'a Path,
+    paths: Vec<PathBuf>,
+}
+
+impl<'a> Runs<'a> {
+    fn new(temp_dir: &'a Path) -> Self {
+        Self {
+            temp_dir,
+            paths: Vec::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    fn spill(&mut self, header: &sam::Header, records: &[RecordBuf]) -> io::Result<()> {
+        let mut writer = self.create(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        finish_run(writer)
+    }
+
+    // Creates a temporary file for a new run and writes the header.
+    fn create(&mut self, header: &sam::Header) -> io::Result<RunWriter> {
+        let id = RUN_ID.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = self
+            .temp_dir
+            .join(format!("noodles-util-sort.{}.{id}.bam", process::id()));
+
+        let file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        self.paths.push(path);
+
+        let inner = bgzf::writer::Builder::default()
+            .set_compression_level(bgzf::writer::CompressionLevel::FAST)
+            .build_with_writer(BufWriter::new(file));
+
+        let mut writer = bam::io::Writer::from(inner);
+        writer.write_header(header)?;
+
+        Ok(writer)
+    }
+
+    fn merge<W>(
+        mut self,
+        header: &sam::Header,
+        cmp: Comparator,
+        max_fan_in: usize,
+        writer: &mut W,
+    ) -> io::Result<()>
+    where
+        W: Write,
+    {
+        // Each pass merges consecutive groups of runs, which keeps the sort stable. The input
+        // runs of a pass are removed when the pass ends.
+        while self.paths.len() > max_fan_in {
+            let inputs = Runs {
+                temp_dir: self.temp_dir,
+                paths: mem::take(&mut self.paths),
+            };
+
+            for paths in inputs.paths.chunks(max_fan_in) {
+                let mut run_writer = self.create(header)?;
+                merge_runs(paths, header, cmp, &mut run_writer)?;
+                finish_run(run_writer)?;
+            }
+        }
+
+        merge_runs(&self.paths, header, cmp, writer)
+    }
+}
+
+type RunWriter = bam::io::Writer<bgzf::Writer<BufWriter<File>>>;
+
+fn finish_run(writer: RunWriter) -> io::Result<()> {
+    writer.into_inner().finish()?.flush()
+}
+
+// Merges sorted runs (k-way) and writes the records.
+fn merge_runs<W>(
+    paths: &[PathBuf],
+    header: &sam::Header,
+    cmp: Comparator,
+    writer: &mut W,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let mut readers = Vec::with_capacity(paths.len());
+    let mut heap = BinaryHeap::with_capacity(paths.len());
+
+    for (i, path) in paths.iter().enumerate() {
+        let mut reader = File::open(path).map(bam::io::Reader::new)?;
+        reader.read_header()?;
+
+        if let Some(record) = read_record(&mut reader, header)? {
+            heap.push(Entry { record, i, cmp });
+        }
+
+        readers.push(reader);
+    }
+
+    while let Some(mut entry) = heap.pop() {
+        writer.write_alignment_record(header, &entry.record)?;
+
+        if let Some(record) = read_record(&mut readers[entry.i], header)? {
+            entry.record = record;
+            heap.push(entry);
+        }
+    }
+
+    Ok(())
+}
+
+impl Drop for Runs<'_> {
+    fn drop(&mut self) {
+        for path in &self.paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn read_record(
+    reader: &mut bam::io::Reader<bgzf::Reader<File>>,
+    header: &sam::Header,
+) -> io::Result<Option<RecordBuf>> {
+    let mut record = RecordBuf::default();
+
+    match reader.read_record_buf(header, &mut record)? {
+        0 => Ok(None),
+        _ => Ok(Some(record)),
+    }
+}
+
+// A merge heap entry.
+//
+// The order is reversed to make the max-heap a min-heap. Ties are broken by run index to keep the
+// sort stable.
+struct Entry {
+    record: RecordBuf,
+    i: usize,
+    cmp: Comparator,
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.cmp)(&other.record, &self.record).then_with(|| other.i.cmp(&self.i))
+    }
+}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::record::{
+            cigar::{op::Kind, Op},
+            Flags,
+        },
+        header::record::value::map::ReferenceSequence,
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .set_header(
+                Map::<map::Header>::builder()
+                    .insert(tag::SORT_ORDER, "unsorted")
+                    .insert(tag::SUBSORT_ORDER, "unsorted:MI")
+                    .build()
+                    .unwrap(),
+            )
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_records() -> Vec<RecordBuf> {
+        (0..64)
+            .map(|i| {
+                let mut builder = RecordBuf::builder()
+                    .set_name(format!("r{}", (i * 7) % 64).as_bytes().into())
+                    .set_sequence(b"ACGT".to_vec().into());
+
+                if i % 9 == 0 {
+                    builder = builder.set_flags(Flags::UNMAPPED);
+                } else {
+                    builder = builder
+                        .set_flags(Flags::empty())
+                        .set_reference_sequence_id(i % 2)
+                        .set_alignment_start(Position::new((i * 37) % 500 + 1).unwrap())
+                        .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect());
+                }
+
+                builder.build()
+            })
+            .collect()
+    }
+
+    fn sort(
+        sorter: &Sorter,
+        header: &sam::Header,
+        records: &[RecordBuf],
+    ) -> io::Result<(sam::Header, Vec<RecordBuf>)> {
+        let mut writer = sam::io::Writer::new(Vec::new());
+        sorter.sort(header, records.iter().cloned().map(Ok), &mut writer)?;
+
+        let mut reader = sam::io::Reader::new(&writer.get_ref()[..]);
+        let header = reader.read_header()?;
+        let records = reader.record_bufs(&header).collect::<io::Result<_>>()?;
+
+        Ok((header, records))
+    }
+
+    fn temp_dir(name: &str) -> io::Result<PathBuf> {
+        let dir = std::env::temp_dir().join(format!("noodles-util-sort-{}-{name}", process::id()));
+        fs::create_dir_all(&dir)?;
+        Ok(dir)
+    }
+
+    fn is_empty_dir(dir: &Path) -> io::Result<bool> {
+        fs::read_dir(dir).map(|mut entries| entries.next().is_none())
+    }
+
+    #[test]
+    fn test_sort() -> io::Result<()> {
+        let header = build_header();
+        let records = build_records();
+        let dir = temp_dir("test_sort")?;
+
+        for sort_order in [SortOrder::Coordinate, SortOrder::QueryName] {
+            let cmp = sort_order.comparator();
+
+            let mut expected = records.clone();
+            expected.sort_by(cmp);
+
+            for memory_limit in [NonZeroUsize::MIN, NonZeroUsize::MAX]
+                .into_iter()
+                .chain(NonZeroUsize::new(4096))
+            {
+                let sorter = Sorter::default()
+                    .set_memory_limit(memory_limit)
+                    .set_temp_dir(&dir)
+                    .set_sort_order(sort_order);
+
+                let (actual_header, actual) = sort(&sorter, &header, &records)?;
+
+                let hd = actual_header.header().unwrap();
+                assert_eq!(
+                    hd.other_fields()
+                        .get(&tag::SORT_ORDER)
+                        .map(|s| s.as_slice()),
+                    Some(sort_order.as_bytes())
+                );
+                assert!(hd.other_fields().get(&tag::SUBSORT_ORDER).is_none());
+
+                assert_eq!(actual, expected);
+                assert!(is_empty_dir(&dir)?);
+            }
+        }
+
+        fs::remove_dir(&dir)
+    }
+
+    #[test]
+    fn test_sort_with_input_error() -> io::Result<()> {
+        let header = build_header();
+        let records = build_records();
+        let dir = temp_dir("test_sort_with_input_error")?;
+
+        let sorter = Sorter::default()
+            .set_memory_limit(NonZeroUsize::MIN)
+            .set_temp_dir(&dir);
+
+        let input = records
+            .into_iter()
+            .map(Ok)
+            .chain([Err(io::Error::from(io::ErrorKind::InvalidData))]);
+
+        let mut writer = sam::io::Writer::new(Vec::new());
+        assert!(matches!(
+            sorter.sort(&header, input, &mut writer),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(writer.get_ref().is_empty());
+        assert!(is_empty_dir(&dir)?);
+
+        fs::remove_dir(&dir)
+    }
+
+    #[test]
+    fn test_sort_with_multiple_merge_passes() -> io::Result<()> {
+        let header = build_header();
+        let records = build_records();
+        let dir = temp_dir("test_sort_with_multiple_merge_passes")?;
+
+        // Each record is spilled to its own run, and the 64 runs are merged in 3 passes: 64 -> 16
+        // -> 4 -> output.
+        let mut sorter = Sorter::default()
+            .set_memory_limit(NonZeroUsize::MIN)
+            .set_temp_dir(&dir);
+        sorter.max_fan_in = 4;
+
+        let mut expected = records.clone();
+        expected.sort_by(cmp::coordinate);
+
+        let (_, actual) = sort(&sorter, &header, &records)?;
+        assert_eq!(actual, expected);
+        assert!(is_empty_dir(&dir)?);
+
+        fs::remove_dir(&dir)
+    }
+
+    #[test]
+    fn test_sorted_header() {
+        let header = sorted_header(&sam::Header::default(), SortOrder::Coordinate);
+        let hd = header.header().unwrap();
+        assert_eq!(
+            hd.other_fields()
+                .get(&tag::SORT_ORDER)
+                .map(|s| s.as_slice()),
+            Some(&b"coordinate"[..])
+        );
+    }
+}
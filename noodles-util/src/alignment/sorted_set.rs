@@ -0,0 +1,360 @@
+//! In-memory coordinate-sorted alignment record set.
+
+use std::{io, ops::Range};
+
+use noodles_core::{Position, Region};
+use noodles_sam::{self as sam, alignment::RecordBuf, header::resolve_region};
+
+use super::cmp;
+
+/// An in-memory coordinate-sorted alignment record set.
+///
+/// This collects alignment records and sorts them by coordinate (see [`cmp::coordinate`]), which
+/// matches the order of `samtools sort`. The reference sequence IDs of the records are expected
+/// to refer to the reference sequences of the header later used to query the set.
+///
+/// All records are held in memory, and there is no spilling to disk. This is intended for test
+/// fixtures and modestly-sized datasets. Use an external sort for large inputs.
+///
+/// Region queries use a per-reference sequence index of the record range and the maximum
+/// alignment span. A query binary searches the start of the window of records that can overlap
+/// the region and then scans it, which costs O(log n + m) time, where m is the number of records
+/// starting within the window. This is efficient when alignment spans are similar, e.g., short
+/// reads, but degrades to a linear scan of the reference sequence if there are very long
+/// alignments.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::RecordBuf;
+/// use noodles_util::alignment::SortedSet;
+///
+/// let set: SortedSet = [
+///     RecordBuf::builder()
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::try_from(13)?)
+///         .build(),
+///     RecordBuf::default(),
+///     RecordBuf::builder()
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::try_from(8)?)
+///         .build(),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let starts: Vec<_> = set.iter().map(|record| record.alignment_start()).collect();
+///
+/// assert_eq!(
+///     starts,
+///     [Some(Position::try_from(8)?), Some(Position::try_from(13)?), None]
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SortedSet {
+    records: Vec<RecordBuf>,
+    reference_sequences: Vec<ReferenceSequence>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+struct ReferenceSequence {
+    // The range of records with an alignment start.
+    range: Range<usize>,
+    max_span: usize,
+}
+
+impl SortedSet {
+    /// Returns the number of records in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::SortedSet;
+    /// let set = SortedSet::default();
+    /// assert_eq!(set.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the set has any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::SortedSet;
+    /// let set = SortedSet::default();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns an iterator over records in coordinate order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::SortedSet;
+    /// let set = SortedSet::default();
+    /// assert!(set.iter().next().is_none());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &RecordBuf> {
+        self.records.iter()
+    }
+
+    /// Returns an iterator over records that overlap the given region.
+    ///
+    /// The region reference sequence name is resolved using the given header. Records are
+    /// returned in coordinate order. Unmapped records that have a reference sequence ID and
+    /// alignment start are treated as having a span of 1 at their alignment start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         record::{
+    ///             cigar::{op::Kind, Op},
+    ///             Flags,
+    ///         },
+    ///         RecordBuf,
+    ///     },
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    /// use noodles_util::alignment::SortedSet;
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let set: SortedSet = [(8, 4), (13, 8), (21, 4)]
+    ///     .into_iter()
+    ///     .map(|(start, len)| {
+    ///         RecordBuf::builder()
+    ///             .set_flags(Flags::empty())
+    ///             .set_reference_sequence_id(0)
+    ///             .set_alignment_start(Position::try_from(start).unwrap())
+    ///             .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+    ///             .build()
+    ///     })
+    ///     .collect();
+    ///
+    /// let region = "sq0:10-15".parse()?;
+    /// let starts: Vec<_> = set
+    ///     .query(&header, &region)?
+    ///     .filter_map(|record| record.alignment_start())
+    ///     .map(usize::from)
+    ///     .collect();
+    ///
+    /// assert_eq!(starts, [8, 13]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query<'a>(
+        &'a self,
+        header: &sam::Header,
+        region: &Region,
+    ) -> io::Result<impl Iterator<Item = &'a RecordBuf> + 'a> {
+        let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
+
+        let interval = region.interval();
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        let records = match self.reference_sequences.get(reference_sequence_id) {
+            Some(reference_sequence) => {
+                let records = &self.records[reference_sequence.range.clone()];
+
+                // A record that starts before this cannot reach the query start.
+                let min_start = usize::from(start)
+                    .saturating_sub(reference_sequence.max_span.saturating_sub(1));
+
+                let lo = records.partition_point(|record| start_position(record) < min_start);
+                let hi = records.partition_point(|record| start_position(record) <= end.into());
+
+                &records[lo..hi]
+            }
+            None => &[],
+        };
+
+        Ok(records.iter().filter(move |record| {
+            let (_, record_end) = alignment_interval(record);
+            record_end >= start
+        }))
+    }
+}
+
+impl FromIterator<RecordBuf> for SortedSet {
+    fn from_iter<T: IntoIterator<Item = RecordBuf>>(iter: T) -> Self {
+        let mut records: Vec<_> = iter.into_iter().collect();
+        records.sort_by(cmp::coordinate);
+
+        let mut reference_sequences: Vec<ReferenceSequence> = Vec::new();
+
+        for (i, record) in records.iter().enumerate() {
+            let (Some(id), Some(_)) = (record.reference_sequence_id(), record.alignment_start())
+            else {
+                continue;
+            };
+
+            if reference_sequences.len() <= id {
+                reference_sequences.resize(id + 1, ReferenceSequence::default());
+            }
+
+            let reference_sequence = &mut reference_sequences[id];
+
+            if reference_sequence.range.is_empty() {
+                reference_sequence.range = i..i + 1;
+            } else {
+                reference_sequence.range.end = i + 1;
+            }
+
+            let (start, end) = alignment_interval(record);
+            let span = usize::from(end) - usize::from(start) + 1;
+            reference_sequence.max_span = reference_sequence.max_span.max(span);
+        }
+
+        Self {
+            records,
+            reference_sequences,
+        }
+    }
+}
+
+fn start_position(record: &RecordBuf) -> usize {
+    record.alignment_start().map(usize::from).unwrap_or(0)
+}
+
+// Returns the alignment interval of a record with an alignment start.
+fn alignment_interval(record: &RecordBuf) -> (Position, Position) {
+    let start = record.alignment_start().unwrap_or(Position::MIN);
+    let end = record.alignment_end().unwrap_or(start).max(start);
+    (start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        alignment::record::{
+            cigar::{op::Kind, Op},
+            Flags,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> sam::Header {
+        sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap()),
+            )
+            .build()
+    }
+
+    fn build_record(reference_sequence_id: usize, start: usize, len: usize) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(Position::new(start).unwrap())
+            .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+            .build()
+    }
+
+    fn query(set: &SortedSet, header: &sam::Header, region: &str) -> Vec<(usize, usize)> {
+        let region = region.parse().unwrap();
+
+        set.query(header, &region)
+            .unwrap()
+            .map(|record| {
+                let (start, end) = alignment_interval(record);
+                (usize::from(start), usize::from(end))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_query() {
+        let header = build_header();
+
+        let set: SortedSet = [
+            build_record(0, 21, 14),
+            build_record(0, 1, 100),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+            build_record(0, 8, 6),
+            build_record(2, 8, 6),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(55).unwrap())
+                .build(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(set.len(), 6);
+
+        assert_eq!(
+            query(&set, &header, "sq0:13-21"),
+            [(1, 100), (8, 13), (21, 34)]
+        );
+        assert_eq!(query(&set, &header, "sq0:14-20"), [(1, 100)]);
+        assert_eq!(query(&set, &header, "sq0:55-55"), [(1, 100), (55, 55)]);
+        assert!(query(&set, &header, "sq0:101-144").is_empty());
+        assert!(query(&set, &header, "sq1").is_empty());
+        assert_eq!(query(&set, &header, "sq2"), [(8, 13)]);
+
+        let region = "sq3".parse().unwrap();
+        assert!(matches!(
+            set.query(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_query_matches_linear_scan() {
+        let header = build_header();
+
+        let records: Vec<_> = (1..=200)
+            .map(|i| build_record(0, (i * 37) % 500 + 1, (i * 13) % 60 + 1))
+            .collect();
+
+        let set: SortedSet = records.iter().cloned().collect();
+
+        for (start, end) in [(1, 1), (10, 20), (250, 260), (480, 600), (1, 600)] {
+            let mut expected: Vec<_> = records
+                .iter()
+                .map(alignment_interval)
+                .map(|(s, e)| (usize::from(s), usize::from(e)))
+                .filter(|&(s, e)| s <= end && e >= start)
+                .collect();
+
+            expected.sort_by_key(|&(s, _)| s);
+
+            let mut actual = query(&set, &header, &format!("sq0:{start}-{end}"));
+            actual.sort_by_key(|&(s, _)| s);
+
+            assert_eq!(actual, expected);
+        }
+    }
+}
@@ -0,0 +1,406 @@
+//! Alignment split read reconstruction.
+
+use std::{io, ops::Range};
+
+use noodles_fasta as fasta;
+use noodles_sam::alignment::{
+    record::cigar::{op::Kind, Op},
+    Record,
+};
+
+/// A read reconstructed from its split alignments.
+///
+/// Positions are 0-based and relative to the original read orientation, i.e., as the read was
+/// sequenced, not as it is stored in the alignment records.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitRead {
+    sequence: Vec<u8>,
+    intervals: Vec<Range<usize>>,
+}
+
+impl SplitRead {
+    /// Returns the full-length read sequence.
+    pub fn sequence(&self) -> &[u8] {
+        &self.sequence
+    }
+
+    /// Returns the query intervals on the read of each alignment.
+    ///
+    /// Intervals are half-open and in the same order as the input alignment records. An interval
+    /// covers the aligned bases of the record, i.e., the read excluding its clips.
+    pub fn intervals(&self) -> &[Range<usize>] {
+        &self.intervals
+    }
+}
+
+// The clip and aligned lengths of a record as stored, i.e., in the record orientation.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct QueryLayout {
+    leading_hard_clip_len: usize,
+    leading_soft_clip_len: usize,
+    aligned_len: usize,
+    trailing_soft_clip_len: usize,
+    trailing_hard_clip_len: usize,
+}
+
+impl QueryLayout {
+    fn read_len(&self) -> usize {
+        self.leading_hard_clip_len + self.sequence_len() + self.trailing_hard_clip_len
+    }
+
+    fn sequence_len(&self) -> usize {
+        self.leading_soft_clip_len + self.aligned_len + self.trailing_soft_clip_len
+    }
+
+    fn aligned_range(&self) -> Range<usize> {
+        let start = self.leading_hard_clip_len + self.leading_soft_clip_len;
+        start..start + self.aligned_len
+    }
+
+    fn sequence_range(&self) -> Range<usize> {
+        let start = self.leading_hard_clip_len;
+        start..start + self.sequence_len()
+    }
+}
+
+/// Reconstructs a read from all of its alignments.
+///
+/// The given records are the alignments of a single read, typically the primary alignment and its
+/// supplementary alignments. Each record only stores part of the read when it is hard clipped
+/// (`H`), so the full read is assembled from the sequences of all records. The read length is the
+/// query length of the CIGAR including hard clips and must be the same for all records.
+///
+/// Reverse complemented records are reverse complemented back to the original read orientation.
+/// This is also true for their query intervals: e.g., a 10 base read with the CIGAR `3H7M` on the
+/// reverse strand has the aligned interval `[0, 7)` on the original read.
+///
+/// Records with a missing sequence (`*`), e.g., secondary alignments, only contribute their query
+/// intervals. When records overlap on the read, the bases of the first record are used.
+///
+/// # Errors
+///
+/// An error is returned if there are no records, a record is missing its CIGAR, the read lengths
+/// differ, a sequence length does not match its CIGAR, or the records do not cover the full read
+/// sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         Flags,
+///     },
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::split_read;
+///
+/// // ACGTACGTTT
+/// let primary = RecordBuf::builder()
+///     .set_cigar([Op::new(Kind::Match, 6), Op::new(Kind::SoftClip, 4)].into_iter().collect())
+///     .set_sequence(b"ACGTACGTTT".to_vec().into())
+///     .build();
+///
+/// let supplementary = RecordBuf::builder()
+///     .set_flags(Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED)
+///     .set_cigar([Op::new(Kind::Match, 4), Op::new(Kind::HardClip, 6)].into_iter().collect())
+///     .set_sequence(b"AAAC".to_vec().into())
+///     .build();
+///
+/// let read = split_read::reconstruct(&[primary, supplementary])?;
+/// assert_eq!(read.sequence(), b"ACGTACGTTT");
+/// assert_eq!(read.intervals(), [0..6, 6..10]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn reconstruct<R>(records: &[R]) -> io::Result<SplitRead>
+where
+    R: Record,
+{
+    let mut read_len = None;
+    let mut sequence: Vec<Option<u8>> = Vec::new();
+    let mut intervals = Vec::with_capacity(records.len());
+
+    for record in records {
+        let ops: Vec<Op> = record.cigar().iter().collect::<io::Result<_>>()?;
+
+        if ops.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "missing CIGAR"));
+        }
+
+        let layout = query_layout(&ops);
+        let len = layout.read_len();
+
+        match read_len {
+            None => {
+                read_len = Some(len);
+                sequence.resize(len, None);
+            }
+            Some(expected) if len != expected => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("read length mismatch: expected {expected}, got {len}"),
+                ));
+            }
+            Some(_) => {}
+        }
+
+        let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+
+        let aligned_range = layout.aligned_range();
+
+        let interval = if is_reverse_complemented {
+            reverse_range(aligned_range, len)
+        } else {
+            aligned_range
+        };
+
+        intervals.push(interval);
+
+        let bases: Vec<u8> = record.sequence().iter().collect();
+
+        if bases.is_empty() {
+            continue;
+        }
+
+        if bases.len() != layout.sequence_len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "sequence length mismatch: expected {}, got {}",
+                    layout.sequence_len(),
+                    bases.len()
+                ),
+            ));
+        }
+
+        let (range, bases) = if is_reverse_complemented {
+            let bases = fasta::record::Sequence::from(bases)
+                .complement()
+                .rev()
+                .collect::<Result<_, _>>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            (reverse_range(layout.sequence_range(), len), bases)
+        } else {
+            (layout.sequence_range(), bases)
+        };
+
+        for (dst, base) in sequence[range].iter_mut().zip(bases) {
+            dst.get_or_insert(base);
+        }
+    }
+
+    if read_len.is_none() {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "no records"));
+    }
+
+    let sequence = sequence.into_iter().collect::<Option<_>>().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "alignment sequences do not cover the read",
+        )
+    })?;
+
+    Ok(SplitRead {
+        sequence,
+        intervals,
+    })
+}
+
+fn query_layout(ops: &[Op]) -> QueryLayout {
+    let mut layout = QueryLayout::default();
+    let mut is_leading = true;
+
+    for op in ops {
+        match op.kind() {
+            Kind::HardClip if is_leading => layout.leading_hard_clip_len += op.len(),
+            Kind::HardClip => layout.trailing_hard_clip_len += op.len(),
+            Kind::SoftClip if is_leading => layout.leading_soft_clip_len += op.len(),
+            Kind::SoftClip => layout.trailing_soft_clip_len += op.len(),
+            kind if kind.consumes_read() => {
+                is_leading = false;
+                layout.aligned_len += layout.trailing_soft_clip_len + op.len();
+                layout.trailing_soft_clip_len = 0;
+            }
+            _ => {}
+        }
+    }
+
+    layout
+}
+
+fn reverse_range(range: Range<usize>, len: usize) -> Range<usize> {
+    len - range.end..len - range.start
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    fn build_record(flags: Flags, ops: &[Op], sequence: &[u8]) -> RecordBuf {
+        RecordBuf::builder()
+            .set_flags(flags)
+            .set_cigar(ops.iter().copied().collect())
+            .set_sequence(sequence.to_vec().into())
+            .build()
+    }
+
+    #[test]
+    fn test_query_layout() {
+        assert_eq!(
+            query_layout(&[
+                Op::new(Kind::HardClip, 5),
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Deletion, 3),
+                Op::new(Kind::Insertion, 1),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::SoftClip, 1),
+                Op::new(Kind::HardClip, 3),
+            ]),
+            QueryLayout {
+                leading_hard_clip_len: 5,
+                leading_soft_clip_len: 2,
+                aligned_len: 7,
+                trailing_soft_clip_len: 1,
+                trailing_hard_clip_len: 3,
+            }
+        );
+
+        assert_eq!(
+            query_layout(&[Op::new(Kind::SoftClip, 4)]),
+            QueryLayout {
+                leading_soft_clip_len: 4,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_reconstruct() -> io::Result<()> {
+        // read: ACGTACGTTT
+        let records = [
+            build_record(
+                Flags::default(),
+                &[Op::new(Kind::SoftClip, 3), Op::new(Kind::Match, 7)],
+                b"ACGTACGTTT",
+            ),
+            build_record(
+                Flags::SUPPLEMENTARY,
+                &[Op::new(Kind::Match, 3), Op::new(Kind::HardClip, 7)],
+                b"ACG",
+            ),
+        ];
+
+        let read = reconstruct(&records)?;
+        assert_eq!(read.sequence(), b"ACGTACGTTT");
+        assert_eq!(read.intervals(), [3..10, 0..3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconstruct_with_reverse_complemented_supplementary_alignments() -> io::Result<()> {
+        // read: ACGTACGTTT
+        let records = [
+            // The primary alignment is hard clipped, so the read is assembled from all records.
+            build_record(
+                Flags::default(),
+                &[
+                    Op::new(Kind::HardClip, 2),
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::HardClip, 4),
+                ],
+                b"GTAC",
+            ),
+            // AAACGTACGT (stored), where the first 2 bases are hard clipped
+            build_record(
+                Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED,
+                &[
+                    Op::new(Kind::HardClip, 2),
+                    Op::new(Kind::SoftClip, 5),
+                    Op::new(Kind::Match, 3),
+                ],
+                b"ACGTACGT",
+            ),
+            // AAACGTACGT (stored), where the last 6 bases are hard clipped
+            build_record(
+                Flags::SUPPLEMENTARY | Flags::REVERSE_COMPLEMENTED,
+                &[Op::new(Kind::Match, 4), Op::new(Kind::HardClip, 6)],
+                b"AAAC",
+            ),
+        ];
+
+        let read = reconstruct(&records)?;
+        assert_eq!(read.sequence(), b"ACGTACGTTT");
+        assert_eq!(read.intervals(), [2..6, 0..3, 6..10]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconstruct_with_missing_sequence() -> io::Result<()> {
+        let records = [
+            build_record(Flags::default(), &[Op::new(Kind::Match, 4)], b"ACGT"),
+            build_record(
+                Flags::SECONDARY | Flags::REVERSE_COMPLEMENTED,
+                &[Op::new(Kind::HardClip, 1), Op::new(Kind::Match, 3)],
+                b"",
+            ),
+        ];
+
+        let read = reconstruct(&records)?;
+        assert_eq!(read.sequence(), b"ACGT");
+        assert_eq!(read.intervals(), [0..4, 0..3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reconstruct_with_invalid_records() {
+        assert!(matches!(
+            reconstruct::<RecordBuf>(&[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let records = [build_record(Flags::UNMAPPED, &[], b"ACGT")];
+        assert!(matches!(
+            reconstruct(&records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // read length mismatch
+        let records = [
+            build_record(Flags::default(), &[Op::new(Kind::Match, 4)], b"ACGT"),
+            build_record(Flags::SUPPLEMENTARY, &[Op::new(Kind::Match, 3)], b"ACG"),
+        ];
+        assert!(matches!(
+            reconstruct(&records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // sequence length mismatch
+        let records = [build_record(
+            Flags::default(),
+            &[Op::new(Kind::Match, 4)],
+            b"ACG",
+        )];
+        assert!(matches!(
+            reconstruct(&records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // uncovered bases
+        let records = [build_record(
+            Flags::default(),
+            &[Op::new(Kind::HardClip, 2), Op::new(Kind::Match, 2)],
+            b"GT",
+        )];
+        assert!(matches!(
+            reconstruct(&records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,338 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use noodles_bam as bam;
+use noodles_bgzf::VirtualPosition;
+use noodles_core::region::Interval;
+use noodles_cram::{self as cram, crai};
+use noodles_csi::BinningIndex;
+use noodles_sam as sam;
+
+use super::io::IndexedReader;
+
+/// Verifies that the index of an indexed reader matches its data file.
+///
+/// This is a lightweight check for stale indices, e.g., when the data file was changed after it
+/// was indexed. It does not rebuild the index.
+///
+/// For SAM and BAM, the number of reference sequences in the index must be the number of
+/// reference sequences in the header. For each reference sequence with records, the reader seeks
+/// to the start of the first chunk, which must be a record on that reference sequence.
+///
+/// For CRAM, each reference sequence ID in the index must be in the header reference sequences.
+/// For each reference sequence, the reader seeks to the container of its first index record,
+/// which must be a container that has the record's slice.
+///
+/// An inconsistency is returned as an [`io::ErrorKind::InvalidData`] error that describes it. The
+/// stream position is changed, so seek or query before reading records afterward.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment::{self, io::indexed_reader};
+///
+/// let mut reader = indexed_reader::Builder::default().build_from_path("sample.bam")?;
+/// let header = reader.read_header()?;
+///
+/// alignment::verify_index(&mut reader, &header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn verify_index<R>(reader: &mut IndexedReader<R>, header: &sam::Header) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    match reader {
+        IndexedReader::Sam(reader) => {
+            let chunk_starts = first_chunk_starts(reader.index(), header)?;
+            let mut record = sam::Record::default();
+
+            for (reference_sequence_id, pos) in chunk_starts {
+                reader.get_mut().seek(pos)?;
+
+                let actual_reference_sequence_id = if reader.read_record(&mut record)? == 0 {
+                    None
+                } else {
+                    record.reference_sequence_id(header).transpose()?
+                };
+
+                verify_record_reference_sequence_id(
+                    reference_sequence_id,
+                    pos,
+                    actual_reference_sequence_id,
+                )?;
+            }
+
+            Ok(())
+        }
+        IndexedReader::Bam(reader) => {
+            let chunk_starts = first_chunk_starts(reader.index(), header)?;
+            let mut record = bam::Record::default();
+
+            for (reference_sequence_id, pos) in chunk_starts {
+                reader.get_mut().seek(pos)?;
+
+                let actual_reference_sequence_id = if reader.read_record(&mut record)? == 0 {
+                    None
+                } else {
+                    record.reference_sequence_id().transpose()?
+                };
+
+                verify_record_reference_sequence_id(
+                    reference_sequence_id,
+                    pos,
+                    actual_reference_sequence_id,
+                )?;
+            }
+
+            Ok(())
+        }
+        IndexedReader::Cram(reader) => {
+            let records = first_crai_records(reader.index(), header)?;
+            verify_crai_records(reader.get_mut(), &records)
+        }
+    }
+}
+
+// Returns the start of the first chunk of each reference sequence with records.
+fn first_chunk_starts(
+    index: &dyn BinningIndex,
+    header: &sam::Header,
+) -> io::Result<Vec<(usize, VirtualPosition)>> {
+    let reference_sequence_count = index.reference_sequences().count();
+    let expected = header.reference_sequences().len();
+
+    if reference_sequence_count != expected {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "index reference sequence count mismatch: expected {expected}, got {reference_sequence_count}"
+            ),
+        ));
+    }
+
+    let mut starts = Vec::new();
+
+    for reference_sequence_id in 0..reference_sequence_count {
+        let chunks = index.query(reference_sequence_id, Interval::from(..))?;
+
+        if let Some(chunk) = chunks.first() {
+            starts.push((reference_sequence_id, chunk.start()));
+        }
+    }
+
+    Ok(starts)
+}
+
+fn verify_record_reference_sequence_id(
+    expected: usize,
+    pos: VirtualPosition,
+    actual: Option<usize>,
+) -> io::Result<()> {
+    if actual == Some(expected) {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "index chunk start ({pos:?}) reference sequence ID mismatch: expected {expected}, got {actual:?}"
+            ),
+        ))
+    }
+}
+
+// Returns the first record of each reference sequence.
+fn first_crai_records(index: &crai::Index, header: &sam::Header) -> io::Result<Vec<crai::Record>> {
+    let reference_sequence_count = header.reference_sequences().len();
+    let mut records: Vec<crai::Record> = Vec::new();
+
+    for record in index {
+        if let Some(id) = record.reference_sequence_id() {
+            if id >= reference_sequence_count {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "index reference sequence ID out of range: expected < {reference_sequence_count}, got {id}"
+                    ),
+                ));
+            }
+        }
+
+        let is_new_reference_sequence = records
+            .last()
+            .map(|last| last.reference_sequence_id() != record.reference_sequence_id())
+            .unwrap_or(true);
+
+        if is_new_reference_sequence {
+            records.push(record.clone());
+        }
+    }
+
+    Ok(records)
+}
+
+fn verify_crai_records<R>(reader: &mut R, records: &[crai::Record]) -> io::Result<()>
+where
+    R: Read + Seek,
+{
+    for record in records {
+        reader.seek(SeekFrom::Start(record.offset()))?;
+
+        let container = cram::io::Reader::new(&mut *reader)
+            .read_raw_container()?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "index container offset ({}) does not point to a data container",
+                        record.offset()
+                    ),
+                )
+            })?;
+
+        let landmark = usize::try_from(record.landmark())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if !container.landmarks().contains(&landmark) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "index slice landmark ({landmark}) not in data container at offset ({})",
+                    record.offset()
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        },
+        header::record::value::{
+            map::{self, reference_sequence::tag, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    fn build_header(reference_sequence_count: usize) -> sam::Header {
+        let mut builder = sam::Header::builder().set_header(
+            Map::<map::Header>::builder()
+                .insert(map::header::tag::SORT_ORDER, "coordinate")
+                .build()
+                .unwrap(),
+        );
+
+        for i in 0..reference_sequence_count {
+            let mut reference_sequence =
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000).unwrap());
+
+            reference_sequence
+                .other_fields_mut()
+                .insert(tag::MD5_CHECKSUM, "d41d8cd98f00b204e9800998ecf8427e".into());
+
+            builder = builder.add_reference_sequence(format!("sq{i}"), reference_sequence);
+        }
+
+        builder.build()
+    }
+
+    fn write_records<W>(writer: &mut W, header: &sam::Header, starts: &[(usize, usize)])
+    where
+        W: Write,
+    {
+        writer.write_alignment_header(header).unwrap();
+
+        for &(reference_sequence_id, start) in starts {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::new(start).unwrap())
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .build();
+
+            writer.write_alignment_record(header, &record).unwrap();
+        }
+
+        writer.finish(header).unwrap();
+    }
+
+    fn write_bam(header: &sam::Header, starts: &[(usize, usize)]) -> Vec<u8> {
+        let mut writer = bam::io::Writer::new(Vec::new());
+        write_records(&mut writer, header, starts);
+        writer.into_inner().finish().unwrap()
+    }
+
+    fn is_invalid_data(result: io::Result<()>) -> bool {
+        matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidData)
+    }
+
+    #[test]
+    fn test_verify_index_with_bam() -> io::Result<()> {
+        let header = build_header(3);
+        let src = write_bam(&header, &[(0, 8), (0, 13), (2, 21)]);
+        let index = bam::index_from_reader(&src[..])?;
+
+        let mut reader =
+            IndexedReader::Bam(bam::io::IndexedReader::new(Cursor::new(src), index.clone()));
+        verify_index(&mut reader, &header)?;
+
+        assert!(is_invalid_data(verify_index(&mut reader, &build_header(2))));
+
+        // The data file changes after it is indexed.
+        let src = write_bam(&header, &[(2, 21)]);
+        let mut reader = IndexedReader::Bam(bam::io::IndexedReader::new(Cursor::new(src), index));
+        assert!(verify_index(&mut reader, &header).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_index_with_cram() -> io::Result<()> {
+        let header = build_header(3);
+
+        let mut writer = cram::io::writer::Builder::default()
+            .set_reference_required(false)
+            .build_with_writer(Vec::new());
+        write_records(&mut writer, &header, &[(0, 8), (0, 13), (2, 21)]);
+        let src = writer.get_ref().clone();
+
+        let index = cram::index_from_reader(Cursor::new(&src))?;
+
+        let mut reader = IndexedReader::Cram(cram::io::IndexedReader::new(
+            Cursor::new(src.clone()),
+            index.clone(),
+        ));
+        verify_index(&mut reader, &header)?;
+
+        assert!(is_invalid_data(verify_index(&mut reader, &build_header(2))));
+
+        let index = index
+            .into_iter()
+            .map(|record| {
+                crai::Record::new(
+                    record.reference_sequence_id(),
+                    record.alignment_start(),
+                    record.alignment_span(),
+                    record.offset(),
+                    record.landmark() + 1,
+                    record.slice_length(),
+                )
+            })
+            .collect();
+
+        let mut reader = IndexedReader::Cram(cram::io::IndexedReader::new(Cursor::new(src), index));
+        assert!(is_invalid_data(verify_index(&mut reader, &header)));
+
+        Ok(())
+    }
+}
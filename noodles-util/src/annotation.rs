@@ -0,0 +1,6 @@
+//! Annotation format utilities.
+
+mod convert;
+pub mod key_map;
+
+pub use self::{convert::gtf_to_gff, key_map::KeyMap};
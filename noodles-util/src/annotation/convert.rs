@@ -0,0 +1,194 @@
+use std::io;
+
+use noodles_gff::{
+    self as gff,
+    record::{attributes::field::Value, Phase},
+};
+use noodles_gtf as gtf;
+
+use super::KeyMap;
+
+/// Converts a GTF record to a GFF record.
+///
+/// The attribute keys are mapped using the given key map (see [`KeyMap`]). Values of keys that
+/// occur more than once, either in the source or after mapping, are collected into an array.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gtf as gtf;
+/// use noodles_util::annotation::{self, KeyMap};
+///
+/// let record = gtf::Record::builder()
+///     .set_type(String::from("transcript"))
+///     .set_attributes(gtf::record::Attributes::from(vec![
+///         gtf::record::attributes::Entry::new("gene_id", "g0"),
+///         gtf::record::attributes::Entry::new("transcript_id", "t0"),
+///     ]))
+///     .build();
+///
+/// let gff_record = annotation::gtf_to_gff(&record, &KeyMap::default())?;
+/// assert_eq!(
+///     gff_record.attributes().to_string(),
+///     "Parent=g0;gene_id=g0;ID=t0;transcript_id=t0"
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn gtf_to_gff(record: &gtf::Record, key_map: &KeyMap) -> io::Result<gff::Record> {
+    let mut builder = gff::Record::builder()
+        .set_reference_sequence_name(record.reference_sequence_name().into())
+        .set_source(record.source().into())
+        .set_type(record.ty().into())
+        .set_start(record.start())
+        .set_end(record.end())
+        .set_strand(convert_strand(record.strand()))
+        .set_attributes(convert_attributes(
+            record.ty(),
+            record.attributes(),
+            key_map,
+        ));
+
+    if let Some(score) = record.score() {
+        builder = builder.set_score(score);
+    }
+
+    if let Some(frame) = record.frame() {
+        builder = convert_frame(frame).map(|phase| builder.set_phase(phase))?;
+    }
+
+    Ok(builder.build())
+}
+
+fn convert_strand(strand: Option<gtf::record::Strand>) -> gff::record::Strand {
+    match strand {
+        None => gff::record::Strand::None,
+        Some(gtf::record::Strand::Forward) => gff::record::Strand::Forward,
+        Some(gtf::record::Strand::Reverse) => gff::record::Strand::Reverse,
+    }
+}
+
+fn convert_frame(frame: gtf::record::Frame) -> io::Result<Phase> {
+    match u8::from(frame) {
+        0 => Ok(Phase::Zero),
+        1 => Ok(Phase::One),
+        2 => Ok(Phase::Two),
+        n => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid frame: {n}"),
+        )),
+    }
+}
+
+fn convert_attributes(
+    ty: &str,
+    attributes: &gtf::record::Attributes,
+    key_map: &KeyMap,
+) -> gff::record::Attributes {
+    let mut gff_attributes = gff::record::Attributes::default();
+
+    for entry in attributes.iter() {
+        match key_map.get(ty, entry.key()) {
+            Some(targets) => {
+                for target in targets {
+                    insert_attribute(&mut gff_attributes, target, entry.value());
+                }
+            }
+            None => insert_attribute(&mut gff_attributes, entry.key(), entry.value()),
+        }
+    }
+
+    gff_attributes
+}
+
+fn insert_attribute(attributes: &mut gff::record::Attributes, key: &str, value: &str) {
+    attributes
+        .entry(key.into())
+        .and_modify(|v| v.extend([value.into()]))
+        .or_insert_with(|| Value::from(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use gtf::record::attributes::Entry;
+    use noodles_core::Position;
+
+    use super::*;
+
+    fn build_record(ty: &str, entries: Vec<Entry>) -> gtf::Record {
+        gtf::Record::builder()
+            .set_type(ty)
+            .set_attributes(gtf::record::Attributes::from(entries))
+            .build()
+    }
+
+    #[test]
+    fn test_gtf_to_gff() -> Result<(), Box<dyn std::error::Error>> {
+        let record = gtf::Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_source(String::from("NOODLES"))
+            .set_type(String::from("CDS"))
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_score(21.0)
+            .set_strand(gtf::record::Strand::Reverse)
+            .set_frame(gtf::record::Frame::try_from(1)?)
+            .set_attributes(gtf::record::Attributes::from(vec![
+                Entry::new("gene_id", "g0"),
+                Entry::new("transcript_id", "t0"),
+                Entry::new("tag", "basic"),
+                Entry::new("tag", "CCDS"),
+            ]))
+            .build();
+
+        let actual = gtf_to_gff(&record, &KeyMap::default())?;
+
+        let expected = gff::Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_source(String::from("NOODLES"))
+            .set_type(String::from("CDS"))
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_score(21.0)
+            .set_strand(gff::record::Strand::Reverse)
+            .set_phase(Phase::One)
+            .set_attributes(
+                [
+                    (String::from("gene_id"), Value::from("g0")),
+                    (String::from("Parent"), Value::from("t0")),
+                    (String::from("transcript_id"), Value::from("t0")),
+                    (
+                        String::from("tag"),
+                        Value::from(vec![String::from("basic"), String::from("CCDS")]),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gtf_to_gff_with_custom_key_map() -> io::Result<()> {
+        let mut key_map = KeyMap::empty();
+        key_map.insert("gene_name", ["Name"]);
+        key_map.insert("gene_version", Vec::<String>::new());
+
+        let record = build_record(
+            "gene",
+            vec![
+                Entry::new("gene_id", "g0"),
+                Entry::new("gene_version", "1"),
+                Entry::new("gene_name", "NDLS"),
+            ],
+        );
+
+        let gff_record = gtf_to_gff(&record, &key_map)?;
+        assert_eq!(gff_record.attributes().to_string(), "gene_id=g0;Name=NDLS");
+
+        Ok(())
+    }
+}
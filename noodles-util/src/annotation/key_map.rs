@@ -0,0 +1,241 @@
+//! Attribute key map.
+
+use std::collections::HashMap;
+
+const GENE_ID: &str = "gene_id";
+const TRANSCRIPT_ID: &str = "transcript_id";
+
+// Ensembl and GENCODE GTF feature types that are children of a transcript.
+const TRANSCRIPT_CHILD_TYPES: &[&str] = &[
+    "exon",
+    "CDS",
+    "UTR",
+    "five_prime_utr",
+    "three_prime_utr",
+    "start_codon",
+    "stop_codon",
+    "Selenocysteine",
+];
+
+/// An attribute key map.
+///
+/// This maps source attribute keys to target attribute keys when converting records between
+/// annotation formats. A mapping applies either to all feature types or to a single feature type,
+/// where the latter takes precedence. A source key can map to multiple target keys, in which case
+/// its value is copied to each target key, or to none, in which case it is dropped. Keys without a
+/// mapping pass through unchanged.
+///
+/// # Default
+///
+/// The default key map follows Ensembl and GENCODE conventions for converting GTF to GFF3, which
+/// define the gene-transcript-exon hierarchy using `ID` and `Parent` while keeping the original
+/// GTF keys:
+///
+/// | feature type                          | source key      | target keys                |
+/// |---------------------------------------|-----------------|----------------------------|
+/// | `gene`                                | `gene_id`       | `ID`, `gene_id`            |
+/// | `transcript`                          | `transcript_id` | `ID`, `transcript_id`      |
+/// | `transcript`                          | `gene_id`       | `Parent`, `gene_id`        |
+/// | `exon`, `CDS`, `UTR`, etc.[^children] | `transcript_id` | `Parent`, `transcript_id`  |
+///
+/// [^children]: `exon`, `CDS`, `UTR`, `five_prime_utr`, `three_prime_utr`, `start_codon`,
+/// `stop_codon`, and `Selenocysteine`.
+///
+/// Only keys are mapped; values are copied verbatim. This matches GENCODE GFF3 IDs. (Ensembl GFF3
+/// additionally prefixes IDs with the feature type, e.g., `gene:ENSG00000223972`.)
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::annotation::KeyMap;
+///
+/// let key_map = KeyMap::default();
+/// assert_eq!(
+///     key_map.get("transcript", "gene_id"),
+///     Some(&[String::from("Parent"), String::from("gene_id")][..])
+/// );
+/// assert!(key_map.get("gene", "gene_name").is_none());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeyMap {
+    keys: HashMap<String, Vec<String>>,
+    typed_keys: HashMap<(String, String), Vec<String>>,
+}
+
+impl KeyMap {
+    /// Creates an empty key map.
+    ///
+    /// All keys pass through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::annotation::KeyMap;
+    /// let key_map = KeyMap::empty();
+    /// assert!(key_map.get("gene", "gene_id").is_none());
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            keys: HashMap::new(),
+            typed_keys: HashMap::new(),
+        }
+    }
+
+    /// Returns the target keys of a source key for the given feature type.
+    ///
+    /// A mapping for the feature type takes precedence over one for all feature types. This
+    /// returns `None` if the key has no mapping, i.e., it passes through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::annotation::KeyMap;
+    ///
+    /// let mut key_map = KeyMap::empty();
+    /// key_map.insert("gene_name", ["Name"]);
+    /// key_map.insert_for_type("gene", "gene_name", ["Name", "gene_name"]);
+    ///
+    /// assert_eq!(key_map.get("exon", "gene_name"), Some(&[String::from("Name")][..]));
+    /// assert_eq!(
+    ///     key_map.get("gene", "gene_name"),
+    ///     Some(&[String::from("Name"), String::from("gene_name")][..])
+    /// );
+    /// ```
+    pub fn get(&self, ty: &str, key: &str) -> Option<&[String]> {
+        self.typed_keys
+            .get(&(ty.into(), key.into()))
+            .or_else(|| self.keys.get(key))
+            .map(|targets| &targets[..])
+    }
+
+    /// Maps a source key to target keys for all feature types.
+    ///
+    /// This replaces any existing mapping of the source key for all feature types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::annotation::KeyMap;
+    ///
+    /// let mut key_map = KeyMap::empty();
+    /// key_map.insert("gene_name", ["Name"]);
+    ///
+    /// assert_eq!(key_map.get("gene", "gene_name"), Some(&[String::from("Name")][..]));
+    /// ```
+    pub fn insert<K, I, V>(&mut self, key: K, targets: I)
+    where
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let targets = targets.into_iter().map(|target| target.into()).collect();
+        self.keys.insert(key.into(), targets);
+    }
+
+    /// Maps a source key to target keys for a feature type.
+    ///
+    /// This replaces any existing mapping of the source key for the feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::annotation::KeyMap;
+    ///
+    /// let mut key_map = KeyMap::empty();
+    /// key_map.insert_for_type("gene", "gene_id", ["ID"]);
+    ///
+    /// assert_eq!(key_map.get("gene", "gene_id"), Some(&[String::from("ID")][..]));
+    /// assert!(key_map.get("exon", "gene_id").is_none());
+    /// ```
+    pub fn insert_for_type<T, K, I, V>(&mut self, ty: T, key: K, targets: I)
+    where
+        T: Into<String>,
+        K: Into<String>,
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let targets = targets.into_iter().map(|target| target.into()).collect();
+        self.typed_keys.insert((ty.into(), key.into()), targets);
+    }
+
+    /// Removes all mappings of a source key.
+    ///
+    /// The key then passes through unchanged for all feature types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::annotation::KeyMap;
+    ///
+    /// let mut key_map = KeyMap::default();
+    /// key_map.remove("gene_id");
+    /// assert!(key_map.get("gene", "gene_id").is_none());
+    /// ```
+    pub fn remove(&mut self, key: &str) {
+        self.keys.remove(key);
+        self.typed_keys.retain(|(_, k), _| k != key);
+    }
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        use noodles_gff::record::attributes::field::tag;
+
+        let mut key_map = Self::empty();
+
+        key_map.insert_for_type("gene", GENE_ID, [tag::ID, GENE_ID]);
+
+        key_map.insert_for_type("transcript", TRANSCRIPT_ID, [tag::ID, TRANSCRIPT_ID]);
+        key_map.insert_for_type("transcript", GENE_ID, [tag::PARENT, GENE_ID]);
+
+        for ty in TRANSCRIPT_CHILD_TYPES {
+            key_map.insert_for_type(*ty, TRANSCRIPT_ID, [tag::PARENT, TRANSCRIPT_ID]);
+        }
+
+        key_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let key_map = KeyMap::default();
+
+        assert_eq!(
+            key_map.get("gene", GENE_ID),
+            Some(&[String::from("ID"), String::from(GENE_ID)][..])
+        );
+        assert!(key_map.get("gene", TRANSCRIPT_ID).is_none());
+
+        assert_eq!(
+            key_map.get("transcript", TRANSCRIPT_ID),
+            Some(&[String::from("ID"), String::from(TRANSCRIPT_ID)][..])
+        );
+
+        assert_eq!(
+            key_map.get("CDS", TRANSCRIPT_ID),
+            Some(&[String::from("Parent"), String::from(TRANSCRIPT_ID)][..])
+        );
+        assert!(key_map.get("CDS", GENE_ID).is_none());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut key_map = KeyMap::empty();
+        key_map.insert(GENE_ID, ["g"]);
+        key_map.insert_for_type("gene", GENE_ID, ["ID"]);
+        key_map.insert_for_type("gene", TRANSCRIPT_ID, ["t"]);
+
+        key_map.remove(GENE_ID);
+
+        assert!(key_map.get("gene", GENE_ID).is_none());
+        assert!(key_map.get("exon", GENE_ID).is_none());
+        assert_eq!(
+            key_map.get("gene", TRANSCRIPT_ID),
+            Some(&[String::from("t")][..])
+        );
+    }
+}
@@ -5,5 +5,8 @@
 #[cfg(feature = "alignment")]
 pub mod alignment;
 
+#[cfg(feature = "annotation")]
+pub mod annotation;
+
 #[cfg(feature = "variant")]
 pub mod variant;
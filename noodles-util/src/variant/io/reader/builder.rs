@@ -127,17 +127,9 @@ pub(crate) fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<
 where
     R: BufRead,
 {
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-
-    let src = reader.fill_buf()?;
-
-    if let Some(buf) = src.get(..GZIP_MAGIC_NUMBER.len()) {
-        if buf == GZIP_MAGIC_NUMBER {
-            return Ok(Some(CompressionMethod::Bgzf));
-        }
-    }
-
-    Ok(None)
+    // Any gzip stream is read as BGZF.
+    bgzf::io::detect_compression_method(reader)
+        .map(|method| method.map(|_| CompressionMethod::Bgzf))
 }
 
 pub(crate) fn detect_format<R>(